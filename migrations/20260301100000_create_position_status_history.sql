@@ -0,0 +1,19 @@
+CREATE TABLE position_status_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid (),
+    position_id UUID NOT NULL,
+    from_status VARCHAR(255) NOT NULL,
+    to_status VARCHAR(255) NOT NULL,
+    automatic BOOLEAN NOT NULL DEFAULT FALSE,
+    changed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (position_id) REFERENCES positions (id) ON DELETE CASCADE
+);
+
+CREATE INDEX position_status_history_position_id_idx ON position_status_history (position_id);
+
+CREATE TABLE staleness_settings (
+    user_id UUID PRIMARY KEY,
+    stale_after_days INTEGER NOT NULL,
+    auto_ghost BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
@@ -0,0 +1,18 @@
+CREATE TABLE digest_subscriptions (
+    user_id UUID PRIMARY KEY,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- One row per user and digest week guarantees a single digest per week,
+-- no matter how many times or from how many instances the scheduler runs.
+CREATE TABLE weekly_digests (
+    user_id UUID NOT NULL,
+    week_start DATE NOT NULL,
+    email_queue_id UUID NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, week_start),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
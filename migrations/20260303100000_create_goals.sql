@@ -0,0 +1,25 @@
+CREATE TABLE goals (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    metric VARCHAR(64) NOT NULL,
+    period VARCHAR(64) NOT NULL,
+    target INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX goals_user_id_idx ON goals (user_id);
+
+-- Manually logged occurrences for goals not derived from positions,
+-- such as networking contacts.
+CREATE TABLE goal_events (
+    id UUID PRIMARY KEY,
+    goal_id UUID NOT NULL,
+    occurred_on DATE NOT NULL,
+    note TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (goal_id) REFERENCES goals (id) ON DELETE CASCADE
+);
+
+CREATE INDEX goal_events_goal_id_idx ON goal_events (goal_id, occurred_on);
@@ -0,0 +1,8 @@
+CREATE TABLE verification_email_sends (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    sent_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX verification_email_sends_user_id_sent_at_idx ON verification_email_sends (user_id, sent_at);
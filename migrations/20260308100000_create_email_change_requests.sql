@@ -0,0 +1,12 @@
+CREATE TABLE email_change_requests (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    new_email TEXT NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    used_at TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX email_change_requests_user_id_idx ON email_change_requests (user_id);
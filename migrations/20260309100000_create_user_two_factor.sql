@@ -0,0 +1,9 @@
+CREATE TABLE user_two_factor (
+    user_id UUID PRIMARY KEY,
+    secret BYTEA NOT NULL,
+    enabled_at TIMESTAMP,
+    last_used_step BIGINT,
+    recovery_code_hashes TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
@@ -0,0 +1,37 @@
+-- Everything a user owns goes with them when their account is purged.
+ALTER TABLE positions
+    DROP CONSTRAINT positions_user_id_fkey,
+    ADD CONSTRAINT positions_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE;
+
+ALTER TABLE comments
+    DROP CONSTRAINT comments_user_id_fkey,
+    ADD CONSTRAINT comments_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE;
+
+-- The queues had no foreign keys; drop the rows already left behind.
+DELETE FROM scraper_queue q
+WHERE NOT EXISTS (SELECT 1 FROM users u WHERE u.id = q.user_id)
+    OR NOT EXISTS (SELECT 1 FROM positions p WHERE p.id = q.position_id);
+
+ALTER TABLE scraper_queue
+    ADD CONSTRAINT scraper_queue_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    ADD CONSTRAINT scraper_queue_position_id_fkey FOREIGN KEY (position_id) REFERENCES positions (id) ON DELETE CASCADE;
+
+CREATE INDEX scraper_queue_user_id_idx ON scraper_queue (user_id);
+CREATE INDEX scraper_queue_position_id_idx ON scraper_queue (position_id);
+
+DELETE FROM email_queue q
+WHERE q.user_id IS NOT NULL
+    AND NOT EXISTS (SELECT 1 FROM users u WHERE u.id = q.user_id);
+
+ALTER TABLE email_queue
+    ADD CONSTRAINT email_queue_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE;
+
+-- Self-service deletions waiting for their grace period to end.
+CREATE TABLE account_deletions (
+    user_id UUID PRIMARY KEY,
+    requested_at TIMESTAMP NOT NULL,
+    purge_after TIMESTAMP NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX account_deletions_purge_after_idx ON account_deletions (purge_after);
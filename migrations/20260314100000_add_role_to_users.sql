@@ -0,0 +1,3 @@
+-- Admins are promoted by hand: UPDATE users SET role = 'admin' WHERE email = '...';
+ALTER TABLE users
+ADD COLUMN role VARCHAR(16) NOT NULL DEFAULT 'user';
@@ -0,0 +1,38 @@
+-- Append-only log of security-relevant events. actor_id has no foreign key so
+-- an admin's actions stay on record after the admin account is gone.
+CREATE TABLE audit_events (
+    id UUID PRIMARY KEY,
+    kind VARCHAR(32) NOT NULL,
+    user_id UUID REFERENCES users (id) ON DELETE CASCADE,
+    actor_id UUID,
+    detail TEXT,
+    ip VARCHAR(64),
+    user_agent TEXT,
+    request_id VARCHAR(64),
+    trace_id VARCHAR(32),
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX audit_events_user_id_occurred_at_idx ON audit_events (user_id, occurred_at DESC);
+CREATE INDEX audit_events_occurred_at_idx ON audit_events (occurred_at DESC);
+
+-- Rows are never changed. The only deletes allowed are the cascade from
+-- purging the user they belong to.
+CREATE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE'
+        AND OLD.user_id IS NOT NULL
+        AND NOT EXISTS (SELECT 1 FROM users WHERE id = OLD.user_id) THEN
+        RETURN OLD;
+    END IF;
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_events_no_update_or_delete
+    BEFORE UPDATE OR DELETE ON audit_events
+    FOR EACH ROW EXECUTE FUNCTION audit_events_append_only();
+
+CREATE TRIGGER audit_events_no_truncate
+    BEFORE TRUNCATE ON audit_events
+    FOR EACH STATEMENT EXECUTE FUNCTION audit_events_append_only();
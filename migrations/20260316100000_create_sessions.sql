@@ -0,0 +1,23 @@
+-- One row per signed-in device. The id is the family_id of its refresh tokens.
+CREATE TABLE sessions (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    device VARCHAR(64) NOT NULL,
+    user_agent TEXT,
+    ip VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX sessions_user_id_idx ON sessions (user_id);
+
+-- Refresh token families still in use become sessions of an unknown device,
+-- so signed-in users are not logged out by the upgrade.
+INSERT INTO sessions (id, user_id, device, created_at, last_seen_at, expires_at)
+SELECT family_id, user_id, 'Unknown device', MIN(created_at), MAX(COALESCE(used_at, created_at)), MAX(expires_at)
+FROM refresh_tokens
+GROUP BY family_id, user_id
+HAVING BOOL_AND(revoked_at IS NULL) AND MAX(expires_at) > CURRENT_TIMESTAMP;
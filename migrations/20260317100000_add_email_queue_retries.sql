@@ -0,0 +1,12 @@
+-- Failed sends are retried with backoff until the worker gives up and marks
+-- the email dead.
+ALTER TABLE email_queue
+    ADD COLUMN IF NOT EXISTS attempt_count INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ADD COLUMN IF NOT EXISTS last_error TEXT,
+    ADD COLUMN IF NOT EXISTS dead BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS dead_at TIMESTAMPTZ;
+
+-- What the worker's poller scans for.
+CREATE INDEX IF NOT EXISTS email_queue_due_idx ON email_queue (next_attempt_at)
+    WHERE processed IS NOT TRUE AND NOT dead;
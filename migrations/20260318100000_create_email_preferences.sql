@@ -0,0 +1,21 @@
+-- Categories the user changed; the others keep their default. Security
+-- emails are always sent and never stored here.
+CREATE TABLE notification_preferences (
+    user_id UUID NOT NULL,
+    category VARCHAR(32) NOT NULL CHECK (category IN ('reminders', 'digests', 'marketing')),
+    enabled BOOLEAN NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, category),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- Addresses nothing but security emails is sent to, e.g. after hard bounces
+-- or spam complaints. Stored lowercased.
+CREATE TABLE email_suppressions (
+    email VARCHAR(255) PRIMARY KEY,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Set instead of sending when the recipient opted out or is suppressed.
+ALTER TABLE email_queue ADD COLUMN IF NOT EXISTS skipped_reason VARCHAR(32);
@@ -0,0 +1,6 @@
+-- Names what an email is for, so the same email is queued at most once
+-- however often its sender retries. Rows queued before keys existed have none.
+ALTER TABLE email_queue
+    ADD COLUMN IF NOT EXISTS idempotency_key VARCHAR(255);
+
+CREATE UNIQUE INDEX IF NOT EXISTS email_queue_idempotency_key_idx ON email_queue (idempotency_key);
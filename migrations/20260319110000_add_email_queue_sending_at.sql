@@ -0,0 +1,5 @@
+-- Set just before an email is handed to the sender and cleared when the send
+-- fails. A job claimed again with it still set may already have been
+-- delivered, so the worker does not send it again.
+ALTER TABLE email_queue
+    ADD COLUMN IF NOT EXISTS sending_at TIMESTAMPTZ;
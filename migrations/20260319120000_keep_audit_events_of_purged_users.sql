@@ -0,0 +1,9 @@
+-- Audit events outlive the user they are about, as they do the admin who
+-- acted: user_id loses its foreign key and the trigger allows no deletes.
+ALTER TABLE audit_events DROP CONSTRAINT IF EXISTS audit_events_user_id_fkey;
+
+CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,19 @@
+-- Purging a user anonymises their audit events in the same transaction:
+-- once the user row is gone, the only change allowed is clearing user_id, ip
+-- and user_agent. Everything else stays append-only.
+CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'UPDATE'
+        AND OLD.user_id IS NOT NULL
+        AND NOT EXISTS (SELECT 1 FROM users WHERE id = OLD.user_id)
+        AND NEW.user_id IS NULL
+        AND NEW.ip IS NULL
+        AND NEW.user_agent IS NULL
+        AND (NEW.id, NEW.kind, NEW.actor_id, NEW.detail, NEW.request_id, NEW.trace_id, NEW.occurred_at)
+            IS NOT DISTINCT FROM
+            (OLD.id, OLD.kind, OLD.actor_id, OLD.detail, OLD.request_id, OLD.trace_id, OLD.occurred_at) THEN
+        RETURN NEW;
+    END IF;
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
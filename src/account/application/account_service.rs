@@ -0,0 +1,352 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use tracing::{error, info};
+
+use crate::{
+    account::{
+        application::errors::AccountServiceError,
+        domain::{
+            entities::{
+                account_deletion::{AccountDeletion, AccountEmail},
+                account_export::AccountExport,
+                notification_preferences::NotificationPreferences,
+                unsubscribe_token::UnsubscribeToken,
+            },
+            repositories::account_repository::IAccountRepository,
+        },
+    },
+    shared::{
+        domain::{
+            audit::{AuditEvent, AuditQuery, IAuditLog},
+            value_objects::UserUuid,
+        },
+        infrastructure::observability::current_trace_context,
+    },
+};
+
+pub struct AccountSettings {
+    pub frontend_url: String,
+    pub deletion_grace_period_secs: i64,
+    /// Shared with the email worker, which signs the unsubscribe links.
+    pub unsubscribe_secret: String,
+}
+
+pub struct AccountService {
+    repo: Box<dyn IAccountRepository>,
+    audit_log: Arc<dyn IAuditLog>,
+    settings: AccountSettings,
+}
+
+impl AccountService {
+    pub fn new(
+        repo: Box<dyn IAccountRepository>,
+        audit_log: Arc<dyn IAuditLog>,
+        settings: AccountSettings,
+    ) -> Self {
+        Self {
+            repo,
+            audit_log,
+            settings,
+        }
+    }
+
+    pub async fn export(&self, user_id: UserUuid) -> Result<AccountExport, AccountServiceError> {
+        let export = self
+            .repo
+            .export(user_id, Utc::now())
+            .await?
+            .ok_or(AccountServiceError::AccountNotFound)?;
+        info!(user_id = %user_id, positions = export.positions.len(), "Account data exported");
+        Ok(export)
+    }
+
+    /// Sign-ins, password changes and the like on the user's own account,
+    /// newest first.
+    pub async fn security_events(
+        &self,
+        user_id: UserUuid,
+        before: Option<DateTime<Utc>>,
+        limit: Option<i64>,
+    ) -> Result<Vec<AuditEvent>, AccountServiceError> {
+        Ok(self
+            .audit_log
+            .search(&AuditQuery::new(Some(user_id), None, before, limit))
+            .await?)
+    }
+
+    /// Schedules the account for deletion once the grace period ends. Asking
+    /// again while one is pending returns it unchanged.
+    pub async fn request_deletion(
+        &self,
+        user_id: UserUuid,
+        password: &str,
+    ) -> Result<AccountDeletion, AccountServiceError> {
+        let holder = self
+            .repo
+            .get_holder(user_id)
+            .await?
+            .ok_or(AccountServiceError::AccountNotFound)?;
+        if !holder.password.verify(password)? {
+            return Err(AccountServiceError::WrongPassword);
+        }
+        if let Some(pending) = self.repo.get_deletion(user_id).await? {
+            return Ok(pending);
+        }
+
+        let deletion = AccountDeletion::schedule(
+            user_id,
+            TimeDelta::seconds(self.settings.deletion_grace_period_secs),
+            Utc::now(),
+        );
+        let notice = AccountEmail::DeletionScheduled {
+            purge_after: deletion.purge_after,
+            cancel_link: format!("{}/account", self.settings.frontend_url),
+        };
+        self.repo
+            .schedule_deletion(&deletion, &holder.email, notice, current_trace_context())
+            .await?;
+        info!(user_id = %user_id, purge_after = %deletion.purge_after, "Account deletion scheduled");
+        Ok(deletion)
+    }
+
+    pub async fn get_deletion(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<AccountDeletion, AccountServiceError> {
+        self.repo
+            .get_deletion(user_id)
+            .await?
+            .ok_or(AccountServiceError::DeletionNotScheduled)
+    }
+
+    pub async fn cancel_deletion(&self, user_id: UserUuid) -> Result<(), AccountServiceError> {
+        if !self.repo.cancel_deletion(user_id).await? {
+            return Err(AccountServiceError::DeletionNotScheduled);
+        }
+        info!(user_id = %user_id, "Account deletion cancelled");
+        Ok(())
+    }
+
+    /// Erases every account whose grace period is over. Safe to run as often
+    /// as needed and from several instances.
+    pub async fn purge_due_accounts(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, AccountServiceError> {
+        let mut purged = 0;
+        for user_id in self.repo.get_due_deletions(now).await? {
+            match self.repo.purge(user_id, now, AccountEmail::Deleted).await {
+                Ok(true) => {
+                    purged += 1;
+                    info!(user_id = %user_id, "Account purged");
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    error!(user_id = %user_id, error = %e, "account_service.purge_due_accounts failed");
+                }
+            }
+        }
+        Ok(purged)
+    }
+
+    pub async fn get_notification_preferences(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<NotificationPreferences, AccountServiceError> {
+        self.repo
+            .get_notification_preferences(user_id)
+            .await?
+            .ok_or(AccountServiceError::AccountNotFound)
+    }
+
+    pub async fn update_notification_preferences(
+        &self,
+        preferences: NotificationPreferences,
+    ) -> Result<NotificationPreferences, AccountServiceError> {
+        if !self
+            .repo
+            .save_notification_preferences(&preferences)
+            .await?
+        {
+            return Err(AccountServiceError::AccountNotFound);
+        }
+        info!(user_id = %preferences.user_id, "Notification preferences updated");
+        Ok(preferences)
+    }
+
+    /// Turns off the category of a one-click unsubscribe link. Links of
+    /// deleted accounts are accepted and do nothing.
+    pub async fn unsubscribe(&self, token: &str) -> Result<(), AccountServiceError> {
+        let token = UnsubscribeToken::verify(token, &self.settings.unsubscribe_secret)
+            .ok_or(AccountServiceError::InvalidUnsubscribeToken)?;
+        let Some(mut preferences) = self
+            .repo
+            .get_notification_preferences(token.user_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        preferences.set(token.category, false);
+        self.repo
+            .save_notification_preferences(&preferences)
+            .await?;
+        info!(user_id = %token.user_id, category = %token.category, "Unsubscribed from emails");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::infrastructure::persistence::repositories::account_in_memory_repository::AccountInMemoryRepository,
+        shared::{
+            domain::email::EmailCategory, fixtures::valid_password,
+            infrastructure::audit::audit_in_memory_log::AuditInMemoryLog,
+        },
+    };
+
+    fn create_service(repo: AccountInMemoryRepository) -> AccountService {
+        AccountService::new(
+            Box::new(repo),
+            Arc::new(AuditInMemoryLog::new()),
+            AccountSettings {
+                frontend_url: "http://localhost:3001".to_string(),
+                deletion_grace_period_secs: 3600,
+                unsubscribe_secret: "unsubscribe-secret".to_string(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_request_deletion_needs_the_password() {
+        let repo = AccountInMemoryRepository::default();
+        let user_id = UserUuid::new();
+        repo.add_user(user_id, "leaving@example.com", valid_password())
+            .await;
+        let service = create_service(repo.clone());
+
+        let wrong = service.request_deletion(user_id, "not-my-password").await;
+        let unknown = service
+            .request_deletion(UserUuid::new(), valid_password())
+            .await;
+
+        assert_eq!(wrong, Err(AccountServiceError::WrongPassword));
+        assert_eq!(unknown, Err(AccountServiceError::AccountNotFound));
+        assert!(repo.sent().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_request_deletion_is_idempotent_and_notifies_once() {
+        let repo = AccountInMemoryRepository::default();
+        let user_id = UserUuid::new();
+        repo.add_user(user_id, "leaving@example.com", valid_password())
+            .await;
+        let service = create_service(repo.clone());
+
+        let first = service
+            .request_deletion(user_id, valid_password())
+            .await
+            .unwrap();
+        let second = service
+            .request_deletion(user_id, valid_password())
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.purge_after - first.requested_at, TimeDelta::hours(1));
+        assert_eq!(service.get_deletion(user_id).await.unwrap(), first);
+        let sent = repo.sent().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, "leaving@example.com");
+        assert_eq!(
+            sent[0].1,
+            AccountEmail::DeletionScheduled {
+                purge_after: first.purge_after,
+                cancel_link: "http://localhost:3001/account".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_only_erases_due_and_not_cancelled_accounts() {
+        let repo = AccountInMemoryRepository::default();
+        let leaving = UserUuid::new();
+        let staying = UserUuid::new();
+        let bystander = UserUuid::new();
+        for (user_id, email) in [
+            (leaving, "leaving@example.com"),
+            (staying, "staying@example.com"),
+            (bystander, "bystander@example.com"),
+        ] {
+            repo.add_user(user_id, email, valid_password()).await;
+        }
+        let service = create_service(repo.clone());
+        let deletion = service
+            .request_deletion(leaving, valid_password())
+            .await
+            .unwrap();
+        service
+            .request_deletion(staying, valid_password())
+            .await
+            .unwrap();
+        service.cancel_deletion(staying).await.unwrap();
+
+        let early = service
+            .purge_due_accounts(deletion.purge_after - TimeDelta::minutes(1))
+            .await
+            .unwrap();
+        let due = service
+            .purge_due_accounts(deletion.purge_after)
+            .await
+            .unwrap();
+
+        assert_eq!(early, 0);
+        assert_eq!(due, 1);
+        assert!(!repo.has_user(leaving).await);
+        assert!(repo.has_user(staying).await);
+        assert!(repo.has_user(bystander).await);
+        assert_eq!(
+            service.cancel_deletion(staying).await,
+            Err(AccountServiceError::DeletionNotScheduled)
+        );
+        let sent = repo.sent().await;
+        assert_eq!(sent.last().unwrap().0, "leaving@example.com");
+        assert_eq!(sent.last().unwrap().1, AccountEmail::Deleted);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_turns_off_the_category_of_the_link() {
+        let repo = AccountInMemoryRepository::default();
+        let user_id = UserUuid::new();
+        repo.add_user(user_id, "reader@example.com", valid_password())
+            .await;
+        let service = create_service(repo);
+        let token = UnsubscribeToken {
+            user_id,
+            category: EmailCategory::Digests,
+        };
+
+        service
+            .unsubscribe(&token.sign("unsubscribe-secret"))
+            .await
+            .unwrap();
+        let forged = service.unsubscribe(&token.sign("guessed-secret")).await;
+        let deleted_account = service
+            .unsubscribe(
+                &UnsubscribeToken {
+                    user_id: UserUuid::new(),
+                    ..token
+                }
+                .sign("unsubscribe-secret"),
+            )
+            .await;
+
+        let preferences = service.get_notification_preferences(user_id).await.unwrap();
+        assert!(!preferences.digests);
+        assert!(preferences.reminders);
+        assert_eq!(forged, Err(AccountServiceError::InvalidUnsubscribeToken));
+        assert_eq!(deleted_account, Ok(()));
+    }
+}
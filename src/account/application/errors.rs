@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+use crate::{account::domain::errors::AccountRepoError, shared::domain::errors::SharedDomainError};
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum AccountServiceError {
+    #[error("Repository error: `{0}`")]
+    RepositoryError(#[from] AccountRepoError),
+
+    #[error("Account not found")]
+    AccountNotFound,
+
+    #[error("Wrong password")]
+    WrongPassword,
+
+    #[error("No account deletion is scheduled")]
+    DeletionNotScheduled,
+
+    #[error("Invalid unsubscribe link")]
+    InvalidUnsubscribeToken,
+
+    #[error("Internal error: `{0}`")]
+    InternalError(String),
+}
+
+impl From<SharedDomainError> for AccountServiceError {
+    fn from(e: SharedDomainError) -> Self {
+        AccountServiceError::InternalError(e.to_string())
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::shared::domain::{
+    email::{EmailCategory, IdempotencyKey},
+    value_objects::{UserPassword, UserUuid},
+};
+
+/// A self-service deletion waiting for its grace period to end. Until
+/// `purge_after` the account keeps working and the user can cancel it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountDeletion {
+    pub user_id: UserUuid,
+    pub requested_at: DateTime<Utc>,
+    pub purge_after: DateTime<Utc>,
+}
+
+impl AccountDeletion {
+    pub fn schedule(user_id: UserUuid, grace_period: TimeDelta, now: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            requested_at: now,
+            purge_after: now + grace_period,
+        }
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        now >= self.purge_after
+    }
+}
+
+/// What is needed to confirm a deletion and tell the user about it.
+#[derive(Debug, Clone)]
+pub struct AccountHolder {
+    pub user_id: UserUuid,
+    pub email: String,
+    pub password: UserPassword,
+}
+
+/// Emails about a deletion. Each names a template of the email worker.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccountEmail {
+    DeletionScheduled {
+        purge_after: DateTime<Utc>,
+        cancel_link: String,
+    },
+    Deleted,
+}
+
+impl AccountEmail {
+    pub const CATEGORY: EmailCategory = EmailCategory::Security;
+
+    pub fn template(&self) -> &'static str {
+        match self {
+            AccountEmail::DeletionScheduled { .. } => "account_deletion_scheduled",
+            AccountEmail::Deleted => "account_deleted",
+        }
+    }
+
+    /// A deletion is told apart by when it was due to be purged; an account
+    /// is deleted only once.
+    pub fn idempotency_key(&self, user_id: UserUuid) -> IdempotencyKey {
+        let user_id = user_id.value().to_string();
+        match self {
+            AccountEmail::DeletionScheduled { purge_after, .. } => IdempotencyKey::new(
+                self.template(),
+                &[&user_id, &purge_after.timestamp().to_string()],
+            ),
+            AccountEmail::Deleted => IdempotencyKey::new(self.template(), &[&user_id]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deletion_is_due_once_the_grace_period_ends() {
+        let now = Utc::now();
+        let deletion = AccountDeletion::schedule(UserUuid::new(), TimeDelta::hours(72), now);
+
+        assert_eq!(deletion.requested_at, now);
+        assert!(!deletion.is_due(now));
+        assert!(!deletion.is_due(now + TimeDelta::hours(71)));
+        assert!(deletion.is_due(now + TimeDelta::hours(72)));
+    }
+}
@@ -0,0 +1,113 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::shared::domain::value_objects::UserUuid;
+
+/// Everything stored about a user, as handed out by the data export.
+/// Secrets (password, token and recovery code hashes, TOTP seed) are left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountExport {
+    pub exported_at: DateTime<Utc>,
+    pub profile: ExportedProfile,
+    pub positions: Vec<ExportedPosition>,
+    pub goals: Vec<ExportedGoal>,
+    pub settings: ExportedSettings,
+    pub weekly_digests_sent: Vec<NaiveDate>,
+    pub scraper_jobs: Vec<ExportedScraperJob>,
+    pub personal_access_tokens: Vec<ExportedAccessToken>,
+    pub identities: Vec<ExportedIdentity>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedProfile {
+    pub id: UserUuid,
+    pub email: String,
+    pub email_verified: bool,
+    pub two_factor_enabled: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Soft-deleted positions are included: they are still stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedPosition {
+    pub id: uuid::Uuid,
+    pub company: String,
+    pub role_title: String,
+    pub description: String,
+    pub applied_on: NaiveDate,
+    pub url: String,
+    pub status: String,
+    pub deleted: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub comments: Vec<ExportedComment>,
+    pub status_history: Vec<ExportedStatusChange>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedComment {
+    pub id: uuid::Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedStatusChange {
+    pub from_status: String,
+    pub to_status: String,
+    pub automatic: bool,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedGoal {
+    pub id: uuid::Uuid,
+    pub metric: String,
+    pub period: String,
+    pub target: i32,
+    pub created_at: DateTime<Utc>,
+    pub events: Vec<ExportedGoalEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedGoalEvent {
+    pub occurred_on: NaiveDate,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExportedSettings {
+    pub stale_after_days: Option<i32>,
+    pub auto_ghost: bool,
+    pub weekly_digest: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedScraperJob {
+    pub position_id: uuid::Uuid,
+    pub url: String,
+    pub status: Option<String>,
+    pub result_key: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedAccessToken {
+    pub name: String,
+    pub scope: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedIdentity {
+    pub provider: String,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: DateTime<Utc>,
+}
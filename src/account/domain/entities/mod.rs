@@ -0,0 +1,4 @@
+pub mod account_deletion;
+pub mod account_export;
+pub mod notification_preferences;
+pub mod unsubscribe_token;
@@ -0,0 +1,87 @@
+use crate::shared::domain::{email::EmailCategory, value_objects::UserUuid};
+
+/// Which emails the user wants, by category. Security emails are always sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationPreferences {
+    pub user_id: UserUuid,
+    pub reminders: bool,
+    pub digests: bool,
+    pub marketing: bool,
+}
+
+impl NotificationPreferences {
+    /// Categories missing from `stored` keep their default.
+    pub fn from_stored(
+        user_id: UserUuid,
+        stored: impl IntoIterator<Item = (EmailCategory, bool)>,
+    ) -> Self {
+        let mut preferences = Self {
+            user_id,
+            reminders: EmailCategory::Reminders.enabled_by_default(),
+            digests: EmailCategory::Digests.enabled_by_default(),
+            marketing: EmailCategory::Marketing.enabled_by_default(),
+        };
+        for (category, enabled) in stored {
+            preferences.set(category, enabled);
+        }
+        preferences
+    }
+
+    pub fn is_enabled(&self, category: EmailCategory) -> bool {
+        match category {
+            EmailCategory::Security => true,
+            EmailCategory::Reminders => self.reminders,
+            EmailCategory::Digests => self.digests,
+            EmailCategory::Marketing => self.marketing,
+        }
+    }
+
+    /// Setting the security category does nothing.
+    pub fn set(&mut self, category: EmailCategory, enabled: bool) {
+        match category {
+            EmailCategory::Security => {}
+            EmailCategory::Reminders => self.reminders = enabled,
+            EmailCategory::Digests => self.digests = enabled,
+            EmailCategory::Marketing => self.marketing = enabled,
+        }
+    }
+
+    /// Every category users can opt out of, with its setting.
+    pub fn categories(&self) -> Vec<(EmailCategory, bool)> {
+        EmailCategory::ALL
+            .into_iter()
+            .filter(EmailCategory::can_opt_out)
+            .map(|category| (category, self.is_enabled(category)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unset_categories_keep_their_default() {
+        let user_id = UserUuid::new();
+        let preferences =
+            NotificationPreferences::from_stored(user_id, [(EmailCategory::Digests, false)]);
+
+        assert_eq!(
+            preferences.categories(),
+            vec![
+                (EmailCategory::Reminders, true),
+                (EmailCategory::Digests, false),
+                (EmailCategory::Marketing, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_security_emails_cannot_be_turned_off() {
+        let mut preferences = NotificationPreferences::from_stored(UserUuid::new(), []);
+
+        preferences.set(EmailCategory::Security, false);
+
+        assert!(preferences.is_enabled(EmailCategory::Security));
+    }
+}
@@ -0,0 +1,95 @@
+use std::str::FromStr;
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::shared::domain::{email::EmailCategory, value_objects::UserUuid};
+
+/// What a one-click unsubscribe link in an email stands for:
+/// `<user_id>.<category>.<signature>`, where the signature is the base64url
+/// HMAC-SHA256 of `<user_id>.<category>` under the secret shared with the
+/// email worker, which signs them. Tokens do not expire, as mailbox providers
+/// may follow `List-Unsubscribe` links long after delivery.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsubscribeToken {
+    pub user_id: UserUuid,
+    pub category: EmailCategory,
+}
+
+impl UnsubscribeToken {
+    /// `None` unless `token` was signed with `secret` for a category users
+    /// can opt out of.
+    pub fn verify(token: &str, secret: &str) -> Option<Self> {
+        let (message, signature) = token.rsplit_once('.')?;
+        let (user_id, category) = message.split_once('.')?;
+        let token = Self {
+            user_id: UserUuid::from_str(user_id).ok()?,
+            category: EmailCategory::from_str(category).ok()?,
+        };
+        if !token.category.can_opt_out() {
+            return None;
+        }
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+        mac(secret, message)?.verify_slice(&signature).ok()?;
+        Some(token)
+    }
+
+    #[cfg(test)]
+    pub fn sign(&self, secret: &str) -> String {
+        let message = format!("{}.{}", self.user_id, self.category);
+        let signature = mac(secret, &message)
+            .map(|mac| URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+            .unwrap_or_default();
+        format!("{message}.{signature}")
+    }
+}
+
+fn mac(secret: &str, message: &str) -> Option<Hmac<Sha256>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).ok()?;
+    mac.update(message.as_bytes());
+    Some(mac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-unsubscribe-secret";
+
+    fn token() -> UnsubscribeToken {
+        UnsubscribeToken {
+            user_id: UserUuid::from_uuid(uuid::Uuid::from_u128(
+                0x6f1c2f2e_8a4b_4c55_9d1e_3a7b9c0d1e2f,
+            )),
+            category: EmailCategory::Digests,
+        }
+    }
+
+    #[test]
+    fn test_matches_the_tokens_the_email_worker_signs() {
+        // Same vector as `workers/email/src/unsubscribe.rs`.
+        let signed = "6f1c2f2e-8a4b-4c55-9d1e-3a7b9c0d1e2f.digests.selhZ2sIU1V5JMNkxZ6RhHTKA8EWPuwQtTrmXvIg2Cs";
+
+        assert_eq!(token().sign(SECRET), signed);
+        assert_eq!(UnsubscribeToken::verify(signed, SECRET), Some(token()));
+    }
+
+    #[test]
+    fn test_rejects_tampered_and_foreign_tokens() {
+        let signed = token().sign(SECRET);
+        let other_user = signed.replacen("6f1c", "7f1c", 1);
+        let other_category = signed.replace(".digests.", ".reminders.");
+        let security = UnsubscribeToken {
+            category: EmailCategory::Security,
+            ..token()
+        }
+        .sign(SECRET);
+
+        assert_eq!(UnsubscribeToken::verify(&signed, "another-secret"), None);
+        assert_eq!(UnsubscribeToken::verify(&other_user, SECRET), None);
+        assert_eq!(UnsubscribeToken::verify(&other_category, SECRET), None);
+        assert_eq!(UnsubscribeToken::verify(&security, SECRET), None);
+        assert_eq!(UnsubscribeToken::verify("not-a-token", SECRET), None);
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::account::domain::{
+    entities::{
+        account_deletion::{AccountDeletion, AccountEmail, AccountHolder},
+        account_export::AccountExport,
+        notification_preferences::NotificationPreferences,
+    },
+    errors::AccountRepoError,
+};
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IAccountRepository: Send + Sync {
+    async fn get_holder(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<AccountHolder>, AccountRepoError>;
+    /// `None` when the user does not exist.
+    async fn export(
+        &self,
+        user_id: UserUuid,
+        exported_at: DateTime<Utc>,
+    ) -> Result<Option<AccountExport>, AccountRepoError>;
+    async fn get_deletion(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<AccountDeletion>, AccountRepoError>;
+    /// Stores the deletion and enqueues `notice` to `email` atomically.
+    async fn schedule_deletion(
+        &self,
+        deletion: &AccountDeletion,
+        email: &str,
+        notice: AccountEmail,
+        trace_context: Option<String>,
+    ) -> Result<(), AccountRepoError>;
+    /// Returns `false` when no deletion was scheduled.
+    async fn cancel_deletion(&self, user_id: UserUuid) -> Result<bool, AccountRepoError>;
+    async fn get_due_deletions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<UserUuid>, AccountRepoError>;
+    /// Erases the user and everything they own, and enqueues `farewell`, in
+    /// one transaction. Returns `false` when the deletion is no longer due,
+    /// e.g. because it was cancelled meanwhile.
+    async fn purge(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+        farewell: AccountEmail,
+    ) -> Result<bool, AccountRepoError>;
+    /// `None` when the user does not exist.
+    async fn get_notification_preferences(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<NotificationPreferences>, AccountRepoError>;
+    /// Returns `false` when the user does not exist.
+    async fn save_notification_preferences(
+        &self,
+        preferences: &NotificationPreferences,
+    ) -> Result<bool, AccountRepoError>;
+}
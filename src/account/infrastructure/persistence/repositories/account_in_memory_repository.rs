@@ -0,0 +1,186 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+
+use crate::{
+    account::domain::{
+        entities::{
+            account_deletion::{AccountDeletion, AccountEmail, AccountHolder},
+            account_export::{AccountExport, ExportedProfile, ExportedSettings},
+            notification_preferences::NotificationPreferences,
+        },
+        errors::AccountRepoError,
+        repositories::account_repository::IAccountRepository,
+    },
+    shared::domain::value_objects::{UserPassword, UserUuid},
+};
+
+#[derive(Clone, Default)]
+pub struct AccountInMemoryRepository {
+    holders: Arc<RwLock<HashMap<uuid::Uuid, AccountHolder>>>,
+    deletions: Arc<RwLock<HashMap<uuid::Uuid, AccountDeletion>>>,
+    sent: Arc<RwLock<Vec<(String, AccountEmail)>>>,
+    notification_preferences: Arc<RwLock<HashMap<uuid::Uuid, NotificationPreferences>>>,
+}
+
+impl AccountInMemoryRepository {
+    pub async fn add_user(&self, user_id: UserUuid, email: &str, password: &str) {
+        let password = UserPassword::hash_password(password).expect("Should hash the password");
+        self.holders.write().await.insert(
+            user_id.value(),
+            AccountHolder {
+                user_id,
+                email: email.to_string(),
+                password: UserPassword::set_password_already_hashed(&password),
+            },
+        );
+    }
+
+    pub async fn has_user(&self, user_id: UserUuid) -> bool {
+        self.holders.read().await.contains_key(&user_id.value())
+    }
+
+    /// Enqueued emails as (recipient, email).
+    pub async fn sent(&self) -> Vec<(String, AccountEmail)> {
+        self.sent.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl IAccountRepository for AccountInMemoryRepository {
+    async fn get_holder(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<AccountHolder>, AccountRepoError> {
+        Ok(self.holders.read().await.get(&user_id.value()).cloned())
+    }
+
+    async fn export(
+        &self,
+        user_id: UserUuid,
+        exported_at: DateTime<Utc>,
+    ) -> Result<Option<AccountExport>, AccountRepoError> {
+        Ok(self
+            .holders
+            .read()
+            .await
+            .get(&user_id.value())
+            .map(|holder| AccountExport {
+                exported_at,
+                profile: ExportedProfile {
+                    id: holder.user_id,
+                    email: holder.email.clone(),
+                    email_verified: false,
+                    two_factor_enabled: false,
+                    created_at: None,
+                    updated_at: None,
+                },
+                positions: Vec::new(),
+                goals: Vec::new(),
+                settings: ExportedSettings::default(),
+                weekly_digests_sent: Vec::new(),
+                scraper_jobs: Vec::new(),
+                personal_access_tokens: Vec::new(),
+                identities: Vec::new(),
+            }))
+    }
+
+    async fn get_deletion(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<AccountDeletion>, AccountRepoError> {
+        Ok(self.deletions.read().await.get(&user_id.value()).cloned())
+    }
+
+    async fn schedule_deletion(
+        &self,
+        deletion: &AccountDeletion,
+        email: &str,
+        notice: AccountEmail,
+        _trace_context: Option<String>,
+    ) -> Result<(), AccountRepoError> {
+        self.deletions
+            .write()
+            .await
+            .entry(deletion.user_id.value())
+            .or_insert_with(|| deletion.clone());
+        self.sent.write().await.push((email.to_string(), notice));
+        Ok(())
+    }
+
+    async fn cancel_deletion(&self, user_id: UserUuid) -> Result<bool, AccountRepoError> {
+        Ok(self
+            .deletions
+            .write()
+            .await
+            .remove(&user_id.value())
+            .is_some())
+    }
+
+    async fn get_due_deletions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<UserUuid>, AccountRepoError> {
+        Ok(self
+            .deletions
+            .read()
+            .await
+            .values()
+            .filter(|deletion| deletion.is_due(now))
+            .map(|deletion| deletion.user_id)
+            .collect())
+    }
+
+    async fn purge(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+        farewell: AccountEmail,
+    ) -> Result<bool, AccountRepoError> {
+        let mut deletions = self.deletions.write().await;
+        if !deletions
+            .get(&user_id.value())
+            .is_some_and(|deletion| deletion.is_due(now))
+        {
+            return Ok(false);
+        }
+        deletions.remove(&user_id.value());
+        if let Some(holder) = self.holders.write().await.remove(&user_id.value()) {
+            self.sent.write().await.push((holder.email, farewell));
+        }
+        Ok(true)
+    }
+
+    async fn get_notification_preferences(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<NotificationPreferences>, AccountRepoError> {
+        if !self.has_user(user_id).await {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.notification_preferences
+                .read()
+                .await
+                .get(&user_id.value())
+                .cloned()
+                .unwrap_or_else(|| NotificationPreferences::from_stored(user_id, [])),
+        ))
+    }
+
+    async fn save_notification_preferences(
+        &self,
+        preferences: &NotificationPreferences,
+    ) -> Result<bool, AccountRepoError> {
+        if !self.has_user(preferences.user_id).await {
+            return Ok(false);
+        }
+        self.notification_preferences
+            .write()
+            .await
+            .insert(preferences.user_id.value(), preferences.clone());
+        Ok(true)
+    }
+}
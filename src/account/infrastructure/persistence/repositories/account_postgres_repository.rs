@@ -0,0 +1,757 @@
+use std::{collections::HashMap, str::FromStr};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use sqlx::postgres::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    account::domain::{
+        entities::{
+            account_deletion::{AccountDeletion, AccountEmail, AccountHolder},
+            account_export::{
+                AccountExport, ExportedAccessToken, ExportedComment, ExportedGoal,
+                ExportedGoalEvent, ExportedIdentity, ExportedPosition, ExportedProfile,
+                ExportedScraperJob, ExportedSettings, ExportedStatusChange,
+            },
+            notification_preferences::NotificationPreferences,
+        },
+        errors::AccountRepoError,
+        repositories::account_repository::IAccountRepository,
+    },
+    shared::{
+        domain::{
+            email::EmailCategory,
+            value_objects::{UserPassword, UserUuid},
+        },
+        infrastructure::email_queue::email_queue_payload,
+    },
+};
+
+struct PositionRow {
+    id: Uuid,
+    company: String,
+    role_title: String,
+    description: String,
+    applied_on: NaiveDate,
+    url: String,
+    status: String,
+    deleted: bool,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+    deleted_at: Option<NaiveDateTime>,
+}
+
+struct CommentRow {
+    id: Uuid,
+    position_id: Uuid,
+    body: String,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+struct StatusChangeRow {
+    position_id: Uuid,
+    from_status: String,
+    to_status: String,
+    automatic: bool,
+    changed_at: NaiveDateTime,
+}
+
+struct GoalRow {
+    id: Uuid,
+    metric: String,
+    period: String,
+    target: i32,
+    created_at: NaiveDateTime,
+}
+
+struct GoalEventRow {
+    goal_id: Uuid,
+    occurred_on: NaiveDate,
+    note: Option<String>,
+}
+
+struct DeletionRow {
+    user_id: Uuid,
+    requested_at: NaiveDateTime,
+    purge_after: NaiveDateTime,
+}
+
+impl From<DeletionRow> for AccountDeletion {
+    fn from(row: DeletionRow) -> Self {
+        Self {
+            user_id: UserUuid::from_uuid(row.user_id),
+            requested_at: row.requested_at.and_utc(),
+            purge_after: row.purge_after.and_utc(),
+        }
+    }
+}
+
+pub struct AccountPostgresRepository {
+    pool: PgPool,
+}
+
+impl AccountPostgresRepository {
+    pub async fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn database_error(operation: &'static str, e: sqlx::Error) -> AccountRepoError {
+    error!(
+        error_kind = "database_error",
+        error = %e,
+        "account_repo.{} failed",
+        operation
+    );
+    AccountRepoError::DatabaseError(e.to_string())
+}
+
+fn email_payload(email: &str, message: AccountEmail) -> serde_json::Value {
+    let variables = match &message {
+        AccountEmail::DeletionScheduled {
+            purge_after,
+            cancel_link,
+        } => serde_json::json!({
+            "purge_after": purge_after.to_rfc3339(),
+            "cancel_link": cancel_link
+        }),
+        AccountEmail::Deleted => serde_json::json!({}),
+    };
+    email_queue_payload(email, AccountEmail::CATEGORY, message.template(), variables)
+}
+
+#[async_trait]
+impl IAccountRepository for AccountPostgresRepository {
+    async fn get_holder(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<AccountHolder>, AccountRepoError> {
+        let row = sqlx::query!(
+            "SELECT id, email, password FROM users WHERE id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get_holder", e))?;
+
+        Ok(row.map(|row| AccountHolder {
+            user_id: UserUuid::from_uuid(row.id),
+            email: row.email,
+            password: UserPassword::set_password_already_hashed(&row.password),
+        }))
+    }
+
+    async fn export(
+        &self,
+        user_id: UserUuid,
+        exported_at: DateTime<Utc>,
+    ) -> Result<Option<AccountExport>, AccountRepoError> {
+        // One snapshot, so the archive is consistent even while the user keeps working.
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| database_error("export", e))?;
+        sqlx::query!("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("export", e))?;
+
+        let Some(profile) = sqlx::query!(
+            r#"SELECT id, email, email_validated, created_at, updated_at, EXISTS (SELECT 1 FROM user_two_factor t WHERE t.user_id = users.id AND t.enabled_at IS NOT NULL) AS "two_factor_enabled!" FROM users WHERE id = $1"#,
+            user_id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?
+        else {
+            return Ok(None);
+        };
+
+        let positions = sqlx::query_as!(
+            PositionRow,
+            "SELECT id, company, role_title, description, applied_on, url, status, deleted, created_at, updated_at, deleted_at FROM positions WHERE user_id = $1 ORDER BY applied_on, created_at",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        let mut comments: HashMap<Uuid, Vec<ExportedComment>> = HashMap::new();
+        for row in sqlx::query_as!(
+            CommentRow,
+            "SELECT c.id, c.position_id, c.body, c.created_at, c.updated_at FROM comments c JOIN positions p ON p.id = c.position_id WHERE p.user_id = $1 ORDER BY c.created_at",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?
+        {
+            comments
+                .entry(row.position_id)
+                .or_default()
+                .push(ExportedComment {
+                    id: row.id,
+                    body: row.body,
+                    created_at: row.created_at.and_utc(),
+                    updated_at: row.updated_at.and_utc(),
+                });
+        }
+
+        let mut status_history: HashMap<Uuid, Vec<ExportedStatusChange>> = HashMap::new();
+        for row in sqlx::query_as!(
+            StatusChangeRow,
+            "SELECT h.position_id, h.from_status, h.to_status, h.automatic, h.changed_at FROM position_status_history h JOIN positions p ON p.id = h.position_id WHERE p.user_id = $1 ORDER BY h.changed_at",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?
+        {
+            status_history
+                .entry(row.position_id)
+                .or_default()
+                .push(ExportedStatusChange {
+                    from_status: row.from_status,
+                    to_status: row.to_status,
+                    automatic: row.automatic,
+                    changed_at: row.changed_at.and_utc(),
+                });
+        }
+
+        let goals = sqlx::query_as!(
+            GoalRow,
+            "SELECT id, metric, period, target, created_at FROM goals WHERE user_id = $1 ORDER BY created_at",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        let mut goal_events: HashMap<Uuid, Vec<ExportedGoalEvent>> = HashMap::new();
+        for row in sqlx::query_as!(
+            GoalEventRow,
+            "SELECT e.goal_id, e.occurred_on, e.note FROM goal_events e JOIN goals g ON g.id = e.goal_id WHERE g.user_id = $1 ORDER BY e.occurred_on, e.created_at",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?
+        {
+            goal_events
+                .entry(row.goal_id)
+                .or_default()
+                .push(ExportedGoalEvent {
+                    occurred_on: row.occurred_on,
+                    note: row.note,
+                });
+        }
+
+        let staleness = sqlx::query!(
+            "SELECT stale_after_days, auto_ghost FROM staleness_settings WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        let weekly_digest = sqlx::query_scalar!(
+            "SELECT enabled FROM digest_subscriptions WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        let weekly_digests_sent = sqlx::query_scalar!(
+            "SELECT week_start FROM weekly_digests WHERE user_id = $1 ORDER BY week_start",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        let scraper_jobs = sqlx::query!(
+            r#"SELECT position_id, url, status::TEXT AS status, s3_key, error_message, created_at FROM scraper_queue WHERE user_id = $1 ORDER BY created_at"#,
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        let personal_access_tokens = sqlx::query!(
+            "SELECT name, scope, created_at, expires_at, last_used_at, revoked_at FROM personal_access_tokens WHERE user_id = $1 ORDER BY created_at",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        let identities = sqlx::query!(
+            "SELECT provider, email, created_at, last_login_at FROM identities WHERE user_id = $1 ORDER BY created_at",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| database_error("export", e))?;
+
+        tx.commit().await.map_err(|e| database_error("export", e))?;
+
+        Ok(Some(AccountExport {
+            exported_at,
+            profile: ExportedProfile {
+                id: UserUuid::from_uuid(profile.id),
+                email: profile.email,
+                email_verified: profile.email_validated,
+                two_factor_enabled: profile.two_factor_enabled,
+                created_at: profile.created_at,
+                updated_at: profile.updated_at,
+            },
+            positions: positions
+                .into_iter()
+                .map(|row| ExportedPosition {
+                    comments: comments.remove(&row.id).unwrap_or_default(),
+                    status_history: status_history.remove(&row.id).unwrap_or_default(),
+                    id: row.id,
+                    company: row.company,
+                    role_title: row.role_title,
+                    description: row.description,
+                    applied_on: row.applied_on,
+                    url: row.url,
+                    status: row.status,
+                    deleted: row.deleted,
+                    created_at: row.created_at.and_utc(),
+                    updated_at: row.updated_at.and_utc(),
+                    deleted_at: row.deleted_at.map(|at| at.and_utc()),
+                })
+                .collect(),
+            goals: goals
+                .into_iter()
+                .map(|row| ExportedGoal {
+                    events: goal_events.remove(&row.id).unwrap_or_default(),
+                    id: row.id,
+                    metric: row.metric,
+                    period: row.period,
+                    target: row.target,
+                    created_at: row.created_at.and_utc(),
+                })
+                .collect(),
+            settings: ExportedSettings {
+                stale_after_days: staleness.as_ref().map(|row| row.stale_after_days),
+                auto_ghost: staleness.is_some_and(|row| row.auto_ghost),
+                weekly_digest: weekly_digest.unwrap_or(false),
+            },
+            weekly_digests_sent,
+            scraper_jobs: scraper_jobs
+                .into_iter()
+                .map(|row| ExportedScraperJob {
+                    position_id: row.position_id,
+                    url: row.url,
+                    status: row.status,
+                    result_key: row.s3_key,
+                    error_message: row.error_message,
+                    created_at: row.created_at,
+                })
+                .collect(),
+            personal_access_tokens: personal_access_tokens
+                .into_iter()
+                .map(|row| ExportedAccessToken {
+                    name: row.name,
+                    scope: row.scope,
+                    created_at: row.created_at.and_utc(),
+                    expires_at: row.expires_at.map(|at| at.and_utc()),
+                    last_used_at: row.last_used_at.map(|at| at.and_utc()),
+                    revoked_at: row.revoked_at.map(|at| at.and_utc()),
+                })
+                .collect(),
+            identities: identities
+                .into_iter()
+                .map(|row| ExportedIdentity {
+                    provider: row.provider,
+                    email: row.email,
+                    created_at: row.created_at.and_utc(),
+                    last_login_at: row.last_login_at.and_utc(),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn get_deletion(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<AccountDeletion>, AccountRepoError> {
+        let row = sqlx::query_as!(
+            DeletionRow,
+            "SELECT user_id, requested_at, purge_after FROM account_deletions WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get_deletion", e))?;
+
+        Ok(row.map(AccountDeletion::from))
+    }
+
+    async fn schedule_deletion(
+        &self,
+        deletion: &AccountDeletion,
+        email: &str,
+        notice: AccountEmail,
+        trace_context: Option<String>,
+    ) -> Result<(), AccountRepoError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| database_error("schedule_deletion", e))?;
+
+        let inserted = sqlx::query!(
+            "INSERT INTO account_deletions (user_id, requested_at, purge_after) VALUES ($1, $2, $3) ON CONFLICT (user_id) DO NOTHING",
+            deletion.user_id.value(),
+            deletion.requested_at.naive_utc(),
+            deletion.purge_after.naive_utc(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| database_error("schedule_deletion", e))?
+        .rows_affected()
+            == 1;
+
+        if inserted {
+            let idempotency_key = notice.idempotency_key(deletion.user_id);
+            sqlx::query!(
+                "INSERT INTO email_queue (payload, user_id, trace_id, idempotency_key) VALUES ($1, $2, $3, $4) ON CONFLICT (idempotency_key) DO NOTHING",
+                email_payload(email, notice),
+                deletion.user_id.value(),
+                trace_context,
+                idempotency_key.as_str()
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("schedule_deletion", e))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| database_error("schedule_deletion", e))?;
+        Ok(())
+    }
+
+    async fn cancel_deletion(&self, user_id: UserUuid) -> Result<bool, AccountRepoError> {
+        let result = sqlx::query!(
+            "DELETE FROM account_deletions WHERE user_id = $1",
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("cancel_deletion", e))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn get_due_deletions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<UserUuid>, AccountRepoError> {
+        let user_ids = sqlx::query_scalar!(
+            "SELECT user_id FROM account_deletions WHERE purge_after <= $1 ORDER BY purge_after",
+            now.naive_utc()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get_due_deletions", e))?;
+
+        Ok(user_ids.into_iter().map(UserUuid::from_uuid).collect())
+    }
+
+    async fn purge(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+        farewell: AccountEmail,
+    ) -> Result<bool, AccountRepoError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| database_error("purge", e))?;
+
+        // Claiming the deletion row first makes a concurrent cancel or a
+        // second purger wait for us, and then find nothing to do.
+        let claimed = sqlx::query!(
+            "DELETE FROM account_deletions WHERE user_id = $1 AND purge_after <= $2",
+            user_id.value(),
+            now.naive_utc()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| database_error("purge", e))?
+        .rows_affected()
+            == 1;
+        if !claimed {
+            tx.rollback()
+                .await
+                .map_err(|e| database_error("purge", e))?;
+            return Ok(false);
+        }
+
+        // Every table owned by the user cascades from `users`.
+        let email = sqlx::query_scalar!(
+            "DELETE FROM users WHERE id = $1 RETURNING email",
+            user_id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| database_error("purge", e))?;
+
+        // The audit trail stays, but no longer says who it was about.
+        sqlx::query!(
+            "UPDATE audit_events SET user_id = NULL, ip = NULL, user_agent = NULL WHERE user_id = $1",
+            user_id.value()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| database_error("purge", e))?;
+
+        if let Some(email) = email {
+            let idempotency_key = farewell.idempotency_key(user_id);
+            // Not tied to the user anymore, or the cascade would take it too.
+            sqlx::query!(
+                "INSERT INTO email_queue (payload, idempotency_key) VALUES ($1, $2) ON CONFLICT (idempotency_key) DO NOTHING",
+                email_payload(&email, farewell),
+                idempotency_key.as_str()
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("purge", e))?;
+        }
+
+        tx.commit().await.map_err(|e| database_error("purge", e))?;
+        Ok(true)
+    }
+
+    async fn get_notification_preferences(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<NotificationPreferences>, AccountRepoError> {
+        let rows = sqlx::query!(
+            r#"SELECT n.category AS "category?", n.enabled AS "enabled?" FROM users u LEFT JOIN notification_preferences n ON n.user_id = u.id WHERE u.id = $1"#,
+            user_id.value()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get_notification_preferences", e))?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        let stored = rows.into_iter().filter_map(|row| {
+            let category = EmailCategory::from_str(&row.category?).ok()?;
+            Some((category, row.enabled?))
+        });
+        Ok(Some(NotificationPreferences::from_stored(user_id, stored)))
+    }
+
+    async fn save_notification_preferences(
+        &self,
+        preferences: &NotificationPreferences,
+    ) -> Result<bool, AccountRepoError> {
+        let (categories, enabled): (Vec<String>, Vec<bool>) = preferences
+            .categories()
+            .into_iter()
+            .map(|(category, enabled)| (category.as_str().to_string(), enabled))
+            .unzip();
+
+        let saved = sqlx::query!(
+            "INSERT INTO notification_preferences (user_id, category, enabled) SELECT u.id, c.category, c.enabled FROM users u CROSS JOIN UNNEST($2::VARCHAR[], $3::BOOLEAN[]) AS c (category, enabled) WHERE u.id = $1 ON CONFLICT (user_id, category) DO UPDATE SET enabled = EXCLUDED.enabled, updated_at = CURRENT_TIMESTAMP",
+            preferences.user_id.value(),
+            &categories,
+            &enabled
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("save_notification_preferences", e))?
+        .rows_affected();
+
+        Ok(saved > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    fn email() -> AccountEmail {
+        AccountEmail::Deleted
+    }
+
+    async fn count(pool: &PgPool, query: &str, user_id: UserUuid) -> i64 {
+        sqlx::query_scalar::<_, i64>(query)
+            .bind(user_id.value())
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    async fn insert_position(pool: &PgPool, user_id: UserUuid) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query!(
+            "INSERT INTO positions (id, user_id, company, role_title, description, applied_on, url, status) VALUES ($1, $2, 'Rust Corp', 'Engineer', '', CURRENT_DATE, 'https://rust.example', 'CvSent')",
+            id,
+            user_id.value(),
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query!(
+            "INSERT INTO comments (id, position_id, user_id, body) VALUES ($1, $2, $3, 'Sent the CV')",
+            Uuid::new_v4(),
+            id,
+            user_id.value(),
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query!(
+            "INSERT INTO scraper_queue (url, user_id, position_id, status) VALUES ('https://rust.example', $1, $2, 'COMPLETED')",
+            user_id.value(),
+            id,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_export_includes_positions_comments_and_scraper_jobs() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let other = factory.create_random_user().await;
+        let repository = AccountPostgresRepository::new(factory.pool.clone()).await;
+        let position_id = insert_position(&factory.pool, user.id).await;
+        insert_position(&factory.pool, other.id).await;
+
+        let export = repository
+            .export(user.id, Utc::now())
+            .await
+            .unwrap()
+            .expect("User should exist");
+
+        assert_eq!(export.profile.email, user.email.value());
+        assert_eq!(export.positions.len(), 1);
+        assert_eq!(export.positions[0].id, position_id);
+        assert_eq!(export.positions[0].comments.len(), 1);
+        assert_eq!(export.scraper_jobs.len(), 1);
+        assert_eq!(export.scraper_jobs[0].status.as_deref(), Some("COMPLETED"));
+        assert_eq!(
+            repository
+                .export(UserUuid::new(), Utc::now())
+                .await
+                .unwrap(),
+            None
+        );
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_purge_erases_everything_the_user_owns() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let other = factory.create_random_user().await;
+        let repository = AccountPostgresRepository::new(factory.pool.clone()).await;
+        insert_position(&factory.pool, user.id).await;
+        insert_position(&factory.pool, other.id).await;
+        let audit_event_id = Uuid::new_v4();
+        sqlx::query!(
+            "INSERT INTO audit_events (id, kind, user_id, ip, user_agent, request_id) VALUES ($1, 'login_succeeded', $2, '203.0.113.7', 'Firefox', 'req-1')",
+            audit_event_id,
+            user.id.value(),
+        )
+        .execute(&factory.pool)
+        .await
+        .unwrap();
+        let now = Utc::now();
+        let deletion = AccountDeletion::schedule(user.id, TimeDelta::hours(1), now);
+
+        repository
+            .schedule_deletion(&deletion, user.email.value(), email(), None)
+            .await
+            .unwrap();
+        repository
+            .schedule_deletion(&deletion, user.email.value(), email(), None)
+            .await
+            .unwrap();
+        assert_eq!(
+            count(
+                &factory.pool,
+                "SELECT COUNT(*) FROM email_queue WHERE user_id = $1",
+                user.id
+            )
+            .await,
+            1
+        );
+        assert!(!repository.purge(user.id, now, email()).await.unwrap());
+        assert_eq!(
+            repository
+                .get_due_deletions(deletion.purge_after)
+                .await
+                .unwrap(),
+            vec![user.id]
+        );
+
+        let purged = repository
+            .purge(user.id, deletion.purge_after, email())
+            .await
+            .unwrap();
+
+        assert!(purged);
+        for query in [
+            "SELECT COUNT(*) FROM users WHERE id = $1",
+            "SELECT COUNT(*) FROM positions WHERE user_id = $1",
+            "SELECT COUNT(*) FROM comments WHERE user_id = $1",
+            "SELECT COUNT(*) FROM scraper_queue WHERE user_id = $1",
+            "SELECT COUNT(*) FROM email_queue WHERE user_id = $1",
+            "SELECT COUNT(*) FROM account_deletions WHERE user_id = $1",
+            "SELECT COUNT(*) FROM audit_events WHERE user_id = $1",
+        ] {
+            assert_eq!(count(&factory.pool, query, user.id).await, 0, "{query}");
+        }
+        let audit_event = sqlx::query!(
+            "SELECT user_id, ip, user_agent, request_id FROM audit_events WHERE id = $1",
+            audit_event_id
+        )
+        .fetch_one(&factory.pool)
+        .await
+        .expect("The audit event should be kept");
+        assert_eq!(
+            (audit_event.user_id, audit_event.ip, audit_event.user_agent),
+            (None, None, None)
+        );
+        assert_eq!(audit_event.request_id.as_deref(), Some("req-1"));
+        assert_eq!(
+            count(
+                &factory.pool,
+                "SELECT COUNT(*) FROM positions WHERE user_id = $1",
+                other.id
+            )
+            .await,
+            1
+        );
+        assert!(
+            !repository
+                .purge(user.id, deletion.purge_after, email())
+                .await
+                .unwrap()
+        );
+
+        factory.teardown().await;
+    }
+}
@@ -0,0 +1,2 @@
+pub mod account_in_memory_repository;
+pub mod account_postgres_repository;
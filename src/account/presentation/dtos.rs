@@ -0,0 +1,345 @@
+use utoipa::ToSchema;
+
+use crate::account::domain::entities::{
+    account_deletion::AccountDeletion,
+    account_export::{
+        AccountExport, ExportedAccessToken, ExportedComment, ExportedGoal, ExportedGoalEvent,
+        ExportedIdentity, ExportedPosition, ExportedProfile, ExportedScraperJob, ExportedSettings,
+        ExportedStatusChange,
+    },
+    notification_preferences::NotificationPreferences,
+};
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct SecurityEventsQuery {
+    /// RFC 3339 timestamp; only older events, to page backwards
+    pub before: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct DeleteAccountDto {
+    /// Current password of the account
+    pub password: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AccountDeletionDto {
+    /// RFC 3339 timestamp
+    pub requested_at: String,
+    /// RFC 3339 timestamp; the deletion can be cancelled until then
+    pub purge_after: String,
+}
+
+impl From<AccountDeletion> for AccountDeletionDto {
+    fn from(deletion: AccountDeletion) -> Self {
+        Self {
+            requested_at: deletion.requested_at.to_rfc3339(),
+            purge_after: deletion.purge_after.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct NotificationPreferencesDto {
+    /// Always true: sign-in alerts, verification links and other security
+    /// emails cannot be turned off
+    pub security: bool,
+    /// Follow-up and interview reminders
+    pub reminders: bool,
+    /// The weekly digest, for users subscribed to it
+    pub digests: bool,
+    /// Product news; off unless turned on
+    pub marketing: bool,
+}
+
+impl From<NotificationPreferences> for NotificationPreferencesDto {
+    fn from(preferences: NotificationPreferences) -> Self {
+        Self {
+            security: true,
+            reminders: preferences.reminders,
+            digests: preferences.digests,
+            marketing: preferences.marketing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct UpdateNotificationPreferencesDto {
+    pub reminders: bool,
+    pub digests: bool,
+    pub marketing: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UnsubscribeQuery {
+    /// Token of the unsubscribe link
+    pub token: String,
+}
+
+/// All timestamps are RFC 3339 and dates `YYYY-MM-DD`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AccountExportDto {
+    pub exported_at: String,
+    pub profile: ExportedProfileDto,
+    /// Removed positions included
+    pub positions: Vec<ExportedPositionDto>,
+    pub goals: Vec<ExportedGoalDto>,
+    pub settings: ExportedSettingsDto,
+    /// Weeks (their Monday) a digest email was sent for
+    pub weekly_digests_sent: Vec<String>,
+    pub scraper_jobs: Vec<ExportedScraperJobDto>,
+    pub personal_access_tokens: Vec<ExportedAccessTokenDto>,
+    /// Sign-in providers linked to the account
+    pub identities: Vec<ExportedIdentityDto>,
+}
+
+impl From<AccountExport> for AccountExportDto {
+    fn from(export: AccountExport) -> Self {
+        Self {
+            exported_at: export.exported_at.to_rfc3339(),
+            profile: export.profile.into(),
+            positions: export.positions.into_iter().map(Into::into).collect(),
+            goals: export.goals.into_iter().map(Into::into).collect(),
+            settings: export.settings.into(),
+            weekly_digests_sent: export
+                .weekly_digests_sent
+                .iter()
+                .map(|week| week.to_string())
+                .collect(),
+            scraper_jobs: export.scraper_jobs.into_iter().map(Into::into).collect(),
+            personal_access_tokens: export
+                .personal_access_tokens
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            identities: export.identities.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedProfileDto {
+    pub id: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub two_factor_enabled: bool,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl From<ExportedProfile> for ExportedProfileDto {
+    fn from(profile: ExportedProfile) -> Self {
+        Self {
+            id: profile.id.to_string(),
+            email: profile.email,
+            email_verified: profile.email_verified,
+            two_factor_enabled: profile.two_factor_enabled,
+            created_at: profile.created_at.map(|at| at.to_rfc3339()),
+            updated_at: profile.updated_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedPositionDto {
+    pub id: String,
+    pub company: String,
+    pub role_title: String,
+    pub description: String,
+    pub applied_on: String,
+    pub url: String,
+    pub status: String,
+    pub deleted: bool,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted_at: Option<String>,
+    pub comments: Vec<ExportedCommentDto>,
+    pub status_history: Vec<ExportedStatusChangeDto>,
+}
+
+impl From<ExportedPosition> for ExportedPositionDto {
+    fn from(position: ExportedPosition) -> Self {
+        Self {
+            id: position.id.to_string(),
+            company: position.company,
+            role_title: position.role_title,
+            description: position.description,
+            applied_on: position.applied_on.to_string(),
+            url: position.url,
+            status: position.status,
+            deleted: position.deleted,
+            created_at: position.created_at.to_rfc3339(),
+            updated_at: position.updated_at.to_rfc3339(),
+            deleted_at: position.deleted_at.map(|at| at.to_rfc3339()),
+            comments: position.comments.into_iter().map(Into::into).collect(),
+            status_history: position
+                .status_history
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedCommentDto {
+    pub id: String,
+    pub body: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<ExportedComment> for ExportedCommentDto {
+    fn from(comment: ExportedComment) -> Self {
+        Self {
+            id: comment.id.to_string(),
+            body: comment.body,
+            created_at: comment.created_at.to_rfc3339(),
+            updated_at: comment.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedStatusChangeDto {
+    pub from_status: String,
+    pub to_status: String,
+    /// `true` when the change was made by auto-ghosting
+    pub automatic: bool,
+    pub changed_at: String,
+}
+
+impl From<ExportedStatusChange> for ExportedStatusChangeDto {
+    fn from(change: ExportedStatusChange) -> Self {
+        Self {
+            from_status: change.from_status,
+            to_status: change.to_status,
+            automatic: change.automatic,
+            changed_at: change.changed_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedGoalDto {
+    pub id: String,
+    pub metric: String,
+    pub period: String,
+    pub target: i32,
+    pub created_at: String,
+    pub events: Vec<ExportedGoalEventDto>,
+}
+
+impl From<ExportedGoal> for ExportedGoalDto {
+    fn from(goal: ExportedGoal) -> Self {
+        Self {
+            id: goal.id.to_string(),
+            metric: goal.metric,
+            period: goal.period,
+            target: goal.target,
+            created_at: goal.created_at.to_rfc3339(),
+            events: goal.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedGoalEventDto {
+    pub occurred_on: String,
+    pub note: Option<String>,
+}
+
+impl From<ExportedGoalEvent> for ExportedGoalEventDto {
+    fn from(event: ExportedGoalEvent) -> Self {
+        Self {
+            occurred_on: event.occurred_on.to_string(),
+            note: event.note,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedSettingsDto {
+    /// `null` when the server default applies
+    pub stale_after_days: Option<i32>,
+    pub auto_ghost: bool,
+    pub weekly_digest: bool,
+}
+
+impl From<ExportedSettings> for ExportedSettingsDto {
+    fn from(settings: ExportedSettings) -> Self {
+        Self {
+            stale_after_days: settings.stale_after_days,
+            auto_ghost: settings.auto_ghost,
+            weekly_digest: settings.weekly_digest,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedScraperJobDto {
+    pub position_id: String,
+    pub url: String,
+    pub status: Option<String>,
+    /// Storage key of the scraped page, when the job completed
+    pub result_key: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: Option<String>,
+}
+
+impl From<ExportedScraperJob> for ExportedScraperJobDto {
+    fn from(job: ExportedScraperJob) -> Self {
+        Self {
+            position_id: job.position_id.to_string(),
+            url: job.url,
+            status: job.status,
+            result_key: job.result_key,
+            error_message: job.error_message,
+            created_at: job.created_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+/// The token secret is never stored, so it cannot be exported.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedAccessTokenDto {
+    pub name: String,
+    pub scope: String,
+    pub created_at: String,
+    pub expires_at: Option<String>,
+    pub last_used_at: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+impl From<ExportedAccessToken> for ExportedAccessTokenDto {
+    fn from(token: ExportedAccessToken) -> Self {
+        Self {
+            name: token.name,
+            scope: token.scope,
+            created_at: token.created_at.to_rfc3339(),
+            expires_at: token.expires_at.map(|at| at.to_rfc3339()),
+            last_used_at: token.last_used_at.map(|at| at.to_rfc3339()),
+            revoked_at: token.revoked_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportedIdentityDto {
+    pub provider: String,
+    pub email: String,
+    pub created_at: String,
+    pub last_login_at: String,
+}
+
+impl From<ExportedIdentity> for ExportedIdentityDto {
+    fn from(identity: ExportedIdentity) -> Self {
+        Self {
+            provider: identity.provider,
+            email: identity.email,
+            created_at: identity.created_at.to_rfc3339(),
+            last_login_at: identity.last_login_at.to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use thiserror::Error;
+
+use crate::{
+    account::application::errors::AccountServiceError,
+    shared::{domain::errors::SharedDomainError, presentation::ApiErrorResponse},
+};
+
+#[derive(Error, Debug)]
+pub enum AccountApiError {
+    #[error("Service error: `{0}`")]
+    ServiceError(#[from] AccountServiceError),
+
+    #[error("Domain error: `{0}`")]
+    SharedDomainError(#[from] SharedDomainError),
+}
+
+impl IntoResponse for AccountApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            AccountApiError::ServiceError(AccountServiceError::AccountNotFound) => {
+                (StatusCode::NOT_FOUND, "Account not found".to_string())
+            }
+            AccountApiError::ServiceError(AccountServiceError::WrongPassword) => {
+                (StatusCode::BAD_REQUEST, "Wrong password".to_string())
+            }
+            AccountApiError::ServiceError(AccountServiceError::DeletionNotScheduled) => (
+                StatusCode::NOT_FOUND,
+                "No account deletion is scheduled".to_string(),
+            ),
+            AccountApiError::ServiceError(AccountServiceError::InvalidUnsubscribeToken) => (
+                StatusCode::BAD_REQUEST,
+                "Invalid unsubscribe link".to_string(),
+            ),
+            AccountApiError::ServiceError(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+            AccountApiError::SharedDomainError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
+        };
+
+        (status, Json(ApiErrorResponse { message })).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrong_password_response() {
+        let error = AccountApiError::from(AccountServiceError::WrongPassword);
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_invalid_unsubscribe_token_response() {
+        let error = AccountApiError::from(AccountServiceError::InvalidUnsubscribeToken);
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_deletion_not_scheduled_response() {
+        let error = AccountApiError::from(AccountServiceError::DeletionNotScheduled);
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+}
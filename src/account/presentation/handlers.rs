@@ -0,0 +1,231 @@
+use std::str::FromStr;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::{HeaderValue, StatusCode, header::CONTENT_DISPOSITION},
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    account::{
+        domain::entities::notification_preferences::NotificationPreferences,
+        presentation::{
+            dtos::{
+                AccountDeletionDto, AccountExportDto, DeleteAccountDto, NotificationPreferencesDto,
+                SecurityEventsQuery, UnsubscribeQuery, UpdateNotificationPreferencesDto,
+            },
+            errors::AccountApiError,
+            routes::AccountState,
+        },
+    },
+    shared::{
+        domain::value_objects::UserUuid,
+        infrastructure::http::auth_extractor::AuthenticatedClaims,
+        presentation::audit_dtos::{AuditEventDto, parse_before},
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/account/export",
+    responses(
+        (status = 200, description = "Everything stored about the current user, as a JSON attachment", body = AccountExportDto),
+        (status = 401, description = "Unauthorized, or not a session token")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Account"
+)]
+pub async fn export_account(
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    State(state): State<AccountState>,
+) -> Result<Response, AccountApiError> {
+    let user_id = UserUuid::from_str(&claims.sub)?;
+    let export = state.service.export(user_id).await?;
+    let filename = format!(
+        "attachment; filename=\"seeker-export-{}.json\"",
+        export.exported_at.format("%Y-%m-%d")
+    );
+    let mut response = Json(AccountExportDto::from(export)).into_response();
+    if let Ok(value) = HeaderValue::from_str(&filename) {
+        response.headers_mut().insert(CONTENT_DISPOSITION, value);
+    }
+    Ok(response)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/account",
+    request_body = DeleteAccountDto,
+    responses(
+        (status = 202, description = "Account scheduled for deletion once the grace period ends", body = AccountDeletionDto),
+        (status = 400, description = "Wrong password"),
+        (status = 401, description = "Unauthorized, or not a session token")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Account"
+)]
+pub async fn delete_account(
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    State(state): State<AccountState>,
+    Json(payload): Json<DeleteAccountDto>,
+) -> Result<(StatusCode, Json<AccountDeletionDto>), AccountApiError> {
+    let user_id = UserUuid::from_str(&claims.sub)?;
+    let deletion = state
+        .service
+        .request_deletion(user_id, &payload.password)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(deletion.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/deletion",
+    responses(
+        (status = 200, description = "Pending deletion of the current user", body = AccountDeletionDto),
+        (status = 401, description = "Unauthorized, or not a session token"),
+        (status = 404, description = "No deletion scheduled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Account"
+)]
+pub async fn get_account_deletion(
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    State(state): State<AccountState>,
+) -> Result<Json<AccountDeletionDto>, AccountApiError> {
+    let user_id = UserUuid::from_str(&claims.sub)?;
+    let deletion = state.service.get_deletion(user_id).await?;
+    Ok(Json(deletion.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/account/deletion",
+    responses(
+        (status = 204, description = "Deletion cancelled, the account is kept"),
+        (status = 401, description = "Unauthorized, or not a session token"),
+        (status = 404, description = "No deletion scheduled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Account"
+)]
+pub async fn cancel_account_deletion(
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    State(state): State<AccountState>,
+) -> Result<StatusCode, AccountApiError> {
+    let user_id = UserUuid::from_str(&claims.sub)?;
+    state.service.cancel_deletion(user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/security-events",
+    params(
+        ("before" = Option<String>, Query, description = "RFC 3339 timestamp; only older events, to page backwards"),
+        ("limit" = Option<i64>, Query, description = "Page size (default 50, max 200)")
+    ),
+    responses(
+        (status = 200, description = "Sign-ins, password changes and other security events of the current user, newest first", body = Vec<AuditEventDto>),
+        (status = 400, description = "Invalid `before` timestamp"),
+        (status = 401, description = "Unauthorized, or not a session token")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Account"
+)]
+pub async fn list_security_events(
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    State(state): State<AccountState>,
+    Query(query): Query<SecurityEventsQuery>,
+) -> Result<Json<Vec<AuditEventDto>>, AccountApiError> {
+    let user_id = UserUuid::from_str(&claims.sub)?;
+    let events = state
+        .service
+        .security_events(user_id, parse_before(query.before.as_deref())?, query.limit)
+        .await?;
+    Ok(Json(events.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/notifications",
+    responses(
+        (status = 200, description = "Which emails the current user gets", body = NotificationPreferencesDto),
+        (status = 401, description = "Unauthorized, or not a session token")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Account"
+)]
+pub async fn get_notification_preferences(
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    State(state): State<AccountState>,
+) -> Result<Json<NotificationPreferencesDto>, AccountApiError> {
+    let user_id = UserUuid::from_str(&claims.sub)?;
+    let preferences = state.service.get_notification_preferences(user_id).await?;
+    Ok(Json(preferences.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/account/notifications",
+    request_body = UpdateNotificationPreferencesDto,
+    responses(
+        (status = 200, description = "Preferences saved", body = NotificationPreferencesDto),
+        (status = 401, description = "Unauthorized, or not a session token")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Account"
+)]
+pub async fn update_notification_preferences(
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    State(state): State<AccountState>,
+    Json(payload): Json<UpdateNotificationPreferencesDto>,
+) -> Result<Json<NotificationPreferencesDto>, AccountApiError> {
+    let preferences = NotificationPreferences {
+        user_id: UserUuid::from_str(&claims.sub)?,
+        reminders: payload.reminders,
+        digests: payload.digests,
+        marketing: payload.marketing,
+    };
+    let preferences = state
+        .service
+        .update_notification_preferences(preferences)
+        .await?;
+    Ok(Json(preferences.into()))
+}
+
+/// Target of the `List-Unsubscribe` header (RFC 8058) and of the unsubscribe
+/// page of the frontend, so it needs no authentication besides the token.
+#[utoipa::path(
+    post,
+    path = "/account/notifications/unsubscribe",
+    params(
+        ("token" = String, Query, description = "Token of the unsubscribe link")
+    ),
+    responses(
+        (status = 204, description = "The category of the link is turned off"),
+        (status = 400, description = "Invalid unsubscribe link")
+    ),
+    tag = "Account"
+)]
+pub async fn unsubscribe(
+    State(state): State<AccountState>,
+    Query(query): Query<UnsubscribeQuery>,
+) -> Result<StatusCode, AccountApiError> {
+    state.service.unsubscribe(&query.token).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,4 @@
+pub mod dtos;
+pub mod errors;
+pub mod handlers;
+pub mod routes;
@@ -0,0 +1,353 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::FromRef,
+    routing::{delete, get, post},
+};
+
+use crate::{
+    account::{
+        application::account_service::AccountService,
+        presentation::handlers::{
+            cancel_account_deletion, delete_account, export_account, get_account_deletion,
+            get_notification_preferences, list_security_events, unsubscribe,
+            update_notification_preferences,
+        },
+    },
+    shared::{config::Config, infrastructure::http::auth_extractor::UserStatusChecker},
+};
+
+#[derive(Clone)]
+pub struct AccountState {
+    pub service: Arc<AccountService>,
+    pub config: Arc<Config>,
+    pub user_checker: Arc<dyn UserStatusChecker>,
+}
+
+impl FromRef<AccountState> for Arc<Config> {
+    fn from_ref(state: &AccountState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<AccountState> for Arc<dyn UserStatusChecker> {
+    fn from_ref(state: &AccountState) -> Self {
+        state.user_checker.clone()
+    }
+}
+
+pub fn create_account_routes(
+    service: Arc<AccountService>,
+    config: Arc<Config>,
+    user_checker: Arc<dyn UserStatusChecker>,
+) -> Router {
+    let state = AccountState {
+        service,
+        config,
+        user_checker,
+    };
+    Router::new()
+        .route("/", delete(delete_account))
+        .route("/export", get(export_account))
+        .route(
+            "/deletion",
+            get(get_account_deletion).delete(cancel_account_deletion),
+        )
+        .route("/security-events", get(list_security_events))
+        .route(
+            "/notifications",
+            get(get_notification_preferences).put(update_notification_preferences),
+        )
+        .route("/notifications/unsubscribe", post(unsubscribe))
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::{
+            application::account_service::AccountSettings,
+            domain::entities::unsubscribe_token::UnsubscribeToken,
+            infrastructure::persistence::repositories::account_in_memory_repository::AccountInMemoryRepository,
+        },
+        shared::{
+            domain::{
+                audit::{AuditEntry, AuditEventKind, IAuditLog},
+                email::EmailCategory,
+                value_objects::UserUuid,
+            },
+            fixtures::valid_password,
+            infrastructure::audit::audit_in_memory_log::AuditInMemoryLog,
+        },
+    };
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode, header::CONTENT_DISPOSITION},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    struct MockUserStatusChecker;
+
+    #[async_trait::async_trait]
+    impl UserStatusChecker for MockUserStatusChecker {
+        async fn is_account_disabled(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn is_token_revoked(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            false
+        }
+
+        async fn is_session_active(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            true
+        }
+
+        async fn is_email_verified(&self, _user_id: &str) -> bool {
+            true
+        }
+
+        async fn is_admin(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn authenticate_personal_access_token(
+            &self,
+            _token: &str,
+        ) -> Option<crate::shared::infrastructure::http::auth_extractor::PersonalAccessTokenGrant>
+        {
+            None
+        }
+    }
+
+    async fn setup_router() -> (Router, String, UserUuid) {
+        let repo = AccountInMemoryRepository::default();
+        let user_id = UserUuid::new();
+        repo.add_user(user_id, "leaving@example.com", valid_password())
+            .await;
+        let audit_log = AuditInMemoryLog::new();
+        audit_log
+            .record(AuditEntry::new(AuditEventKind::Signup, Some(user_id)))
+            .await;
+        audit_log
+            .record(AuditEntry::new(
+                AuditEventKind::Signup,
+                Some(UserUuid::new()),
+            ))
+            .await;
+        audit_log
+            .record(
+                AuditEntry::new(AuditEventKind::LoginFailed, Some(user_id))
+                    .detail("invalid_credentials"),
+            )
+            .await;
+        let service = Arc::new(AccountService::new(
+            Box::new(repo),
+            Arc::new(audit_log),
+            AccountSettings {
+                frontend_url: "http://localhost:3001".to_string(),
+                deletion_grace_period_secs: 3600,
+                unsubscribe_secret: "unsubscribe-secret".to_string(),
+            },
+        ));
+        let config = Config::test_default();
+        let token = crate::shared::infrastructure::http::auth_extractor::create_jwt(
+            &user_id.to_string(),
+            "leaving@example.com",
+            &config,
+        )
+        .unwrap();
+        (
+            create_account_routes(service, Arc::new(config), Arc::new(MockUserStatusChecker)),
+            format!("Bearer {}", token),
+            user_id,
+        )
+    }
+
+    async fn send(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        auth: &str,
+        body: &str,
+    ) -> (StatusCode, Value) {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .header("Authorization", auth)
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_export_is_an_attachment() {
+        let (app, auth, _) = setup_router().await;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/export")
+                    .header("Authorization", &auth)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            response.headers()[CONTENT_DISPOSITION]
+                .to_str()
+                .unwrap()
+                .starts_with("attachment; filename=\"seeker-export-")
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let export: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(export["profile"]["email"], "leaving@example.com");
+        assert_eq!(export["positions"], Value::Array(Vec::new()));
+    }
+
+    #[tokio::test]
+    async fn test_delete_then_cancel() {
+        let (app, auth, _) = setup_router().await;
+
+        let (wrong, _) = send(&app, "DELETE", "/", &auth, r#"{"password":"nope"}"#).await;
+        let (none, _) = send(&app, "GET", "/deletion", &auth, "").await;
+        let (scheduled, deletion) = send(
+            &app,
+            "DELETE",
+            "/",
+            &auth,
+            &format!(r#"{{"password":"{}"}}"#, valid_password()),
+        )
+        .await;
+        let (pending, pending_body) = send(&app, "GET", "/deletion", &auth, "").await;
+        let (cancelled, _) = send(&app, "DELETE", "/deletion", &auth, "").await;
+        let (cancelled_again, _) = send(&app, "DELETE", "/deletion", &auth, "").await;
+
+        assert_eq!(wrong, StatusCode::BAD_REQUEST);
+        assert_eq!(none, StatusCode::NOT_FOUND);
+        assert_eq!(scheduled, StatusCode::ACCEPTED);
+        assert_eq!(pending, StatusCode::OK);
+        assert_eq!(pending_body, deletion);
+        assert_eq!(cancelled, StatusCode::NO_CONTENT);
+        assert_eq!(cancelled_again, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_security_events_are_only_the_users_own() {
+        let (app, auth, _) = setup_router().await;
+
+        let (status, events) = send(&app, "GET", "/security-events", &auth, "").await;
+        let (_, first_page) = send(&app, "GET", "/security-events?limit=1", &auth, "").await;
+        let (bad_cursor, _) = send(&app, "GET", "/security-events?before=soon", &auth, "").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(events.as_array().unwrap().len(), 2);
+        assert_eq!(events[0]["kind"], "login_failed");
+        assert_eq!(events[0]["detail"], "invalid_credentials");
+        assert_eq!(events[1]["kind"], "signup");
+        assert_eq!(first_page.as_array().unwrap().len(), 1);
+        assert_eq!(bad_cursor, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_account_routes_require_a_session() {
+        let (app, _, _) = setup_router().await;
+
+        let (status, _) = send(&app, "GET", "/export", "Bearer skr_pat_not-a-session", "").await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_update_notification_preferences() {
+        let (app, auth, _) = setup_router().await;
+
+        let (_, defaults) = send(&app, "GET", "/notifications", &auth, "").await;
+        let (updated, body) = send(
+            &app,
+            "PUT",
+            "/notifications",
+            &auth,
+            r#"{"reminders":false,"digests":true,"marketing":true}"#,
+        )
+        .await;
+        let (incomplete, _) = send(
+            &app,
+            "PUT",
+            "/notifications",
+            &auth,
+            r#"{"reminders":true}"#,
+        )
+        .await;
+        let (_, saved) = send(&app, "GET", "/notifications", &auth, "").await;
+
+        assert_eq!(
+            defaults,
+            serde_json::json!({"security": true, "reminders": true, "digests": true, "marketing": false})
+        );
+        assert_eq!(updated, StatusCode::OK);
+        assert_eq!(incomplete, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(saved, body);
+        assert_eq!(saved["reminders"], false);
+        assert_eq!(saved["security"], true);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_needs_a_valid_token_but_no_session() {
+        let (app, auth, user_id) = setup_router().await;
+        let token = UnsubscribeToken {
+            user_id,
+            category: EmailCategory::Reminders,
+        }
+        .sign("unsubscribe-secret");
+
+        let (unsubscribed, _) = send(
+            &app,
+            "POST",
+            &format!("/notifications/unsubscribe?token={token}"),
+            "",
+            "List-Unsubscribe=One-Click",
+        )
+        .await;
+        let (forged, _) = send(
+            &app,
+            "POST",
+            &format!(
+                "/notifications/unsubscribe?token={}",
+                token.replace('.', "x")
+            ),
+            "",
+            "",
+        )
+        .await;
+        let (_, preferences) = send(&app, "GET", "/notifications", &auth, "").await;
+
+        assert_eq!(unsubscribed, StatusCode::NO_CONTENT);
+        assert_eq!(forged, StatusCode::BAD_REQUEST);
+        assert_eq!(preferences["reminders"], false);
+    }
+}
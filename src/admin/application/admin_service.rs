@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use tracing::info;
+
+use crate::{
+    admin::{
+        application::errors::AdminServiceError,
+        domain::{
+            entities::{
+                email_suppression::{EmailSuppression, normalize_email},
+                managed_user::{ManagedUser, UserPage, UserSearch},
+                queue_health::QueueHealth,
+            },
+            repositories::admin_repository::IAdminRepository,
+        },
+    },
+    shared::domain::{
+        audit::{AuditEntry, AuditEvent, AuditEventKind, AuditQuery, IAuditLog},
+        value_objects::UserUuid,
+    },
+};
+
+pub struct AdminService {
+    repo: Box<dyn IAdminRepository>,
+    audit_log: Arc<dyn IAuditLog>,
+}
+
+impl AdminService {
+    pub fn new(repo: Box<dyn IAdminRepository>, audit_log: Arc<dyn IAuditLog>) -> Self {
+        Self { repo, audit_log }
+    }
+
+    pub async fn search_users(&self, search: &UserSearch) -> Result<UserPage, AdminServiceError> {
+        Ok(self.repo.search_users(search).await?)
+    }
+
+    /// Every request of a disabled user is rejected from then on, whatever
+    /// tokens they hold.
+    pub async fn disable_user(
+        &self,
+        admin_id: UserUuid,
+        user_id: UserUuid,
+    ) -> Result<ManagedUser, AdminServiceError> {
+        if admin_id == user_id {
+            return Err(AdminServiceError::CannotDisableSelf);
+        }
+        let user = self.set_account_disabled(user_id, true).await?;
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::AccountDisabled, Some(user_id)).by(admin_id))
+            .await;
+        info!(admin_id = %admin_id, user_id = %user_id, "Account disabled by admin");
+        Ok(user)
+    }
+
+    pub async fn enable_user(
+        &self,
+        admin_id: UserUuid,
+        user_id: UserUuid,
+    ) -> Result<ManagedUser, AdminServiceError> {
+        let user = self.set_account_disabled(user_id, false).await?;
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::AccountEnabled, Some(user_id)).by(admin_id))
+            .await;
+        info!(admin_id = %admin_id, user_id = %user_id, "Account enabled by admin");
+        Ok(user)
+    }
+
+    pub async fn verify_email(
+        &self,
+        admin_id: UserUuid,
+        user_id: UserUuid,
+    ) -> Result<ManagedUser, AdminServiceError> {
+        if !self.repo.mark_email_verified(user_id).await? {
+            return Err(AdminServiceError::UserNotFound);
+        }
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::EmailVerified, Some(user_id)).by(admin_id))
+            .await;
+        info!(admin_id = %admin_id, user_id = %user_id, "Email verified by admin");
+        self.get_user(user_id).await
+    }
+
+    pub async fn queue_health(&self) -> Result<QueueHealth, AdminServiceError> {
+        Ok(self.repo.queue_health().await?)
+    }
+
+    pub async fn retry_failed_scraper_jobs(
+        &self,
+        admin_id: UserUuid,
+    ) -> Result<u64, AdminServiceError> {
+        let retried = self.repo.retry_failed_scraper_jobs().await?;
+        self.audit_log
+            .record(
+                AuditEntry::new(AuditEventKind::AdminAction, None)
+                    .by(admin_id)
+                    .detail(format!("retried {retried} failed scraper jobs")),
+            )
+            .await;
+        info!(admin_id = %admin_id, retried, "Failed scraper jobs re-driven");
+        Ok(retried)
+    }
+
+    pub async fn redrive_dead_emails(&self, admin_id: UserUuid) -> Result<u64, AdminServiceError> {
+        let redriven = self.repo.redrive_dead_emails().await?;
+        self.audit_log
+            .record(
+                AuditEntry::new(AuditEventKind::AdminAction, None)
+                    .by(admin_id)
+                    .detail(format!("re-drove {redriven} dead emails")),
+            )
+            .await;
+        info!(admin_id = %admin_id, redriven, "Dead emails re-driven");
+        Ok(redriven)
+    }
+
+    pub async fn list_email_suppressions(
+        &self,
+    ) -> Result<Vec<EmailSuppression>, AdminServiceError> {
+        Ok(self.repo.list_email_suppressions().await?)
+    }
+
+    /// Stops every email but security ones to the suppressed address.
+    pub async fn suppress_email(
+        &self,
+        admin_id: UserUuid,
+        suppression: EmailSuppression,
+    ) -> Result<EmailSuppression, AdminServiceError> {
+        let suppression = self.repo.add_email_suppression(&suppression).await?;
+        self.audit_log
+            .record(
+                AuditEntry::new(AuditEventKind::AdminAction, None)
+                    .by(admin_id)
+                    .detail(format!("suppressed emails to {}", suppression.email)),
+            )
+            .await;
+        info!(admin_id = %admin_id, "Email address suppressed");
+        Ok(suppression)
+    }
+
+    pub async fn unsuppress_email(
+        &self,
+        admin_id: UserUuid,
+        email: &str,
+    ) -> Result<(), AdminServiceError> {
+        let email = normalize_email(email);
+        if !self.repo.remove_email_suppression(&email).await? {
+            return Err(AdminServiceError::SuppressionNotFound);
+        }
+        self.audit_log
+            .record(
+                AuditEntry::new(AuditEventKind::AdminAction, None)
+                    .by(admin_id)
+                    .detail(format!("lifted the suppression of {email}")),
+            )
+            .await;
+        info!(admin_id = %admin_id, "Email suppression lifted");
+        Ok(())
+    }
+
+    /// Audit events of every user, newest first.
+    pub async fn search_audit_events(
+        &self,
+        query: &AuditQuery,
+    ) -> Result<Vec<AuditEvent>, AdminServiceError> {
+        Ok(self.audit_log.search(query).await?)
+    }
+
+    async fn set_account_disabled(
+        &self,
+        user_id: UserUuid,
+        disabled: bool,
+    ) -> Result<ManagedUser, AdminServiceError> {
+        if !self.repo.set_account_disabled(user_id, disabled).await? {
+            return Err(AdminServiceError::UserNotFound);
+        }
+        self.get_user(user_id).await
+    }
+
+    async fn get_user(&self, user_id: UserUuid) -> Result<ManagedUser, AdminServiceError> {
+        self.repo
+            .get_user(user_id)
+            .await?
+            .ok_or(AdminServiceError::UserNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        admin::infrastructure::persistence::repositories::admin_in_memory_repository::AdminInMemoryRepository,
+        shared::{
+            domain::value_objects::UserRole,
+            infrastructure::audit::audit_in_memory_log::AuditInMemoryLog,
+        },
+    };
+
+    async fn setup() -> (AdminService, AdminInMemoryRepository, UserUuid, UserUuid) {
+        let repo = AdminInMemoryRepository::default();
+        let admin_id = repo.add_user("admin@example.com", UserRole::Admin).await;
+        let user_id = repo.add_user("jane@example.com", UserRole::User).await;
+        (
+            AdminService::new(Box::new(repo.clone()), Arc::new(AuditInMemoryLog::new())),
+            repo,
+            admin_id,
+            user_id,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_disable_and_enable_user() {
+        let (service, _, admin_id, user_id) = setup().await;
+
+        let disabled = service.disable_user(admin_id, user_id).await.unwrap();
+        let enabled = service.enable_user(admin_id, user_id).await.unwrap();
+        let events = service
+            .search_audit_events(&AuditQuery::new(Some(user_id), None, None, None))
+            .await
+            .unwrap();
+
+        assert!(disabled.account_disabled);
+        assert!(!enabled.account_disabled);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, AuditEventKind::AccountEnabled);
+        assert_eq!(events[1].kind, AuditEventKind::AccountDisabled);
+        assert_eq!(events[1].actor_id, Some(admin_id));
+    }
+
+    #[tokio::test]
+    async fn test_admins_cannot_disable_themselves() {
+        let (service, _, admin_id, _) = setup().await;
+
+        let result = service.disable_user(admin_id, admin_id).await;
+
+        assert_eq!(result, Err(AdminServiceError::CannotDisableSelf));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_users_are_not_found() {
+        let (service, _, admin_id, _) = setup().await;
+
+        let disable = service.disable_user(admin_id, UserUuid::new()).await;
+        let verify = service.verify_email(admin_id, UserUuid::new()).await;
+
+        assert_eq!(disable, Err(AdminServiceError::UserNotFound));
+        assert_eq!(verify, Err(AdminServiceError::UserNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_verify_email() {
+        let (service, _, admin_id, user_id) = setup().await;
+
+        let user = service.verify_email(admin_id, user_id).await.unwrap();
+
+        assert!(user.email_verified);
+    }
+
+    #[tokio::test]
+    async fn test_search_users_pages_newest_first() {
+        let (service, _, _, _) = setup().await;
+
+        let page = service
+            .search_users(&UserSearch::new(None, Some(1), Some(0)))
+            .await
+            .unwrap();
+        let matching = service
+            .search_users(&UserSearch::new(Some("JANE".to_string()), None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 2);
+        assert_eq!(page.users.len(), 1);
+        assert_eq!(page.users[0].email, "jane@example.com");
+        assert_eq!(matching.total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_failed_scraper_jobs() {
+        let (service, repo, admin_id, _) = setup().await;
+        repo.add_failed_scraper_jobs(2).await;
+
+        let retried = service.retry_failed_scraper_jobs(admin_id).await.unwrap();
+        let health = service.queue_health().await.unwrap();
+
+        assert_eq!(retried, 2);
+        assert_eq!(health.scraper.failed, 0);
+        assert_eq!(health.scraper.pending, 2);
+    }
+
+    #[tokio::test]
+    async fn test_redrive_dead_emails() {
+        let (service, repo, admin_id, _) = setup().await;
+        repo.add_pending_emails(4).await;
+        repo.add_dead_emails(2).await;
+
+        let redriven = service.redrive_dead_emails(admin_id).await.unwrap();
+        let health = service.queue_health().await.unwrap();
+
+        assert_eq!(redriven, 2);
+        assert_eq!(health.email.pending, 6);
+        assert_eq!(health.email.dead, 0);
+    }
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::{admin::domain::errors::AdminRepoError, shared::domain::errors::SharedDomainError};
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum AdminServiceError {
+    #[error("Repository error: `{0}`")]
+    RepositoryError(#[from] AdminRepoError),
+
+    #[error("Audit log error: `{0}`")]
+    AuditLogError(#[from] SharedDomainError),
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Admins cannot disable their own account")]
+    CannotDisableSelf,
+
+    #[error("Email address is not suppressed")]
+    SuppressionNotFound,
+}
@@ -0,0 +1,2 @@
+pub mod admin_service;
+pub mod errors;
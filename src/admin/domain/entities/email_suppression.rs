@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+
+use crate::shared::domain::errors::SharedDomainError;
+
+/// An address the email worker sends nothing but security emails to, e.g.
+/// after it bounced or its owner reported us as spam.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailSuppression {
+    /// Lowercased, as the worker compares it.
+    pub email: String,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EmailSuppression {
+    pub fn new(email: &str, reason: &str, now: DateTime<Utc>) -> Result<Self, SharedDomainError> {
+        let email = normalize_email(email);
+        if !email.contains('@') {
+            return Err(SharedDomainError::InvalidEmail(email));
+        }
+        Ok(Self {
+            email,
+            reason: reason.trim().to_string(),
+            created_at: now,
+        })
+    }
+}
+
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suppressed_addresses_are_lowercased() {
+        let suppression = EmailSuppression::new(" Bounced@Example.COM ", "hard bounce", Utc::now());
+
+        assert_eq!(
+            suppression.map(|suppression| suppression.email),
+            Ok("bounced@example.com".to_string())
+        );
+        assert_eq!(
+            EmailSuppression::new("not-an-address", "", Utc::now()),
+            Err(SharedDomainError::InvalidEmail(
+                "not-an-address".to_string()
+            ))
+        );
+    }
+}
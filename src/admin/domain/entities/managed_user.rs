@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+
+use crate::shared::domain::value_objects::{UserRole, UserUuid};
+
+/// A user as seen by an admin: status flags only, never credentials.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManagedUser {
+    pub id: UserUuid,
+    pub email: String,
+    pub role: UserRole,
+    pub email_verified: bool,
+    pub account_disabled: bool,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+pub const DEFAULT_USER_PAGE_SIZE: i64 = 50;
+pub const MAX_USER_PAGE_SIZE: i64 = 200;
+
+/// Case-insensitive substring match on the email, newest accounts first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSearch {
+    pub query: Option<String>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl UserSearch {
+    pub fn new(query: Option<String>, limit: Option<i64>, offset: Option<i64>) -> Self {
+        Self {
+            query: query
+                .map(|query| query.trim().to_string())
+                .filter(|query| !query.is_empty()),
+            limit: limit
+                .unwrap_or(DEFAULT_USER_PAGE_SIZE)
+                .clamp(1, MAX_USER_PAGE_SIZE),
+            offset: offset.unwrap_or(0).max(0),
+        }
+    }
+
+    pub fn matches(&self, email: &str) -> bool {
+        self.query
+            .as_ref()
+            .is_none_or(|query| email.to_lowercase().contains(&query.to_lowercase()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserPage {
+    pub users: Vec<ManagedUser>,
+    /// Matches before paging.
+    pub total: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_search_is_clamped() {
+        let defaults = UserSearch::new(None, None, None);
+        let clamped = UserSearch::new(Some("   ".to_string()), Some(10_000), Some(-5));
+
+        assert_eq!(defaults.limit, DEFAULT_USER_PAGE_SIZE);
+        assert_eq!(defaults.offset, 0);
+        assert_eq!(clamped.query, None);
+        assert_eq!(clamped.limit, MAX_USER_PAGE_SIZE);
+        assert_eq!(clamped.offset, 0);
+        assert_eq!(UserSearch::new(None, Some(0), None).limit, 1);
+    }
+
+    #[test]
+    fn test_user_search_matches_email_substrings() {
+        let search = UserSearch::new(Some(" Example.COM ".to_string()), None, None);
+
+        assert!(search.matches("jane@example.com"));
+        assert!(!search.matches("jane@example.org"));
+        assert!(UserSearch::new(None, None, None).matches("anyone@example.org"));
+    }
+}
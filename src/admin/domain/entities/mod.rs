@@ -0,0 +1,3 @@
+pub mod email_suppression;
+pub mod managed_user;
+pub mod queue_health;
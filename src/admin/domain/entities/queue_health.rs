@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+
+/// Emails are sent (`processed`), waiting for a first send or a retry
+/// (`pending`), or `dead` once the worker gave up on them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmailQueueHealth {
+    pub pending: i64,
+    pub processed: i64,
+    pub dead: i64,
+    pub oldest_pending_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScraperQueueHealth {
+    pub pending: i64,
+    pub processing: i64,
+    pub completed: i64,
+    pub failed: i64,
+    pub oldest_pending_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueueHealth {
+    pub email: EmailQueueHealth,
+    pub scraper: ScraperQueueHealth,
+}
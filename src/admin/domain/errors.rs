@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum AdminRepoError {
+    #[error("Database error: `{0}`")]
+    DatabaseError(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_repo_database_error() {
+        let error = AdminRepoError::DatabaseError("connection failed".to_string());
+        assert_eq!(error.to_string(), "Database error: `connection failed`");
+    }
+}
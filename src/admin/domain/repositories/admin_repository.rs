@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::admin::domain::{
+    entities::{
+        email_suppression::EmailSuppression,
+        managed_user::{ManagedUser, UserPage, UserSearch},
+        queue_health::QueueHealth,
+    },
+    errors::AdminRepoError,
+};
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IAdminRepository: Send + Sync {
+    async fn search_users(&self, search: &UserSearch) -> Result<UserPage, AdminRepoError>;
+    async fn get_user(&self, user_id: UserUuid) -> Result<Option<ManagedUser>, AdminRepoError>;
+    /// Returns `false` when the user does not exist.
+    async fn set_account_disabled(
+        &self,
+        user_id: UserUuid,
+        disabled: bool,
+    ) -> Result<bool, AdminRepoError>;
+    /// Returns `false` when the user does not exist.
+    async fn mark_email_verified(&self, user_id: UserUuid) -> Result<bool, AdminRepoError>;
+    async fn queue_health(&self) -> Result<QueueHealth, AdminRepoError>;
+    /// Puts failed scraper jobs back to pending. Returns how many.
+    async fn retry_failed_scraper_jobs(&self) -> Result<u64, AdminRepoError>;
+    /// Gives dead emails a fresh set of attempts and announces them to the
+    /// email worker. Returns how many.
+    async fn redrive_dead_emails(&self) -> Result<u64, AdminRepoError>;
+    /// Newest first.
+    async fn list_email_suppressions(&self) -> Result<Vec<EmailSuppression>, AdminRepoError>;
+    /// Updates the reason of an address already suppressed, and returns the
+    /// stored suppression.
+    async fn add_email_suppression(
+        &self,
+        suppression: &EmailSuppression,
+    ) -> Result<EmailSuppression, AdminRepoError>;
+    /// Returns `false` when the address was not suppressed.
+    async fn remove_email_suppression(&self, email: &str) -> Result<bool, AdminRepoError>;
+}
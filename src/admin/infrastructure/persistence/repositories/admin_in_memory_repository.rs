@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use tokio::sync::RwLock;
+
+use crate::{
+    admin::domain::{
+        entities::{
+            email_suppression::EmailSuppression,
+            managed_user::{ManagedUser, UserPage, UserSearch},
+            queue_health::QueueHealth,
+        },
+        errors::AdminRepoError,
+        repositories::admin_repository::IAdminRepository,
+    },
+    shared::domain::value_objects::{UserRole, UserUuid},
+};
+
+#[derive(Clone, Default)]
+pub struct AdminInMemoryRepository {
+    users: Arc<RwLock<Vec<ManagedUser>>>,
+    queues: Arc<RwLock<QueueHealth>>,
+    suppressions: Arc<RwLock<Vec<EmailSuppression>>>,
+}
+
+impl AdminInMemoryRepository {
+    /// Each user added is newer than the previous one.
+    pub async fn add_user(&self, email: &str, role: UserRole) -> UserUuid {
+        let mut users = self.users.write().await;
+        let user = ManagedUser {
+            id: UserUuid::new(),
+            email: email.to_string(),
+            role,
+            email_verified: false,
+            account_disabled: false,
+            created_at: Some(Utc::now() + TimeDelta::seconds(users.len() as i64)),
+        };
+        let user_id = user.id;
+        users.push(user);
+        user_id
+    }
+
+    pub async fn add_failed_scraper_jobs(&self, count: i64) {
+        self.queues.write().await.scraper.failed += count;
+    }
+
+    pub async fn add_pending_emails(&self, count: i64) {
+        self.queues.write().await.email.pending += count;
+    }
+
+    pub async fn add_dead_emails(&self, count: i64) {
+        self.queues.write().await.email.dead += count;
+    }
+
+    async fn update_user(&self, user_id: UserUuid, update: impl FnOnce(&mut ManagedUser)) -> bool {
+        let mut users = self.users.write().await;
+        match users.iter_mut().find(|user| user.id == user_id) {
+            Some(user) => {
+                update(user);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl IAdminRepository for AdminInMemoryRepository {
+    async fn search_users(&self, search: &UserSearch) -> Result<UserPage, AdminRepoError> {
+        let mut matching: Vec<ManagedUser> = self
+            .users
+            .read()
+            .await
+            .iter()
+            .filter(|user| search.matches(&user.email))
+            .cloned()
+            .collect();
+        matching.sort_by_key(|user| std::cmp::Reverse(user.created_at));
+        Ok(UserPage {
+            total: matching.len() as i64,
+            users: matching
+                .into_iter()
+                .skip(search.offset as usize)
+                .take(search.limit as usize)
+                .collect(),
+        })
+    }
+
+    async fn get_user(&self, user_id: UserUuid) -> Result<Option<ManagedUser>, AdminRepoError> {
+        Ok(self
+            .users
+            .read()
+            .await
+            .iter()
+            .find(|user| user.id == user_id)
+            .cloned())
+    }
+
+    async fn set_account_disabled(
+        &self,
+        user_id: UserUuid,
+        disabled: bool,
+    ) -> Result<bool, AdminRepoError> {
+        Ok(self
+            .update_user(user_id, |user| user.account_disabled = disabled)
+            .await)
+    }
+
+    async fn mark_email_verified(&self, user_id: UserUuid) -> Result<bool, AdminRepoError> {
+        Ok(self
+            .update_user(user_id, |user| user.email_verified = true)
+            .await)
+    }
+
+    async fn queue_health(&self) -> Result<QueueHealth, AdminRepoError> {
+        Ok(self.queues.read().await.clone())
+    }
+
+    async fn retry_failed_scraper_jobs(&self) -> Result<u64, AdminRepoError> {
+        let mut queues = self.queues.write().await;
+        let failed = queues.scraper.failed;
+        queues.scraper.pending += failed;
+        queues.scraper.failed = 0;
+        Ok(failed as u64)
+    }
+
+    async fn redrive_dead_emails(&self) -> Result<u64, AdminRepoError> {
+        let mut queues = self.queues.write().await;
+        let dead = queues.email.dead;
+        queues.email.pending += dead;
+        queues.email.dead = 0;
+        Ok(dead as u64)
+    }
+
+    async fn list_email_suppressions(&self) -> Result<Vec<EmailSuppression>, AdminRepoError> {
+        let mut suppressions = self.suppressions.read().await.clone();
+        suppressions.sort_by_key(|suppression| std::cmp::Reverse(suppression.created_at));
+        Ok(suppressions)
+    }
+
+    async fn add_email_suppression(
+        &self,
+        suppression: &EmailSuppression,
+    ) -> Result<EmailSuppression, AdminRepoError> {
+        let mut suppressions = self.suppressions.write().await;
+        match suppressions
+            .iter_mut()
+            .find(|existing| existing.email == suppression.email)
+        {
+            Some(existing) => {
+                existing.reason = suppression.reason.clone();
+                Ok(existing.clone())
+            }
+            None => {
+                suppressions.push(suppression.clone());
+                Ok(suppression.clone())
+            }
+        }
+    }
+
+    async fn remove_email_suppression(&self, email: &str) -> Result<bool, AdminRepoError> {
+        let mut suppressions = self.suppressions.write().await;
+        let before = suppressions.len();
+        suppressions.retain(|suppression| suppression.email != email);
+        Ok(suppressions.len() < before)
+    }
+}
@@ -0,0 +1,411 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    admin::domain::{
+        entities::{
+            email_suppression::EmailSuppression,
+            managed_user::{ManagedUser, UserPage, UserSearch},
+            queue_health::{EmailQueueHealth, QueueHealth, ScraperQueueHealth},
+        },
+        errors::AdminRepoError,
+        repositories::admin_repository::IAdminRepository,
+    },
+    shared::domain::value_objects::{UserRole, UserUuid},
+};
+
+struct ManagedUserRow {
+    id: Uuid,
+    email: String,
+    role: String,
+    email_validated: bool,
+    account_disabled: bool,
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<ManagedUserRow> for ManagedUser {
+    type Error = AdminRepoError;
+
+    fn try_from(row: ManagedUserRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: UserUuid::from_uuid(row.id),
+            email: row.email,
+            role: UserRole::from_str(&row.role)
+                .map_err(|e| AdminRepoError::DatabaseError(e.to_string()))?,
+            email_verified: row.email_validated,
+            account_disabled: row.account_disabled,
+            created_at: row.created_at,
+        })
+    }
+}
+
+pub struct AdminPostgresRepository {
+    pool: PgPool,
+}
+
+impl AdminPostgresRepository {
+    pub async fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn database_error(operation: &'static str, e: sqlx::Error) -> AdminRepoError {
+    error!(
+        error_kind = "database_error",
+        error = %e,
+        "admin_repo.{} failed",
+        operation
+    );
+    AdminRepoError::DatabaseError(e.to_string())
+}
+
+#[async_trait]
+impl IAdminRepository for AdminPostgresRepository {
+    async fn search_users(&self, search: &UserSearch) -> Result<UserPage, AdminRepoError> {
+        // strpos rather than LIKE, so `%` and `_` in the query match literally.
+        let users = sqlx::query_as!(
+            ManagedUserRow,
+            "SELECT id, email, role, email_validated, account_disabled, created_at FROM users WHERE $1::text IS NULL OR strpos(lower(email), lower($1)) > 0 ORDER BY created_at DESC, id LIMIT $2 OFFSET $3",
+            search.query.as_deref(),
+            search.limit,
+            search.offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("search_users", e))?;
+
+        let total = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM users WHERE $1::text IS NULL OR strpos(lower(email), lower($1)) > 0"#,
+            search.query.as_deref()
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("search_users", e))?;
+
+        Ok(UserPage {
+            users: users
+                .into_iter()
+                .map(ManagedUser::try_from)
+                .collect::<Result<_, _>>()?,
+            total,
+        })
+    }
+
+    async fn get_user(&self, user_id: UserUuid) -> Result<Option<ManagedUser>, AdminRepoError> {
+        sqlx::query_as!(
+            ManagedUserRow,
+            "SELECT id, email, role, email_validated, account_disabled, created_at FROM users WHERE id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get_user", e))?
+        .map(ManagedUser::try_from)
+        .transpose()
+    }
+
+    async fn set_account_disabled(
+        &self,
+        user_id: UserUuid,
+        disabled: bool,
+    ) -> Result<bool, AdminRepoError> {
+        let result = sqlx::query!(
+            "UPDATE users SET account_disabled = $1, updated_at = NOW() WHERE id = $2",
+            disabled,
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("set_account_disabled", e))?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn mark_email_verified(&self, user_id: UserUuid) -> Result<bool, AdminRepoError> {
+        let result = sqlx::query!(
+            "UPDATE users SET email_validated = TRUE, updated_at = NOW() WHERE id = $1",
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("mark_email_verified", e))?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn queue_health(&self) -> Result<QueueHealth, AdminRepoError> {
+        let email = sqlx::query!(
+            r#"SELECT COUNT(*) FILTER (WHERE processed IS NOT TRUE AND NOT dead) AS "pending!", COUNT(*) FILTER (WHERE processed) AS "processed!", COUNT(*) FILTER (WHERE dead) AS "dead!", MIN(created_at) FILTER (WHERE processed IS NOT TRUE AND NOT dead) AS oldest_pending_at FROM email_queue"#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("queue_health", e))?;
+
+        let scraper = sqlx::query!(
+            r#"SELECT COUNT(*) FILTER (WHERE status = 'PENDING') AS "pending!", COUNT(*) FILTER (WHERE status = 'PROCESSING') AS "processing!", COUNT(*) FILTER (WHERE status = 'COMPLETED') AS "completed!", COUNT(*) FILTER (WHERE status = 'FAILED') AS "failed!", MIN(created_at) FILTER (WHERE status = 'PENDING') AS oldest_pending_at FROM scraper_queue"#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("queue_health", e))?;
+
+        Ok(QueueHealth {
+            email: EmailQueueHealth {
+                pending: email.pending,
+                processed: email.processed,
+                dead: email.dead,
+                oldest_pending_at: email.oldest_pending_at,
+            },
+            scraper: ScraperQueueHealth {
+                pending: scraper.pending,
+                processing: scraper.processing,
+                completed: scraper.completed,
+                failed: scraper.failed,
+                oldest_pending_at: scraper.oldest_pending_at,
+            },
+        })
+    }
+
+    async fn retry_failed_scraper_jobs(&self) -> Result<u64, AdminRepoError> {
+        // The scraper worker polls for pending jobs, so this is all it takes.
+        let result = sqlx::query!(
+            "UPDATE scraper_queue SET status = 'PENDING', error_message = NULL, started_at = NULL, updated_at = NOW() WHERE status = 'FAILED'"
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("retry_failed_scraper_jobs", e))?;
+        Ok(result.rows_affected())
+    }
+
+    async fn redrive_dead_emails(&self) -> Result<u64, AdminRepoError> {
+        // The email worker would find them on its next poll; announcing them
+        // as the insert trigger does gets them sent right away. `pg_notify`
+        // returns void, which is cast to count the notified rows.
+        let redriven = sqlx::query_scalar!(
+            r#"WITH redriven AS (
+                UPDATE email_queue SET dead = false, dead_at = NULL, attempt_count = 0, next_attempt_at = NOW(), sending_at = NULL
+                WHERE dead RETURNING id
+            )
+            SELECT COUNT(pg_notify('email_queue', json_build_object('id', id)::text)::text) AS "redriven!" FROM redriven"#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("redrive_dead_emails", e))?;
+        Ok(redriven as u64)
+    }
+
+    async fn list_email_suppressions(&self) -> Result<Vec<EmailSuppression>, AdminRepoError> {
+        let rows = sqlx::query!(
+            "SELECT email, reason, created_at FROM email_suppressions ORDER BY created_at DESC, email"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list_email_suppressions", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| EmailSuppression {
+                email: row.email,
+                reason: row.reason,
+                created_at: row.created_at.and_utc(),
+            })
+            .collect())
+    }
+
+    async fn add_email_suppression(
+        &self,
+        suppression: &EmailSuppression,
+    ) -> Result<EmailSuppression, AdminRepoError> {
+        let created_at = sqlx::query_scalar!(
+            "INSERT INTO email_suppressions (email, reason, created_at) VALUES ($1, $2, $3) ON CONFLICT (email) DO UPDATE SET reason = EXCLUDED.reason RETURNING created_at",
+            suppression.email,
+            suppression.reason,
+            suppression.created_at.naive_utc()
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("add_email_suppression", e))?;
+
+        Ok(EmailSuppression {
+            created_at: created_at.and_utc(),
+            ..suppression.clone()
+        })
+    }
+
+    async fn remove_email_suppression(&self, email: &str) -> Result<bool, AdminRepoError> {
+        let result = sqlx::query!("DELETE FROM email_suppressions WHERE email = $1", email)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| database_error("remove_email_suppression", e))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_disable_verify_and_find_users() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repo = AdminPostgresRepository::new(factory.pool.clone()).await;
+
+        let disabled = repo.set_account_disabled(user.id, true).await.unwrap();
+        let verified = repo.mark_email_verified(user.id).await.unwrap();
+        let unknown = repo
+            .set_account_disabled(UserUuid::new(), true)
+            .await
+            .unwrap();
+        let found = repo.get_user(user.id).await.unwrap().unwrap();
+        let page = repo
+            .search_users(&UserSearch::new(
+                Some(user.email.value().to_uppercase()),
+                None,
+                None,
+            ))
+            .await
+            .unwrap();
+
+        assert!(disabled);
+        assert!(verified);
+        assert!(!unknown);
+        assert!(found.account_disabled);
+        assert!(found.email_verified);
+        assert_eq!(found.role, UserRole::User);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.users, vec![found]);
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_failed_scraper_jobs_are_retried() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repo = AdminPostgresRepository::new(factory.pool.clone()).await;
+        let position_id = Uuid::new_v4();
+        sqlx::query!(
+            "INSERT INTO positions (id, user_id, company, role_title, description, applied_on, url, status) VALUES ($1, $2, 'Acme', 'Engineer', '', CURRENT_DATE, 'https://example.com', 'CvSent')",
+            position_id,
+            user.id.value()
+        )
+        .execute(&factory.pool)
+        .await
+        .unwrap();
+        let job_id: Uuid = sqlx::query_scalar!(
+            "INSERT INTO scraper_queue (url, user_id, position_id, status, error_message) VALUES ('https://example.com', $1, $2, 'FAILED', 'timeout') RETURNING id",
+            user.id.value(),
+            position_id
+        )
+        .fetch_one(&factory.pool)
+        .await
+        .unwrap();
+
+        let retried = repo.retry_failed_scraper_jobs().await.unwrap();
+        let job = sqlx::query!(
+            r#"SELECT status::text AS "status!", error_message FROM scraper_queue WHERE id = $1"#,
+            job_id
+        )
+        .fetch_one(&factory.pool)
+        .await
+        .unwrap();
+
+        assert!(retried >= 1);
+        assert_eq!(job.status, "PENDING");
+        assert_eq!(job.error_message, None);
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_dead_emails_are_redriven() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repo = AdminPostgresRepository::new(factory.pool.clone()).await;
+        sqlx::query!(
+            "INSERT INTO email_queue (payload, user_id) VALUES ($1, $2)",
+            serde_json::json!({"to": user.email.value(), "subject": "Hi", "body": "Hello"}),
+            user.id.value()
+        )
+        .execute(&factory.pool)
+        .await
+        .unwrap();
+        let dead_id = sqlx::query_scalar!(
+            "INSERT INTO email_queue (payload, user_id, attempt_count, last_error, dead, dead_at) VALUES ($1, $2, 8, 'timeout', true, NOW()) RETURNING id",
+            serde_json::json!({"to": user.email.value(), "subject": "Hi", "body": "Hello"}),
+            user.id.value()
+        )
+        .fetch_one(&factory.pool)
+        .await
+        .unwrap();
+        assert!(repo.queue_health().await.unwrap().email.dead >= 1);
+
+        let mut listener = sqlx::postgres::PgListener::connect_with(&factory.pool)
+            .await
+            .unwrap();
+        listener.listen("email_queue").await.unwrap();
+
+        let redriven = repo.redrive_dead_emails().await.unwrap();
+        // Other tests queue emails too, so wait for the one about this job.
+        let notified = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                let notification = listener.recv().await.unwrap();
+                if notification.payload().contains(&dead_id.to_string()) {
+                    break true;
+                }
+            }
+        })
+        .await;
+        let health = repo.queue_health().await.unwrap();
+        let revived = sqlx::query!(
+            "SELECT dead, attempt_count FROM email_queue WHERE id = $1",
+            dead_id
+        )
+        .fetch_one(&factory.pool)
+        .await
+        .unwrap();
+
+        assert!(redriven >= 1);
+        assert_eq!(notified, Ok(true));
+        assert!(health.email.pending >= 2);
+        assert!(health.email.oldest_pending_at.is_some());
+        assert!(!revived.dead);
+        assert_eq!(revived.attempt_count, 0);
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_email_suppressions_are_upserted_and_removed() {
+        let factory = TestFactory::new().await;
+        let repo = AdminPostgresRepository::new(factory.pool.clone()).await;
+        let email = format!("{}@bounced.example", Uuid::new_v4());
+        let suppression = EmailSuppression::new(&email, "hard bounce", Utc::now()).unwrap();
+
+        let added = repo.add_email_suppression(&suppression).await.unwrap();
+        let updated = repo
+            .add_email_suppression(&EmailSuppression {
+                reason: "spam complaint".to_string(),
+                ..suppression.clone()
+            })
+            .await
+            .unwrap();
+        let listed = repo.list_email_suppressions().await.unwrap();
+        let removed = repo.remove_email_suppression(&email).await.unwrap();
+        let removed_again = repo.remove_email_suppression(&email).await.unwrap();
+
+        assert_eq!(added.email, email);
+        assert_eq!(updated.reason, "spam complaint");
+        assert_eq!(updated.created_at, added.created_at);
+        assert!(listed.contains(&updated));
+        assert!(removed);
+        assert!(!removed_again);
+
+        factory.teardown().await;
+    }
+}
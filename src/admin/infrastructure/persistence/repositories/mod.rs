@@ -0,0 +1,2 @@
+pub mod admin_in_memory_repository;
+pub mod admin_postgres_repository;
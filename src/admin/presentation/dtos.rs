@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use utoipa::ToSchema;
+
+use crate::{
+    admin::domain::entities::{
+        email_suppression::EmailSuppression,
+        managed_user::{ManagedUser, UserPage, UserSearch},
+        queue_health::{EmailQueueHealth, QueueHealth, ScraperQueueHealth},
+    },
+    shared::{
+        domain::{
+            audit::{AuditEventKind, AuditQuery},
+            errors::SharedDomainError,
+            value_objects::UserUuid,
+        },
+        presentation::audit_dtos::parse_before,
+    },
+};
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct UserSearchQuery {
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+impl From<UserSearchQuery> for UserSearch {
+    fn from(query: UserSearchQuery) -> Self {
+        UserSearch::new(query.q, query.limit, query.offset)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ManagedUserDto {
+    pub id: String,
+    pub email: String,
+    /// `user` or `admin`
+    pub role: String,
+    pub email_verified: bool,
+    pub account_disabled: bool,
+    /// RFC 3339 timestamp
+    pub created_at: Option<String>,
+}
+
+impl From<ManagedUser> for ManagedUserDto {
+    fn from(user: ManagedUser) -> Self {
+        Self {
+            id: user.id.to_string(),
+            email: user.email,
+            role: user.role.to_string(),
+            email_verified: user.email_verified,
+            account_disabled: user.account_disabled,
+            created_at: user.created_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct UserPageDto {
+    /// Newest accounts first
+    pub users: Vec<ManagedUserDto>,
+    /// Matching users across all pages
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl UserPageDto {
+    pub fn new(page: UserPage, search: &UserSearch) -> Self {
+        Self {
+            users: page.users.into_iter().map(Into::into).collect(),
+            total: page.total,
+            limit: search.limit,
+            offset: search.offset,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EmailQueueHealthDto {
+    /// Not sent yet, failed sends waiting for a retry included
+    pub pending: i64,
+    pub processed: i64,
+    /// Failed too many times, no longer retried
+    pub dead: i64,
+    /// RFC 3339 timestamp
+    pub oldest_pending_at: Option<String>,
+}
+
+impl From<EmailQueueHealth> for EmailQueueHealthDto {
+    fn from(health: EmailQueueHealth) -> Self {
+        Self {
+            pending: health.pending,
+            processed: health.processed,
+            dead: health.dead,
+            oldest_pending_at: health.oldest_pending_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ScraperQueueHealthDto {
+    pub pending: i64,
+    pub processing: i64,
+    pub completed: i64,
+    pub failed: i64,
+    /// RFC 3339 timestamp
+    pub oldest_pending_at: Option<String>,
+}
+
+impl From<ScraperQueueHealth> for ScraperQueueHealthDto {
+    fn from(health: ScraperQueueHealth) -> Self {
+        Self {
+            pending: health.pending,
+            processing: health.processing,
+            completed: health.completed,
+            failed: health.failed,
+            oldest_pending_at: health.oldest_pending_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct QueueHealthDto {
+    pub email: EmailQueueHealthDto,
+    pub scraper: ScraperQueueHealthDto,
+}
+
+impl From<QueueHealth> for QueueHealthDto {
+    fn from(health: QueueHealth) -> Self {
+        Self {
+            email: health.email.into(),
+            scraper: health.scraper.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RedriveResultDto {
+    /// Jobs handed back to their worker
+    pub redriven: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EmailSuppressionDto {
+    pub email: String,
+    pub reason: String,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+}
+
+impl From<EmailSuppression> for EmailSuppressionDto {
+    fn from(suppression: EmailSuppression) -> Self {
+        Self {
+            email: suppression.email,
+            reason: suppression.reason,
+            created_at: suppression.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SuppressEmailDto {
+    /// Matched case-insensitively
+    pub email: String,
+    /// Why nothing but security emails should go there, e.g. `hard bounce`
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct AuditEventsQuery {
+    pub user_id: Option<String>,
+    pub kind: Option<String>,
+    pub before: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl TryFrom<AuditEventsQuery> for AuditQuery {
+    type Error = SharedDomainError;
+
+    fn try_from(query: AuditEventsQuery) -> Result<Self, Self::Error> {
+        Ok(AuditQuery::new(
+            query
+                .user_id
+                .as_deref()
+                .map(UserUuid::from_str)
+                .transpose()?,
+            query
+                .kind
+                .as_deref()
+                .map(AuditEventKind::from_str)
+                .transpose()?,
+            parse_before(query.before.as_deref())?,
+            query.limit,
+        ))
+    }
+}
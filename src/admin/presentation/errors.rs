@@ -0,0 +1,59 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use thiserror::Error;
+
+use crate::{
+    admin::application::errors::AdminServiceError,
+    shared::{domain::errors::SharedDomainError, presentation::ApiErrorResponse},
+};
+
+#[derive(Error, Debug)]
+pub enum AdminApiError {
+    #[error("Service error: `{0}`")]
+    ServiceError(#[from] AdminServiceError),
+
+    #[error("Domain error: `{0}`")]
+    SharedDomainError(#[from] SharedDomainError),
+}
+
+impl IntoResponse for AdminApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            AdminApiError::ServiceError(AdminServiceError::UserNotFound) => {
+                (StatusCode::NOT_FOUND, "User not found".to_string())
+            }
+            AdminApiError::ServiceError(AdminServiceError::CannotDisableSelf) => (
+                StatusCode::CONFLICT,
+                "Admins cannot disable their own account".to_string(),
+            ),
+            AdminApiError::ServiceError(AdminServiceError::SuppressionNotFound) => (
+                StatusCode::NOT_FOUND,
+                "Email address is not suppressed".to_string(),
+            ),
+            AdminApiError::ServiceError(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+            AdminApiError::SharedDomainError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
+        };
+
+        (status, Json(ApiErrorResponse { message })).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_not_found_response() {
+        let error = AdminApiError::from(AdminServiceError::UserNotFound);
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_cannot_disable_self_response() {
+        let error = AdminApiError::from(AdminServiceError::CannotDisableSelf);
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,327 @@
+use std::str::FromStr;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::Utc;
+
+use crate::{
+    admin::{
+        application::errors::AdminServiceError,
+        domain::entities::{email_suppression::EmailSuppression, managed_user::UserSearch},
+        presentation::{
+            dtos::{
+                AuditEventsQuery, EmailSuppressionDto, ManagedUserDto, QueueHealthDto,
+                RedriveResultDto, SuppressEmailDto, UserPageDto, UserSearchQuery,
+            },
+            errors::AdminApiError,
+            routes::AdminState,
+        },
+    },
+    shared::{
+        domain::{audit::AuditQuery, value_objects::UserUuid},
+        infrastructure::http::auth_extractor::AdminUser,
+        presentation::audit_dtos::AuditEventDto,
+    },
+};
+
+fn target_user(user_id: &str) -> Result<UserUuid, AdminApiError> {
+    UserUuid::from_str(user_id).map_err(|_| AdminServiceError::UserNotFound.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/users",
+    params(
+        ("q" = Option<String>, Query, description = "Case-insensitive part of the email"),
+        ("limit" = Option<i64>, Query, description = "Page size (default 50, max 200)"),
+        ("offset" = Option<i64>, Query, description = "Users to skip (default 0)")
+    ),
+    responses(
+        (status = 200, description = "Matching users, newest first", body = UserPageDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_users(
+    _admin: AdminUser,
+    State(state): State<AdminState>,
+    Query(query): Query<UserSearchQuery>,
+) -> Result<Json<UserPageDto>, AdminApiError> {
+    let search = UserSearch::from(query);
+    let page = state.service.search_users(&search).await?;
+    Ok(Json(UserPageDto::new(page, &search)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{id}/disable",
+    params(
+        ("id" = String, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Account disabled; every request of the user is rejected from now on", body = ManagedUserDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "Admins cannot disable their own account")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn disable_user(
+    AdminUser(admin_id): AdminUser,
+    State(state): State<AdminState>,
+    Path(user_id): Path<String>,
+) -> Result<Json<ManagedUserDto>, AdminApiError> {
+    let user = state
+        .service
+        .disable_user(UserUuid::from_str(&admin_id)?, target_user(&user_id)?)
+        .await?;
+    Ok(Json(user.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{id}/enable",
+    params(
+        ("id" = String, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Account enabled", body = ManagedUserDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn enable_user(
+    AdminUser(admin_id): AdminUser,
+    State(state): State<AdminState>,
+    Path(user_id): Path<String>,
+) -> Result<Json<ManagedUserDto>, AdminApiError> {
+    let user = state
+        .service
+        .enable_user(UserUuid::from_str(&admin_id)?, target_user(&user_id)?)
+        .await?;
+    Ok(Json(user.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{id}/verify-email",
+    params(
+        ("id" = String, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Email marked as verified", body = ManagedUserDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn verify_user_email(
+    AdminUser(admin_id): AdminUser,
+    State(state): State<AdminState>,
+    Path(user_id): Path<String>,
+) -> Result<Json<ManagedUserDto>, AdminApiError> {
+    let user = state
+        .service
+        .verify_email(UserUuid::from_str(&admin_id)?, target_user(&user_id)?)
+        .await?;
+    Ok(Json(user.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/queues",
+    responses(
+        (status = 200, description = "Backlog of the email and scraper queues", body = QueueHealthDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn get_queue_health(
+    _admin: AdminUser,
+    State(state): State<AdminState>,
+) -> Result<Json<QueueHealthDto>, AdminApiError> {
+    let health = state.service.queue_health().await?;
+    Ok(Json(health.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/queues/scraper/retry",
+    responses(
+        (status = 200, description = "Failed scraper jobs put back to pending", body = RedriveResultDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn retry_scraper_jobs(
+    AdminUser(admin_id): AdminUser,
+    State(state): State<AdminState>,
+) -> Result<Json<RedriveResultDto>, AdminApiError> {
+    let redriven = state
+        .service
+        .retry_failed_scraper_jobs(UserUuid::from_str(&admin_id)?)
+        .await?;
+    Ok(Json(RedriveResultDto { redriven }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/queues/email/redrive",
+    responses(
+        (status = 200, description = "Dead emails given new attempts and announced to the email worker", body = RedriveResultDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn redrive_emails(
+    AdminUser(admin_id): AdminUser,
+    State(state): State<AdminState>,
+) -> Result<Json<RedriveResultDto>, AdminApiError> {
+    let redriven = state
+        .service
+        .redrive_dead_emails(UserUuid::from_str(&admin_id)?)
+        .await?;
+    Ok(Json(RedriveResultDto { redriven }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/email-suppressions",
+    responses(
+        (status = 200, description = "Addresses only security emails are sent to, newest first", body = Vec<EmailSuppressionDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_email_suppressions(
+    _admin: AdminUser,
+    State(state): State<AdminState>,
+) -> Result<Json<Vec<EmailSuppressionDto>>, AdminApiError> {
+    let suppressions = state.service.list_email_suppressions().await?;
+    Ok(Json(suppressions.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/email-suppressions",
+    request_body = SuppressEmailDto,
+    responses(
+        (status = 200, description = "Address suppressed; suppressing it again updates the reason", body = EmailSuppressionDto),
+        (status = 400, description = "Invalid email address"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn suppress_email(
+    AdminUser(admin_id): AdminUser,
+    State(state): State<AdminState>,
+    Json(payload): Json<SuppressEmailDto>,
+) -> Result<Json<EmailSuppressionDto>, AdminApiError> {
+    let suppression = EmailSuppression::new(&payload.email, &payload.reason, Utc::now())?;
+    let suppression = state
+        .service
+        .suppress_email(UserUuid::from_str(&admin_id)?, suppression)
+        .await?;
+    Ok(Json(suppression.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/email-suppressions/{email}",
+    params(
+        ("email" = String, Path, description = "Suppressed email address")
+    ),
+    responses(
+        (status = 204, description = "Suppression lifted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "Email address is not suppressed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn unsuppress_email(
+    AdminUser(admin_id): AdminUser,
+    State(state): State<AdminState>,
+    Path(email): Path<String>,
+) -> Result<StatusCode, AdminApiError> {
+    state
+        .service
+        .unsuppress_email(UserUuid::from_str(&admin_id)?, &email)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/audit-events",
+    params(
+        ("user_id" = Option<String>, Query, description = "Only events about this user"),
+        ("kind" = Option<String>, Query, description = "Only events of this kind, e.g. `login_failed`"),
+        ("before" = Option<String>, Query, description = "RFC 3339 timestamp; only older events, to page backwards"),
+        ("limit" = Option<i64>, Query, description = "Page size (default 50, max 200)")
+    ),
+    responses(
+        (status = 200, description = "Audit events, newest first", body = Vec<AuditEventDto>),
+        (status = 400, description = "Invalid filter"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_audit_events(
+    _admin: AdminUser,
+    State(state): State<AdminState>,
+    Query(query): Query<AuditEventsQuery>,
+) -> Result<Json<Vec<AuditEventDto>>, AdminApiError> {
+    let events = state
+        .service
+        .search_audit_events(&AuditQuery::try_from(query)?)
+        .await?;
+    Ok(Json(events.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,388 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::FromRef,
+    routing::{delete, get, post},
+};
+
+use crate::{
+    admin::{
+        application::admin_service::AdminService,
+        presentation::handlers::{
+            disable_user, enable_user, get_queue_health, list_audit_events,
+            list_email_suppressions, list_users, redrive_emails, retry_scraper_jobs,
+            suppress_email, unsuppress_email, verify_user_email,
+        },
+    },
+    shared::{config::Config, infrastructure::http::auth_extractor::UserStatusChecker},
+};
+
+#[derive(Clone)]
+pub struct AdminState {
+    pub service: Arc<AdminService>,
+    pub config: Arc<Config>,
+    pub user_checker: Arc<dyn UserStatusChecker>,
+}
+
+impl FromRef<AdminState> for Arc<Config> {
+    fn from_ref(state: &AdminState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<AdminState> for Arc<dyn UserStatusChecker> {
+    fn from_ref(state: &AdminState) -> Self {
+        state.user_checker.clone()
+    }
+}
+
+pub fn create_admin_routes(
+    service: Arc<AdminService>,
+    config: Arc<Config>,
+    user_checker: Arc<dyn UserStatusChecker>,
+) -> Router {
+    let state = AdminState {
+        service,
+        config,
+        user_checker,
+    };
+    Router::new()
+        .route("/users", get(list_users))
+        .route("/users/{id}/disable", post(disable_user))
+        .route("/users/{id}/enable", post(enable_user))
+        .route("/users/{id}/verify-email", post(verify_user_email))
+        .route("/queues", get(get_queue_health))
+        .route("/queues/scraper/retry", post(retry_scraper_jobs))
+        .route("/queues/email/redrive", post(redrive_emails))
+        .route(
+            "/email-suppressions",
+            get(list_email_suppressions).post(suppress_email),
+        )
+        .route("/email-suppressions/{email}", delete(unsuppress_email))
+        .route("/audit-events", get(list_audit_events))
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        admin::infrastructure::persistence::repositories::admin_in_memory_repository::AdminInMemoryRepository,
+        shared::{
+            domain::value_objects::{UserRole, UserUuid},
+            infrastructure::audit::audit_in_memory_log::AuditInMemoryLog,
+        },
+    };
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    /// Only `admin_id` has the admin role.
+    struct MockUserStatusChecker {
+        admin_id: String,
+    }
+
+    #[async_trait::async_trait]
+    impl UserStatusChecker for MockUserStatusChecker {
+        async fn is_account_disabled(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn is_token_revoked(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            false
+        }
+
+        async fn is_session_active(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            true
+        }
+
+        async fn is_email_verified(&self, _user_id: &str) -> bool {
+            true
+        }
+
+        async fn is_admin(&self, user_id: &str) -> bool {
+            user_id == self.admin_id
+        }
+
+        async fn authenticate_personal_access_token(
+            &self,
+            _token: &str,
+        ) -> Option<crate::shared::infrastructure::http::auth_extractor::PersonalAccessTokenGrant>
+        {
+            None
+        }
+    }
+
+    struct TestApp {
+        router: Router,
+        admin_id: UserUuid,
+        user_id: UserUuid,
+        admin_auth: String,
+        user_auth: String,
+    }
+
+    async fn setup_router() -> TestApp {
+        let repo = AdminInMemoryRepository::default();
+        let admin_id = repo.add_user("admin@example.com", UserRole::Admin).await;
+        let user_id = repo.add_user("jane@example.com", UserRole::User).await;
+        repo.add_failed_scraper_jobs(3).await;
+        let config = Config::test_default();
+        let bearer = |user_id: UserUuid, email: &str| {
+            format!(
+                "Bearer {}",
+                crate::shared::infrastructure::http::auth_extractor::create_jwt(
+                    &user_id.to_string(),
+                    email,
+                    &config,
+                )
+                .unwrap()
+            )
+        };
+        let admin_auth = bearer(admin_id, "admin@example.com");
+        let user_auth = bearer(user_id, "jane@example.com");
+        TestApp {
+            router: create_admin_routes(
+                Arc::new(AdminService::new(
+                    Box::new(repo),
+                    Arc::new(AuditInMemoryLog::new()),
+                )),
+                Arc::new(config),
+                Arc::new(MockUserStatusChecker {
+                    admin_id: admin_id.to_string(),
+                }),
+            ),
+            admin_id,
+            user_id,
+            admin_auth,
+            user_auth,
+        }
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str, auth: &str) -> (StatusCode, Value) {
+        send_json(app, method, uri, auth, "").await
+    }
+
+    async fn send_json(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        auth: &str,
+        body: &str,
+    ) -> (StatusCode, Value) {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .header("Authorization", auth)
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_admin_routes_reject_regular_users() {
+        let app = setup_router().await;
+
+        let (users, _) = send(&app.router, "GET", "/users", &app.user_auth).await;
+        let (queues, _) = send(&app.router, "GET", "/queues", &app.user_auth).await;
+
+        assert_eq!(users, StatusCode::FORBIDDEN);
+        assert_eq!(queues, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_list_and_search_users() {
+        let app = setup_router().await;
+
+        let (status, all) = send(&app.router, "GET", "/users", &app.admin_auth).await;
+        let (_, search) = send(&app.router, "GET", "/users?q=jane&limit=1", &app.admin_auth).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(all["total"], 2);
+        assert_eq!(search["total"], 1);
+        assert_eq!(search["limit"], 1);
+        assert_eq!(search["users"][0]["id"], app.user_id.to_string());
+        assert_eq!(search["users"][0]["role"], "user");
+    }
+
+    #[tokio::test]
+    async fn test_disable_enable_and_verify_users() {
+        let app = setup_router().await;
+        let user = format!("/users/{}", app.user_id);
+
+        let (disabled, disabled_body) = send(
+            &app.router,
+            "POST",
+            &format!("{user}/disable"),
+            &app.admin_auth,
+        )
+        .await;
+        let (_, enabled_body) = send(
+            &app.router,
+            "POST",
+            &format!("{user}/enable"),
+            &app.admin_auth,
+        )
+        .await;
+        let (_, verified_body) = send(
+            &app.router,
+            "POST",
+            &format!("{user}/verify-email"),
+            &app.admin_auth,
+        )
+        .await;
+        let (own, _) = send(
+            &app.router,
+            "POST",
+            &format!("/users/{}/disable", app.admin_id),
+            &app.admin_auth,
+        )
+        .await;
+        let (unknown, _) = send(
+            &app.router,
+            "POST",
+            "/users/not-a-uuid/disable",
+            &app.admin_auth,
+        )
+        .await;
+
+        assert_eq!(disabled, StatusCode::OK);
+        assert_eq!(disabled_body["account_disabled"], true);
+        assert_eq!(enabled_body["account_disabled"], false);
+        assert_eq!(verified_body["email_verified"], true);
+        assert_eq!(own, StatusCode::CONFLICT);
+        assert_eq!(unknown, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_queue_health_and_retry() {
+        let app = setup_router().await;
+
+        let (_, before) = send(&app.router, "GET", "/queues", &app.admin_auth).await;
+        let (status, retried) = send(
+            &app.router,
+            "POST",
+            "/queues/scraper/retry",
+            &app.admin_auth,
+        )
+        .await;
+        let (_, after) = send(&app.router, "GET", "/queues", &app.admin_auth).await;
+
+        assert_eq!(before["scraper"]["failed"], 3);
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(retried["redriven"], 3);
+        assert_eq!(after["scraper"]["failed"], 0);
+        assert_eq!(after["scraper"]["pending"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_audit_events() {
+        let app = setup_router().await;
+        send(
+            &app.router,
+            "POST",
+            &format!("/users/{}/disable", app.user_id),
+            &app.admin_auth,
+        )
+        .await;
+        send(
+            &app.router,
+            "POST",
+            "/queues/scraper/retry",
+            &app.admin_auth,
+        )
+        .await;
+
+        let (status, all) = send(&app.router, "GET", "/audit-events", &app.admin_auth).await;
+        let (_, for_user) = send(
+            &app.router,
+            "GET",
+            &format!("/audit-events?user_id={}", app.user_id),
+            &app.admin_auth,
+        )
+        .await;
+        let (bad_kind, _) = send(
+            &app.router,
+            "GET",
+            "/audit-events?kind=coffee_break",
+            &app.admin_auth,
+        )
+        .await;
+        let (forbidden, _) = send(&app.router, "GET", "/audit-events", &app.user_auth).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(all.as_array().unwrap().len(), 2);
+        assert_eq!(all[0]["kind"], "admin_action");
+        assert_eq!(for_user.as_array().unwrap().len(), 1);
+        assert_eq!(for_user[0]["kind"], "account_disabled");
+        assert_eq!(for_user[0]["actor_id"], app.admin_id.to_string());
+        assert_eq!(bad_kind, StatusCode::BAD_REQUEST);
+        assert_eq!(forbidden, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_suppress_and_unsuppress_email() {
+        let app = setup_router().await;
+
+        let (suppressed, suppression) = send_json(
+            &app.router,
+            "POST",
+            "/email-suppressions",
+            &app.admin_auth,
+            r#"{"email":"Bounced@Example.com","reason":"hard bounce"}"#,
+        )
+        .await;
+        let (invalid, _) = send_json(
+            &app.router,
+            "POST",
+            "/email-suppressions",
+            &app.admin_auth,
+            r#"{"email":"nowhere","reason":"typo"}"#,
+        )
+        .await;
+        let (_, listed) = send(&app.router, "GET", "/email-suppressions", &app.admin_auth).await;
+        let (lifted, _) = send(
+            &app.router,
+            "DELETE",
+            "/email-suppressions/bounced@example.com",
+            &app.admin_auth,
+        )
+        .await;
+        let (lifted_again, _) = send(
+            &app.router,
+            "DELETE",
+            "/email-suppressions/bounced@example.com",
+            &app.admin_auth,
+        )
+        .await;
+        let (forbidden, _) = send(&app.router, "GET", "/email-suppressions", &app.user_auth).await;
+
+        assert_eq!(suppressed, StatusCode::OK);
+        assert_eq!(suppression["email"], "bounced@example.com");
+        assert_eq!(invalid, StatusCode::BAD_REQUEST);
+        assert_eq!(listed, Value::Array(vec![suppression]));
+        assert_eq!(lifted, StatusCode::NO_CONTENT);
+        assert_eq!(lifted_again, StatusCode::NOT_FOUND);
+        assert_eq!(forbidden, StatusCode::FORBIDDEN);
+    }
+}
@@ -1,50 +1,150 @@
+use chrono::{DateTime, TimeDelta, Utc};
 use std::str::FromStr;
+use std::sync::Arc;
 use uuid::Uuid;
 
-use tracing_opentelemetry::OpenTelemetrySpanExt;
-
 use crate::auth::{
     application::errors::AuthError,
     domain::{
-        entities::user::{User, UserEmail},
-        repositories::user_repository::IUserRepository,
+        entities::{
+            auth_email::AuthEmail,
+            email_change_request::EmailChangeRequest,
+            login_attempts::{FailureOutcome, LoginDecision, LoginLockoutPolicy},
+            opaque_token::OpaqueToken,
+            password_reset_token::PasswordResetToken,
+            personal_access_token::PersonalAccessToken,
+            refresh_token::RefreshToken,
+            session::Session,
+            two_factor::TwoFactor,
+            user::{User, UserEmail},
+            verification_email_throttle::{SendDecision, VerificationEmailThrottle},
+        },
+        repositories::{
+            email_change_request_repository::IEmailChangeRequestRepository,
+            login_attempt_repository::ILoginAttemptRepository,
+            password_reset_token_repository::IPasswordResetTokenRepository,
+            personal_access_token_repository::IPersonalAccessTokenRepository,
+            refresh_token_repository::IRefreshTokenRepository,
+            session_repository::ISessionRepository, two_factor_repository::ITwoFactorRepository,
+            user_repository::IUserRepository,
+            verification_email_repository::IVerificationEmailRepository,
+        },
     },
 };
-use crate::shared::domain::value_objects::UserUuid;
+use crate::shared::domain::audit::{AuditEntry, AuditEventKind, IAuditLog, RequestOrigin};
+use crate::shared::domain::value_objects::{TokenScope, UserUuid};
+use crate::shared::infrastructure::observability::current_trace_context;
 
 use crate::auth::application::email_queue_enqueuer::IEmailQueueEnqueuer;
-use crate::auth::application::token_generator::ITokenGenerator;
+use crate::auth::application::token_generator::{ITokenGenerator, TokenPurpose};
+use crate::auth::application::token_revocation_service::TokenRevocationService;
 use tracing::{error, info, warn};
 
 #[derive(Debug, PartialEq)]
 pub struct LoginResponse {
     pub token: String,
+    pub token_expires_at: DateTime<Utc>,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: DateTime<Utc>,
     pub is_email_verified: bool,
 }
 
+/// Short-lived proof that the password was right, traded for a session
+/// together with a second factor code.
+#[derive(Debug, PartialEq)]
+pub struct MfaChallenge {
+    pub mfa_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LoginOutcome {
+    Authenticated(LoginResponse),
+    MfaRequired(MfaChallenge),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MfaEnrollment {
+    /// Base32 secret for authenticator apps that cannot scan the URI
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthSettings {
+    /// Base URL of the links sent by email
+    pub frontend_url: String,
+    pub refresh_token_ttl_secs: i64,
+    pub verification_email_throttle: VerificationEmailThrottle,
+    /// Issuer shown next to the account in authenticator apps
+    pub totp_issuer: String,
+    pub login_lockout: LoginLockoutPolicy,
+}
+
+pub struct AuthRepositories {
+    pub users: Box<dyn IUserRepository>,
+    pub refresh_tokens: Box<dyn IRefreshTokenRepository>,
+    pub password_reset_tokens: Box<dyn IPasswordResetTokenRepository>,
+    pub verification_emails: Box<dyn IVerificationEmailRepository>,
+    pub email_change_requests: Box<dyn IEmailChangeRequestRepository>,
+    pub two_factor: Box<dyn ITwoFactorRepository>,
+    pub login_attempts: Box<dyn ILoginAttemptRepository>,
+    pub personal_access_tokens: Box<dyn IPersonalAccessTokenRepository>,
+    pub sessions: Box<dyn ISessionRepository>,
+}
+
 pub struct AuthService {
     user_repository: Box<dyn IUserRepository>,
+    refresh_token_repository: Box<dyn IRefreshTokenRepository>,
+    password_reset_token_repository: Box<dyn IPasswordResetTokenRepository>,
+    verification_email_repository: Box<dyn IVerificationEmailRepository>,
+    email_change_request_repository: Box<dyn IEmailChangeRequestRepository>,
+    two_factor_repository: Box<dyn ITwoFactorRepository>,
+    login_attempt_repository: Box<dyn ILoginAttemptRepository>,
+    personal_access_token_repository: Box<dyn IPersonalAccessTokenRepository>,
+    session_repository: Box<dyn ISessionRepository>,
     token_generator: Box<dyn ITokenGenerator>,
+    token_revocations: Arc<TokenRevocationService>,
     email_queue: Box<dyn IEmailQueueEnqueuer>,
-    frontend_url: String,
+    audit_log: Arc<dyn IAuditLog>,
+    settings: AuthSettings,
 }
 
 impl AuthService {
     pub fn new(
-        user_repository: Box<dyn IUserRepository>,
+        repositories: AuthRepositories,
         token_generator: Box<dyn ITokenGenerator>,
+        token_revocations: Arc<TokenRevocationService>,
         email_queue: Box<dyn IEmailQueueEnqueuer>,
-        frontend_url: String,
+        audit_log: Arc<dyn IAuditLog>,
+        settings: AuthSettings,
     ) -> Self {
         Self {
-            user_repository,
+            user_repository: repositories.users,
+            refresh_token_repository: repositories.refresh_tokens,
+            password_reset_token_repository: repositories.password_reset_tokens,
+            verification_email_repository: repositories.verification_emails,
+            email_change_request_repository: repositories.email_change_requests,
+            two_factor_repository: repositories.two_factor,
+            login_attempt_repository: repositories.login_attempts,
+            personal_access_token_repository: repositories.personal_access_tokens,
+            session_repository: repositories.sessions,
             token_generator,
+            token_revocations,
             email_queue,
-            frontend_url,
+            audit_log,
+            settings,
         }
     }
 
-    pub async fn login(&self, email: &str, password: &str) -> Result<LoginResponse, AuthError> {
+    /// Checks the password. Users with two-factor authentication get an
+    /// [`MfaChallenge`] to finish with [`Self::complete_mfa_login`].
+    pub async fn login(
+        &self,
+        email: &str,
+        password: &str,
+        origin: &RequestOrigin,
+    ) -> Result<LoginOutcome, AuthError> {
         let user_email: UserEmail = UserEmail::new(email).map_err(AuthError::from)?;
         let user = self.user_repository.find_by_email(user_email).await;
         match user {
@@ -55,23 +155,22 @@ impl AuthService {
                         email = %user.email.value(),
                         "Account is disabled"
                     );
+                    self.audit_login_failure(Some(user.id), "account_disabled")
+                        .await;
                     return Err(AuthError::InvalidCredentials);
                 }
+                self.ensure_login_allowed(&user).await?;
                 match user.verify_password(password) {
-                    Ok(true) => {
-                        let token = self
-                            .token_generator
-                            .generate_token(&user.id.value().to_string(), user.email.value())?;
-                        Ok(LoginResponse {
-                            token,
-                            is_email_verified: user.email_validated,
-                        })
-                    }
+                    Ok(true) => self.first_factor_passed(&user, origin).await,
                     Ok(false) => {
                         warn!(
                             error_kind = "invalid_credentials",
+                            user_id = %user.id,
                             "auth_service.login failed"
                         );
+                        self.audit_login_failure(Some(user.id), "invalid_credentials")
+                            .await;
+                        self.record_failed_login(&user).await?;
                         Err(AuthError::InvalidCredentials)
                     }
                     Err(e) => {
@@ -89,6 +188,7 @@ impl AuthService {
                     error_kind = "invalid_credentials",
                     "auth_service.login failed"
                 );
+                self.audit_login_failure(None, "unknown_email").await;
                 Err(AuthError::InvalidCredentials)
             }
             Err(err) => {
@@ -102,6 +202,877 @@ impl AuthService {
         }
     }
 
+    /// Logs in a user whose identity was proven by other means than the
+    /// password, such as an OpenID Connect provider. The second factor is
+    /// still required when enabled.
+    pub async fn login_with_verified_identity(
+        &self,
+        user: &User,
+        origin: &RequestOrigin,
+    ) -> Result<LoginOutcome, AuthError> {
+        if user.account_disabled {
+            warn!(
+                error_kind = "account_disabled",
+                user_id = %user.id,
+                "auth_service.login_with_verified_identity failed"
+            );
+            self.audit_login_failure(Some(user.id), "account_disabled")
+                .await;
+            return Err(AuthError::InvalidCredentials);
+        }
+        self.first_factor_passed(user, origin).await
+    }
+
+    async fn first_factor_passed(
+        &self,
+        user: &User,
+        origin: &RequestOrigin,
+    ) -> Result<LoginOutcome, AuthError> {
+        // The failure count is kept until the second factor is passed too.
+        if self.enabled_two_factor(user.id).await?.is_some() {
+            let challenge = self.token_generator.generate_purpose_token(
+                TokenPurpose::MfaPending,
+                &user.id.value().to_string(),
+                user.email.value(),
+            )?;
+            return Ok(LoginOutcome::MfaRequired(MfaChallenge {
+                mfa_token: challenge.token,
+                expires_at: challenge.expires_at,
+            }));
+        }
+        self.login_attempt_repository.reset(user.id).await?;
+        let response = self.start_session(user, origin).await?;
+        self.audit_log
+            .record(AuditEntry::new(
+                AuditEventKind::LoginSucceeded,
+                Some(user.id),
+            ))
+            .await;
+        Ok(LoginOutcome::Authenticated(response))
+    }
+
+    /// Second step of the login: trades the token of the [`MfaChallenge`]
+    /// and an authenticator or recovery code for a session.
+    pub async fn complete_mfa_login(
+        &self,
+        mfa_token: &str,
+        code: &str,
+        origin: &RequestOrigin,
+    ) -> Result<LoginResponse, AuthError> {
+        let claims = self
+            .token_generator
+            .validate_purpose_token(TokenPurpose::MfaPending, mfa_token)?;
+        let user_id = UserUuid::from_str(&claims.user_id).map_err(|_| AuthError::InvalidToken)?;
+        let user = self
+            .user_repository
+            .get(user_id)
+            .await?
+            .filter(|user| !user.account_disabled)
+            .ok_or(AuthError::InvalidToken)?;
+        let two_factor = self
+            .enabled_two_factor(user.id)
+            .await?
+            .ok_or(AuthError::InvalidToken)?;
+        self.ensure_login_allowed(&user).await?;
+        if let Err(e) = self.verify_second_factor(&two_factor, code).await {
+            if e == AuthError::InvalidMfaCode {
+                self.audit_login_failure(Some(user.id), "invalid_mfa_code")
+                    .await;
+                self.record_failed_login(&user).await?;
+            }
+            return Err(e);
+        }
+        self.login_attempt_repository.reset(user.id).await?;
+
+        let response = self.start_session(&user, origin).await?;
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::LoginSucceeded, Some(user.id)).detail("mfa"))
+            .await;
+        Ok(response)
+    }
+
+    /// Creates a new authenticator secret. It is only enforced once confirmed
+    /// with [`Self::confirm_mfa_enrollment`]; enrolling again replaces a
+    /// pending secret.
+    pub async fn start_mfa_enrollment(
+        &self,
+        user_id: &str,
+        password: &str,
+    ) -> Result<MfaEnrollment, AuthError> {
+        let user = self.verified_credentials(user_id, password).await?;
+        if self.enabled_two_factor(user.id).await?.is_some() {
+            return Err(AuthError::MfaAlreadyEnabled);
+        }
+
+        let two_factor = TwoFactor::enroll(user.id, Utc::now());
+        self.two_factor_repository.save(&two_factor).await?;
+
+        info!(user_id = %user.id, "Two-factor enrolment started");
+        Ok(MfaEnrollment {
+            secret: two_factor.secret.base32(),
+            otpauth_uri: two_factor
+                .secret
+                .otpauth_uri(&self.settings.totp_issuer, user.email.value()),
+        })
+    }
+
+    /// Turns two-factor authentication on with a first code from the
+    /// authenticator and returns the recovery codes, shown only this once.
+    pub async fn confirm_mfa_enrollment(
+        &self,
+        user_id: &str,
+        code: &str,
+    ) -> Result<Vec<String>, AuthError> {
+        let user_id =
+            UserUuid::from_str(user_id).map_err(|e| AuthError::InternalError(e.to_string()))?;
+        let mut two_factor = self
+            .two_factor_repository
+            .get(user_id)
+            .await?
+            .ok_or(AuthError::MfaNotEnabled)?;
+        if two_factor.is_enabled() {
+            return Err(AuthError::MfaAlreadyEnabled);
+        }
+        let now = Utc::now();
+        let step = two_factor
+            .accepts_code(code, now)?
+            .ok_or(AuthError::InvalidMfaCode)?;
+
+        let recovery_codes = two_factor.enable(step, now);
+        self.two_factor_repository.save(&two_factor).await?;
+
+        if let Some(user) = self.user_repository.get(user_id).await? {
+            self.enqueue_email(&user, AuthEmail::TwoFactorEnabled { enabled_at: now })
+                .await;
+        }
+        self.audit_log
+            .record(AuditEntry::new(
+                AuditEventKind::TwoFactorEnabled,
+                Some(user_id),
+            ))
+            .await;
+        info!(user_id = %user_id, "Two-factor authentication enabled");
+        Ok(recovery_codes)
+    }
+
+    /// Turns two-factor authentication off. Needs the password and a current
+    /// authenticator or recovery code.
+    pub async fn disable_mfa(
+        &self,
+        user_id: &str,
+        password: &str,
+        code: &str,
+    ) -> Result<(), AuthError> {
+        let user = self.verified_credentials(user_id, password).await?;
+        let two_factor = self
+            .enabled_two_factor(user.id)
+            .await?
+            .ok_or(AuthError::MfaNotEnabled)?;
+        self.verify_second_factor(&two_factor, code).await?;
+        self.two_factor_repository.delete(user.id).await?;
+
+        if let Some(enabled_at) = two_factor.enabled_at {
+            self.enqueue_email(&user, AuthEmail::TwoFactorDisabled { enabled_at })
+                .await;
+        }
+        self.audit_log
+            .record(AuditEntry::new(
+                AuditEventKind::TwoFactorDisabled,
+                Some(user.id),
+            ))
+            .await;
+        info!(user_id = %user.id, "Two-factor authentication disabled");
+        Ok(())
+    }
+
+    /// Rejects attempts while the account is locked or within the delay that
+    /// follows repeated failures, before spending time on the password hash.
+    async fn ensure_login_allowed(&self, user: &User) -> Result<(), AuthError> {
+        let Some(attempts) = self.login_attempt_repository.get(user.id).await? else {
+            return Ok(());
+        };
+        match self.settings.login_lockout.check(&attempts, Utc::now()) {
+            LoginDecision::Allowed => Ok(()),
+            LoginDecision::Delayed { retry_after_secs } => {
+                warn!(
+                    error_kind = "login_delayed",
+                    user_id = %user.id,
+                    retry_after_secs,
+                    "auth_service.login rejected"
+                );
+                self.audit_login_failure(Some(user.id), "login_delayed")
+                    .await;
+                Err(AuthError::TooManyRequests { retry_after_secs })
+            }
+            LoginDecision::Locked { retry_after_secs } => {
+                warn!(
+                    error_kind = "account_locked",
+                    user_id = %user.id,
+                    retry_after_secs,
+                    "auth_service.login rejected"
+                );
+                self.audit_login_failure(Some(user.id), "account_locked")
+                    .await;
+                Err(AuthError::TooManyRequests { retry_after_secs })
+            }
+        }
+    }
+
+    /// Records an event for flows built on top of this service, such as
+    /// OpenID Connect sign-ups.
+    pub async fn audit(&self, entry: AuditEntry) {
+        self.audit_log.record(entry).await;
+    }
+
+    async fn audit_login_failure(&self, user_id: Option<UserUuid>, reason: &str) {
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::LoginFailed, user_id).detail(reason))
+            .await;
+    }
+
+    async fn record_failed_login(&self, user: &User) -> Result<(), AuthError> {
+        let outcome = self
+            .login_attempt_repository
+            .record_failure(user.id, &self.settings.login_lockout, Utc::now())
+            .await?;
+        if let FailureOutcome::LockedOut { until } = outcome {
+            warn!(user_id = %user.id, locked_until = %until, "Account locked after failed logins");
+            self.enqueue_email(user, AuthEmail::SignInsBlocked { until })
+                .await;
+        }
+        Ok(())
+    }
+
+    async fn enabled_two_factor(&self, user_id: UserUuid) -> Result<Option<TwoFactor>, AuthError> {
+        Ok(self
+            .two_factor_repository
+            .get(user_id)
+            .await?
+            .filter(|two_factor| two_factor.is_enabled()))
+    }
+
+    /// Accepts an authenticator code once per time step, or an unused
+    /// recovery code.
+    async fn verify_second_factor(
+        &self,
+        two_factor: &TwoFactor,
+        code: &str,
+    ) -> Result<(), AuthError> {
+        if let Some(step) = two_factor.accepts_code(code, Utc::now())?
+            && self
+                .two_factor_repository
+                .record_used_step(two_factor.user_id, step)
+                .await?
+        {
+            return Ok(());
+        }
+        if self
+            .two_factor_repository
+            .consume_recovery_code(two_factor.user_id, &TwoFactor::hash_recovery_code(code))
+            .await?
+        {
+            warn!(
+                user_id = %two_factor.user_id,
+                recovery_codes_left = two_factor.recovery_code_hashes.len().saturating_sub(1),
+                "Recovery code used"
+            );
+            return Ok(());
+        }
+        warn!(
+            error_kind = "invalid_mfa_code",
+            user_id = %two_factor.user_id,
+            "auth_service.verify_second_factor failed"
+        );
+        Err(AuthError::InvalidMfaCode)
+    }
+
+    /// Redeems a refresh token for a new access token and a rotated refresh
+    /// token. Redeeming a token twice revokes every token of its family.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<LoginResponse, AuthError> {
+        let now = Utc::now();
+        let token_hash = OpaqueToken::from_string(refresh_token).hash();
+        let token = self
+            .refresh_token_repository
+            .find_by_hash(&token_hash)
+            .await?
+            .ok_or(AuthError::InvalidToken)?;
+
+        if token.revoked_at.is_some() {
+            warn!(
+                error_kind = "refresh_token_revoked",
+                user_id = %token.user_id,
+                "auth_service.refresh failed"
+            );
+            return Err(AuthError::InvalidToken);
+        }
+        if token.is_expired(now) {
+            return Err(AuthError::TokenExpired);
+        }
+        if !self
+            .refresh_token_repository
+            .mark_used(token.id, now)
+            .await?
+        {
+            warn!(
+                error_kind = "refresh_token_reuse",
+                user_id = %token.user_id,
+                family_id = %token.family_id,
+                "Refresh token reused, revoking its family"
+            );
+            self.refresh_token_repository
+                .revoke_family(token.family_id, now)
+                .await?;
+            self.audit_token_revoked(token.user_id, "refresh_token_reuse")
+                .await;
+            return Err(AuthError::InvalidToken);
+        }
+
+        let user = self
+            .user_repository
+            .get(token.user_id)
+            .await?
+            .filter(|user| !user.account_disabled)
+            .ok_or(AuthError::InvalidToken)?;
+        let session_active = self
+            .session_repository
+            .get(token.family_id)
+            .await?
+            .is_some_and(|session| session.is_active(now));
+        if !session_active {
+            warn!(
+                error_kind = "session_revoked",
+                user_id = %token.user_id,
+                session_id = %token.family_id,
+                "auth_service.refresh failed"
+            );
+            return Err(AuthError::InvalidToken);
+        }
+
+        let (rotated, secret) = token.rotate(self.settings.refresh_token_ttl_secs, now);
+        self.session_repository
+            .extend(rotated.family_id, rotated.expires_at, now)
+            .await?;
+        self.issue_tokens(&user, rotated, secret).await
+    }
+
+    /// Revokes the access token identified by `jti`, its session and, when
+    /// given, the refresh token family of the same session.
+    pub async fn logout(
+        &self,
+        user_id: &str,
+        jti: &str,
+        access_token_expires_at: DateTime<Utc>,
+        session_id: Option<&str>,
+        refresh_token: Option<&str>,
+    ) -> Result<(), AuthError> {
+        let user_id = UserUuid::from_str(user_id).map_err(|_| AuthError::InvalidToken)?;
+        self.token_revocations
+            .revoke_token(jti, user_id, access_token_expires_at)
+            .await?;
+
+        if let Some(session_id) = session_id.and_then(|id| Uuid::parse_str(id).ok()) {
+            self.end_session(user_id, session_id).await?;
+        }
+
+        if let Some(refresh_token) = refresh_token {
+            let token_hash = OpaqueToken::from_string(refresh_token).hash();
+            if let Some(token) = self
+                .refresh_token_repository
+                .find_by_hash(&token_hash)
+                .await?
+                .filter(|token| token.user_id == user_id)
+            {
+                self.refresh_token_repository
+                    .revoke_family(token.family_id, Utc::now())
+                    .await?;
+            }
+        }
+
+        self.audit_token_revoked(user_id, "logout").await;
+        info!(user_id = %user_id, "User logged out");
+        Ok(())
+    }
+
+    /// Revokes every access and refresh token of the user, on every device.
+    pub async fn logout_all(&self, user_id: &str) -> Result<(), AuthError> {
+        let user_id = UserUuid::from_str(user_id).map_err(|_| AuthError::InvalidToken)?;
+        self.revoke_all_sessions(user_id).await?;
+
+        self.audit_token_revoked(user_id, "logout_all").await;
+        info!(user_id = %user_id, "User logged out of every session");
+        Ok(())
+    }
+
+    async fn revoke_all_sessions(&self, user_id: UserUuid) -> Result<(), AuthError> {
+        let now = Utc::now();
+        self.token_revocations.revoke_all_tokens(user_id).await?;
+        self.refresh_token_repository
+            .revoke_all_for_user(user_id, now)
+            .await?;
+        self.session_repository
+            .revoke_all_for_user(user_id, now)
+            .await?;
+        Ok(())
+    }
+
+    /// Signed-in devices of the user, most recently seen first.
+    pub async fn list_sessions(&self, user_id: &str) -> Result<Vec<Session>, AuthError> {
+        let user_id = UserUuid::from_str(user_id).map_err(|_| AuthError::InvalidToken)?;
+        Ok(self
+            .session_repository
+            .list_active(user_id, Utc::now())
+            .await?)
+    }
+
+    /// Signs a device out: its access tokens are rejected from the next
+    /// request on and its refresh token can no longer be redeemed.
+    pub async fn revoke_session(&self, user_id: &str, session_id: Uuid) -> Result<(), AuthError> {
+        let user_id = UserUuid::from_str(user_id).map_err(|_| AuthError::InvalidToken)?;
+        if !self.end_session(user_id, session_id).await? {
+            return Err(AuthError::SessionNotFound);
+        }
+
+        self.audit_token_revoked(user_id, "session").await;
+        info!(user_id = %user_id, session_id = %session_id, "Session revoked");
+        Ok(())
+    }
+
+    /// Returns `false` when the session is not an active one of the user.
+    async fn end_session(&self, user_id: UserUuid, session_id: Uuid) -> Result<bool, AuthError> {
+        let now = Utc::now();
+        if !self
+            .session_repository
+            .revoke(user_id, session_id, now)
+            .await?
+        {
+            return Ok(false);
+        }
+        self.refresh_token_repository
+            .revoke_family(session_id, now)
+            .await?;
+        Ok(true)
+    }
+
+    async fn audit_token_revoked(&self, user_id: UserUuid, what: &str) {
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::TokenRevoked, Some(user_id)).detail(what))
+            .await;
+    }
+
+    /// Returns the token together with its secret, which is not stored and
+    /// cannot be shown again.
+    pub async fn create_personal_access_token(
+        &self,
+        user_id: &str,
+        name: &str,
+        scope: TokenScope,
+        expires_in_days: Option<u32>,
+    ) -> Result<(PersonalAccessToken, String), AuthError> {
+        let user_id =
+            UserUuid::from_str(user_id).map_err(|e| AuthError::InternalError(e.to_string()))?;
+        let user = self
+            .user_repository
+            .get(user_id)
+            .await?
+            .ok_or(AuthError::UserNotFound)?;
+        let lifetime = expires_in_days.map(|days| TimeDelta::days(i64::from(days)));
+        let (token, secret) =
+            PersonalAccessToken::issue(user.id, name, scope, lifetime, Utc::now())?;
+        self.personal_access_token_repository.save(&token).await?;
+
+        self.enqueue_email(
+            &user,
+            AuthEmail::PersonalAccessTokenCreated {
+                token_id: token.id,
+                name: token.name.clone(),
+                scope: token.scope,
+            },
+        )
+        .await;
+        info!(user_id = %user.id, token_id = %token.id, scope = %token.scope, "Personal access token created");
+        Ok((token, secret))
+    }
+
+    pub async fn list_personal_access_tokens(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<PersonalAccessToken>, AuthError> {
+        let user_id =
+            UserUuid::from_str(user_id).map_err(|e| AuthError::InternalError(e.to_string()))?;
+        Ok(self
+            .personal_access_token_repository
+            .list_for_user(user_id)
+            .await?)
+    }
+
+    pub async fn revoke_personal_access_token(
+        &self,
+        user_id: &str,
+        token_id: Uuid,
+    ) -> Result<(), AuthError> {
+        let user_id =
+            UserUuid::from_str(user_id).map_err(|e| AuthError::InternalError(e.to_string()))?;
+        if !self
+            .personal_access_token_repository
+            .revoke(user_id, token_id, Utc::now())
+            .await?
+        {
+            return Err(AuthError::TokenNotFound);
+        }
+        self.audit_token_revoked(user_id, "personal_access_token")
+            .await;
+        info!(user_id = %user_id, token_id = %token_id, "Personal access token revoked");
+        Ok(())
+    }
+
+    /// Mails a reset link when the email belongs to an active account. It
+    /// succeeds either way, so callers cannot probe which emails exist.
+    pub async fn request_password_reset(&self, email: &str) -> Result<(), AuthError> {
+        let Ok(user_email) = UserEmail::new(email) else {
+            return Ok(());
+        };
+        let Some(user) = self
+            .user_repository
+            .find_by_email(user_email)
+            .await?
+            .filter(|user| !user.account_disabled)
+        else {
+            info!("Password reset requested for an unknown or disabled account");
+            return Ok(());
+        };
+
+        let reset = self.token_generator.generate_purpose_token(
+            TokenPurpose::PasswordReset,
+            &user.id.value().to_string(),
+            user.email.value(),
+        )?;
+        let token = PasswordResetToken::new(user.id, &reset.token_id, reset.expires_at, Utc::now());
+        // Throttled like verification resends, but still Ok so the response
+        // does not tell whether the account exists.
+        if let SendDecision::Throttled { retry_after_secs } = self
+            .password_reset_token_repository
+            .save_if_allowed(&token, &self.settings.verification_email_throttle)
+            .await?
+        {
+            warn!(
+                error_kind = "password_reset_email_throttled",
+                user_id = %user.id,
+                retry_after_secs,
+                "auth_service.request_password_reset failed"
+            );
+            return Ok(());
+        }
+
+        let reset_link = format!(
+            "{}/auth/reset-password?token={}",
+            self.settings.frontend_url, reset.token
+        );
+        self.enqueue_email(&user, AuthEmail::ResetPassword { link: reset_link })
+            .await;
+
+        info!(user_id = %user.id, "Password reset requested");
+        Ok(())
+    }
+
+    /// Sets a new password with a token from [`Self::request_password_reset`]
+    /// and signs the user out everywhere.
+    pub async fn reset_password(&self, token: &str, new_password: &str) -> Result<(), AuthError> {
+        let now = Utc::now();
+        let claims = self
+            .token_generator
+            .validate_purpose_token(TokenPurpose::PasswordReset, token)?;
+        let token = self
+            .password_reset_token_repository
+            .find_by_hash(&PasswordResetToken::hash_token_id(&claims.token_id))
+            .await?
+            .filter(|token| token.user_id.value().to_string() == claims.user_id)
+            .ok_or(AuthError::InvalidToken)?;
+        if token.is_expired(now) {
+            return Err(AuthError::TokenExpired);
+        }
+        if !token.is_usable(now) {
+            warn!(
+                error_kind = "password_reset_token_reuse",
+                user_id = %token.user_id,
+                "auth_service.reset_password failed"
+            );
+            return Err(AuthError::InvalidToken);
+        }
+
+        let mut user = self
+            .user_repository
+            .get(token.user_id)
+            .await?
+            .filter(|user| !user.account_disabled)
+            .ok_or(AuthError::InvalidToken)?;
+        // Checked before burning the token so a weak password can be retried.
+        user.change_password(new_password)?;
+
+        // Signed out first: if this fails, the token and password are untouched.
+        self.revoke_all_sessions(user.id).await?;
+        if !self
+            .password_reset_token_repository
+            .reset_password(token.id, &user, now)
+            .await?
+        {
+            return Err(AuthError::InvalidToken);
+        }
+
+        self.audit_log
+            .record(AuditEntry::new(
+                AuditEventKind::PasswordReset,
+                Some(user.id),
+            ))
+            .await;
+        info!(user_id = %user.id, "Password reset");
+        Ok(())
+    }
+
+    /// Checks the current password before setting the new one. Every other
+    /// session is signed out, the caller gets a fresh token pair.
+    pub async fn change_password(
+        &self,
+        user_id: &str,
+        current_password: &str,
+        new_password: &str,
+        origin: &RequestOrigin,
+    ) -> Result<LoginResponse, AuthError> {
+        let mut user = self.verified_credentials(user_id, current_password).await?;
+        user.change_password(new_password)?;
+
+        let now = Utc::now();
+        self.user_repository.update(&user).await?;
+        // Reset links mailed before the change must not undo it.
+        self.password_reset_token_repository
+            .mark_all_used(user.id, now)
+            .await?;
+        self.revoke_all_sessions(user.id).await?;
+
+        self.audit_log
+            .record(AuditEntry::new(
+                AuditEventKind::PasswordChanged,
+                Some(user.id),
+            ))
+            .await;
+        info!(user_id = %user.id, "Password changed");
+        self.start_session(&user, origin).await
+    }
+
+    /// Mails a confirmation link to `new_email` and a notice to the current
+    /// address. The email only changes once the link is followed, see
+    /// [`Self::confirm_email_change`].
+    pub async fn request_email_change(
+        &self,
+        user_id: &str,
+        password: &str,
+        new_email: &str,
+    ) -> Result<(), AuthError> {
+        let user = self.verified_credentials(user_id, password).await?;
+        let new_email = UserEmail::new(new_email)?;
+        if new_email == user.email
+            || self
+                .user_repository
+                .find_by_email(new_email.clone())
+                .await?
+                .is_some()
+        {
+            return Err(AuthError::EmailTaken);
+        }
+
+        let now = Utc::now();
+        let confirmation = self.token_generator.generate_purpose_token(
+            TokenPurpose::EmailChange,
+            &user.id.value().to_string(),
+            new_email.value(),
+        )?;
+        // Only the latest link can confirm a change.
+        self.email_change_request_repository
+            .mark_all_used(user.id, now)
+            .await?;
+        let request = EmailChangeRequest::new(
+            user.id,
+            new_email.clone(),
+            &confirmation.token_id,
+            confirmation.expires_at,
+            now,
+        );
+        self.email_change_request_repository.save(&request).await?;
+
+        let confirmation_link = format!(
+            "{}/auth/confirm-email-change?token={}",
+            self.settings.frontend_url, confirmation.token
+        );
+        self.enqueue_email_to(
+            new_email.value(),
+            &user,
+            AuthEmail::ConfirmEmailChange {
+                link: confirmation_link,
+            },
+        )
+        .await;
+        self.enqueue_email(
+            &user,
+            AuthEmail::EmailChangeRequested {
+                request_id: request.id,
+                new_email: new_email.value().to_string(),
+            },
+        )
+        .await;
+
+        info!(user_id = %user.id, "Email change requested");
+        Ok(())
+    }
+
+    /// Swaps the email with a token from [`Self::request_email_change`].
+    pub async fn confirm_email_change(&self, token: &str) -> Result<(), AuthError> {
+        let now = Utc::now();
+        let claims = self
+            .token_generator
+            .validate_purpose_token(TokenPurpose::EmailChange, token)?;
+        let request = self
+            .email_change_request_repository
+            .find_by_hash(&EmailChangeRequest::hash_token_id(&claims.token_id))
+            .await?
+            .filter(|request| {
+                request.user_id.value().to_string() == claims.user_id
+                    && request.new_email.value() == claims.email
+            })
+            .ok_or(AuthError::InvalidToken)?;
+        if request.is_expired(now) {
+            return Err(AuthError::TokenExpired);
+        }
+        if !request.is_usable(now) {
+            warn!(
+                error_kind = "email_change_token_reuse",
+                user_id = %request.user_id,
+                "auth_service.confirm_email_change failed"
+            );
+            return Err(AuthError::InvalidToken);
+        }
+
+        let mut user = self
+            .user_repository
+            .get(request.user_id)
+            .await?
+            .filter(|user| !user.account_disabled)
+            .ok_or(AuthError::InvalidToken)?;
+        if !self
+            .email_change_request_repository
+            .mark_used(request.id, now)
+            .await?
+        {
+            return Err(AuthError::InvalidToken);
+        }
+        user.change_email(request.new_email);
+        // The address may have been taken since the request; the unique
+        // constraint on users.email settles it.
+        match self.user_repository.update(&user).await {
+            Err(crate::auth::domain::errors::AuthRepoError::UserAlreadyExists(_)) => {
+                return Err(AuthError::EmailTaken);
+            }
+            result => result?,
+        }
+
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::EmailChanged, Some(user.id)))
+            .await;
+        info!(user_id = %user.id, "Email changed");
+        Ok(())
+    }
+
+    /// Checks the password of a signed-in user before a sensitive change.
+    /// Wrong guesses count towards the same lockout as failed logins, so a
+    /// stolen session cannot be used to brute-force the password.
+    async fn verified_credentials(&self, user_id: &str, password: &str) -> Result<User, AuthError> {
+        let user_id =
+            UserUuid::from_str(user_id).map_err(|e| AuthError::InternalError(e.to_string()))?;
+        let user = self
+            .user_repository
+            .get(user_id)
+            .await?
+            .ok_or(AuthError::UserNotFound)?;
+        self.ensure_login_allowed(&user).await?;
+        if !user.verify_password(password)? {
+            warn!(
+                error_kind = "invalid_credentials",
+                user_id = %user.id,
+                "auth_service.verified_credentials failed"
+            );
+            self.audit_login_failure(Some(user.id), "invalid_credentials")
+                .await;
+            self.record_failed_login(&user).await?;
+            return Err(AuthError::InvalidCredentials);
+        }
+        Ok(user)
+    }
+
+    /// Records the device behind a successful sign-in and hands it its
+    /// first token pair. The user is told by email about unfamiliar devices.
+    async fn start_session(
+        &self,
+        user: &User,
+        origin: &RequestOrigin,
+    ) -> Result<LoginResponse, AuthError> {
+        let now = Utc::now();
+        let history = self
+            .session_repository
+            .sign_in_history(user.id, origin.user_agent.as_deref())
+            .await?;
+        let (refresh_token, secret) =
+            RefreshToken::issue(user.id, self.settings.refresh_token_ttl_secs, now);
+        let session = Session::start(
+            refresh_token.family_id,
+            user.id,
+            origin,
+            refresh_token.expires_at,
+            now,
+        );
+        self.session_repository.save(&session).await?;
+        let response = self.issue_tokens(user, refresh_token, secret).await?;
+
+        if history.is_unfamiliar_device() {
+            self.enqueue_email(
+                user,
+                AuthEmail::NewSignIn {
+                    device: session.device.to_string(),
+                    ip: session.ip.clone(),
+                    signed_in_at: now,
+                },
+            )
+            .await;
+        }
+        info!(user_id = %user.id, session_id = %session.id, device = %session.device, "Session started");
+        Ok(response)
+    }
+
+    async fn issue_tokens(
+        &self,
+        user: &User,
+        refresh_token: RefreshToken,
+        secret: OpaqueToken,
+    ) -> Result<LoginResponse, AuthError> {
+        let token_version = self
+            .token_revocations
+            .current_token_version(user.id)
+            .await?;
+        let access_token = self.token_generator.generate_access_token(
+            &user.id.value().to_string(),
+            user.email.value(),
+            token_version,
+            user.role,
+            refresh_token.family_id,
+        )?;
+        self.refresh_token_repository.save(&refresh_token).await?;
+
+        Ok(LoginResponse {
+            token: access_token.token,
+            token_expires_at: access_token.expires_at,
+            refresh_token: secret.value().to_string(),
+            refresh_token_expires_at: refresh_token.expires_at,
+            is_email_verified: user.email_validated,
+        })
+    }
+
     pub async fn signup(&self, email: &str, password: &str) -> Result<UserUuid, AuthError> {
         let user_id = Uuid::new_v4().to_string();
         let user = match User::new(&user_id, email, password) {
@@ -129,13 +1100,71 @@ impl AuthService {
             }
         };
 
+        // The first send always fits the throttle, recording it starts the cooldown.
+        if let Err(e) = self
+            .verification_email_repository
+            .record_send_if_allowed(
+                user.id,
+                &self.settings.verification_email_throttle,
+                Utc::now(),
+            )
+            .await
+        {
+            error!(error = %e, user_id = %user.id, "Failed to record verification email");
+        }
         self.enqueue_verification_email(&user).await;
+        self.audit_log
+            .record(AuditEntry::new(AuditEventKind::Signup, Some(user.id)))
+            .await;
 
         Ok(saved_id)
     }
 
+    /// Mails a new verification link to a user that has not verified yet,
+    /// within the cooldown and daily cap of the throttle.
+    pub async fn resend_verification_email(&self, user_id: &str) -> Result<(), AuthError> {
+        let user_id =
+            UserUuid::from_str(user_id).map_err(|e| AuthError::InternalError(e.to_string()))?;
+        let user = self
+            .user_repository
+            .get(user_id)
+            .await?
+            .ok_or(AuthError::UserNotFound)?;
+        if user.email_validated {
+            return Err(AuthError::EmailAlreadyVerified);
+        }
+
+        match self
+            .verification_email_repository
+            .record_send_if_allowed(
+                user.id,
+                &self.settings.verification_email_throttle,
+                Utc::now(),
+            )
+            .await?
+        {
+            SendDecision::Allowed => {
+                self.enqueue_verification_email(&user).await;
+                info!(user_id = %user.id, "Verification email resent");
+                Ok(())
+            }
+            SendDecision::Throttled { retry_after_secs } => {
+                warn!(
+                    error_kind = "verification_email_throttled",
+                    user_id = %user.id,
+                    retry_after_secs,
+                    "auth_service.resend_verification_email failed"
+                );
+                Err(AuthError::TooManyRequests { retry_after_secs })
+            }
+        }
+    }
+
     pub async fn verify_email(&self, token: &str) -> Result<(), AuthError> {
-        let user_id_str = self.token_generator.validate_token(token)?;
+        let claims = self
+            .token_generator
+            .validate_purpose_token(TokenPurpose::EmailVerification, token)?;
+        let user_id_str = claims.user_id;
 
         let user_id = UserUuid::from_str(&user_id_str)
             .map_err(|e| AuthError::InternalError(e.to_string()))?;
@@ -148,6 +1177,10 @@ impl AuthService {
                 AuthError::InternalError(e.to_string())
             })?
             .ok_or(AuthError::UserNotFound)?;
+        // A link sent to a previous address must not verify the current one.
+        if user.email.value() != claims.email {
+            return Err(AuthError::InvalidToken);
+        }
 
         user.validate_email();
 
@@ -155,53 +1188,59 @@ impl AuthService {
             |e: crate::auth::domain::errors::AuthRepoError| AuthError::InternalError(e.to_string()),
         )?;
 
+        self.audit_log
+            .record(AuditEntry::new(
+                AuditEventKind::EmailVerified,
+                Some(user.id),
+            ))
+            .await;
         info!(user_id = %user_id_str, "Email verified successfully");
         Ok(())
     }
 
     async fn enqueue_verification_email(&self, user: &User) {
-        let email_queue = &self.email_queue;
-
-        let token = match self
-            .token_generator
-            .generate_token(&user.id.value().to_string(), user.email.value())
-        {
-            Ok(t) => t,
+        let token = match self.token_generator.generate_purpose_token(
+            TokenPurpose::EmailVerification,
+            &user.id.value().to_string(),
+            user.email.value(),
+        ) {
+            Ok(t) => t.token,
             Err(e) => {
                 error!(error = %e, "Failed to generate verification token");
                 return;
             }
         };
 
-        let verification_link = format!("{}/auth/verify-email?token={}", self.frontend_url, token);
-
-        let subject = "Verify your email";
-        let body = format!(
-            "Hello,\n\nPlease verify your email by clicking the link below:\n\n{}\n\nThis link will expire in a few hours.\n\nIf you did not register, please ignore this message.",
-            verification_link
+        let verification_link = format!(
+            "{}/auth/verify-email?token={}",
+            self.settings.frontend_url, token
         );
 
-        let context = tracing::Span::current().context();
-        let mut carrier = std::collections::HashMap::new();
-        opentelemetry::global::get_text_map_propagator(|propagator| {
-            propagator.inject_context(&context, &mut carrier);
-        });
-        let trace_context = carrier.get("traceparent").cloned();
-
-        if let Err(e) = email_queue
-            .enqueue(
-                user.email.value(),
-                subject,
-                &body,
-                user.id.value(),
-                trace_context,
-            )
+        self.enqueue_email(
+            user,
+            AuthEmail::VerifyEmail {
+                link: verification_link,
+            },
+        )
+        .await;
+    }
+
+    async fn enqueue_email(&self, user: &User, email: AuthEmail) {
+        self.enqueue_email_to(user.email.value(), user, email).await;
+    }
+
+    /// Failing to enqueue is logged but does not fail the caller's operation.
+    async fn enqueue_email_to(&self, to: &str, user: &User, email: AuthEmail) {
+        if let Err(e) = self
+            .email_queue
+            .enqueue(to, &email, user.id.value(), current_trace_context())
             .await
         {
             error!(
                 error = %e,
-                email = user.email.value(),
-                "Failed to enqueue verification email"
+                email = to,
+                template = email.template(),
+                "Failed to enqueue email"
             );
         }
     }
@@ -211,6 +1250,7 @@ fn auth_domain_error_kind(err: &crate::auth::domain::errors::AuthDomainError) ->
     use crate::auth::domain::errors::AuthDomainError;
     match err {
         AuthDomainError::Shared(_) => "shared_domain_error",
+        AuthDomainError::InvalidPersonalAccessToken(_) => "invalid_personal_access_token",
         AuthDomainError::InternalError(_) => "internal_error",
     }
 }
@@ -222,61 +1262,297 @@ fn auth_repo_error_kind(err: &crate::auth::domain::errors::AuthRepoError) -> &'s
         AuthRepoError::ConversionError(_) => "conversion_error",
         AuthRepoError::NotFound(_) => "not_found",
         AuthRepoError::UserAlreadyExists(_) => "user_already_exists",
+        AuthRepoError::IdentityAlreadyLinked(_) => "identity_already_linked",
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth::domain::entities::totp::time_step;
     use crate::auth::{
+        application::token_generator::{AccessToken, PurposeClaims, PurposeToken},
         domain::{entities::user::User, errors::AuthRepoError},
-        infrastructure::persistence::repositories::user_in_memory_repository::UserInMemoryRepository,
+        infrastructure::persistence::repositories::{
+            email_change_request_in_memory_repository::EmailChangeRequestInMemoryRepository,
+            login_attempt_in_memory_repository::LoginAttemptInMemoryRepository,
+            password_reset_token_in_memory_repository::PasswordResetTokenInMemoryRepository,
+            personal_access_token_in_memory_repository::PersonalAccessTokenInMemoryRepository,
+            refresh_token_in_memory_repository::RefreshTokenInMemoryRepository,
+            session_in_memory_repository::SessionInMemoryRepository,
+            token_revocation_in_memory_repository::TokenRevocationInMemoryRepository,
+            two_factor_in_memory_repository::TwoFactorInMemoryRepository,
+            user_in_memory_repository::UserInMemoryRepository,
+            verification_email_in_memory_repository::VerificationEmailInMemoryRepository,
+        },
+    };
+    use crate::shared::{
+        domain::value_objects::UserRole,
+        infrastructure::audit::audit_in_memory_log::AuditInMemoryLog,
     };
     use std::sync::Mutex;
+    use std::time::Duration;
     use uuid::Uuid;
 
-    struct MockTokenGenerator {
-        last_user_id: Mutex<Option<String>>,
+    /// Purpose tokens are readable `audience|user_id|email|token_id` strings.
+    struct MockTokenGenerator;
+
+    struct SentEmail {
+        to: String,
+        email: AuthEmail,
+    }
+
+    #[derive(Clone, Default)]
+    struct MockEmailQueue {
+        sent: Arc<Mutex<Vec<SentEmail>>>,
+    }
+
+    impl MockEmailQueue {
+        fn sent_templates(&self) -> Vec<&'static str> {
+            let sent = self.sent.lock().unwrap();
+            sent.iter().map(|sent| sent.email.template()).collect()
+        }
+
+        fn sent_to(&self) -> Vec<String> {
+            let sent = self.sent.lock().unwrap();
+            sent.iter().map(|email| email.to.clone()).collect()
+        }
+
+        fn last_link_token(&self) -> String {
+            let sent = self.sent.lock().unwrap();
+            let link = sent
+                .iter()
+                .rev()
+                .find_map(|sent| match &sent.email {
+                    AuthEmail::VerifyEmail { link }
+                    | AuthEmail::ResetPassword { link }
+                    | AuthEmail::ConfirmEmailChange { link } => Some(link),
+                    _ => None,
+                })
+                .expect("An email with a link should have been sent");
+            let start = link.find("token=").unwrap() + 6;
+            link[start..].to_string()
+        }
     }
 
-    struct MockEmailQueue;
     #[async_trait::async_trait]
     impl IEmailQueueEnqueuer for MockEmailQueue {
         async fn enqueue(
             &self,
-            _email: &str,
-            _subject: &str,
-            _body: &str,
+            to: &str,
+            email: &AuthEmail,
             _user_id: uuid::Uuid,
             _trace_context: Option<String>,
         ) -> Result<(), AuthError> {
+            self.sent.lock().unwrap().push(SentEmail {
+                to: to.to_string(),
+                email: email.clone(),
+            });
             Ok(())
         }
     }
 
     impl MockTokenGenerator {
         fn new() -> Self {
-            Self {
-                last_user_id: Mutex::new(None),
-            }
+            Self
         }
     }
 
     impl ITokenGenerator for MockTokenGenerator {
-        fn generate_token(&self, user_id: &str, _email: &str) -> Result<String, AuthError> {
-            *self.last_user_id.lock().unwrap() = Some(user_id.to_string());
-            Ok("mock-token".to_string())
-        }
-
-        fn validate_token(&self, token: &str) -> Result<String, AuthError> {
-            if token == "mock-token" {
-                let user_id = self.last_user_id.lock().unwrap();
-                Ok(user_id.clone().unwrap_or_default())
-            } else if token == "expired-token" {
-                Err(AuthError::TokenExpired)
-            } else {
-                Err(AuthError::InvalidToken)
+        fn generate_access_token(
+            &self,
+            _user_id: &str,
+            _email: &str,
+            token_version: i32,
+            _role: UserRole,
+            _session_id: Uuid,
+        ) -> Result<AccessToken, AuthError> {
+            Ok(AccessToken {
+                token: format!("mock-access-token-v{}", token_version),
+                expires_at: Utc::now(),
+            })
+        }
+
+        fn generate_purpose_token(
+            &self,
+            purpose: TokenPurpose,
+            user_id: &str,
+            email: &str,
+        ) -> Result<PurposeToken, AuthError> {
+            let token_id = Uuid::new_v4().to_string();
+            Ok(PurposeToken {
+                token: format!("{}|{}|{}|{}", purpose.audience(), user_id, email, token_id),
+                token_id,
+                expires_at: Utc::now() + chrono::TimeDelta::hours(1),
+            })
+        }
+
+        fn validate_purpose_token(
+            &self,
+            purpose: TokenPurpose,
+            token: &str,
+        ) -> Result<PurposeClaims, AuthError> {
+            if token == "expired-token" {
+                return Err(AuthError::TokenExpired);
             }
+            match token.split('|').collect::<Vec<_>>().as_slice() {
+                [audience, user_id, email, token_id] if *audience == purpose.audience() => {
+                    Ok(PurposeClaims {
+                        user_id: user_id.to_string(),
+                        email: email.to_string(),
+                        token_id: token_id.to_string(),
+                    })
+                }
+                _ => Err(AuthError::InvalidToken),
+            }
+        }
+    }
+
+    fn test_settings() -> AuthSettings {
+        AuthSettings {
+            frontend_url: "http://localhost:3000".to_string(),
+            refresh_token_ttl_secs: 3600,
+            verification_email_throttle: VerificationEmailThrottle {
+                cooldown_secs: 60,
+                daily_cap: 5,
+            },
+            totp_issuer: "Seeker".to_string(),
+            login_lockout: LoginLockoutPolicy {
+                delay_after: 3,
+                base_delay_secs: 1,
+                max_delay_secs: 30,
+                lockout_threshold: 10,
+                lockout_secs: 900,
+            },
+        }
+    }
+
+    fn build_service_with(
+        repo: UserInMemoryRepository,
+        token_generator: Box<dyn ITokenGenerator>,
+        email_queue: MockEmailQueue,
+        settings: AuthSettings,
+    ) -> AuthService {
+        build_service_with_two_factor(
+            repo,
+            token_generator,
+            email_queue,
+            settings,
+            TwoFactorInMemoryRepository::default(),
+        )
+    }
+
+    fn build_service_with_two_factor(
+        repo: UserInMemoryRepository,
+        token_generator: Box<dyn ITokenGenerator>,
+        email_queue: MockEmailQueue,
+        settings: AuthSettings,
+        two_factor: TwoFactorInMemoryRepository,
+    ) -> AuthService {
+        build_service_with_audit_log(
+            repo,
+            token_generator,
+            email_queue,
+            settings,
+            two_factor,
+            AuditInMemoryLog::new(),
+        )
+    }
+
+    fn build_service_with_audit_log(
+        repo: UserInMemoryRepository,
+        token_generator: Box<dyn ITokenGenerator>,
+        email_queue: MockEmailQueue,
+        settings: AuthSettings,
+        two_factor: TwoFactorInMemoryRepository,
+        audit_log: AuditInMemoryLog,
+    ) -> AuthService {
+        build_service_with_sessions(
+            repo,
+            token_generator,
+            email_queue,
+            settings,
+            two_factor,
+            audit_log,
+            SessionInMemoryRepository::default(),
+        )
+    }
+
+    fn build_service_with_sessions(
+        repo: UserInMemoryRepository,
+        token_generator: Box<dyn ITokenGenerator>,
+        email_queue: MockEmailQueue,
+        settings: AuthSettings,
+        two_factor: TwoFactorInMemoryRepository,
+        audit_log: AuditInMemoryLog,
+        sessions: SessionInMemoryRepository,
+    ) -> AuthService {
+        AuthService::new(
+            AuthRepositories {
+                users: Box::new(repo.clone()),
+                refresh_tokens: Box::new(RefreshTokenInMemoryRepository::default()),
+                password_reset_tokens: Box::new(PasswordResetTokenInMemoryRepository::new(repo)),
+                verification_emails: Box::new(VerificationEmailInMemoryRepository::default()),
+                email_change_requests: Box::new(EmailChangeRequestInMemoryRepository::default()),
+                two_factor: Box::new(two_factor),
+                login_attempts: Box::new(LoginAttemptInMemoryRepository::default()),
+                personal_access_tokens: Box::new(PersonalAccessTokenInMemoryRepository::default()),
+                sessions: Box::new(sessions),
+            },
+            token_generator,
+            Arc::new(TokenRevocationService::new(
+                Box::new(TokenRevocationInMemoryRepository::default()),
+                Duration::from_secs(30),
+            )),
+            Box::new(email_queue),
+            Arc::new(audit_log),
+            settings,
+        )
+    }
+
+    fn build_service(
+        repo: UserInMemoryRepository,
+        token_generator: Box<dyn ITokenGenerator>,
+    ) -> AuthService {
+        build_service_with(
+            repo,
+            token_generator,
+            MockEmailQueue::default(),
+            test_settings(),
+        )
+    }
+
+    async fn login_test_user(
+        auth_service: &AuthService,
+        repo: &UserInMemoryRepository,
+    ) -> LoginResponse {
+        let user = User::new(
+            &Uuid::new_v4().to_string(),
+            "test@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .expect("Error creating user");
+        repo.save(&user).await.unwrap();
+        login_test_user_again(auth_service).await
+    }
+
+    async fn login_test_user_again(auth_service: &AuthService) -> LoginResponse {
+        authenticated(
+            auth_service
+                .login(
+                    "test@example.com",
+                    "S0m3V3ryStr0ngP@ssw0rd!",
+                    &RequestOrigin::default(),
+                )
+                .await
+                .unwrap(),
+        )
+    }
+
+    fn authenticated(outcome: LoginOutcome) -> LoginResponse {
+        match outcome {
+            LoginOutcome::Authenticated(response) => response,
+            LoginOutcome::MfaRequired(_) => panic!("Expected a session, got an MFA challenge"),
         }
     }
 
@@ -289,36 +1565,33 @@ mod tests {
             "S0m3V3ryStr0ngP@ssw0rd!",
         )
         .expect("Error creating user");
-        let repo = Box::new(UserInMemoryRepository::default());
+        let repo = UserInMemoryRepository::default();
         repo.save(&user).await.unwrap();
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service
-            .login("test@example.com", "S0m3V3ryStr0ngP@ssw0rd!")
+            .login(
+                "test@example.com",
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                &RequestOrigin::default(),
+            )
             .await;
 
         assert!(result.is_ok());
-        let response = result.unwrap();
-        assert_eq!(response.token, "mock-token");
+        let response = authenticated(result.unwrap());
+        assert_eq!(response.token, "mock-access-token-v0");
+        assert!(!response.refresh_token.is_empty());
         assert!(!response.is_email_verified);
     }
 
     #[tokio::test]
     async fn test_auth_service_login_invalid_email() {
-        let repo = Box::new(UserInMemoryRepository::default());
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
-        let result = auth_service.login("invalid-email", "password").await;
+        let result = auth_service
+            .login("invalid-email", "password", &RequestOrigin::default())
+            .await;
         assert!(matches!(result, Err(AuthError::DomainError(_))));
     }
 
@@ -331,46 +1604,39 @@ mod tests {
             "S0m3V3ryStr0ngP@ssw0rd!",
         )
         .expect("Error creating user");
-        let repo = Box::new(UserInMemoryRepository::default());
+        let repo = UserInMemoryRepository::default();
         repo.save(&user).await.unwrap();
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service
-            .login("test@example.com", "wrong-password")
+            .login(
+                "test@example.com",
+                "wrong-password",
+                &RequestOrigin::default(),
+            )
             .await;
         assert_eq!(result, Err(AuthError::InvalidCredentials));
     }
 
     #[tokio::test]
     async fn test_auth_service_login_user_not_found() {
-        let repo = Box::new(UserInMemoryRepository::default());
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service
-            .login("nonexistent@example.com", "password")
+            .login(
+                "nonexistent@example.com",
+                "password",
+                &RequestOrigin::default(),
+            )
             .await;
         assert_eq!(result, Err(AuthError::InvalidCredentials));
     }
 
     #[tokio::test]
     async fn test_auth_service_signup_success() {
-        let repo = Box::new(UserInMemoryRepository::default());
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service
             .signup("test@example.com", "S0m3V3ryStr0ngP@ssw0rd!")
@@ -380,13 +1646,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_auth_service_signup_invalid_email() {
-        let repo = Box::new(UserInMemoryRepository::default());
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service.signup("invalid-email", "password").await;
         assert!(matches!(result, Err(AuthError::DomainError(_))));
@@ -394,13 +1655,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_auth_service_signup_invalid_password() {
-        let repo = Box::new(UserInMemoryRepository::default());
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service.signup("test@example.com", "weak").await;
         assert!(matches!(result, Err(AuthError::DomainError(_))));
@@ -412,14 +1668,9 @@ mod tests {
         let user_id = Uuid::new_v4();
         let user = User::new(&user_id.to_string(), email, "S0m3V3ryStr0ngP@ssw0rd!")
             .expect("Error creating user");
-        let repo = Box::new(UserInMemoryRepository::default());
+        let repo = UserInMemoryRepository::default();
         repo.save(&user).await.unwrap();
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service.signup(email, "S0m3V3ryStr0ngP@ssw0rd!").await;
         assert!(matches!(
@@ -442,22 +1693,22 @@ mod tests {
 
         assert!(!user.email_validated);
 
-        let repo = Box::new(UserInMemoryRepository::default());
+        let repo = UserInMemoryRepository::default();
         repo.save(&user).await.unwrap();
 
         let token_gen = MockTokenGenerator::new();
-        token_gen
-            .generate_token(&user_id.to_string(), "test@example.com")
-            .unwrap();
+        let token = token_gen
+            .generate_purpose_token(
+                TokenPurpose::EmailVerification,
+                &user_id.to_string(),
+                "test@example.com",
+            )
+            .unwrap()
+            .token;
 
-        let auth_service = AuthService::new(
-            repo.clone(),
-            Box::new(token_gen),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let auth_service = build_service(repo.clone(), Box::new(token_gen));
 
-        let result = auth_service.verify_email("mock-token").await;
+        let result = auth_service.verify_email(&token).await;
         assert!(result.is_ok());
 
         let updated_user = repo
@@ -468,39 +1719,170 @@ mod tests {
         assert!(updated_user.email_validated);
     }
 
+    #[tokio::test]
+    async fn test_resend_verification_sends_a_new_link() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            AuthSettings {
+                verification_email_throttle: VerificationEmailThrottle {
+                    cooldown_secs: 0,
+                    daily_cap: 2,
+                },
+                ..test_settings()
+            },
+        );
+        let user_id = auth_service
+            .signup("test@example.com", "S0m3V3ryStr0ngP@ssw0rd!")
+            .await
+            .unwrap();
+
+        auth_service
+            .resend_verification_email(&user_id.to_string())
+            .await
+            .unwrap();
+        let over_cap = auth_service
+            .resend_verification_email(&user_id.to_string())
+            .await;
+
+        assert!(matches!(over_cap, Err(AuthError::TooManyRequests { .. })));
+        assert_eq!(
+            email_queue.sent_templates(),
+            vec!["verify_email", "verify_email"]
+        );
+        auth_service
+            .verify_email(&email_queue.last_link_token())
+            .await
+            .unwrap();
+        let verified = repo.get(user_id).await.unwrap().unwrap();
+        assert!(verified.email_validated);
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_cooldown_starts_at_signup() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
+        let user_id = auth_service
+            .signup("test@example.com", "S0m3V3ryStr0ngP@ssw0rd!")
+            .await
+            .unwrap();
+
+        let result = auth_service
+            .resend_verification_email(&user_id.to_string())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AuthError::TooManyRequests { retry_after_secs }) if retry_after_secs > 0
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_for_verified_user() {
+        let mut user = User::new(
+            &Uuid::new_v4().to_string(),
+            "test@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .unwrap();
+        user.validate_email();
+        let repo = UserInMemoryRepository::default();
+        repo.save(&user).await.unwrap();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
+
+        let result = auth_service
+            .resend_verification_email(&user.id.to_string())
+            .await;
+
+        assert_eq!(result, Err(AuthError::EmailAlreadyVerified));
+    }
+
     #[tokio::test]
     async fn test_verify_email_user_not_found() {
-        let repo = Box::new(UserInMemoryRepository::default());
+        let repo = UserInMemoryRepository::default();
         let non_existent_id = Uuid::new_v4();
         let token_gen = MockTokenGenerator::new();
-        token_gen
-            .generate_token(&non_existent_id.to_string(), "ghost@example.com")
-            .unwrap();
+        let token = token_gen
+            .generate_purpose_token(
+                TokenPurpose::EmailVerification,
+                &non_existent_id.to_string(),
+                "ghost@example.com",
+            )
+            .unwrap()
+            .token;
 
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(token_gen),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let auth_service = build_service(repo, Box::new(token_gen));
 
-        let result = auth_service.verify_email("mock-token").await;
+        let result = auth_service.verify_email(&token).await;
         assert_eq!(result, Err(AuthError::UserNotFound));
     }
 
     #[tokio::test]
     async fn test_verify_email_invalid_token() {
-        let repo = Box::new(UserInMemoryRepository::default());
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service.verify_email("bad-token").await;
         assert_eq!(result, Err(AuthError::InvalidToken));
     }
+
+    #[tokio::test]
+    async fn test_verify_email_rejects_tokens_for_other_purposes() {
+        let user_id = Uuid::new_v4();
+        let user = User::new(
+            &user_id.to_string(),
+            "test@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .expect("Error creating user");
+        let repo = UserInMemoryRepository::default();
+        repo.save(&user).await.unwrap();
+        let token_gen = MockTokenGenerator::new();
+        let reset_token = token_gen
+            .generate_purpose_token(
+                TokenPurpose::PasswordReset,
+                &user_id.to_string(),
+                "test@example.com",
+            )
+            .unwrap()
+            .token;
+        let auth_service = build_service(repo, Box::new(token_gen));
+
+        let result = auth_service.verify_email(&reset_token).await;
+
+        assert_eq!(result, Err(AuthError::InvalidToken));
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_rejects_links_sent_to_another_address() {
+        let user_id = Uuid::new_v4();
+        let user = User::new(
+            &user_id.to_string(),
+            "test@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .expect("Error creating user");
+        let repo = UserInMemoryRepository::default();
+        repo.save(&user).await.unwrap();
+        let token_gen = MockTokenGenerator::new();
+        let token = token_gen
+            .generate_purpose_token(
+                TokenPurpose::EmailVerification,
+                &user_id.to_string(),
+                "old@example.com",
+            )
+            .unwrap()
+            .token;
+        let auth_service = build_service(repo, Box::new(token_gen));
+
+        let result = auth_service.verify_email(&token).await;
+
+        assert_eq!(result, Err(AuthError::InvalidToken));
+    }
+
     #[tokio::test]
     async fn test_auth_service_login_account_disabled() {
         let user_id = Uuid::new_v4();
@@ -512,18 +1894,1090 @@ mod tests {
         .expect("Error creating user");
         user.account_disabled = true;
 
-        let repo = Box::new(UserInMemoryRepository::default());
+        let repo = UserInMemoryRepository::default();
         repo.save(&user).await.unwrap();
-        let auth_service = AuthService::new(
-            repo,
-            Box::new(MockTokenGenerator::new()),
-            Box::new(MockEmailQueue),
-            "http://localhost:3000".to_string(),
-        );
+        let auth_service = build_service(repo, Box::new(MockTokenGenerator::new()));
 
         let result = auth_service
-            .login("disabled@example.com", "S0m3V3ryStr0ngP@ssw0rd!")
+            .login(
+                "disabled@example.com",
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                &RequestOrigin::default(),
+            )
             .await;
         assert_eq!(result, Err(AuthError::InvalidCredentials));
     }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_the_refresh_token() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo.clone(), Box::new(MockTokenGenerator::new()));
+        let login = login_test_user(&auth_service, &repo).await;
+
+        let refreshed = auth_service.refresh(&login.refresh_token).await.unwrap();
+
+        assert_eq!(refreshed.token, "mock-access-token-v0");
+        assert_ne!(refreshed.refresh_token, login.refresh_token);
+        assert!(auth_service.refresh(&refreshed.refresh_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_reuse_revokes_the_family() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo.clone(), Box::new(MockTokenGenerator::new()));
+        let login = login_test_user(&auth_service, &repo).await;
+        let refreshed = auth_service.refresh(&login.refresh_token).await.unwrap();
+
+        let reused = auth_service.refresh(&login.refresh_token).await;
+
+        assert_eq!(reused, Err(AuthError::InvalidToken));
+        assert_eq!(
+            auth_service.refresh(&refreshed.refresh_token).await,
+            Err(AuthError::InvalidToken)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_with_unknown_token() {
+        let auth_service = build_service(
+            UserInMemoryRepository::default(),
+            Box::new(MockTokenGenerator::new()),
+        );
+
+        let result = auth_service.refresh("not-a-refresh-token").await;
+
+        assert_eq!(result, Err(AuthError::InvalidToken));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_with_expired_token() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            MockEmailQueue::default(),
+            AuthSettings {
+                refresh_token_ttl_secs: 0,
+                ..test_settings()
+            },
+        );
+        let login = login_test_user(&auth_service, &repo).await;
+
+        let result = auth_service.refresh(&login.refresh_token).await;
+
+        assert_eq!(result, Err(AuthError::TokenExpired));
+    }
+
+    #[tokio::test]
+    async fn test_logout_revokes_the_access_token_and_its_session() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo.clone(), Box::new(MockTokenGenerator::new()));
+        let login = login_test_user(&auth_service, &repo).await;
+        let other_session = login_test_user_again(&auth_service).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id;
+
+        auth_service
+            .logout(
+                &user_id.value().to_string(),
+                "current-jti",
+                login.token_expires_at + chrono::TimeDelta::minutes(15),
+                None,
+                Some(&login.refresh_token),
+            )
+            .await
+            .unwrap();
+
+        let revocations = &auth_service.token_revocations;
+        assert!(
+            revocations
+                .is_revoked(user_id, "current-jti", 0)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !revocations
+                .is_revoked(user_id, "other-jti", 0)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            auth_service.refresh(&login.refresh_token).await,
+            Err(AuthError::InvalidToken)
+        );
+        assert!(
+            auth_service
+                .refresh(&other_session.refresh_token)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_logout_all_revokes_every_session() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo.clone(), Box::new(MockTokenGenerator::new()));
+        let login = login_test_user(&auth_service, &repo).await;
+        let other_session = login_test_user_again(&auth_service).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id;
+
+        auth_service
+            .logout_all(&user_id.value().to_string())
+            .await
+            .unwrap();
+
+        let revocations = &auth_service.token_revocations;
+        assert!(revocations.is_revoked(user_id, "any-jti", 0).await.unwrap());
+        assert!(auth_service.refresh(&login.refresh_token).await.is_err());
+        assert!(
+            auth_service
+                .refresh(&other_session.refresh_token)
+                .await
+                .is_err()
+        );
+        let new_login = login_test_user_again(&auth_service).await;
+        assert_eq!(new_login.token, "mock-access-token-v1");
+    }
+
+    #[tokio::test]
+    async fn test_sessions_are_listed_revoked_and_new_devices_reported() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let sessions = SessionInMemoryRepository::default();
+        let auth_service = build_service_with_sessions(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+            TwoFactorInMemoryRepository::default(),
+            AuditInMemoryLog::new(),
+            sessions.clone(),
+        );
+        let user = User::new(
+            &Uuid::new_v4().to_string(),
+            "test@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .unwrap();
+        repo.save(&user).await.unwrap();
+        let sign_in = |user_agent: &str| RequestOrigin {
+            ip: Some("203.0.113.7".to_string()),
+            user_agent: Some(user_agent.to_string()),
+            ..RequestOrigin::default()
+        };
+        let laptop_agent = "Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0";
+        let phone_agent = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1";
+
+        for user_agent in [laptop_agent, laptop_agent, phone_agent] {
+            auth_service
+                .login(
+                    "test@example.com",
+                    "S0m3V3ryStr0ngP@ssw0rd!",
+                    &sign_in(user_agent),
+                )
+                .await
+                .unwrap();
+        }
+
+        // Neither the first sign-in nor a known device is worth a warning.
+        assert_eq!(email_queue.sent_templates(), vec!["new_sign_in"]);
+        let user_id = user.id.value().to_string();
+        let listed = auth_service.list_sessions(&user_id).await.unwrap();
+        assert_eq!(listed.len(), 3);
+        let phone = listed
+            .iter()
+            .find(|session| session.user_agent.as_deref() == Some(phone_agent))
+            .unwrap();
+        assert_eq!(phone.device, "Safari on iOS");
+        assert_eq!(phone.ip.as_deref(), Some("203.0.113.7"));
+
+        auth_service
+            .revoke_session(&user_id, phone.id)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            auth_service.revoke_session(&user_id, phone.id).await,
+            Err(AuthError::SessionNotFound)
+        );
+        assert_eq!(
+            auth_service
+                .revoke_session(&Uuid::new_v4().to_string(), listed[0].id)
+                .await,
+            Err(AuthError::SessionNotFound)
+        );
+        assert_eq!(auth_service.list_sessions(&user_id).await.unwrap().len(), 2);
+        assert!(
+            !sessions
+                .get(phone.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .is_active(Utc::now())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_password_reset_for_unknown_email_sends_nothing() {
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            UserInMemoryRepository::default(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+
+        assert!(
+            auth_service
+                .request_password_reset("nobody@example.com")
+                .await
+                .is_ok()
+        );
+        assert!(
+            auth_service
+                .request_password_reset("not-an-email")
+                .await
+                .is_ok()
+        );
+        assert!(email_queue.sent_templates().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_password_reset_emails_are_throttled_without_telling_the_caller() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+        login_test_user(&auth_service, &repo).await;
+
+        for _ in 0..3 {
+            assert_eq!(
+                auth_service
+                    .request_password_reset("test@example.com")
+                    .await,
+                Ok(())
+            );
+        }
+
+        assert_eq!(email_queue.sent_templates(), vec!["reset_password"]);
+    }
+
+    #[tokio::test]
+    async fn test_password_reset_changes_password_and_revokes_sessions() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+        let session = login_test_user(&auth_service, &repo).await;
+        let new_password = "An0ther-V3ry-Str0ng-P@ss";
+
+        auth_service
+            .request_password_reset("test@example.com")
+            .await
+            .unwrap();
+        assert_eq!(email_queue.sent_templates(), vec!["reset_password"]);
+        let token = email_queue.last_link_token();
+        auth_service
+            .reset_password(&token, new_password)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            auth_service
+                .login(
+                    "test@example.com",
+                    "S0m3V3ryStr0ngP@ssw0rd!",
+                    &RequestOrigin::default()
+                )
+                .await,
+            Err(AuthError::InvalidCredentials)
+        );
+        assert!(
+            auth_service
+                .login("test@example.com", new_password, &RequestOrigin::default())
+                .await
+                .is_ok()
+        );
+        assert!(auth_service.refresh(&session.refresh_token).await.is_err());
+        assert_eq!(
+            auth_service.reset_password(&token, new_password).await,
+            Err(AuthError::InvalidToken),
+            "Reset tokens are single use"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_password_reset_rejects_weak_password_without_burning_the_token() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+        login_test_user(&auth_service, &repo).await;
+        auth_service
+            .request_password_reset("test@example.com")
+            .await
+            .unwrap();
+        let token = email_queue.last_link_token();
+
+        let weak = auth_service.reset_password(&token, "password").await;
+
+        assert!(matches!(weak, Err(AuthError::DomainError(_))));
+        assert!(
+            auth_service
+                .reset_password(&token, "An0ther-V3ry-Str0ng-P@ss")
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_password_reset_rejects_expired_and_foreign_tokens() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+        login_test_user(&auth_service, &repo).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id;
+        let verification_token = MockTokenGenerator::new()
+            .generate_purpose_token(
+                TokenPurpose::EmailVerification,
+                &user_id.value().to_string(),
+                "test@example.com",
+            )
+            .unwrap()
+            .token;
+
+        assert_eq!(
+            auth_service
+                .reset_password("expired-token", "An0ther-V3ry-Str0ng-P@ss")
+                .await,
+            Err(AuthError::TokenExpired)
+        );
+        assert_eq!(
+            auth_service
+                .reset_password(&verification_token, "An0ther-V3ry-Str0ng-P@ss")
+                .await,
+            Err(AuthError::InvalidToken)
+        );
+        assert_eq!(
+            auth_service
+                .reset_password("unknown", "An0ther-V3ry-Str0ng-P@ss")
+                .await,
+            Err(AuthError::InvalidToken)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_password_requires_the_current_one_and_revokes_sessions() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo.clone(), Box::new(MockTokenGenerator::new()));
+        let login = login_test_user(&auth_service, &repo).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id
+            .to_string();
+        let new_password = "An0ther-V3ry-Str0ng-P@ss";
+
+        let wrong_current = auth_service
+            .change_password(
+                &user_id,
+                "not-my-password",
+                new_password,
+                &RequestOrigin::default(),
+            )
+            .await;
+        let weak = auth_service
+            .change_password(
+                &user_id,
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                "weak",
+                &RequestOrigin::default(),
+            )
+            .await;
+        let changed = auth_service
+            .change_password(
+                &user_id,
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                new_password,
+                &RequestOrigin::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(wrong_current, Err(AuthError::InvalidCredentials));
+        assert!(matches!(weak, Err(AuthError::DomainError(_))));
+        assert_eq!(changed.token, "mock-access-token-v1");
+        assert_eq!(
+            auth_service.refresh(&login.refresh_token).await,
+            Err(AuthError::InvalidToken)
+        );
+        assert!(auth_service.refresh(&changed.refresh_token).await.is_ok());
+        assert!(
+            auth_service
+                .login("test@example.com", new_password, &RequestOrigin::default())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_email_change_applies_only_after_confirmation() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+        let user = User::new(
+            &Uuid::new_v4().to_string(),
+            "old@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .unwrap();
+        repo.save(&user).await.unwrap();
+
+        auth_service
+            .request_email_change(
+                &user.id.to_string(),
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                "new@example.com",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            email_queue.sent_to(),
+            vec!["new@example.com", "old@example.com"]
+        );
+        assert_eq!(
+            email_queue.sent_templates(),
+            vec!["confirm_email_change", "email_change_requested"]
+        );
+        let pending = repo.get(user.id).await.unwrap().unwrap();
+        assert_eq!(pending.email.value(), "old@example.com");
+
+        let token = email_queue.last_link_token();
+        auth_service.confirm_email_change(&token).await.unwrap();
+
+        let changed = repo.get(user.id).await.unwrap().unwrap();
+        assert_eq!(changed.email.value(), "new@example.com");
+        assert!(changed.email_validated);
+        assert_eq!(
+            auth_service.confirm_email_change(&token).await,
+            Err(AuthError::InvalidToken),
+            "The link is single use"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_email_change_requires_password_and_a_free_address() {
+        let repo = UserInMemoryRepository::default();
+        let auth_service = build_service(repo.clone(), Box::new(MockTokenGenerator::new()));
+        let user = User::new(
+            &Uuid::new_v4().to_string(),
+            "old@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .unwrap();
+        let other = User::new(
+            &Uuid::new_v4().to_string(),
+            "taken@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .unwrap();
+        repo.save(&user).await.unwrap();
+        repo.save(&other).await.unwrap();
+        let user_id = user.id.to_string();
+
+        let wrong_password = auth_service
+            .request_email_change(&user_id, "not-my-password", "new@example.com")
+            .await;
+        let taken = auth_service
+            .request_email_change(&user_id, "S0m3V3ryStr0ngP@ssw0rd!", "taken@example.com")
+            .await;
+        let invalid = auth_service
+            .request_email_change(&user_id, "S0m3V3ryStr0ngP@ssw0rd!", "not-an-email")
+            .await;
+
+        assert_eq!(wrong_password, Err(AuthError::InvalidCredentials));
+        assert_eq!(taken, Err(AuthError::EmailTaken));
+        assert!(matches!(invalid, Err(AuthError::DomainError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_email_change_confirmation_fails_if_the_address_was_taken_meanwhile() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+        let user = User::new(
+            &Uuid::new_v4().to_string(),
+            "old@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .unwrap();
+        repo.save(&user).await.unwrap();
+        auth_service
+            .request_email_change(
+                &user.id.to_string(),
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                "new@example.com",
+            )
+            .await
+            .unwrap();
+        let token = email_queue.last_link_token();
+        auth_service
+            .signup("new@example.com", "S0m3V3ryStr0ngP@ssw0rd!")
+            .await
+            .unwrap();
+
+        let result = auth_service.confirm_email_change(&token).await;
+
+        assert_eq!(result, Err(AuthError::EmailTaken));
+        let unchanged = repo.get(user.id).await.unwrap().unwrap();
+        assert_eq!(unchanged.email.value(), "old@example.com");
+    }
+
+    async fn enable_mfa(
+        auth_service: &AuthService,
+        two_factor: &TwoFactorInMemoryRepository,
+        user_id: &str,
+    ) -> (TwoFactor, Vec<String>) {
+        auth_service
+            .start_mfa_enrollment(user_id, "S0m3V3ryStr0ngP@ssw0rd!")
+            .await
+            .unwrap();
+        let pending = two_factor
+            .get(UserUuid::from_str(user_id).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        let code = pending.secret.code_at_step(time_step(Utc::now())).unwrap();
+        let recovery_codes = auth_service
+            .confirm_mfa_enrollment(user_id, &code)
+            .await
+            .unwrap();
+        (pending, recovery_codes)
+    }
+
+    #[tokio::test]
+    async fn test_mfa_enrollment_turns_login_into_two_steps() {
+        let repo = UserInMemoryRepository::default();
+        let two_factor = TwoFactorInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with_two_factor(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+            two_factor.clone(),
+        );
+        login_test_user(&auth_service, &repo).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id
+            .to_string();
+
+        assert_eq!(
+            auth_service
+                .start_mfa_enrollment(&user_id, "not-my-password")
+                .await,
+            Err(AuthError::InvalidCredentials)
+        );
+        let enrollment = auth_service
+            .start_mfa_enrollment(&user_id, "S0m3V3ryStr0ngP@ssw0rd!")
+            .await
+            .unwrap();
+        assert!(
+            enrollment
+                .otpauth_uri
+                .starts_with("otpauth://totp/Seeker:test%40example.com?secret=")
+        );
+        // Pending until confirmed.
+        login_test_user_again(&auth_service).await;
+        assert_eq!(
+            auth_service
+                .confirm_mfa_enrollment(&user_id, "not-a-code")
+                .await,
+            Err(AuthError::InvalidMfaCode)
+        );
+
+        let (secret_holder, recovery_codes) =
+            enable_mfa(&auth_service, &two_factor, &user_id).await;
+        assert_eq!(recovery_codes.len(), 10);
+        assert!(email_queue.sent_templates().contains(&"two_factor_enabled"));
+
+        let LoginOutcome::MfaRequired(challenge) = auth_service
+            .login(
+                "test@example.com",
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                &RequestOrigin::default(),
+            )
+            .await
+            .unwrap()
+        else {
+            panic!("Expected an MFA challenge");
+        };
+        let step = time_step(Utc::now());
+        let replayed = secret_holder.secret.code_at_step(step).unwrap();
+        let next = secret_holder.secret.code_at_step(step + 1).unwrap();
+
+        assert_eq!(
+            auth_service
+                .complete_mfa_login(&challenge.mfa_token, &replayed, &RequestOrigin::default())
+                .await,
+            Err(AuthError::InvalidMfaCode)
+        );
+        assert!(
+            auth_service
+                .complete_mfa_login(&challenge.mfa_token, &next, &RequestOrigin::default())
+                .await
+                .is_ok()
+        );
+        assert!(
+            auth_service
+                .complete_mfa_login(
+                    &challenge.mfa_token,
+                    &recovery_codes[0].to_uppercase(),
+                    &RequestOrigin::default()
+                )
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            auth_service
+                .complete_mfa_login(
+                    &challenge.mfa_token,
+                    &recovery_codes[0],
+                    &RequestOrigin::default()
+                )
+                .await,
+            Err(AuthError::InvalidMfaCode)
+        );
+        assert_eq!(
+            auth_service
+                .complete_mfa_login(
+                    "email-verification|x|y|z",
+                    &recovery_codes[1],
+                    &RequestOrigin::default()
+                )
+                .await,
+            Err(AuthError::InvalidToken)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_disable_mfa_requires_password_and_a_code() {
+        let repo = UserInMemoryRepository::default();
+        let two_factor = TwoFactorInMemoryRepository::default();
+        let auth_service = build_service_with_two_factor(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            MockEmailQueue::default(),
+            test_settings(),
+            two_factor.clone(),
+        );
+        login_test_user(&auth_service, &repo).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id
+            .to_string();
+        let (_, recovery_codes) = enable_mfa(&auth_service, &two_factor, &user_id).await;
+
+        assert_eq!(
+            auth_service
+                .start_mfa_enrollment(&user_id, "S0m3V3ryStr0ngP@ssw0rd!")
+                .await,
+            Err(AuthError::MfaAlreadyEnabled)
+        );
+        assert_eq!(
+            auth_service
+                .disable_mfa(&user_id, "not-my-password", &recovery_codes[0])
+                .await,
+            Err(AuthError::InvalidCredentials)
+        );
+        assert_eq!(
+            auth_service
+                .disable_mfa(&user_id, "S0m3V3ryStr0ngP@ssw0rd!", "not-a-code")
+                .await,
+            Err(AuthError::InvalidMfaCode)
+        );
+        auth_service
+            .disable_mfa(&user_id, "S0m3V3ryStr0ngP@ssw0rd!", &recovery_codes[0])
+            .await
+            .unwrap();
+
+        login_test_user_again(&auth_service).await;
+        assert_eq!(
+            auth_service
+                .disable_mfa(&user_id, "S0m3V3ryStr0ngP@ssw0rd!", &recovery_codes[1])
+                .await,
+            Err(AuthError::MfaNotEnabled)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_repeated_login_failures_lock_the_account() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let mut settings = test_settings();
+        settings.login_lockout = LoginLockoutPolicy {
+            delay_after: 10,
+            base_delay_secs: 1,
+            max_delay_secs: 30,
+            lockout_threshold: 3,
+            lockout_secs: 900,
+        };
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            settings,
+        );
+        login_test_user(&auth_service, &repo).await;
+
+        for _ in 0..3 {
+            assert_eq!(
+                auth_service
+                    .login(
+                        "test@example.com",
+                        "wrong-password",
+                        &RequestOrigin::default()
+                    )
+                    .await,
+                Err(AuthError::InvalidCredentials)
+            );
+        }
+        let locked = auth_service
+            .login(
+                "test@example.com",
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                &RequestOrigin::default(),
+            )
+            .await;
+
+        assert!(matches!(
+            locked,
+            Err(AuthError::TooManyRequests { retry_after_secs }) if retry_after_secs > 890
+        ));
+        assert_eq!(
+            email_queue
+                .sent_templates()
+                .iter()
+                .filter(|t| **t == "sign_ins_blocked")
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wrong_passwords_on_sensitive_changes_lock_the_account() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let mut settings = test_settings();
+        settings.login_lockout = LoginLockoutPolicy {
+            delay_after: 10,
+            base_delay_secs: 1,
+            max_delay_secs: 30,
+            lockout_threshold: 3,
+            lockout_secs: 900,
+        };
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            settings,
+        );
+        login_test_user(&auth_service, &repo).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id
+            .to_string();
+
+        for _ in 0..3 {
+            assert_eq!(
+                auth_service
+                    .change_password(
+                        &user_id,
+                        "wrong-password",
+                        "An0ther-V3ry-Str0ng-P@ss",
+                        &RequestOrigin::default()
+                    )
+                    .await,
+                Err(AuthError::InvalidCredentials)
+            );
+        }
+        let locked = auth_service
+            .start_mfa_enrollment(&user_id, "S0m3V3ryStr0ngP@ssw0rd!")
+            .await;
+        let login = auth_service
+            .login(
+                "test@example.com",
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                &RequestOrigin::default(),
+            )
+            .await;
+
+        assert!(matches!(locked, Err(AuthError::TooManyRequests { .. })));
+        assert!(matches!(login, Err(AuthError::TooManyRequests { .. })));
+        assert!(email_queue.sent_templates().contains(&"sign_ins_blocked"));
+    }
+
+    #[tokio::test]
+    async fn test_login_failures_are_delayed_and_reset_on_success() {
+        let repo = UserInMemoryRepository::default();
+        let mut settings = test_settings();
+        settings.login_lockout = LoginLockoutPolicy {
+            delay_after: 2,
+            base_delay_secs: 60,
+            max_delay_secs: 600,
+            lockout_threshold: 10,
+            lockout_secs: 900,
+        };
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            MockEmailQueue::default(),
+            settings,
+        );
+        login_test_user(&auth_service, &repo).await;
+
+        auth_service
+            .login(
+                "test@example.com",
+                "wrong-password",
+                &RequestOrigin::default(),
+            )
+            .await
+            .unwrap_err();
+        // One failure is below the delay, and the success resets the count.
+        login_test_user_again(&auth_service).await;
+        auth_service
+            .login(
+                "test@example.com",
+                "wrong-password",
+                &RequestOrigin::default(),
+            )
+            .await
+            .unwrap_err();
+        login_test_user_again(&auth_service).await;
+
+        for _ in 0..2 {
+            auth_service
+                .login(
+                    "test@example.com",
+                    "wrong-password",
+                    &RequestOrigin::default(),
+                )
+                .await
+                .unwrap_err();
+        }
+        let delayed = auth_service
+            .login(
+                "test@example.com",
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                &RequestOrigin::default(),
+            )
+            .await;
+
+        assert!(matches!(
+            delayed,
+            Err(AuthError::TooManyRequests { retry_after_secs }) if retry_after_secs <= 60
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_security_events_are_recorded_in_the_audit_log() {
+        let repo = UserInMemoryRepository::default();
+        let audit_log = AuditInMemoryLog::new();
+        let auth_service = build_service_with_audit_log(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            MockEmailQueue::default(),
+            test_settings(),
+            TwoFactorInMemoryRepository::default(),
+            audit_log.clone(),
+        );
+        login_test_user(&auth_service, &repo).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id;
+
+        auth_service
+            .login(
+                "test@example.com",
+                "wrong-password",
+                &RequestOrigin::default(),
+            )
+            .await
+            .unwrap_err();
+        auth_service
+            .login(
+                "nobody@example.com",
+                "wrong-password",
+                &RequestOrigin::default(),
+            )
+            .await
+            .unwrap_err();
+        auth_service
+            .change_password(
+                &user_id.value().to_string(),
+                "S0m3V3ryStr0ngP@ssw0rd!",
+                "An0th3rV3ryStr0ngP@ssw0rd!",
+                &RequestOrigin::default(),
+            )
+            .await
+            .unwrap();
+        auth_service
+            .logout_all(&user_id.value().to_string())
+            .await
+            .unwrap();
+
+        let recorded: Vec<_> = audit_log
+            .events()
+            .await
+            .into_iter()
+            .map(|event| (event.kind, event.user_id, event.detail))
+            .collect();
+        assert_eq!(
+            recorded,
+            vec![
+                (AuditEventKind::LoginSucceeded, Some(user_id), None),
+                (
+                    AuditEventKind::LoginFailed,
+                    Some(user_id),
+                    Some("invalid_credentials".to_string())
+                ),
+                (
+                    AuditEventKind::LoginFailed,
+                    None,
+                    Some("unknown_email".to_string())
+                ),
+                (AuditEventKind::PasswordChanged, Some(user_id), None),
+                (
+                    AuditEventKind::TokenRevoked,
+                    Some(user_id),
+                    Some("logout_all".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_personal_access_tokens_can_be_created_listed_and_revoked() {
+        let repo = UserInMemoryRepository::default();
+        let email_queue = MockEmailQueue::default();
+        let auth_service = build_service_with(
+            repo.clone(),
+            Box::new(MockTokenGenerator::new()),
+            email_queue.clone(),
+            test_settings(),
+        );
+        login_test_user(&auth_service, &repo).await;
+        let user_id = repo
+            .find_by_email(UserEmail::new("test@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .id
+            .to_string();
+
+        let (token, secret) = auth_service
+            .create_personal_access_token(
+                &user_id,
+                "Extension",
+                TokenScope::PositionsWrite,
+                Some(30),
+            )
+            .await
+            .unwrap();
+        let invalid = auth_service
+            .create_personal_access_token(&user_id, " ", TokenScope::ReadOnly, None)
+            .await;
+
+        assert_eq!(token.token_hash, PersonalAccessToken::hash_secret(&secret));
+        assert!(token.expires_at.is_some());
+        assert!(matches!(invalid, Err(AuthError::DomainError(_))));
+        assert!(
+            email_queue
+                .sent_templates()
+                .contains(&"personal_access_token_created")
+        );
+        let listed = auth_service
+            .list_personal_access_tokens(&user_id)
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, token.id);
+
+        let other_user = UserUuid::new().to_string();
+        assert_eq!(
+            auth_service
+                .revoke_personal_access_token(&other_user, token.id)
+                .await,
+            Err(AuthError::TokenNotFound)
+        );
+        auth_service
+            .revoke_personal_access_token(&user_id, token.id)
+            .await
+            .unwrap();
+        assert!(
+            auth_service
+                .list_personal_access_tokens(&user_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
@@ -1,14 +1,13 @@
 use async_trait::async_trait;
 
-use crate::auth::application::errors::AuthError;
+use crate::auth::{application::errors::AuthError, domain::entities::auth_email::AuthEmail};
 
 #[async_trait]
 pub trait IEmailQueueEnqueuer: Send + Sync {
     async fn enqueue(
         &self,
         to: &str,
-        subject: &str,
-        body: &str,
+        email: &AuthEmail,
         user_id: uuid::Uuid,
         trace_context: Option<String>,
     ) -> Result<(), AuthError>;
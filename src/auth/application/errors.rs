@@ -21,6 +21,39 @@ pub enum AuthError {
     #[error("Invalid token")]
     InvalidToken,
 
+    #[error("Email already in use")]
+    EmailTaken,
+
+    #[error("Email already verified")]
+    EmailAlreadyVerified,
+
+    #[error("Two-factor authentication already enabled")]
+    MfaAlreadyEnabled,
+
+    #[error("Two-factor authentication not enabled")]
+    MfaNotEnabled,
+
+    #[error("Invalid two-factor code")]
+    InvalidMfaCode,
+
+    #[error("Access token not found")]
+    TokenNotFound,
+
+    #[error("Session not found")]
+    SessionNotFound,
+
+    #[error("Unknown identity provider")]
+    UnknownOidcProvider,
+
+    #[error("Identity provider error: `{0}`")]
+    OidcProviderError(String),
+
+    #[error("The email must be verified by the identity provider and by this account to link them")]
+    IdentityNotLinkable,
+
+    #[error("Too many requests, retry in {retry_after_secs} seconds")]
+    TooManyRequests { retry_after_secs: i64 },
+
     #[error("Domain error: `{0}`")]
     DomainError(#[from] AuthDomainError),
 
@@ -47,6 +80,17 @@ impl From<AuthError> for axum::http::StatusCode {
             AuthError::UserNotFound => Self::NOT_FOUND,
             AuthError::TokenExpired => Self::UNAUTHORIZED,
             AuthError::InvalidToken => Self::UNAUTHORIZED,
+            AuthError::EmailTaken => Self::CONFLICT,
+            AuthError::EmailAlreadyVerified => Self::CONFLICT,
+            AuthError::MfaAlreadyEnabled => Self::CONFLICT,
+            AuthError::MfaNotEnabled => Self::CONFLICT,
+            AuthError::InvalidMfaCode => Self::UNAUTHORIZED,
+            AuthError::TokenNotFound => Self::NOT_FOUND,
+            AuthError::SessionNotFound => Self::NOT_FOUND,
+            AuthError::UnknownOidcProvider => Self::NOT_FOUND,
+            AuthError::OidcProviderError(_) => Self::BAD_GATEWAY,
+            AuthError::IdentityNotLinkable => Self::CONFLICT,
+            AuthError::TooManyRequests { .. } => Self::TOO_MANY_REQUESTS,
             AuthError::DomainError(_) => Self::BAD_REQUEST,
             AuthError::RepositoryError(_) => Self::INTERNAL_SERVER_ERROR,
         }
@@ -110,6 +154,30 @@ mod tests {
         assert_eq!(StatusCode::from(error), StatusCode::UNAUTHORIZED);
     }
 
+    #[test]
+    fn test_status_code_from_oidc_errors() {
+        assert_eq!(
+            StatusCode::from(AuthError::UnknownOidcProvider),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            StatusCode::from(AuthError::OidcProviderError("down".to_string())),
+            StatusCode::BAD_GATEWAY
+        );
+        assert_eq!(
+            StatusCode::from(AuthError::IdentityNotLinkable),
+            StatusCode::CONFLICT
+        );
+    }
+
+    #[test]
+    fn test_status_code_from_too_many_requests() {
+        let error = AuthError::TooManyRequests {
+            retry_after_secs: 30,
+        };
+        assert_eq!(StatusCode::from(error), StatusCode::TOO_MANY_REQUESTS);
+    }
+
     #[test]
     fn test_auth_error_display() {
         let error = AuthError::InvalidCredentials;
@@ -1,5 +1,8 @@
 pub mod auth_service;
 pub mod email_queue_enqueuer;
 pub mod errors;
+pub mod oidc_provider;
+pub mod oidc_service;
 pub mod token_generator;
+pub mod token_revocation_service;
 pub mod user_status_checker;
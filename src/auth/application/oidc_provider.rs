@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::auth::application::errors::AuthError;
+
+/// Parameters of the authorization request the browser is sent with.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OidcAuthorizationRequest {
+    pub state: String,
+    pub nonce: String,
+    /// `S256` PKCE challenge
+    pub code_challenge: String,
+    pub redirect_uri: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct OidcCodeExchange {
+    pub code: String,
+    pub code_verifier: String,
+    pub redirect_uri: String,
+    /// Nonce the ID token must carry
+    pub nonce: String,
+}
+
+/// Claims of a validated ID token.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OidcUserInfo {
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+}
+
+/// An OpenID Connect issuer users can sign in with.
+#[async_trait]
+pub trait IOidcProvider: Send + Sync {
+    async fn authorization_url(
+        &self,
+        request: &OidcAuthorizationRequest,
+    ) -> Result<String, AuthError>;
+    /// Trades the authorization code for an ID token and validates it.
+    async fn exchange_code(&self, exchange: &OidcCodeExchange) -> Result<OidcUserInfo, AuthError>;
+}
@@ -0,0 +1,490 @@
+use chrono::{TimeDelta, Utc};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use tracing::{info, warn};
+
+use crate::auth::{
+    application::{
+        auth_service::{AuthService, LoginOutcome},
+        errors::AuthError,
+        oidc_provider::{IOidcProvider, OidcAuthorizationRequest, OidcCodeExchange, OidcUserInfo},
+    },
+    domain::{
+        entities::{
+            identity::Identity,
+            oidc_login_state::OidcLoginState,
+            user::{User, UserEmail},
+        },
+        repositories::{
+            identity_repository::IIdentityRepository,
+            oidc_login_state_repository::IOidcLoginStateRepository,
+            user_repository::IUserRepository,
+        },
+    },
+};
+use crate::shared::domain::audit::{AuditEntry, AuditEventKind, RequestOrigin};
+
+#[derive(Debug, Clone)]
+pub struct OidcSettings {
+    /// Providers send the browser back to
+    /// `{redirect_base_url}/auth/oidc/{provider}/callback`.
+    pub redirect_base_url: String,
+    pub login_state_ttl_secs: i64,
+}
+
+pub struct OidcRepositories {
+    pub users: Box<dyn IUserRepository>,
+    pub identities: Box<dyn IIdentityRepository>,
+    pub login_states: Box<dyn IOidcLoginStateRepository>,
+}
+
+/// "Sign in with ..." through the OpenID Connect authorization code flow
+/// with PKCE. Sessions are then issued by the [`AuthService`].
+pub struct OidcService {
+    providers: BTreeMap<String, Box<dyn IOidcProvider>>,
+    user_repository: Box<dyn IUserRepository>,
+    identity_repository: Box<dyn IIdentityRepository>,
+    login_state_repository: Box<dyn IOidcLoginStateRepository>,
+    auth_service: Arc<AuthService>,
+    settings: OidcSettings,
+}
+
+impl OidcService {
+    pub fn new(
+        providers: BTreeMap<String, Box<dyn IOidcProvider>>,
+        repositories: OidcRepositories,
+        auth_service: Arc<AuthService>,
+        settings: OidcSettings,
+    ) -> Self {
+        Self {
+            providers,
+            user_repository: repositories.users,
+            identity_repository: repositories.identities,
+            login_state_repository: repositories.login_states,
+            auth_service,
+            settings,
+        }
+    }
+
+    pub fn provider_names(&self) -> Vec<String> {
+        self.providers.keys().cloned().collect()
+    }
+
+    /// Returns the provider URL to send the browser to.
+    pub async fn start_login(&self, provider_name: &str) -> Result<String, AuthError> {
+        let provider = self.provider(provider_name)?;
+        let now = Utc::now();
+        self.login_state_repository.delete_expired(now).await?;
+        let (login_state, state) = OidcLoginState::begin(
+            provider_name,
+            TimeDelta::seconds(self.settings.login_state_ttl_secs),
+            now,
+        );
+        self.login_state_repository.save(&login_state).await?;
+
+        provider
+            .authorization_url(&OidcAuthorizationRequest {
+                state,
+                nonce: login_state.nonce.clone(),
+                code_challenge: login_state.code_challenge(),
+                redirect_uri: self.redirect_uri(provider_name),
+            })
+            .await
+    }
+
+    /// Finishes the login with what the provider sent back to the redirect
+    /// URI. Unknown identities are linked to the account with the same email,
+    /// or to a new account, as long as the email is verified.
+    pub async fn complete_login(
+        &self,
+        provider_name: &str,
+        code: &str,
+        state: &str,
+        origin: &RequestOrigin,
+    ) -> Result<LoginOutcome, AuthError> {
+        let provider = self.provider(provider_name)?;
+        let login_state = self
+            .login_state_repository
+            .take(&OidcLoginState::hash_state(state))
+            .await?
+            .filter(|s| s.provider == provider_name && !s.is_expired(Utc::now()))
+            .ok_or(AuthError::InvalidToken)?;
+
+        let user_info = provider
+            .exchange_code(&OidcCodeExchange {
+                code: code.to_string(),
+                code_verifier: login_state.code_verifier,
+                redirect_uri: self.redirect_uri(provider_name),
+                nonce: login_state.nonce,
+            })
+            .await?;
+        let user = self.resolve_user(provider_name, &user_info).await?;
+
+        self.auth_service
+            .login_with_verified_identity(&user, origin)
+            .await
+    }
+
+    async fn resolve_user(
+        &self,
+        provider_name: &str,
+        user_info: &OidcUserInfo,
+    ) -> Result<User, AuthError> {
+        let now = Utc::now();
+        if let Some(identity) = self
+            .identity_repository
+            .find(provider_name, &user_info.subject)
+            .await?
+        {
+            self.identity_repository.touch(identity.id, now).await?;
+            return self
+                .user_repository
+                .get(identity.user_id)
+                .await?
+                .ok_or(AuthError::UserNotFound);
+        }
+
+        // Linking by email is only safe when both sides proved they own it.
+        let email = match (&user_info.email, user_info.email_verified) {
+            (Some(email), true) => UserEmail::new(email)?,
+            _ => {
+                warn!(
+                    error_kind = "oidc_email_not_verified",
+                    provider = provider_name,
+                    "oidc_service.resolve_user failed"
+                );
+                return Err(AuthError::IdentityNotLinkable);
+            }
+        };
+        let user = match self.user_repository.find_by_email(email.clone()).await? {
+            Some(user) if user.email_validated => user,
+            Some(user) => {
+                warn!(
+                    error_kind = "local_email_not_verified",
+                    provider = provider_name,
+                    user_id = %user.id,
+                    "oidc_service.resolve_user failed"
+                );
+                return Err(AuthError::IdentityNotLinkable);
+            }
+            None => {
+                let user = User::new_external(&Uuid::new_v4().to_string(), email.value())?;
+                self.user_repository.save(&user).await?;
+                self.auth_service
+                    .audit(
+                        AuditEntry::new(AuditEventKind::Signup, Some(user.id))
+                            .detail(provider_name),
+                    )
+                    .await;
+                info!(user_id = %user.id, provider = provider_name, "User signed up with OIDC");
+                user
+            }
+        };
+
+        self.identity_repository
+            .save(&Identity::link(
+                user.id,
+                provider_name,
+                &user_info.subject,
+                email.value(),
+                now,
+            ))
+            .await?;
+        info!(user_id = %user.id, provider = provider_name, "OIDC identity linked");
+        Ok(user)
+    }
+
+    fn provider(&self, name: &str) -> Result<&dyn IOidcProvider, AuthError> {
+        self.providers
+            .get(name)
+            .map(|provider| provider.as_ref())
+            .ok_or(AuthError::UnknownOidcProvider)
+    }
+
+    fn redirect_uri(&self, provider_name: &str) -> String {
+        format!(
+            "{}/auth/oidc/{}/callback",
+            self.settings.redirect_base_url, provider_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{
+        application::{
+            auth_service::{AuthRepositories, AuthSettings},
+            email_queue_enqueuer::IEmailQueueEnqueuer,
+            token_revocation_service::TokenRevocationService,
+        },
+        domain::entities::{
+            auth_email::AuthEmail, login_attempts::LoginLockoutPolicy,
+            verification_email_throttle::VerificationEmailThrottle,
+        },
+        infrastructure::{
+            persistence::repositories::{
+                email_change_request_in_memory_repository::EmailChangeRequestInMemoryRepository,
+                identity_in_memory_repository::IdentityInMemoryRepository,
+                login_attempt_in_memory_repository::LoginAttemptInMemoryRepository,
+                oidc_login_state_in_memory_repository::OidcLoginStateInMemoryRepository,
+                password_reset_token_in_memory_repository::PasswordResetTokenInMemoryRepository,
+                personal_access_token_in_memory_repository::PersonalAccessTokenInMemoryRepository,
+                refresh_token_in_memory_repository::RefreshTokenInMemoryRepository,
+                session_in_memory_repository::SessionInMemoryRepository,
+                token_revocation_in_memory_repository::TokenRevocationInMemoryRepository,
+                two_factor_in_memory_repository::TwoFactorInMemoryRepository,
+                user_in_memory_repository::UserInMemoryRepository,
+                verification_email_in_memory_repository::VerificationEmailInMemoryRepository,
+            },
+            services::{
+                jwt_token_generator::JwtTokenGenerator,
+                oidc_http_provider::OidcHttpProvider,
+                oidc_stand_in_provider::{StandInOidcProvider, StandInUser},
+            },
+        },
+    };
+    use crate::shared::{
+        config::Config,
+        fixtures::{valid_email, valid_password},
+        infrastructure::audit::audit_in_memory_log::AuditInMemoryLog,
+    };
+    use std::time::Duration;
+
+    struct NoEmails;
+
+    #[async_trait::async_trait]
+    impl IEmailQueueEnqueuer for NoEmails {
+        async fn enqueue(
+            &self,
+            _to: &str,
+            _email: &AuthEmail,
+            _user_id: uuid::Uuid,
+            _trace_context: Option<String>,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        service: OidcService,
+        stand_in: StandInOidcProvider,
+        users: UserInMemoryRepository,
+    }
+
+    impl Fixture {
+        async fn new() -> Self {
+            let stand_in = StandInOidcProvider::start().await;
+            let users = UserInMemoryRepository::default();
+            let auth_service = AuthService::new(
+                AuthRepositories {
+                    users: Box::new(users.clone()),
+                    refresh_tokens: Box::new(RefreshTokenInMemoryRepository::default()),
+                    password_reset_tokens: Box::new(PasswordResetTokenInMemoryRepository::new(
+                        users.clone(),
+                    )),
+                    verification_emails: Box::new(VerificationEmailInMemoryRepository::default()),
+                    email_change_requests: Box::new(EmailChangeRequestInMemoryRepository::default()),
+                    two_factor: Box::new(TwoFactorInMemoryRepository::default()),
+                    login_attempts: Box::new(LoginAttemptInMemoryRepository::default()),
+                    personal_access_tokens: Box::new(
+                        PersonalAccessTokenInMemoryRepository::default(),
+                    ),
+                    sessions: Box::new(SessionInMemoryRepository::default()),
+                },
+                Box::new(JwtTokenGenerator::new(Arc::new(Config::test_default()))),
+                Arc::new(TokenRevocationService::new(
+                    Box::new(TokenRevocationInMemoryRepository::default()),
+                    Duration::from_secs(30),
+                )),
+                Box::new(NoEmails),
+                Arc::new(AuditInMemoryLog::new()),
+                AuthSettings {
+                    frontend_url: "http://localhost:3001".to_string(),
+                    refresh_token_ttl_secs: 3600,
+                    verification_email_throttle: VerificationEmailThrottle {
+                        cooldown_secs: 60,
+                        daily_cap: 5,
+                    },
+                    totp_issuer: "Seeker".to_string(),
+                    login_lockout: LoginLockoutPolicy {
+                        delay_after: 3,
+                        base_delay_secs: 1,
+                        max_delay_secs: 30,
+                        lockout_threshold: 10,
+                        lockout_secs: 900,
+                    },
+                },
+            );
+            let mut providers: BTreeMap<String, Box<dyn IOidcProvider>> = BTreeMap::new();
+            providers.insert(
+                "stand-in".to_string(),
+                Box::new(OidcHttpProvider::new(stand_in.config())),
+            );
+            let service = OidcService::new(
+                providers,
+                OidcRepositories {
+                    users: Box::new(users.clone()),
+                    identities: Box::new(IdentityInMemoryRepository::default()),
+                    login_states: Box::new(OidcLoginStateInMemoryRepository::default()),
+                },
+                Arc::new(auth_service),
+                OidcSettings {
+                    redirect_base_url: "http://localhost:3001".to_string(),
+                    login_state_ttl_secs: 600,
+                },
+            );
+            Self {
+                service,
+                stand_in,
+                users,
+            }
+        }
+
+        async fn sign_in(&self, user: StandInUser) -> Result<LoginOutcome, AuthError> {
+            let url = self.service.start_login("stand-in").await?;
+            let (code, state) = self.stand_in.authorize(&url, user);
+            self.service
+                .complete_login("stand-in", &code, &state, &RequestOrigin::default())
+                .await
+        }
+
+        async fn user(&self, email: &str) -> Option<User> {
+            self.users
+                .find_by_email(UserEmail::new(email).unwrap())
+                .await
+                .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_sign_in_creates_a_verified_user() {
+        let fixture = Fixture::new().await;
+
+        let outcome = fixture
+            .sign_in(StandInUser::verified("sub-1", "new@example.com"))
+            .await
+            .unwrap();
+
+        let LoginOutcome::Authenticated(session) = outcome else {
+            panic!("Expected a session");
+        };
+        assert!(session.is_email_verified);
+        assert!(
+            fixture
+                .user("new@example.com")
+                .await
+                .unwrap()
+                .email_validated
+        );
+    }
+
+    #[tokio::test]
+    async fn test_linked_identities_keep_their_user_when_the_email_changes() {
+        let fixture = Fixture::new().await;
+        fixture
+            .sign_in(StandInUser::verified("sub-1", "first@example.com"))
+            .await
+            .unwrap();
+
+        fixture
+            .sign_in(StandInUser::unverified("sub-1", "second@example.com"))
+            .await
+            .unwrap();
+
+        assert!(fixture.user("second@example.com").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_existing_accounts_are_linked_only_with_verified_emails() {
+        let fixture = Fixture::new().await;
+        let mut user =
+            User::new(&Uuid::new_v4().to_string(), valid_email(), valid_password()).unwrap();
+        fixture.users.save(&user).await.unwrap();
+
+        assert_eq!(
+            fixture
+                .sign_in(StandInUser::verified("sub-1", valid_email()))
+                .await,
+            Err(AuthError::IdentityNotLinkable)
+        );
+
+        user.validate_email();
+        fixture.users.update(&user).await.unwrap();
+        assert_eq!(
+            fixture
+                .sign_in(StandInUser::unverified("sub-1", valid_email()))
+                .await,
+            Err(AuthError::IdentityNotLinkable)
+        );
+        assert!(
+            fixture
+                .sign_in(StandInUser::verified("sub-1", valid_email()))
+                .await
+                .is_ok()
+        );
+        let linked = fixture.user(valid_email()).await.unwrap();
+        assert_eq!(linked.id, user.id);
+        assert!(linked.verify_password(valid_password()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_disabled_accounts_cannot_sign_in() {
+        let fixture = Fixture::new().await;
+        fixture
+            .sign_in(StandInUser::verified("sub-1", "new@example.com"))
+            .await
+            .unwrap();
+        let mut user = fixture.user("new@example.com").await.unwrap();
+        user.account_disabled = true;
+        fixture.users.update(&user).await.unwrap();
+
+        assert_eq!(
+            fixture
+                .sign_in(StandInUser::verified("sub-1", "new@example.com"))
+                .await,
+            Err(AuthError::InvalidCredentials)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_states_are_single_use_and_bound_to_the_provider() {
+        let fixture = Fixture::new().await;
+        let url = fixture.service.start_login("stand-in").await.unwrap();
+        let (code, state) = fixture
+            .stand_in
+            .authorize(&url, StandInUser::verified("sub-1", "new@example.com"));
+
+        assert_eq!(
+            fixture
+                .service
+                .complete_login("other", &code, &state, &RequestOrigin::default())
+                .await,
+            Err(AuthError::UnknownOidcProvider)
+        );
+        assert_eq!(
+            fixture
+                .service
+                .complete_login("stand-in", &code, "forged-state", &RequestOrigin::default())
+                .await,
+            Err(AuthError::InvalidToken)
+        );
+        assert!(
+            fixture
+                .service
+                .complete_login("stand-in", &code, &state, &RequestOrigin::default())
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            fixture
+                .service
+                .complete_login("stand-in", &code, &state, &RequestOrigin::default())
+                .await,
+            Err(AuthError::InvalidToken)
+        );
+        assert_eq!(fixture.service.provider_names(), vec!["stand-in"]);
+    }
+}
@@ -1,6 +1,78 @@
-use crate::auth::application::errors::AuthError;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{auth::application::errors::AuthError, shared::domain::value_objects::UserRole};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AccessToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// What a non-session token is for. Each purpose has its own audience and
+/// lifetime, so a token minted for one is rejected by all the others and by
+/// the API itself.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TokenPurpose {
+    EmailVerification,
+    PasswordReset,
+    EmailChange,
+    /// Proves the password was right while the second factor is pending.
+    MfaPending,
+}
+
+impl TokenPurpose {
+    pub const ALL: [TokenPurpose; 4] = [
+        TokenPurpose::EmailVerification,
+        TokenPurpose::PasswordReset,
+        TokenPurpose::EmailChange,
+        TokenPurpose::MfaPending,
+    ];
+
+    pub fn audience(&self) -> &'static str {
+        match self {
+            TokenPurpose::EmailVerification => "email-verification",
+            TokenPurpose::PasswordReset => "password-reset",
+            TokenPurpose::EmailChange => "email-change",
+            TokenPurpose::MfaPending => "mfa-pending",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PurposeToken {
+    pub token: String,
+    /// Unique id of the token, for purposes that must be single use.
+    pub token_id: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PurposeClaims {
+    pub user_id: String,
+    /// Address the token was sent to.
+    pub email: String,
+    pub token_id: String,
+}
 
 pub trait ITokenGenerator: Send + Sync {
-    fn generate_token(&self, user_id: &str, email: &str) -> Result<String, AuthError>;
-    fn validate_token(&self, token: &str) -> Result<String, AuthError>;
+    fn generate_access_token(
+        &self,
+        user_id: &str,
+        email: &str,
+        token_version: i32,
+        role: UserRole,
+        session_id: Uuid,
+    ) -> Result<AccessToken, AuthError>;
+    fn generate_purpose_token(
+        &self,
+        purpose: TokenPurpose,
+        user_id: &str,
+        email: &str,
+    ) -> Result<PurposeToken, AuthError>;
+    fn validate_purpose_token(
+        &self,
+        purpose: TokenPurpose,
+        token: &str,
+    ) -> Result<PurposeClaims, AuthError>;
 }
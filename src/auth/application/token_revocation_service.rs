@@ -0,0 +1,154 @@
+use std::{collections::HashMap, time::Duration};
+
+use chrono::{DateTime, Utc};
+use tokio::{sync::RwLock, time::Instant};
+
+use crate::{
+    auth::{
+        application::errors::AuthError,
+        domain::{
+            entities::token_revocations::TokenRevocations,
+            repositories::token_revocation_repository::ITokenRevocationRepository,
+        },
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct CachedRevocations {
+    revocations: TokenRevocations,
+    cached_at: Instant,
+}
+
+/// Answers "was this access token revoked?" for every authenticated request,
+/// so revocations are cached per user for `cache_ttl`. Revoking through this
+/// service drops the user's entry right away; other instances pick the change
+/// up once their entry expires.
+pub struct TokenRevocationService {
+    repository: Box<dyn ITokenRevocationRepository>,
+    cache: RwLock<HashMap<UserUuid, CachedRevocations>>,
+    cache_ttl: Duration,
+}
+
+impl TokenRevocationService {
+    pub fn new(repository: Box<dyn ITokenRevocationRepository>, cache_ttl: Duration) -> Self {
+        Self {
+            repository,
+            cache: RwLock::new(HashMap::new()),
+            cache_ttl,
+        }
+    }
+
+    pub async fn revoke_token(
+        &self,
+        jti: &str,
+        user_id: UserUuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), AuthError> {
+        self.repository
+            .revoke_token(jti, user_id, expires_at)
+            .await?;
+        self.cache.write().await.remove(&user_id);
+        Ok(())
+    }
+
+    pub async fn revoke_all_tokens(&self, user_id: UserUuid) -> Result<i32, AuthError> {
+        let version = self.repository.revoke_all_tokens(user_id).await?;
+        self.cache.write().await.remove(&user_id);
+        Ok(version)
+    }
+
+    pub async fn current_token_version(&self, user_id: UserUuid) -> Result<i32, AuthError> {
+        Ok(self.revocations(user_id).await?.token_version)
+    }
+
+    pub async fn is_revoked(
+        &self,
+        user_id: UserUuid,
+        jti: &str,
+        token_version: i32,
+    ) -> Result<bool, AuthError> {
+        Ok(self.revocations(user_id).await?.rejects(jti, token_version))
+    }
+
+    async fn revocations(&self, user_id: UserUuid) -> Result<TokenRevocations, AuthError> {
+        if let Some(entry) = self.cache.read().await.get(&user_id)
+            && entry.cached_at.elapsed() < self.cache_ttl
+        {
+            return Ok(entry.revocations.clone());
+        }
+
+        let revocations = self.repository.get_revocations(user_id, Utc::now()).await?;
+        let mut cache = self.cache.write().await;
+        cache.retain(|_, entry| entry.cached_at.elapsed() < self.cache_ttl);
+        cache.insert(
+            user_id,
+            CachedRevocations {
+                revocations: revocations.clone(),
+                cached_at: Instant::now(),
+            },
+        );
+        Ok(revocations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    use crate::{
+        auth::infrastructure::persistence::repositories::token_revocation_in_memory_repository::TokenRevocationInMemoryRepository,
+        shared::domain::value_objects::UserUuid,
+    };
+
+    fn build_service() -> (TokenRevocationService, TokenRevocationInMemoryRepository) {
+        let repository = TokenRevocationInMemoryRepository::default();
+        let service =
+            TokenRevocationService::new(Box::new(repository.clone()), Duration::from_secs(30));
+        (service, repository)
+    }
+
+    #[tokio::test]
+    async fn test_revoked_token_is_rejected() {
+        let (service, _) = build_service();
+        let user_id = UserUuid::new();
+        assert!(!service.is_revoked(user_id, "jti", 0).await.unwrap());
+
+        service
+            .revoke_token("jti", user_id, Utc::now() + TimeDelta::minutes(5))
+            .await
+            .unwrap();
+
+        assert!(service.is_revoked(user_id, "jti", 0).await.unwrap());
+        assert!(!service.is_revoked(user_id, "other", 0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_rejects_older_versions() {
+        let (service, _) = build_service();
+        let user_id = UserUuid::new();
+
+        let version = service.revoke_all_tokens(user_id).await.unwrap();
+
+        assert!(service.is_revoked(user_id, "jti", 0).await.unwrap());
+        assert!(!service.is_revoked(user_id, "jti", version).await.unwrap());
+        assert_eq!(
+            service.current_token_version(user_id).await.unwrap(),
+            version
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_revocations_are_cached_until_ttl() {
+        let (service, repository) = build_service();
+        let user_id = UserUuid::new();
+        assert!(!service.is_revoked(user_id, "jti", 0).await.unwrap());
+
+        // Revoked by another instance, bypassing this service's cache.
+        repository.revoke_all_tokens(user_id).await.unwrap();
+        assert!(!service.is_revoked(user_id, "jti", 0).await.unwrap());
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        assert!(service.is_revoked(user_id, "jti", 0).await.unwrap());
+    }
+}
@@ -1,20 +1,48 @@
+use chrono::Utc;
 use std::str::FromStr;
 use std::sync::Arc;
+use tracing::error;
+use uuid::Uuid;
 
 use crate::{
-    auth::domain::repositories::user_repository::IUserRepository,
+    auth::{
+        application::token_revocation_service::TokenRevocationService,
+        domain::{
+            entities::personal_access_token::PersonalAccessToken,
+            repositories::{
+                personal_access_token_repository::IPersonalAccessTokenRepository,
+                session_repository::ISessionRepository, user_repository::IUserRepository,
+            },
+        },
+    },
     shared::{
-        domain::value_objects::UserUuid, infrastructure::http::auth_extractor::UserStatusChecker,
+        domain::value_objects::{UserRole, UserUuid},
+        infrastructure::http::auth_extractor::{
+            Claims, PersonalAccessTokenGrant, UserStatusChecker,
+        },
     },
 };
 
 pub struct UserStatusCheckerImpl {
     user_repository: Arc<Box<dyn IUserRepository>>,
+    token_revocations: Arc<TokenRevocationService>,
+    personal_access_token_repository: Arc<Box<dyn IPersonalAccessTokenRepository>>,
+    session_repository: Arc<Box<dyn ISessionRepository>>,
 }
 
 impl UserStatusCheckerImpl {
-    pub fn new(user_repository: Arc<Box<dyn IUserRepository>>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<Box<dyn IUserRepository>>,
+        token_revocations: Arc<TokenRevocationService>,
+        personal_access_token_repository: Arc<Box<dyn IPersonalAccessTokenRepository>>,
+        session_repository: Arc<Box<dyn ISessionRepository>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            token_revocations,
+            personal_access_token_repository,
+            session_repository,
+        }
     }
 }
 
@@ -32,4 +60,92 @@ impl UserStatusChecker for UserStatusCheckerImpl {
             Err(_) => true,   // Treat errors as disabled (fail safe)
         }
     }
+
+    async fn is_token_revoked(&self, claims: &Claims) -> bool {
+        let Ok(uuid) = UserUuid::from_str(&claims.sub) else {
+            return true;
+        };
+
+        self.token_revocations
+            .is_revoked(uuid, &claims.jti, claims.ver)
+            .await
+            .unwrap_or(true) // Fail safe, as above
+    }
+
+    async fn is_session_active(&self, claims: &Claims) -> bool {
+        // Tokens issued before sessions existed expire on their own.
+        let Some(sid) = claims.sid.as_deref() else {
+            return true;
+        };
+        let Ok(id) = Uuid::parse_str(sid) else {
+            return false;
+        };
+
+        let now = Utc::now();
+        let session = match self.session_repository.get(id).await {
+            Ok(Some(session)) if session.user_id.to_string() == claims.sub => session,
+            _ => return false, // Fail safe, as above
+        };
+        if !session.is_active(now) {
+            return false;
+        }
+
+        if session.needs_last_seen_update(now)
+            && let Err(e) = self.session_repository.touch(id, now).await
+        {
+            error!(error = %e, session_id = %id, "Failed to record session activity");
+        }
+        true
+    }
+
+    async fn is_email_verified(&self, user_id: &str) -> bool {
+        let Ok(uuid) = UserUuid::from_str(user_id) else {
+            return false;
+        };
+
+        matches!(
+            self.user_repository.get(uuid).await,
+            Ok(Some(user)) if user.email_validated
+        )
+    }
+
+    async fn is_admin(&self, user_id: &str) -> bool {
+        let Ok(uuid) = UserUuid::from_str(user_id) else {
+            return false;
+        };
+
+        matches!(
+            self.user_repository.get(uuid).await,
+            Ok(Some(user)) if user.role == UserRole::Admin
+        )
+    }
+
+    async fn authenticate_personal_access_token(
+        &self,
+        token: &str,
+    ) -> Option<PersonalAccessTokenGrant> {
+        let token = self
+            .personal_access_token_repository
+            .find_by_hash(&PersonalAccessToken::hash_secret(token))
+            .await
+            .ok()
+            .flatten()?;
+        let now = Utc::now();
+        if !token.is_usable(now) {
+            return None;
+        }
+
+        if token.needs_last_used_update(now)
+            && let Err(e) = self
+                .personal_access_token_repository
+                .touch(token.id, now)
+                .await
+        {
+            error!(error = %e, token_id = %token.id, "Failed to record personal access token use");
+        }
+        Some(PersonalAccessTokenGrant {
+            user_id: token.user_id.to_string(),
+            scope: token.scope,
+        })
+    }
 }
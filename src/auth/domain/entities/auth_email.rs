@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::shared::domain::{
+    email::{EmailCategory, IdempotencyKey},
+    value_objects::TokenScope,
+};
+
+/// Emails about the account itself. Each names a template of the email
+/// worker, which renders it from these variables.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthEmail {
+    VerifyEmail {
+        link: String,
+    },
+    ResetPassword {
+        link: String,
+    },
+    /// Sent to the new address, which must confirm the change.
+    ConfirmEmailChange {
+        link: String,
+    },
+    /// Sent to the current address while a change waits for confirmation.
+    EmailChangeRequested {
+        request_id: Uuid,
+        new_email: String,
+    },
+    TwoFactorEnabled {
+        enabled_at: DateTime<Utc>,
+    },
+    /// `enabled_at` is when the two-factor being turned off was turned on.
+    TwoFactorDisabled {
+        enabled_at: DateTime<Utc>,
+    },
+    SignInsBlocked {
+        until: DateTime<Utc>,
+    },
+    PersonalAccessTokenCreated {
+        token_id: Uuid,
+        name: String,
+        scope: TokenScope,
+    },
+    NewSignIn {
+        device: String,
+        ip: Option<String>,
+        signed_in_at: DateTime<Utc>,
+    },
+}
+
+impl AuthEmail {
+    /// Nobody can opt out of emails about their own account.
+    pub const CATEGORY: EmailCategory = EmailCategory::Security;
+
+    pub fn template(&self) -> &'static str {
+        match self {
+            AuthEmail::VerifyEmail { .. } => "verify_email",
+            AuthEmail::ResetPassword { .. } => "reset_password",
+            AuthEmail::ConfirmEmailChange { .. } => "confirm_email_change",
+            AuthEmail::EmailChangeRequested { .. } => "email_change_requested",
+            AuthEmail::TwoFactorEnabled { .. } => "two_factor_enabled",
+            AuthEmail::TwoFactorDisabled { .. } => "two_factor_disabled",
+            AuthEmail::SignInsBlocked { .. } => "sign_ins_blocked",
+            AuthEmail::PersonalAccessTokenCreated { .. } => "personal_access_token_created",
+            AuthEmail::NewSignIn { .. } => "new_sign_in",
+        }
+    }
+
+    /// Links are told apart by their token, notices by the request or
+    /// token they are about, alerts by when they happened.
+    pub fn idempotency_key(&self, user_id: Uuid) -> IdempotencyKey {
+        let template = self.template();
+        match self {
+            AuthEmail::VerifyEmail { link }
+            | AuthEmail::ResetPassword { link }
+            | AuthEmail::ConfirmEmailChange { link } => IdempotencyKey::from_link(template, link),
+            AuthEmail::EmailChangeRequested { request_id: id, .. }
+            | AuthEmail::PersonalAccessTokenCreated { token_id: id, .. } => {
+                IdempotencyKey::new(template, &[&id.to_string()])
+            }
+            AuthEmail::TwoFactorEnabled { enabled_at: at }
+            | AuthEmail::TwoFactorDisabled { enabled_at: at }
+            | AuthEmail::SignInsBlocked { until: at }
+            | AuthEmail::NewSignIn {
+                signed_in_at: at, ..
+            } => IdempotencyKey::new(
+                template,
+                &[&user_id.to_string(), &at.timestamp().to_string()],
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_the_same_alert_gets_the_same_key() {
+        let user_id = Uuid::from_u128(1);
+        let blocked = |minute| {
+            Utc.with_ymd_and_hms(2026, 3, 1, 9, minute, 0)
+                .single()
+                .map(|until| AuthEmail::SignInsBlocked { until })
+        };
+
+        assert_eq!(
+            blocked(30).map(|email| email.idempotency_key(user_id)),
+            blocked(30).map(|email| email.idempotency_key(user_id))
+        );
+        assert_ne!(
+            blocked(30).map(|email| email.idempotency_key(user_id)),
+            blocked(45).map(|email| email.idempotency_key(user_id))
+        );
+    }
+
+    #[test]
+    fn test_notices_are_keyed_by_what_they_are_about() {
+        let user_id = Uuid::from_u128(1);
+        let token = |token_id| AuthEmail::PersonalAccessTokenCreated {
+            token_id,
+            name: "ci".to_string(),
+            scope: TokenScope::ReadOnly,
+        };
+
+        assert_eq!(
+            token(Uuid::from_u128(2)).idempotency_key(user_id).as_str(),
+            "personal_access_token_created:00000000-0000-0000-0000-000000000002"
+        );
+        assert_ne!(
+            token(Uuid::from_u128(2)).idempotency_key(user_id),
+            token(Uuid::from_u128(3)).idempotency_key(user_id)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::entities::{opaque_token::OpaqueToken, user::UserEmail},
+    shared::domain::value_objects::UserUuid,
+};
+
+/// A pending switch to `new_email`, applied once the user follows the link
+/// mailed to that address. Like [`super::password_reset_token::PasswordResetToken`],
+/// only the hashed id of the signed link token is stored, and it is single use.
+#[derive(PartialEq, Debug, Clone)]
+pub struct EmailChangeRequest {
+    pub id: Uuid,
+    pub user_id: UserUuid,
+    pub new_email: UserEmail,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl EmailChangeRequest {
+    pub fn new(
+        user_id: UserUuid,
+        new_email: UserEmail,
+        token_id: &str,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            new_email,
+            token_hash: Self::hash_token_id(token_id),
+            created_at: now,
+            expires_at,
+            used_at: None,
+        }
+    }
+
+    pub fn hash_token_id(token_id: &str) -> String {
+        OpaqueToken::from_string(token_id).hash()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.used_at.is_none() && !self.is_expired(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::domain::errors::AuthDomainError;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_request_is_usable_once_until_it_expires() -> Result<(), AuthDomainError> {
+        let now = Utc::now();
+        let mut request = EmailChangeRequest::new(
+            UserUuid::new(),
+            UserEmail::new("new@example.com")?,
+            "jti",
+            now + TimeDelta::seconds(60),
+            now,
+        );
+
+        assert_eq!(request.token_hash, EmailChangeRequest::hash_token_id("jti"));
+        assert!(request.is_usable(now));
+        assert!(!request.is_usable(now + TimeDelta::seconds(60)));
+
+        request.used_at = Some(now);
+        assert!(!request.is_usable(now));
+        Ok(())
+    }
+}
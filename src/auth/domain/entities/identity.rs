@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::shared::domain::value_objects::UserUuid;
+
+/// Account at an OpenID Connect provider linked to a user. The provider and
+/// its `sub` claim identify it; the email is the one it had when linked.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Identity {
+    pub id: Uuid,
+    pub user_id: UserUuid,
+    pub provider: String,
+    pub subject: String,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: DateTime<Utc>,
+}
+
+impl Identity {
+    pub fn link(
+        user_id: UserUuid,
+        provider: &str,
+        subject: &str,
+        email: &str,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            provider: provider.to_string(),
+            subject: subject.to_string(),
+            email: email.to_string(),
+            created_at: now,
+            last_login_at: now,
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::shared::domain::value_objects::UserUuid;
+
+/// Failures older than this no longer count towards a lockout.
+pub const FAILURE_WINDOW: TimeDelta = TimeDelta::hours(24);
+
+/// Failed logins of one account, whatever IP they come from.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LoginAttempts {
+    pub user_id: UserUuid,
+    pub failed_count: u32,
+    pub last_failed_at: Option<DateTime<Utc>>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginAttempts {
+    pub fn new(user_id: UserUuid) -> Self {
+        Self {
+            user_id,
+            failed_count: 0,
+            last_failed_at: None,
+            locked_until: None,
+        }
+    }
+}
+
+/// From the `delay_after` failure on, each attempt has to wait twice as long
+/// as the previous one, up to `max_delay_secs`. At `lockout_threshold`
+/// failures the account is locked for `lockout_secs` and the count restarts.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct LoginLockoutPolicy {
+    pub delay_after: u32,
+    pub base_delay_secs: i64,
+    pub max_delay_secs: i64,
+    pub lockout_threshold: u32,
+    pub lockout_secs: i64,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum LoginDecision {
+    Allowed,
+    Delayed { retry_after_secs: i64 },
+    Locked { retry_after_secs: i64 },
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum FailureOutcome {
+    Counted,
+    /// This failure started a lockout.
+    LockedOut {
+        until: DateTime<Utc>,
+    },
+}
+
+impl LoginLockoutPolicy {
+    pub fn check(&self, attempts: &LoginAttempts, now: DateTime<Utc>) -> LoginDecision {
+        if let Some(locked_until) = attempts.locked_until.filter(|until| *until > now) {
+            return LoginDecision::Locked {
+                retry_after_secs: seconds_until(locked_until, now),
+            };
+        }
+        let failed_count = self.counted_failures(attempts, now);
+        if let Some(last_failed_at) = attempts.last_failed_at
+            && failed_count >= self.delay_after
+        {
+            let allowed_at = last_failed_at + self.delay(failed_count);
+            if allowed_at > now {
+                return LoginDecision::Delayed {
+                    retry_after_secs: seconds_until(allowed_at, now),
+                };
+            }
+        }
+        LoginDecision::Allowed
+    }
+
+    pub fn register_failure(
+        &self,
+        attempts: &mut LoginAttempts,
+        now: DateTime<Utc>,
+    ) -> FailureOutcome {
+        attempts.failed_count = self.counted_failures(attempts, now) + 1;
+        attempts.last_failed_at = Some(now);
+        if attempts.locked_until.is_some_and(|until| until <= now) {
+            attempts.locked_until = None;
+        }
+
+        if attempts.failed_count >= self.lockout_threshold {
+            let until = now + TimeDelta::seconds(self.lockout_secs);
+            attempts.failed_count = 0;
+            attempts.locked_until = Some(until);
+            return FailureOutcome::LockedOut { until };
+        }
+        FailureOutcome::Counted
+    }
+
+    fn counted_failures(&self, attempts: &LoginAttempts, now: DateTime<Utc>) -> u32 {
+        match attempts.last_failed_at {
+            Some(last_failed_at) if last_failed_at > now - FAILURE_WINDOW => attempts.failed_count,
+            _ => 0,
+        }
+    }
+
+    fn delay(&self, failed_count: u32) -> TimeDelta {
+        let doublings = failed_count.saturating_sub(self.delay_after).min(30);
+        let secs = self
+            .base_delay_secs
+            .saturating_mul(1i64 << doublings)
+            .min(self.max_delay_secs);
+        TimeDelta::seconds(secs)
+    }
+}
+
+/// Rounded up so clients retrying after the hint are not rejected again.
+fn seconds_until(at: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+    let wait = at - now;
+    wait.num_seconds() + i64::from(wait.subsec_nanos() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY: LoginLockoutPolicy = LoginLockoutPolicy {
+        delay_after: 3,
+        base_delay_secs: 2,
+        max_delay_secs: 10,
+        lockout_threshold: 6,
+        lockout_secs: 900,
+    };
+
+    fn fixed_now() -> DateTime<Utc> {
+        DateTime::<Utc>::UNIX_EPOCH + TimeDelta::days(20_000)
+    }
+
+    fn fail(attempts: &mut LoginAttempts, times: u32, now: DateTime<Utc>) -> FailureOutcome {
+        let mut outcome = FailureOutcome::Counted;
+        for _ in 0..times {
+            outcome = POLICY.register_failure(attempts, now);
+        }
+        outcome
+    }
+
+    #[test]
+    fn test_first_failures_are_not_delayed() {
+        let now = fixed_now();
+        let mut attempts = LoginAttempts::new(UserUuid::new());
+
+        fail(&mut attempts, 2, now);
+
+        assert_eq!(POLICY.check(&attempts, now), LoginDecision::Allowed);
+    }
+
+    #[test]
+    fn test_delay_doubles_with_each_failure_up_to_the_max() {
+        let now = fixed_now();
+        let mut attempts = LoginAttempts::new(UserUuid::new());
+
+        let delays: Vec<_> = (0..5)
+            .map(|_| {
+                POLICY.register_failure(&mut attempts, now);
+                POLICY.check(&attempts, now)
+            })
+            .collect();
+
+        assert_eq!(
+            &delays[2..],
+            &[
+                LoginDecision::Delayed {
+                    retry_after_secs: 2
+                },
+                LoginDecision::Delayed {
+                    retry_after_secs: 4
+                },
+                LoginDecision::Delayed {
+                    retry_after_secs: 8
+                },
+            ]
+        );
+        assert_eq!(
+            POLICY.check(&attempts, now + TimeDelta::seconds(8)),
+            LoginDecision::Allowed
+        );
+        assert_eq!(
+            POLICY.delay(POLICY.delay_after + 10),
+            TimeDelta::seconds(POLICY.max_delay_secs)
+        );
+    }
+
+    #[test]
+    fn test_lockout_after_threshold_and_count_restarts() {
+        let now = fixed_now();
+        let mut attempts = LoginAttempts::new(UserUuid::new());
+
+        assert_eq!(fail(&mut attempts, 5, now), FailureOutcome::Counted);
+        let outcome = fail(&mut attempts, 1, now);
+
+        let until = now + TimeDelta::seconds(900);
+        assert_eq!(outcome, FailureOutcome::LockedOut { until });
+        assert_eq!(
+            POLICY.check(&attempts, now + TimeDelta::seconds(100)),
+            LoginDecision::Locked {
+                retry_after_secs: 800
+            }
+        );
+        assert_eq!(POLICY.check(&attempts, until), LoginDecision::Allowed);
+        assert_eq!(
+            POLICY.register_failure(&mut attempts, until),
+            FailureOutcome::Counted
+        );
+        assert_eq!(attempts.failed_count, 1);
+        assert_eq!(attempts.locked_until, None);
+    }
+
+    #[test]
+    fn test_old_failures_are_forgotten() {
+        let now = fixed_now();
+        let mut attempts = LoginAttempts::new(UserUuid::new());
+        fail(&mut attempts, 5, now - TimeDelta::hours(25));
+
+        assert_eq!(POLICY.check(&attempts, now), LoginDecision::Allowed);
+        assert_eq!(fail(&mut attempts, 1, now), FailureOutcome::Counted);
+        assert_eq!(attempts.failed_count, 1);
+    }
+}
@@ -1 +1,15 @@
+pub mod auth_email;
+pub mod email_change_request;
+pub mod identity;
+pub mod login_attempts;
+pub mod oidc_login_state;
+pub mod opaque_token;
+pub mod password_reset_token;
+pub mod personal_access_token;
+pub mod refresh_token;
+pub mod session;
+pub mod token_revocations;
+pub mod totp;
+pub mod two_factor;
 pub mod user;
+pub mod verification_email_throttle;
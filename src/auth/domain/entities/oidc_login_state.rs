@@ -0,0 +1,88 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, TimeDelta, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::auth::domain::entities::opaque_token::OpaqueToken;
+
+/// What the backend remembers between sending the browser to a provider and
+/// getting the authorization code back. The `state` parameter finds it (only
+/// its hash is stored), the PKCE verifier proves the code exchange comes
+/// from whoever started the login, and the nonce ties the ID token to it.
+#[derive(PartialEq, Debug, Clone)]
+pub struct OidcLoginState {
+    pub state_hash: String,
+    pub provider: String,
+    pub code_verifier: String,
+    pub nonce: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OidcLoginState {
+    /// Returns the login state and the `state` parameter of the request.
+    pub fn begin(provider: &str, lifetime: TimeDelta, now: DateTime<Utc>) -> (Self, String) {
+        let state = OpaqueToken::generate();
+        let login_state = Self {
+            state_hash: state.hash(),
+            provider: provider.to_string(),
+            code_verifier: OpaqueToken::generate().value().to_string(),
+            nonce: OpaqueToken::generate().value().to_string(),
+            expires_at: now + lifetime,
+        };
+        (login_state, state.value().to_string())
+    }
+
+    pub fn hash_state(state: &str) -> String {
+        OpaqueToken::from_string(state).hash()
+    }
+
+    /// `S256` challenge of the verifier, as defined by RFC 7636.
+    pub fn code_challenge(&self) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(self.code_verifier.as_bytes()))
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_now() -> DateTime<Utc> {
+        DateTime::<Utc>::UNIX_EPOCH + TimeDelta::days(20_000)
+    }
+
+    #[test]
+    fn test_begin_stores_only_the_hash_of_the_state() {
+        let (login_state, state) =
+            OidcLoginState::begin("google", TimeDelta::minutes(10), fixed_now());
+
+        assert_eq!(login_state.state_hash, OidcLoginState::hash_state(&state));
+        assert_ne!(login_state.state_hash, state);
+        assert_ne!(login_state.code_verifier, login_state.nonce);
+        assert_eq!(login_state.provider, "google");
+    }
+
+    #[test]
+    fn test_code_challenge_follows_rfc_7636() {
+        let (mut login_state, _) =
+            OidcLoginState::begin("google", TimeDelta::minutes(10), fixed_now());
+        // Example of RFC 7636, appendix B.
+        login_state.code_verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string();
+
+        assert_eq!(
+            login_state.code_challenge(),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_login_states_expire() {
+        let now = fixed_now();
+        let (login_state, _) = OidcLoginState::begin("google", TimeDelta::minutes(10), now);
+
+        assert!(!login_state.is_expired(now + TimeDelta::minutes(9)));
+        assert!(login_state.is_expired(now + TimeDelta::minutes(10)));
+    }
+}
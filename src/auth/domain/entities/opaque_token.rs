@@ -0,0 +1,67 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+const TOKEN_BYTES: usize = 32;
+
+/// Random bearer secret handed to the client exactly once. Only its SHA-256
+/// digest is persisted, so a database leak does not expose usable tokens.
+#[derive(PartialEq, Debug, Clone)]
+pub struct OpaqueToken {
+    value: String,
+}
+
+impl OpaqueToken {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        Self {
+            value: URL_SAFE_NO_PAD.encode(bytes),
+        }
+    }
+
+    pub fn from_string(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn hash(&self) -> String {
+        format!("{:x}", Sha256::digest(self.value.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_tokens_are_unique_and_url_safe() {
+        let first = OpaqueToken::generate();
+        let second = OpaqueToken::generate();
+
+        assert_ne!(first, second);
+        assert_eq!(first.value().len(), 43);
+        assert!(
+            first
+                .value()
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+    }
+
+    #[test]
+    fn test_hash_is_stable_and_hides_the_value() {
+        let token = OpaqueToken::from_string("secret");
+
+        assert_eq!(token.hash(), OpaqueToken::from_string("secret").hash());
+        assert_eq!(
+            token.hash(),
+            "2bb80d537b1da3e38bd30361aa855686bde0eacd7162fef6a25fe97bf527a25b"
+        );
+    }
+}
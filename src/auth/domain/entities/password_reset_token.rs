@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::entities::opaque_token::OpaqueToken, shared::domain::value_objects::UserUuid,
+};
+
+/// Tracks a reset link mailed to a user who forgot their password, so it can
+/// be used only once. The link carries a signed token whose id is stored here
+/// hashed.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PasswordResetToken {
+    pub id: Uuid,
+    pub user_id: UserUuid,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl PasswordResetToken {
+    pub fn new(
+        user_id: UserUuid,
+        token_id: &str,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash: Self::hash_token_id(token_id),
+            created_at: now,
+            expires_at,
+            used_at: None,
+        }
+    }
+
+    pub fn hash_token_id(token_id: &str) -> String {
+        OpaqueToken::from_string(token_id).hash()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.used_at.is_none() && !self.is_expired(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_new_token_is_usable_until_it_expires() {
+        let now = Utc::now();
+
+        let token =
+            PasswordResetToken::new(UserUuid::new(), "jti", now + TimeDelta::seconds(60), now);
+
+        assert_eq!(token.token_hash, PasswordResetToken::hash_token_id("jti"));
+        assert_ne!(token.token_hash, "jti");
+        assert!(token.is_usable(now));
+        assert!(!token.is_usable(now + TimeDelta::seconds(60)));
+    }
+
+    #[test]
+    fn test_used_token_is_not_usable() {
+        let now = Utc::now();
+        let mut token =
+            PasswordResetToken::new(UserUuid::new(), "jti", now + TimeDelta::seconds(60), now);
+
+        token.used_at = Some(now);
+
+        assert!(!token.is_usable(now));
+        assert!(!token.is_expired(now));
+    }
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{entities::opaque_token::OpaqueToken, errors::AuthDomainError},
+    shared::domain::value_objects::{PERSONAL_ACCESS_TOKEN_PREFIX, TokenScope, UserUuid},
+};
+
+const MAX_NAME_LEN: usize = 100;
+pub const MAX_LIFETIME: TimeDelta = TimeDelta::days(365);
+/// `last_used_at` is written at most this often, not on every request.
+const LAST_USED_PRECISION: TimeDelta = TimeDelta::minutes(1);
+
+/// Long-lived, user-managed credential for scripts and browser extensions.
+/// The secret is shown once when issued; only its SHA-256 hash is stored.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PersonalAccessToken {
+    pub id: Uuid,
+    pub user_id: UserUuid,
+    pub name: String,
+    pub scope: TokenScope,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl PersonalAccessToken {
+    /// Returns the token and its secret. Without a `lifetime` the token
+    /// works until it is revoked.
+    pub fn issue(
+        user_id: UserUuid,
+        name: &str,
+        scope: TokenScope,
+        lifetime: Option<TimeDelta>,
+        now: DateTime<Utc>,
+    ) -> Result<(Self, String), AuthDomainError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            return Err(AuthDomainError::InvalidPersonalAccessToken(format!(
+                "name must have between 1 and {} characters",
+                MAX_NAME_LEN
+            )));
+        }
+        if lifetime.is_some_and(|lifetime| lifetime <= TimeDelta::zero() || lifetime > MAX_LIFETIME)
+        {
+            return Err(AuthDomainError::InvalidPersonalAccessToken(format!(
+                "lifetime must be positive and at most {} days",
+                MAX_LIFETIME.num_days()
+            )));
+        }
+
+        let secret = format!(
+            "{}{}",
+            PERSONAL_ACCESS_TOKEN_PREFIX,
+            OpaqueToken::generate().value()
+        );
+        let token = Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.to_string(),
+            scope,
+            token_hash: Self::hash_secret(&secret),
+            created_at: now,
+            expires_at: lifetime.map(|lifetime| now + lifetime),
+            last_used_at: None,
+            revoked_at: None,
+        };
+        Ok((token, secret))
+    }
+
+    pub fn hash_secret(secret: &str) -> String {
+        OpaqueToken::from_string(secret).hash()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && !self.is_expired(now)
+    }
+
+    pub fn needs_last_used_update(&self, now: DateTime<Utc>) -> bool {
+        self.last_used_at
+            .is_none_or(|last_used_at| now - last_used_at >= LAST_USED_PRECISION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_now() -> DateTime<Utc> {
+        DateTime::<Utc>::UNIX_EPOCH + TimeDelta::days(20_000)
+    }
+
+    #[test]
+    fn test_issue_returns_a_prefixed_secret_stored_hashed() -> Result<(), AuthDomainError> {
+        let now = fixed_now();
+
+        let (token, secret) = PersonalAccessToken::issue(
+            UserUuid::new(),
+            "  Bookmarklet  ",
+            TokenScope::PositionsWrite,
+            None,
+            now,
+        )?;
+
+        assert!(secret.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX));
+        assert_eq!(token.token_hash, PersonalAccessToken::hash_secret(&secret));
+        assert_ne!(token.token_hash, secret);
+        assert_eq!(token.name, "Bookmarklet");
+        assert!(token.is_usable(now));
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_rejects_blank_names_and_out_of_range_lifetimes() {
+        let now = fixed_now();
+        let issue = |name: &str, lifetime| {
+            PersonalAccessToken::issue(UserUuid::new(), name, TokenScope::ReadOnly, lifetime, now)
+        };
+
+        assert!(issue(" ", None).is_err());
+        assert!(issue(&"x".repeat(MAX_NAME_LEN + 1), None).is_err());
+        assert!(issue("script", Some(TimeDelta::zero())).is_err());
+        assert!(issue("script", Some(MAX_LIFETIME + TimeDelta::days(1))).is_err());
+        assert!(issue("script", Some(MAX_LIFETIME)).is_ok());
+    }
+
+    #[test]
+    fn test_expired_and_revoked_tokens_are_not_usable() -> Result<(), AuthDomainError> {
+        let now = fixed_now();
+        let (mut token, _) = PersonalAccessToken::issue(
+            UserUuid::new(),
+            "script",
+            TokenScope::ReadOnly,
+            Some(TimeDelta::days(1)),
+            now,
+        )?;
+
+        assert!(!token.is_usable(now + TimeDelta::days(1)));
+        token.revoked_at = Some(now);
+        assert!(!token.is_usable(now));
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_used_is_updated_at_most_once_a_minute() -> Result<(), AuthDomainError> {
+        let now = fixed_now();
+        let (mut token, _) =
+            PersonalAccessToken::issue(UserUuid::new(), "script", TokenScope::ReadOnly, None, now)?;
+        assert!(token.needs_last_used_update(now));
+
+        token.last_used_at = Some(now);
+
+        assert!(!token.needs_last_used_update(now + TimeDelta::seconds(59)));
+        assert!(token.needs_last_used_update(now + TimeDelta::seconds(60)));
+        Ok(())
+    }
+}
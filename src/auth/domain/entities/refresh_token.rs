@@ -0,0 +1,87 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::entities::opaque_token::OpaqueToken, shared::domain::value_objects::UserUuid,
+};
+
+/// A refresh token is single use: redeeming it yields a new one in the same
+/// family. Presenting an already used token means it leaked, so the whole
+/// family gets revoked.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RefreshToken {
+    pub id: Uuid,
+    pub user_id: UserUuid,
+    pub family_id: Uuid,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl RefreshToken {
+    /// Starts a new token family, typically on login.
+    pub fn issue(user_id: UserUuid, ttl_secs: i64, now: DateTime<Utc>) -> (Self, OpaqueToken) {
+        Self::issue_in_family(user_id, Uuid::new_v4(), ttl_secs, now)
+    }
+
+    pub fn rotate(&self, ttl_secs: i64, now: DateTime<Utc>) -> (Self, OpaqueToken) {
+        Self::issue_in_family(self.user_id, self.family_id, ttl_secs, now)
+    }
+
+    fn issue_in_family(
+        user_id: UserUuid,
+        family_id: Uuid,
+        ttl_secs: i64,
+        now: DateTime<Utc>,
+    ) -> (Self, OpaqueToken) {
+        let secret = OpaqueToken::generate();
+        let token = Self {
+            id: Uuid::new_v4(),
+            user_id,
+            family_id,
+            token_hash: secret.hash(),
+            created_at: now,
+            expires_at: now + TimeDelta::seconds(ttl_secs),
+            used_at: None,
+            revoked_at: None,
+        };
+        (token, secret)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_stores_only_the_hash() {
+        let now = Utc::now();
+
+        let (token, secret) = RefreshToken::issue(UserUuid::new(), 60, now);
+
+        assert_eq!(token.token_hash, secret.hash());
+        assert_ne!(token.token_hash, secret.value());
+        assert_eq!(token.expires_at, now + TimeDelta::seconds(60));
+        assert!(!token.is_expired(now));
+        assert!(token.is_expired(now + TimeDelta::seconds(60)));
+    }
+
+    #[test]
+    fn test_rotation_keeps_the_family() {
+        let now = Utc::now();
+        let (token, secret) = RefreshToken::issue(UserUuid::new(), 60, now);
+
+        let (rotated, rotated_secret) = token.rotate(60, now);
+
+        assert_eq!(rotated.family_id, token.family_id);
+        assert_eq!(rotated.user_id, token.user_id);
+        assert_ne!(rotated.id, token.id);
+        assert_ne!(rotated_secret, secret);
+    }
+}
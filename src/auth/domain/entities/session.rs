@@ -0,0 +1,184 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use uuid::Uuid;
+
+use crate::shared::domain::{audit::RequestOrigin, value_objects::UserUuid};
+
+/// `last_seen_at` is written at most this often, not on every request.
+const LAST_SEEN_PRECISION: TimeDelta = TimeDelta::minutes(1);
+
+/// One signed-in device. It shares its id with the family of refresh tokens
+/// issued to it, and access tokens carry it as their `sid` claim, so
+/// revoking the session ends both at once.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Session {
+    pub id: Uuid,
+    pub user_id: UserUuid,
+    /// Readable summary of the user agent, such as "Firefox on Linux".
+    pub device: String,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// Follows the latest refresh token of the session.
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    pub fn start(
+        id: Uuid,
+        user_id: UserUuid,
+        origin: &RequestOrigin,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            device: describe_device(origin.user_agent.as_deref()),
+            user_agent: origin.user_agent.clone(),
+            ip: origin.ip.clone(),
+            created_at: now,
+            last_seen_at: now,
+            expires_at,
+            revoked_at: None,
+        }
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+
+    pub fn needs_last_seen_update(&self, now: DateTime<Utc>) -> bool {
+        now - self.last_seen_at >= LAST_SEEN_PRECISION
+    }
+}
+
+/// What the user's earlier sessions say about a new sign-in.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct SignInHistory {
+    pub has_sessions: bool,
+    /// An earlier session, revoked or not, had the same user agent.
+    pub knows_user_agent: bool,
+}
+
+impl SignInHistory {
+    /// The very first sign-in is not worth a warning.
+    pub fn is_unfamiliar_device(&self) -> bool {
+        self.has_sessions && !self.knows_user_agent
+    }
+}
+
+/// Best-effort "Browser on OS" label for a user agent string.
+pub fn describe_device(user_agent: Option<&str>) -> String {
+    let Some(user_agent) = user_agent.filter(|ua| !ua.trim().is_empty()) else {
+        return "Unknown device".to_string();
+    };
+    // Order matters: Edge and Opera also claim to be Chrome, Chrome claims to be Safari.
+    let browser = [
+        ("Edg/", "Edge"),
+        ("OPR/", "Opera"),
+        ("Firefox/", "Firefox"),
+        ("Chrome/", "Chrome"),
+        ("Safari/", "Safari"),
+        ("curl/", "curl"),
+    ]
+    .into_iter()
+    .find(|(marker, _)| user_agent.contains(marker))
+    .map(|(_, name)| name);
+    let os = [
+        ("Android", "Android"),
+        ("iPhone", "iOS"),
+        ("iPad", "iPadOS"),
+        ("Windows", "Windows"),
+        ("Mac OS X", "macOS"),
+        ("CrOS", "ChromeOS"),
+        ("Linux", "Linux"),
+    ]
+    .into_iter()
+    .find(|(marker, _)| user_agent.contains(marker))
+    .map(|(_, name)| name);
+
+    match (browser, os) {
+        (Some(browser), Some(os)) => format!("{browser} on {os}"),
+        (Some(browser), None) => browser.to_string(),
+        (None, Some(os)) => format!("Unknown browser on {os}"),
+        (None, None) => user_agent.chars().take(64).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_records_where_the_session_comes_from() {
+        let now = Utc::now();
+        let origin = RequestOrigin {
+            ip: Some("203.0.113.7".to_string()),
+            user_agent: Some(
+                "Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0"
+                    .to_string(),
+            ),
+            ..RequestOrigin::default()
+        };
+
+        let session = Session::start(
+            Uuid::new_v4(),
+            UserUuid::new(),
+            &origin,
+            now + TimeDelta::days(30),
+            now,
+        );
+
+        assert_eq!(session.device, "Firefox on Linux");
+        assert_eq!(session.ip.as_deref(), Some("203.0.113.7"));
+        assert!(session.is_active(now));
+        assert!(!session.is_active(now + TimeDelta::days(30)));
+        assert!(!session.needs_last_seen_update(now + TimeDelta::seconds(59)));
+        assert!(session.needs_last_seen_update(now + TimeDelta::seconds(60)));
+    }
+
+    #[test]
+    fn test_describe_device() {
+        let cases = [
+            (
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36 Edg/126.0.0.0",
+                "Edge on Windows",
+            ),
+            (
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15",
+                "Safari on macOS",
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 14) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Mobile Safari/537.36",
+                "Chrome on Android",
+            ),
+            ("curl/8.5.0", "curl"),
+        ];
+        for (user_agent, expected) in cases {
+            assert_eq!(describe_device(Some(user_agent)), expected);
+        }
+        assert_eq!(describe_device(None), "Unknown device");
+    }
+
+    #[test]
+    fn test_only_new_devices_of_known_users_are_unfamiliar() {
+        let first_ever = SignInHistory {
+            has_sessions: false,
+            knows_user_agent: false,
+        };
+        let known = SignInHistory {
+            has_sessions: true,
+            knows_user_agent: true,
+        };
+        let new_device = SignInHistory {
+            has_sessions: true,
+            knows_user_agent: false,
+        };
+
+        assert!(!first_ever.is_unfamiliar_device());
+        assert!(!known.is_unfamiliar_device());
+        assert!(new_device.is_unfamiliar_device());
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+/// Access tokens a user revoked before they expired. `logout` denies a single
+/// token by its `jti`, while `logout-all` bumps `token_version` so every token
+/// issued before it is rejected.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct TokenRevocations {
+    pub token_version: i32,
+    pub revoked_jtis: HashSet<String>,
+}
+
+impl TokenRevocations {
+    pub fn rejects(&self, jti: &str, token_version: i32) -> bool {
+        token_version < self.token_version || self.revoked_jtis.contains(jti)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_revoked_jti() {
+        let revocations = TokenRevocations {
+            token_version: 0,
+            revoked_jtis: HashSet::from(["revoked".to_string()]),
+        };
+
+        assert!(revocations.rejects("revoked", 0));
+        assert!(!revocations.rejects("other", 0));
+    }
+
+    #[test]
+    fn test_rejects_tokens_from_older_versions() {
+        let revocations = TokenRevocations {
+            token_version: 2,
+            revoked_jtis: HashSet::new(),
+        };
+
+        assert!(revocations.rejects("any", 1));
+        assert!(!revocations.rejects("any", 2));
+    }
+}
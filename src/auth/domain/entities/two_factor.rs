@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use rand::{Rng, rngs::OsRng};
+
+use crate::{
+    auth::domain::{
+        entities::{opaque_token::OpaqueToken, totp::TotpSecret},
+        errors::AuthDomainError,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// TOTP second factor of a user. It is pending until the user proves their
+/// authenticator works by sending a first code, only then is it enforced at
+/// login. Recovery codes are stored hashed and each works once.
+#[derive(PartialEq, Debug, Clone)]
+pub struct TwoFactor {
+    pub user_id: UserUuid,
+    pub secret: TotpSecret,
+    pub enabled_at: Option<DateTime<Utc>>,
+    /// Last time step a code was accepted for, so a code cannot be replayed.
+    pub last_used_step: Option<i64>,
+    pub recovery_code_hashes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TwoFactor {
+    pub fn enroll(user_id: UserUuid, now: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            secret: TotpSecret::generate(),
+            enabled_at: None,
+            last_used_step: None,
+            recovery_code_hashes: Vec::new(),
+            created_at: now,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+
+    /// Turns the factor on and returns the plain recovery codes, which are
+    /// shown to the user this once.
+    pub fn enable(&mut self, step: i64, now: DateTime<Utc>) -> Vec<String> {
+        let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| generate_recovery_code())
+            .collect();
+        self.recovery_code_hashes = codes.iter().map(|c| Self::hash_recovery_code(c)).collect();
+        self.enabled_at = Some(now);
+        self.last_used_step = Some(step);
+        codes
+    }
+
+    /// Returns the step of `code` when it is valid and was not used yet.
+    pub fn accepts_code(
+        &self,
+        code: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<i64>, AuthDomainError> {
+        Ok(self
+            .secret
+            .matching_step(code, now)?
+            .filter(|step| self.last_used_step.is_none_or(|last| *step > last)))
+    }
+
+    /// Recovery codes are compared case insensitively, ignoring the dash.
+    pub fn hash_recovery_code(code: &str) -> String {
+        let normalized: String = code
+            .trim()
+            .to_lowercase()
+            .chars()
+            .filter(|c| *c != '-')
+            .collect();
+        OpaqueToken::from_string(&normalized).hash()
+    }
+}
+
+fn generate_recovery_code() -> String {
+    let mut rng = OsRng;
+    let mut chars: Vec<char> = (0..10)
+        .map(|_| RECOVERY_CODE_ALPHABET[rng.gen_range(0..RECOVERY_CODE_ALPHABET.len())] as char)
+        .collect();
+    chars.insert(5, '-');
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::domain::entities::totp::time_step;
+    use std::collections::HashSet;
+
+    fn fixed_now() -> Result<DateTime<Utc>, AuthDomainError> {
+        DateTime::from_timestamp(1_700_000_000, 0)
+            .ok_or_else(|| AuthDomainError::InternalError("bad timestamp".to_string()))
+    }
+
+    #[test]
+    fn test_enrolment_is_pending_until_enabled() {
+        let now = Utc::now();
+        let mut two_factor = TwoFactor::enroll(UserUuid::new(), now);
+        assert!(!two_factor.is_enabled());
+
+        let codes = two_factor.enable(time_step(now), now);
+
+        assert!(two_factor.is_enabled());
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        assert_eq!(codes.iter().collect::<HashSet<_>>().len(), codes.len());
+        assert!(codes.iter().all(|c| {
+            two_factor
+                .recovery_code_hashes
+                .contains(&TwoFactor::hash_recovery_code(c))
+        }));
+    }
+
+    #[test]
+    fn test_recovery_codes_are_normalized_before_hashing() {
+        assert_eq!(
+            TwoFactor::hash_recovery_code(" ABCDE-FGHJK "),
+            TwoFactor::hash_recovery_code("abcdefghjk")
+        );
+    }
+
+    #[test]
+    fn test_codes_cannot_be_replayed() -> Result<(), AuthDomainError> {
+        let now = fixed_now()?;
+        let step = time_step(now);
+        let mut two_factor = TwoFactor::enroll(UserUuid::new(), now);
+        let code = two_factor.secret.code_at_step(step)?;
+        assert_eq!(two_factor.accepts_code(&code, now)?, Some(step));
+
+        two_factor.enable(step, now);
+
+        assert_eq!(two_factor.accepts_code(&code, now)?, None);
+        let next = two_factor.secret.code_at_step(step + 1)?;
+        assert_eq!(two_factor.accepts_code(&next, now)?, Some(step + 1));
+        Ok(())
+    }
+}
@@ -1,12 +1,12 @@
 use std::str::FromStr;
 
-use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use chrono::{Local, NaiveDate};
 
 use email_address::EmailAddress;
 
+use crate::auth::domain::entities::opaque_token::OpaqueToken;
 use crate::auth::domain::errors::AuthDomainError;
-use crate::shared::domain::value_objects::{UserPassword, UserUuid};
+use crate::shared::domain::value_objects::{UserPassword, UserRole, UserUuid};
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct UserEmail {
@@ -34,6 +34,7 @@ pub struct User {
     password: UserPassword,
     pub email_validated: bool,
     pub account_disabled: bool,
+    pub role: UserRole,
     pub created: NaiveDate,
     pub updated: NaiveDate,
 }
@@ -56,11 +57,21 @@ impl User {
             password,
             email_validated: false,
             account_disabled: false,
+            role: UserRole::User,
             created,
             updated,
         })
     }
 
+    /// User signed up through an OpenID Connect provider, which already
+    /// verified the address. The password is random and unknown to anyone;
+    /// a password reset sets a usable one.
+    pub fn new_external(id: &str, email: &str) -> Result<Self, AuthDomainError> {
+        let password = UserPassword::hash_password(OpaqueToken::generate().value())?;
+        let today = Local::now().naive_local().date();
+        Self::load_existing(id, email, &password, true, false, today, today)
+    }
+
     pub fn load_existing(
         id: &str,
         email: &str,
@@ -79,24 +90,40 @@ impl User {
             password,
             email_validated,
             account_disabled,
+            role: UserRole::User,
             created,
             updated,
         })
     }
 
+    pub fn with_role(mut self, role: UserRole) -> Self {
+        self.role = role;
+        self
+    }
+
     pub fn verify_password(&self, password: &str) -> Result<bool, AuthDomainError> {
-        Ok(Argon2::default()
-            .verify_password(
-                password.as_bytes(),
-                &PasswordHash::try_from(self.password.value())
-                    .map_err(|e| AuthDomainError::InternalError(e.to_string()))?,
-            )
-            .is_ok())
+        self.password
+            .verify(password)
+            .map_err(|e| AuthDomainError::InternalError(e.to_string()))
     }
 
     pub fn validate_email(&mut self) {
         self.email_validated = true;
     }
+
+    pub fn change_password(&mut self, password: &str) -> Result<(), AuthDomainError> {
+        self.password = UserPassword::new(password)?;
+        self.updated = Local::now().naive_local().date();
+        Ok(())
+    }
+
+    /// Only called once the new address confirmed it receives mail, hence
+    /// it stays verified.
+    pub fn change_email(&mut self, email: UserEmail) {
+        self.email = email;
+        self.email_validated = true;
+        self.updated = Local::now().naive_local().date();
+    }
 }
 
 #[cfg(test)]
@@ -140,6 +167,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_change_password() -> Result<(), AuthDomainError> {
+        let mut user = User::new(&valid_id(), valid_email(), valid_password())?;
+        let new_password = "An0ther-V3ry-Str0ng-P@ss";
+
+        assert!(user.change_password("weak").is_err());
+        user.change_password(new_password)?;
+
+        assert!(user.verify_password(new_password)?);
+        assert!(!user.verify_password(valid_password())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_change_email_keeps_it_verified() -> Result<(), AuthDomainError> {
+        let mut user = User::new(&valid_id(), valid_email(), valid_password())?;
+
+        user.change_email(UserEmail::new("new@example.com")?);
+
+        assert_eq!(user.email.value(), "new@example.com");
+        assert!(user.email_validated);
+        Ok(())
+    }
+
     #[test]
     fn test_user() {
         let result = User::new(&valid_id(), valid_email(), valid_password());
@@ -193,13 +244,15 @@ mod tests {
             false,
             created,
             updated,
-        )?;
+        )?
+        .with_role(UserRole::Admin);
 
         assert_eq!(user.id.value().to_string(), id);
         assert_eq!(user.email.value(), email);
         assert_ne!(user.password.value(), password);
         assert!(!user.email_validated);
         assert!(!user.account_disabled);
+        assert_eq!(user.role, UserRole::Admin);
         assert_eq!(user.created, created);
         assert_eq!(user.updated, updated);
 
@@ -219,4 +272,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_external_users_are_verified_and_have_no_known_password() -> Result<(), AuthDomainError>
+    {
+        let user = User::new_external(&valid_id(), valid_email())?;
+
+        assert!(user.email_validated);
+        assert!(user.password().value().starts_with("$argon2"));
+        assert!(!user.verify_password(valid_password())?);
+        assert!(User::new_external(&valid_id(), "not-an-email").is_err());
+
+        Ok(())
+    }
 }
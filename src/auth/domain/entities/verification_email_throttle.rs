@@ -0,0 +1,118 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// Window the daily cap is counted over.
+pub const THROTTLE_WINDOW: TimeDelta = TimeDelta::hours(24);
+
+/// Limits how often a user can ask for a new verification email: one every
+/// `cooldown_secs`, and at most `daily_cap` in any 24 hour window.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct VerificationEmailThrottle {
+    pub cooldown_secs: i64,
+    pub daily_cap: u32,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SendDecision {
+    Allowed,
+    Throttled { retry_after_secs: i64 },
+}
+
+impl VerificationEmailThrottle {
+    /// `previous_sends` are the user's sends within [`THROTTLE_WINDOW`], in
+    /// any order.
+    pub fn check(&self, previous_sends: &[DateTime<Utc>], now: DateTime<Utc>) -> SendDecision {
+        let mut sends: Vec<_> = previous_sends
+            .iter()
+            .copied()
+            .filter(|sent_at| *sent_at > now - THROTTLE_WINDOW)
+            .collect();
+        sends.sort();
+
+        let mut allowed_at = now;
+        if let Some(last) = sends.last() {
+            allowed_at = allowed_at.max(*last + TimeDelta::seconds(self.cooldown_secs));
+        }
+        // At the cap, a slot frees up once enough of the oldest sends leave
+        // the window.
+        if let Some(oldest_to_expire) = sends
+            .len()
+            .checked_sub(self.daily_cap as usize)
+            .and_then(|index| sends.get(index))
+        {
+            allowed_at = allowed_at.max(*oldest_to_expire + THROTTLE_WINDOW);
+        }
+
+        if allowed_at > now {
+            // Rounded up so clients retrying after the hint are not rejected again.
+            let wait = allowed_at - now;
+            let retry_after_secs = wait.num_seconds() + i64::from(wait.subsec_nanos() > 0);
+            SendDecision::Throttled { retry_after_secs }
+        } else {
+            SendDecision::Allowed
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THROTTLE: VerificationEmailThrottle = VerificationEmailThrottle {
+        cooldown_secs: 60,
+        daily_cap: 3,
+    };
+
+    #[test]
+    fn test_first_send_is_allowed() {
+        assert_eq!(THROTTLE.check(&[], Utc::now()), SendDecision::Allowed);
+    }
+
+    #[test]
+    fn test_cooldown_after_last_send() {
+        let now = Utc::now();
+
+        let decision = THROTTLE.check(&[now - TimeDelta::seconds(45)], now);
+
+        assert_eq!(
+            decision,
+            SendDecision::Throttled {
+                retry_after_secs: 15
+            }
+        );
+        assert_eq!(
+            THROTTLE.check(&[now - TimeDelta::seconds(60)], now),
+            SendDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn test_daily_cap_waits_for_the_oldest_send_to_expire() {
+        let now = Utc::now();
+        let sends = [
+            now - TimeDelta::hours(2),
+            now - TimeDelta::hours(23),
+            now - TimeDelta::hours(5),
+        ];
+
+        let decision = THROTTLE.check(&sends, now);
+
+        assert_eq!(
+            decision,
+            SendDecision::Throttled {
+                retry_after_secs: 3600
+            }
+        );
+    }
+
+    #[test]
+    fn test_sends_outside_the_window_do_not_count() {
+        let now = Utc::now();
+        let sends = [
+            now - TimeDelta::hours(2),
+            now - TimeDelta::hours(25),
+            now - TimeDelta::hours(30),
+        ];
+
+        assert_eq!(THROTTLE.check(&sends, now), SendDecision::Allowed);
+    }
+}
@@ -6,6 +6,9 @@ pub enum AuthDomainError {
     #[error(transparent)]
     Shared(#[from] SharedDomainError),
 
+    #[error("Invalid personal access token: {0}")]
+    InvalidPersonalAccessToken(String),
+
     #[error("Internal error: {0}")]
     InternalError(String),
 }
@@ -54,6 +57,9 @@ pub enum AuthRepoError {
 
     #[error("User with email `{0}` already exists")]
     UserAlreadyExists(String),
+
+    #[error("Identity `{0}` is already linked")]
+    IdentityAlreadyLinked(String),
 }
 
 #[cfg(test)]
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::domain::entities::email_change_request::EmailChangeRequest;
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IEmailChangeRequestRepository: Send + Sync {
+    async fn save(&self, request: &EmailChangeRequest) -> Result<(), AuthRepoError>;
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailChangeRequest>, AuthRepoError>;
+    /// Marks the request as used unless it already was. Returns `false` when
+    /// another request used it first.
+    async fn mark_used(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<bool, AuthRepoError>;
+    /// Burns every pending request of the user, so only the latest link works.
+    async fn mark_all_used(
+        &self,
+        user_id: UserUuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError>;
+}
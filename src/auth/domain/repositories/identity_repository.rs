@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::domain::entities::identity::Identity;
+use crate::auth::domain::errors::AuthRepoError;
+
+#[async_trait]
+pub trait IIdentityRepository: Send + Sync {
+    /// Fails with [`AuthRepoError::IdentityAlreadyLinked`] when the provider
+    /// account is already linked.
+    async fn save(&self, identity: &Identity) -> Result<(), AuthRepoError>;
+    async fn find(&self, provider: &str, subject: &str) -> Result<Option<Identity>, AuthRepoError>;
+    async fn touch(&self, id: Uuid, logged_in_at: DateTime<Utc>) -> Result<(), AuthRepoError>;
+}
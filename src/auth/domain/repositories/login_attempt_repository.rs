@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::auth::domain::entities::login_attempts::{
+    FailureOutcome, LoginAttempts, LoginLockoutPolicy,
+};
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait ILoginAttemptRepository: Send + Sync {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<LoginAttempts>, AuthRepoError>;
+    /// Counts a failed login under `policy`. Concurrent failures of the same
+    /// user are serialized, so none of them is lost.
+    async fn record_failure(
+        &self,
+        user_id: UserUuid,
+        policy: &LoginLockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<FailureOutcome, AuthRepoError>;
+    /// Forgets the failures after a successful login.
+    async fn reset(&self, user_id: UserUuid) -> Result<(), AuthRepoError>;
+}
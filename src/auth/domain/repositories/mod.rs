@@ -1 +1,12 @@
+pub mod email_change_request_repository;
+pub mod identity_repository;
+pub mod login_attempt_repository;
+pub mod oidc_login_state_repository;
+pub mod password_reset_token_repository;
+pub mod personal_access_token_repository;
+pub mod refresh_token_repository;
+pub mod session_repository;
+pub mod token_revocation_repository;
+pub mod two_factor_repository;
 pub mod user_repository;
+pub mod verification_email_repository;
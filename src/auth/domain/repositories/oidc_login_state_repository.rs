@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::auth::domain::entities::oidc_login_state::OidcLoginState;
+use crate::auth::domain::errors::AuthRepoError;
+
+#[async_trait]
+pub trait IOidcLoginStateRepository: Send + Sync {
+    async fn save(&self, login_state: &OidcLoginState) -> Result<(), AuthRepoError>;
+    /// Removes and returns the login state, so each one is used at most once.
+    async fn take(&self, state_hash: &str) -> Result<Option<OidcLoginState>, AuthRepoError>;
+    /// Drops logins that were started but never finished.
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<(), AuthRepoError>;
+}
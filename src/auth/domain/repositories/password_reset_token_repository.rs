@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::domain::entities::password_reset_token::PasswordResetToken;
+use crate::auth::domain::entities::user::User;
+use crate::auth::domain::entities::verification_email_throttle::{
+    SendDecision, VerificationEmailThrottle,
+};
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IPasswordResetTokenRepository: Send + Sync {
+    /// Saves the token when `throttle` allows another reset email for its
+    /// user, counting the tokens created before it. The check and the insert
+    /// are atomic, so concurrent requests cannot both slip under the limit.
+    async fn save_if_allowed(
+        &self,
+        token: &PasswordResetToken,
+        throttle: &VerificationEmailThrottle,
+    ) -> Result<SendDecision, AuthRepoError>;
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PasswordResetToken>, AuthRepoError>;
+    /// Burns every pending token of the user, e.g. once one of them was used.
+    async fn mark_all_used(
+        &self,
+        user_id: UserUuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError>;
+    /// Uses the token, burns the other pending tokens of `user` and saves its
+    /// new password, all or nothing. Returns `false` when another request
+    /// used the token first, and `NotFound` when the user is gone or disabled.
+    async fn reset_password(
+        &self,
+        token_id: Uuid,
+        user: &User,
+        used_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError>;
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::domain::entities::personal_access_token::PersonalAccessToken;
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IPersonalAccessTokenRepository: Send + Sync {
+    async fn save(&self, token: &PersonalAccessToken) -> Result<(), AuthRepoError>;
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalAccessToken>, AuthRepoError>;
+    /// Tokens of the user that were not revoked, newest first.
+    async fn list_for_user(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Vec<PersonalAccessToken>, AuthRepoError>;
+    /// Returns `false` when the user has no such active token.
+    async fn revoke(
+        &self,
+        user_id: UserUuid,
+        id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError>;
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), AuthRepoError>;
+}
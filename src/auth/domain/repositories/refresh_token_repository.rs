@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::domain::entities::refresh_token::RefreshToken;
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IRefreshTokenRepository: Send + Sync {
+    async fn save(&self, token: &RefreshToken) -> Result<(), AuthRepoError>;
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, AuthRepoError>;
+    /// Marks the token as used unless it already was or has been revoked.
+    /// Returns `false` when another request redeemed it first.
+    async fn mark_used(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<bool, AuthRepoError>;
+    async fn revoke_family(
+        &self,
+        family_id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError>;
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserUuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError>;
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::domain::entities::session::{Session, SignInHistory};
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait ISessionRepository: Send + Sync {
+    async fn save(&self, session: &Session) -> Result<(), AuthRepoError>;
+    async fn get(&self, id: Uuid) -> Result<Option<Session>, AuthRepoError>;
+    /// Sessions neither revoked nor expired, most recently seen first.
+    async fn list_active(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, AuthRepoError>;
+    async fn sign_in_history(
+        &self,
+        user_id: UserUuid,
+        user_agent: Option<&str>,
+    ) -> Result<SignInHistory, AuthRepoError>;
+    /// Records a refresh: the session is seen now and lives as long as its
+    /// new refresh token.
+    async fn extend(
+        &self,
+        id: Uuid,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError>;
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), AuthRepoError>;
+    /// Returns `false` when the session is unknown, another user's or
+    /// already revoked.
+    async fn revoke(
+        &self,
+        user_id: UserUuid,
+        id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError>;
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserUuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError>;
+}
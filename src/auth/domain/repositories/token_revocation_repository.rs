@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::auth::domain::entities::token_revocations::TokenRevocations;
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait ITokenRevocationRepository: Send + Sync {
+    async fn revoke_token(
+        &self,
+        jti: &str,
+        user_id: UserUuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError>;
+    /// Invalidates every token issued so far and returns the new version.
+    async fn revoke_all_tokens(&self, user_id: UserUuid) -> Result<i32, AuthRepoError>;
+    /// Only revocations of tokens that have not expired yet are returned.
+    async fn get_revocations(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+    ) -> Result<TokenRevocations, AuthRepoError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::auth::domain::entities::two_factor::TwoFactor;
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait ITwoFactorRepository: Send + Sync {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<TwoFactor>, AuthRepoError>;
+    /// Inserts or replaces the user's second factor.
+    async fn save(&self, two_factor: &TwoFactor) -> Result<(), AuthRepoError>;
+    async fn delete(&self, user_id: UserUuid) -> Result<(), AuthRepoError>;
+    /// Moves `last_used_step` forward to `step`. Returns `false` when a code of
+    /// that step or a later one was already accepted, so codes work once.
+    async fn record_used_step(&self, user_id: UserUuid, step: i64) -> Result<bool, AuthRepoError>;
+    /// Removes the recovery code. Returns `false` when it was not there.
+    async fn consume_recovery_code(
+        &self,
+        user_id: UserUuid,
+        code_hash: &str,
+    ) -> Result<bool, AuthRepoError>;
+}
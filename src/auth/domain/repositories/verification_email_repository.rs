@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::auth::domain::entities::verification_email_throttle::{
+    SendDecision, VerificationEmailThrottle,
+};
+use crate::auth::domain::errors::AuthRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IVerificationEmailRepository: Send + Sync {
+    /// Records a verification email send when `throttle` allows it. The check
+    /// and the insert are atomic, so concurrent requests cannot both slip
+    /// under the limit.
+    async fn record_send_if_allowed(
+        &self,
+        user_id: UserUuid,
+        throttle: &VerificationEmailThrottle,
+        now: DateTime<Utc>,
+    ) -> Result<SendDecision, AuthRepoError>;
+}
@@ -1,6 +1,36 @@
-use crate::auth::domain::entities::user::User;
+use chrono::{TimeDelta, Utc};
+
+use crate::auth::domain::entities::email_change_request::EmailChangeRequest;
+use crate::auth::domain::entities::identity::Identity;
+use crate::auth::domain::entities::login_attempts::{
+    FailureOutcome, LoginDecision, LoginLockoutPolicy,
+};
+use crate::auth::domain::entities::oidc_login_state::OidcLoginState;
+use crate::auth::domain::entities::password_reset_token::PasswordResetToken;
+use crate::auth::domain::entities::personal_access_token::PersonalAccessToken;
+use crate::auth::domain::entities::refresh_token::RefreshToken;
+use crate::auth::domain::entities::session::Session;
+use crate::auth::domain::entities::token_revocations::TokenRevocations;
+use crate::auth::domain::entities::two_factor::TwoFactor;
+use crate::auth::domain::entities::user::{User, UserEmail};
+use crate::auth::domain::entities::verification_email_throttle::{
+    SendDecision, VerificationEmailThrottle,
+};
+use crate::auth::domain::errors::AuthRepoError;
+use crate::auth::domain::repositories::email_change_request_repository::IEmailChangeRequestRepository;
+use crate::auth::domain::repositories::identity_repository::IIdentityRepository;
+use crate::auth::domain::repositories::login_attempt_repository::ILoginAttemptRepository;
+use crate::auth::domain::repositories::oidc_login_state_repository::IOidcLoginStateRepository;
+use crate::auth::domain::repositories::password_reset_token_repository::IPasswordResetTokenRepository;
+use crate::auth::domain::repositories::personal_access_token_repository::IPersonalAccessTokenRepository;
+use crate::auth::domain::repositories::refresh_token_repository::IRefreshTokenRepository;
+use crate::auth::domain::repositories::session_repository::ISessionRepository;
+use crate::auth::domain::repositories::token_revocation_repository::ITokenRevocationRepository;
+use crate::auth::domain::repositories::two_factor_repository::ITwoFactorRepository;
 use crate::auth::domain::repositories::user_repository::IUserRepository;
-use crate::shared::domain::value_objects::UserUuid;
+use crate::auth::domain::repositories::verification_email_repository::IVerificationEmailRepository;
+use crate::shared::domain::audit::RequestOrigin;
+use crate::shared::domain::value_objects::{TokenScope, UserUuid};
 
 #[cfg(test)]
 pub async fn assert_user_repository_behavior(repo: Box<dyn IUserRepository>, user: User) {
@@ -10,6 +40,7 @@ pub async fn assert_user_repository_behavior(repo: Box<dyn IUserRepository>, use
     test_find_by_email(repo.as_ref(), &user).await;
     test_find_by_email_non_existent(repo.as_ref()).await;
     test_save_duplicate_user(repo.as_ref(), &user).await;
+    test_update_email(repo.as_ref(), &user).await;
 }
 
 async fn test_save_and_get(repo: &dyn IUserRepository, user: &User) {
@@ -49,7 +80,6 @@ async fn test_find_by_email(repo: &dyn IUserRepository, user: &User) {
 }
 
 async fn test_find_by_email_non_existent(repo: &dyn IUserRepository) {
-    use crate::auth::domain::entities::user::UserEmail;
     let non_existent_email = UserEmail::new("nonexistent@example.com").unwrap();
     let result = repo
         .find_by_email(non_existent_email)
@@ -79,3 +109,625 @@ async fn test_save_duplicate_user(repo: &dyn IUserRepository, user: &User) {
         "Should return error when saving user with duplicate email"
     );
 }
+
+async fn test_update_email(repo: &dyn IUserRepository, user: &User) {
+    // Unique addresses, the postgres contract runs against a shared database.
+    let other = User::new(
+        &UserUuid::new().to_string(),
+        &format!("other.{}@example.com", user.id),
+        "AnotherP@ssw0rd!",
+    )
+    .expect("Should create user");
+    repo.save(&other).await.expect("Should save other user");
+
+    let mut taken = user.clone();
+    taken.change_email(other.email.clone());
+    assert!(
+        matches!(
+            repo.update(&taken).await,
+            Err(AuthRepoError::UserAlreadyExists(_))
+        ),
+        "Should keep emails unique on update"
+    );
+
+    let mut changed = user.clone();
+    changed.change_email(UserEmail::new(&format!("changed.{}@example.com", user.id)).unwrap());
+    repo.update(&changed).await.expect("Should update email");
+    let fetched = repo
+        .find_by_email(changed.email.clone())
+        .await
+        .unwrap()
+        .expect("Should find user by new email");
+    assert_eq!(fetched.id, user.id);
+    assert!(fetched.email_validated);
+}
+
+#[cfg(test)]
+pub async fn assert_refresh_token_repository_behavior(
+    repo: Box<dyn IRefreshTokenRepository>,
+    user_id: UserUuid,
+) {
+    let now = Utc::now();
+    let (token, secret) = RefreshToken::issue(user_id, 3600, now);
+    let (sibling, _) = token.rotate(3600, now);
+    repo.save(&token).await.expect("Should save token");
+    repo.save(&sibling).await.expect("Should save sibling");
+
+    let fetched = repo
+        .find_by_hash(&secret.hash())
+        .await
+        .expect("Should not error on find")
+        .expect("Should find token by hash");
+    assert_eq!(fetched.id, token.id);
+    assert_eq!(fetched.family_id, token.family_id);
+    assert!(
+        repo.find_by_hash("unknown")
+            .await
+            .expect("Should not error on find")
+            .is_none()
+    );
+
+    assert!(repo.mark_used(token.id, now).await.unwrap());
+    assert!(
+        !repo.mark_used(token.id, now).await.unwrap(),
+        "A token can only be redeemed once"
+    );
+
+    repo.revoke_family(token.family_id, now).await.unwrap();
+    let sibling = repo
+        .find_by_hash(&sibling.token_hash)
+        .await
+        .unwrap()
+        .expect("Should find sibling");
+    assert!(sibling.revoked_at.is_some());
+    assert!(
+        !repo.mark_used(sibling.id, now).await.unwrap(),
+        "Revoked tokens cannot be redeemed"
+    );
+
+    let (other_family, other_secret) = RefreshToken::issue(user_id, 3600, now);
+    repo.save(&other_family).await.unwrap();
+    repo.revoke_all_for_user(user_id, now).await.unwrap();
+    let other_family = repo
+        .find_by_hash(&other_secret.hash())
+        .await
+        .unwrap()
+        .expect("Should find token");
+    assert!(other_family.revoked_at.is_some());
+}
+
+pub async fn assert_token_revocation_repository_behavior(
+    repo: Box<dyn ITokenRevocationRepository>,
+    user_id: UserUuid,
+) {
+    let now = Utc::now();
+    let initial = repo.get_revocations(user_id, now).await.unwrap();
+    assert_eq!(initial, TokenRevocations::default());
+
+    repo.revoke_token("live", user_id, now + TimeDelta::minutes(5))
+        .await
+        .unwrap();
+    repo.revoke_token("expired", user_id, now - TimeDelta::minutes(5))
+        .await
+        .unwrap();
+    repo.revoke_token("live", user_id, now + TimeDelta::minutes(5))
+        .await
+        .expect("Revoking twice should be idempotent");
+    let revocations = repo.get_revocations(user_id, now).await.unwrap();
+    assert!(revocations.revoked_jtis.contains("live"));
+    assert!(
+        !revocations.revoked_jtis.contains("expired"),
+        "Expired tokens are rejected anyway"
+    );
+
+    assert_eq!(repo.revoke_all_tokens(user_id).await.unwrap(), 1);
+    assert_eq!(repo.revoke_all_tokens(user_id).await.unwrap(), 2);
+    let revocations = repo.get_revocations(user_id, now).await.unwrap();
+    assert_eq!(revocations.token_version, 2);
+}
+
+pub async fn assert_password_reset_token_repository_behavior(
+    repo: Box<dyn IPasswordResetTokenRepository>,
+    users: Box<dyn IUserRepository>,
+    user: User,
+) {
+    let user_id = user.id;
+    let now = Utc::now();
+    let expires_at = now + TimeDelta::hours(1);
+    let throttle = VerificationEmailThrottle {
+        cooldown_secs: 60,
+        daily_cap: 5,
+    };
+    let token = PasswordResetToken::new(user_id, "first", expires_at, now);
+    let pending = PasswordResetToken::new(user_id, "second", expires_at, now);
+    let allowed = repo
+        .save_if_allowed(&token, &throttle)
+        .await
+        .expect("Should save token");
+    assert_eq!(allowed, SendDecision::Allowed);
+    let during_cooldown = repo.save_if_allowed(&pending, &throttle).await.unwrap();
+    assert_eq!(
+        during_cooldown,
+        SendDecision::Throttled {
+            retry_after_secs: 60
+        }
+    );
+    assert!(
+        repo.find_by_hash(&pending.token_hash)
+            .await
+            .unwrap()
+            .is_none(),
+        "Throttled tokens are not saved"
+    );
+    let pending =
+        PasswordResetToken::new(user_id, "second", expires_at, now + TimeDelta::minutes(1));
+    repo.save_if_allowed(&pending, &throttle)
+        .await
+        .expect("Should save pending token");
+
+    let fetched = repo
+        .find_by_hash(&PasswordResetToken::hash_token_id("first"))
+        .await
+        .expect("Should not error on find")
+        .expect("Should find token by hash");
+    assert_eq!(fetched.id, token.id);
+    assert_eq!(fetched.user_id, user_id);
+    assert!(repo.find_by_hash("unknown").await.unwrap().is_none());
+
+    repo.mark_all_used(user_id, now).await.unwrap();
+    let pending = repo
+        .find_by_hash(&pending.token_hash)
+        .await
+        .unwrap()
+        .expect("Should find pending token");
+    assert!(pending.used_at.is_some());
+
+    test_reset_password(repo.as_ref(), users.as_ref(), user, &throttle).await;
+}
+
+async fn test_reset_password(
+    repo: &dyn IPasswordResetTokenRepository,
+    users: &dyn IUserRepository,
+    mut user: User,
+    throttle: &VerificationEmailThrottle,
+) {
+    let now = Utc::now();
+    let expires_at = now + TimeDelta::hours(1);
+    let token = PasswordResetToken::new(user.id, "third", expires_at, now + TimeDelta::minutes(2));
+    let other = PasswordResetToken::new(user.id, "fourth", expires_at, now + TimeDelta::minutes(3));
+    repo.save_if_allowed(&token, throttle).await.unwrap();
+    repo.save_if_allowed(&other, throttle).await.unwrap();
+    let new_password = "An0ther-V3ry-Str0ng-P@ss";
+
+    user.account_disabled = true;
+    users.update(&user).await.unwrap();
+    let mut reset = user.clone();
+    reset.account_disabled = false;
+    reset.change_password(new_password).unwrap();
+    assert_eq!(
+        repo.reset_password(token.id, &reset, now).await,
+        Err(AuthRepoError::NotFound(user.id))
+    );
+    let unused = repo.find_by_hash(&token.token_hash).await.unwrap().unwrap();
+    assert!(
+        unused.used_at.is_none(),
+        "A failed reset leaves the token usable"
+    );
+    let stored = users.get(user.id).await.unwrap().unwrap();
+    assert!(!stored.verify_password(new_password).unwrap());
+
+    user.account_disabled = false;
+    users.update(&user).await.unwrap();
+    assert!(repo.reset_password(token.id, &reset, now).await.unwrap());
+    let stored = users.get(user.id).await.unwrap().unwrap();
+    assert!(stored.verify_password(new_password).unwrap());
+    let other = repo.find_by_hash(&other.token_hash).await.unwrap().unwrap();
+    assert!(
+        other.used_at.is_some(),
+        "The other pending tokens are burned"
+    );
+    assert!(
+        !repo.reset_password(token.id, &reset, now).await.unwrap(),
+        "A token can only be used once"
+    );
+}
+
+pub async fn assert_verification_email_repository_behavior(
+    repo: Box<dyn IVerificationEmailRepository>,
+    user_id: UserUuid,
+) {
+    let throttle = VerificationEmailThrottle {
+        cooldown_secs: 60,
+        daily_cap: 2,
+    };
+    let start = Utc::now() - TimeDelta::hours(30);
+
+    let first = repo
+        .record_send_if_allowed(user_id, &throttle, start)
+        .await
+        .expect("Should record send");
+    assert_eq!(first, SendDecision::Allowed);
+    let during_cooldown = repo
+        .record_send_if_allowed(user_id, &throttle, start + TimeDelta::seconds(10))
+        .await
+        .unwrap();
+    assert_eq!(
+        during_cooldown,
+        SendDecision::Throttled {
+            retry_after_secs: 50
+        },
+        "Throttled sends are not recorded"
+    );
+    let second = repo
+        .record_send_if_allowed(user_id, &throttle, start + TimeDelta::minutes(1))
+        .await
+        .unwrap();
+    assert_eq!(second, SendDecision::Allowed);
+
+    let over_cap = repo
+        .record_send_if_allowed(user_id, &throttle, start + TimeDelta::hours(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        over_cap,
+        SendDecision::Throttled {
+            retry_after_secs: 23 * 3600
+        }
+    );
+
+    let next_day = repo
+        .record_send_if_allowed(user_id, &throttle, start + TimeDelta::hours(25))
+        .await
+        .unwrap();
+    assert_eq!(next_day, SendDecision::Allowed);
+}
+
+pub async fn assert_email_change_request_repository_behavior(
+    repo: Box<dyn IEmailChangeRequestRepository>,
+    user_id: UserUuid,
+) {
+    let now = Utc::now();
+    let expires_at = now + TimeDelta::hours(1);
+    let new_email = UserEmail::new("new@example.com").unwrap();
+    let request = EmailChangeRequest::new(user_id, new_email.clone(), "first", expires_at, now);
+    let pending = EmailChangeRequest::new(user_id, new_email, "second", expires_at, now);
+    repo.save(&request).await.expect("Should save request");
+    repo.save(&pending)
+        .await
+        .expect("Should save pending request");
+
+    let fetched = repo
+        .find_by_hash(&EmailChangeRequest::hash_token_id("first"))
+        .await
+        .expect("Should not error on find")
+        .expect("Should find request by hash");
+    assert_eq!(fetched.id, request.id);
+    assert_eq!(fetched.new_email.value(), "new@example.com");
+    assert!(repo.find_by_hash("unknown").await.unwrap().is_none());
+
+    assert!(repo.mark_used(request.id, now).await.unwrap());
+    assert!(
+        !repo.mark_used(request.id, now).await.unwrap(),
+        "A request can only be used once"
+    );
+
+    repo.mark_all_used(user_id, now).await.unwrap();
+    let pending = repo
+        .find_by_hash(&pending.token_hash)
+        .await
+        .unwrap()
+        .expect("Should find pending request");
+    assert!(pending.used_at.is_some());
+}
+
+pub async fn assert_two_factor_repository_behavior(
+    repo: Box<dyn ITwoFactorRepository>,
+    user_id: UserUuid,
+) {
+    let now = Utc::now();
+    assert!(repo.get(user_id).await.unwrap().is_none());
+
+    let mut two_factor = TwoFactor::enroll(user_id, now);
+    repo.save(&two_factor)
+        .await
+        .expect("Should save pending factor");
+    let codes = two_factor.enable(100, now);
+    repo.save(&two_factor).await.expect("Should replace factor");
+
+    let fetched = repo
+        .get(user_id)
+        .await
+        .expect("Should not error on get")
+        .expect("Should find saved factor");
+    assert_eq!(fetched.secret, two_factor.secret);
+    assert!(fetched.is_enabled());
+    assert_eq!(fetched.last_used_step, Some(100));
+    assert_eq!(
+        fetched.recovery_code_hashes,
+        two_factor.recovery_code_hashes
+    );
+
+    assert!(!repo.record_used_step(user_id, 100).await.unwrap());
+    assert!(repo.record_used_step(user_id, 101).await.unwrap());
+    assert!(
+        !repo.record_used_step(user_id, 101).await.unwrap(),
+        "A step can only be used once"
+    );
+
+    let code_hash = TwoFactor::hash_recovery_code(&codes[0]);
+    assert!(
+        repo.consume_recovery_code(user_id, &code_hash)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !repo
+            .consume_recovery_code(user_id, &code_hash)
+            .await
+            .unwrap(),
+        "A recovery code can only be used once"
+    );
+    let fetched = repo.get(user_id).await.unwrap().unwrap();
+    assert_eq!(fetched.recovery_code_hashes.len(), codes.len() - 1);
+
+    repo.delete(user_id).await.expect("Should delete factor");
+    assert!(repo.get(user_id).await.unwrap().is_none());
+}
+
+pub async fn assert_login_attempt_repository_behavior(
+    repo: Box<dyn ILoginAttemptRepository>,
+    user_id: UserUuid,
+) {
+    let policy = LoginLockoutPolicy {
+        delay_after: 2,
+        base_delay_secs: 1,
+        max_delay_secs: 60,
+        lockout_threshold: 3,
+        lockout_secs: 600,
+    };
+    // Whole seconds, TIMESTAMP columns keep microseconds only.
+    let now = chrono::DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+    assert!(repo.get(user_id).await.unwrap().is_none());
+
+    for _ in 0..2 {
+        let outcome = repo.record_failure(user_id, &policy, now).await.unwrap();
+        assert_eq!(outcome, FailureOutcome::Counted);
+    }
+    let attempts = repo
+        .get(user_id)
+        .await
+        .expect("Should not error on get")
+        .expect("Should find the failures");
+    assert_eq!(attempts.failed_count, 2);
+    assert_eq!(attempts.last_failed_at, Some(now));
+    assert!(matches!(
+        policy.check(&attempts, now),
+        LoginDecision::Delayed { .. }
+    ));
+
+    let outcome = repo.record_failure(user_id, &policy, now).await.unwrap();
+    let until = now + TimeDelta::seconds(600);
+    assert_eq!(outcome, FailureOutcome::LockedOut { until });
+    assert_eq!(
+        repo.get(user_id).await.unwrap().unwrap().locked_until,
+        Some(until)
+    );
+
+    repo.reset(user_id)
+        .await
+        .expect("Should reset the failures");
+    assert!(repo.get(user_id).await.unwrap().is_none());
+}
+
+pub async fn assert_personal_access_token_repository_behavior(
+    repo: Box<dyn IPersonalAccessTokenRepository>,
+    user_id: UserUuid,
+) {
+    // Whole seconds, TIMESTAMP columns keep microseconds only.
+    let now = chrono::DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+    let (older, _) = PersonalAccessToken::issue(
+        user_id,
+        "CLI",
+        TokenScope::ReadOnly,
+        Some(TimeDelta::days(30)),
+        now - TimeDelta::minutes(5),
+    )
+    .unwrap();
+    let (newer, secret) =
+        PersonalAccessToken::issue(user_id, "Extension", TokenScope::PositionsWrite, None, now)
+            .unwrap();
+    repo.save(&older).await.expect("Should save token");
+    repo.save(&newer).await.expect("Should save token");
+
+    let fetched = repo
+        .find_by_hash(&PersonalAccessToken::hash_secret(&secret))
+        .await
+        .expect("Should not error on find")
+        .expect("Should find token by hash");
+    assert_eq!(fetched, newer);
+    assert!(repo.find_by_hash("unknown").await.unwrap().is_none());
+
+    let listed: Vec<_> = repo
+        .list_for_user(user_id)
+        .await
+        .expect("Should list tokens")
+        .into_iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(listed, vec![newer.id, older.id]);
+
+    repo.touch(newer.id, now).await.expect("Should touch token");
+    let touched = repo.find_by_hash(&newer.token_hash).await.unwrap().unwrap();
+    assert_eq!(touched.last_used_at, Some(now));
+
+    assert!(
+        !repo.revoke(UserUuid::new(), older.id, now).await.unwrap(),
+        "Only the owner can revoke a token"
+    );
+    assert!(repo.revoke(user_id, older.id, now).await.unwrap());
+    assert!(!repo.revoke(user_id, older.id, now).await.unwrap());
+    let listed = repo.list_for_user(user_id).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, newer.id);
+}
+
+pub async fn assert_identity_repository_behavior(
+    repo: Box<dyn IIdentityRepository>,
+    user_id: UserUuid,
+    other_user_id: UserUuid,
+) {
+    let now = chrono::DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+    let subject = uuid::Uuid::new_v4().to_string();
+    let identity = Identity::link(user_id, "google", &subject, "user@example.com", now);
+    repo.save(&identity).await.expect("Should save identity");
+
+    let found = repo
+        .find("google", &subject)
+        .await
+        .expect("Should not error on find")
+        .expect("Should find identity by provider and subject");
+    assert_eq!(found, identity);
+    assert!(repo.find("other", &subject).await.unwrap().is_none());
+
+    let taken = Identity::link(other_user_id, "google", &subject, "other@example.com", now);
+    assert!(matches!(
+        repo.save(&taken).await,
+        Err(AuthRepoError::IdentityAlreadyLinked(_))
+    ));
+    let same_subject_other_provider =
+        Identity::link(other_user_id, "gitlab", &subject, "other@example.com", now);
+    repo.save(&same_subject_other_provider)
+        .await
+        .expect("Subjects are only unique per provider");
+
+    let later = now + TimeDelta::hours(1);
+    repo.touch(identity.id, later)
+        .await
+        .expect("Should touch identity");
+    let touched = repo.find("google", &subject).await.unwrap().unwrap();
+    assert_eq!(touched.last_login_at, later);
+}
+
+pub async fn assert_oidc_login_state_repository_behavior(repo: Box<dyn IOidcLoginStateRepository>) {
+    let now = chrono::DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+    let (login_state, state) = OidcLoginState::begin("google", TimeDelta::minutes(10), now);
+    let (expired, expired_state) =
+        OidcLoginState::begin("google", TimeDelta::minutes(10), now - TimeDelta::hours(1));
+    repo.save(&login_state).await.expect("Should save state");
+    repo.save(&expired).await.expect("Should save state");
+
+    repo.delete_expired(now)
+        .await
+        .expect("Should delete expired states");
+    assert!(
+        repo.take(&OidcLoginState::hash_state(&expired_state))
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let taken = repo
+        .take(&OidcLoginState::hash_state(&state))
+        .await
+        .expect("Should not error on take");
+    assert_eq!(taken, Some(login_state));
+    assert!(
+        repo.take(&OidcLoginState::hash_state(&state))
+            .await
+            .unwrap()
+            .is_none(),
+        "A login state is used only once"
+    );
+}
+
+pub async fn assert_session_repository_behavior(
+    repo: Box<dyn ISessionRepository>,
+    user_id: UserUuid,
+) {
+    // Whole seconds, TIMESTAMP columns keep microseconds only.
+    let now = chrono::DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+    let laptop = RequestOrigin {
+        ip: Some("203.0.113.7".to_string()),
+        user_agent: Some("Mozilla/5.0 (X11; Linux x86_64) Firefox/128.0".to_string()),
+        ..RequestOrigin::default()
+    };
+    let history = repo
+        .sign_in_history(user_id, laptop.user_agent.as_deref())
+        .await
+        .expect("Should read the history");
+    assert!(!history.has_sessions);
+
+    let older = Session::start(
+        uuid::Uuid::new_v4(),
+        user_id,
+        &laptop,
+        now + TimeDelta::days(30),
+        now - TimeDelta::hours(1),
+    );
+    let newer = Session::start(
+        uuid::Uuid::new_v4(),
+        user_id,
+        &RequestOrigin::default(),
+        now + TimeDelta::days(30),
+        now,
+    );
+    repo.save(&older).await.expect("Should save session");
+    repo.save(&newer).await.expect("Should save session");
+
+    assert_eq!(repo.get(older.id).await.unwrap(), Some(older.clone()));
+    assert!(repo.get(uuid::Uuid::new_v4()).await.unwrap().is_none());
+    let history = repo
+        .sign_in_history(user_id, laptop.user_agent.as_deref())
+        .await
+        .unwrap();
+    assert!(history.has_sessions && history.knows_user_agent);
+    let history = repo
+        .sign_in_history(user_id, Some("Mozilla/5.0 (Windows NT 10.0) Chrome/126.0"))
+        .await
+        .unwrap();
+    assert!(history.is_unfamiliar_device());
+
+    let listed: Vec<_> = repo
+        .list_active(user_id, now)
+        .await
+        .expect("Should list sessions")
+        .into_iter()
+        .map(|s| s.id)
+        .collect();
+    assert_eq!(listed, vec![newer.id, older.id]);
+
+    let later = now + TimeDelta::minutes(5);
+    repo.touch(older.id, later)
+        .await
+        .expect("Should touch session");
+    repo.touch(older.id, now).await.unwrap();
+    assert_eq!(
+        repo.get(older.id).await.unwrap().unwrap().last_seen_at,
+        later
+    );
+    repo.extend(newer.id, now + TimeDelta::days(60), later)
+        .await
+        .expect("Should extend session");
+    let extended = repo.get(newer.id).await.unwrap().unwrap();
+    assert_eq!(extended.expires_at, now + TimeDelta::days(60));
+    assert!(
+        repo.list_active(user_id, now + TimeDelta::days(45))
+            .await
+            .unwrap()
+            .iter()
+            .all(|s| s.id == newer.id)
+    );
+
+    assert!(
+        !repo.revoke(UserUuid::new(), older.id, now).await.unwrap(),
+        "Only the owner can revoke a session"
+    );
+    assert!(repo.revoke(user_id, older.id, now).await.unwrap());
+    assert!(!repo.revoke(user_id, older.id, now).await.unwrap());
+    assert!(!repo.get(older.id).await.unwrap().unwrap().is_active(now));
+
+    repo.revoke_all_for_user(user_id, now)
+        .await
+        .expect("Should revoke every session");
+    assert!(repo.list_active(user_id, now).await.unwrap().is_empty());
+}
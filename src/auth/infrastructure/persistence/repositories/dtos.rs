@@ -1,8 +1,10 @@
+use std::str::FromStr;
+
 use sqlx::{Row, postgres::PgRow};
 
 use crate::{
     auth::domain::{entities::user::User, errors::AuthDomainError},
-    shared::domain::errors::SharedDomainError,
+    shared::domain::{errors::SharedDomainError, value_objects::UserRole},
 };
 
 #[derive(Clone, Debug)]
@@ -12,6 +14,7 @@ pub struct UserDto {
     password: String,
     email_validated: bool,
     account_disabled: bool,
+    role: String,
     created_at: chrono::NaiveDateTime,
     updated_at: chrono::NaiveDateTime,
 }
@@ -24,6 +27,7 @@ impl UserDto {
             password: row.get("password"),
             email_validated: row.get("email_validated"),
             account_disabled: row.get("account_disabled"),
+            role: row.get("role"),
             created_at: row
                 .get::<chrono::DateTime<chrono::Utc>, _>("created_at")
                 .naive_utc(),
@@ -40,6 +44,7 @@ impl UserDto {
             password: user.password().value().to_string(),
             email_validated: user.email_validated,
             account_disabled: user.account_disabled,
+            role: user.role.to_string(),
             created_at: user
                 .created
                 .and_hms_opt(0, 0, 0)
@@ -57,6 +62,7 @@ impl UserDto {
     }
 
     pub fn to_domain(self) -> Result<User, AuthDomainError> {
+        let role = UserRole::from_str(&self.role)?;
         User::load_existing(
             &self.id.to_string(),
             &self.email,
@@ -66,6 +72,7 @@ impl UserDto {
             self.created_at.date(),
             self.updated_at.date(),
         )
+        .map(|user| user.with_role(role))
     }
 }
 
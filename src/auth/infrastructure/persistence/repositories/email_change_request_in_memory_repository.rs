@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::email_change_request::EmailChangeRequest, errors::AuthRepoError,
+        repositories::email_change_request_repository::IEmailChangeRequestRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct EmailChangeRequestInMemoryRepository {
+    requests: Arc<RwLock<Vec<EmailChangeRequest>>>,
+}
+
+#[async_trait]
+impl IEmailChangeRequestRepository for EmailChangeRequestInMemoryRepository {
+    async fn save(&self, request: &EmailChangeRequest) -> Result<(), AuthRepoError> {
+        self.requests.write().await.push(request.clone());
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailChangeRequest>, AuthRepoError> {
+        Ok(self
+            .requests
+            .read()
+            .await
+            .iter()
+            .find(|r| r.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn mark_used(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<bool, AuthRepoError> {
+        let mut requests = self.requests.write().await;
+        match requests
+            .iter_mut()
+            .find(|r| r.id == id && r.used_at.is_none())
+        {
+            Some(request) => {
+                request.used_at = Some(used_at);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn mark_all_used(
+        &self,
+        user_id: UserUuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        for request in self
+            .requests
+            .write()
+            .await
+            .iter_mut()
+            .filter(|r| r.user_id == user_id && r.used_at.is_none())
+        {
+            request.used_at = Some(used_at);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_email_change_request_repository_behavior(
+            Box::new(EmailChangeRequestInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::{email_change_request::EmailChangeRequest, user::UserEmail},
+        errors::AuthRepoError,
+        repositories::email_change_request_repository::IEmailChangeRequestRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct EmailChangeRequestRow {
+    id: Uuid,
+    user_id: Uuid,
+    new_email: String,
+    token_hash: String,
+    created_at: NaiveDateTime,
+    expires_at: NaiveDateTime,
+    used_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<EmailChangeRequestRow> for EmailChangeRequest {
+    type Error = AuthRepoError;
+
+    fn try_from(row: EmailChangeRequestRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            user_id: UserUuid::from_uuid(row.user_id),
+            new_email: UserEmail::new(&row.new_email)?,
+            token_hash: row.token_hash,
+            created_at: row.created_at.and_utc(),
+            expires_at: row.expires_at.and_utc(),
+            used_at: row.used_at.map(|at| at.and_utc()),
+        })
+    }
+}
+
+pub struct EmailChangeRequestPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl EmailChangeRequestPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IEmailChangeRequestRepository for EmailChangeRequestPostgresRepository {
+    async fn save(&self, request: &EmailChangeRequest) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "INSERT INTO email_change_requests (id, user_id, new_email, token_hash, created_at, expires_at) VALUES ($1, $2, $3, $4, $5, $6)",
+            request.id,
+            request.user_id.value(),
+            request.new_email.value(),
+            request.token_hash,
+            request.created_at.naive_utc(),
+            request.expires_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailChangeRequest>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            EmailChangeRequestRow,
+            "SELECT id, user_id, new_email, token_hash, created_at, expires_at, used_at FROM email_change_requests WHERE token_hash = $1",
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        row.map(EmailChangeRequest::try_from).transpose()
+    }
+
+    async fn mark_used(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<bool, AuthRepoError> {
+        let result = sqlx::query!(
+            "UPDATE email_change_requests SET used_at = $1 WHERE id = $2 AND used_at IS NULL",
+            used_at.naive_utc(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn mark_all_used(
+        &self,
+        user_id: UserUuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE email_change_requests SET used_at = $1 WHERE user_id = $2 AND used_at IS NULL",
+            used_at.naive_utc(),
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = EmailChangeRequestPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_email_change_request_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
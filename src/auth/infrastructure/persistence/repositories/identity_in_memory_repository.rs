@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::auth::domain::{
+    entities::identity::Identity, errors::AuthRepoError,
+    repositories::identity_repository::IIdentityRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct IdentityInMemoryRepository {
+    identities: Arc<RwLock<Vec<Identity>>>,
+}
+
+#[async_trait]
+impl IIdentityRepository for IdentityInMemoryRepository {
+    async fn save(&self, identity: &Identity) -> Result<(), AuthRepoError> {
+        let mut identities = self.identities.write().await;
+        if identities
+            .iter()
+            .any(|i| i.provider == identity.provider && i.subject == identity.subject)
+        {
+            return Err(AuthRepoError::IdentityAlreadyLinked(format!(
+                "{}:{}",
+                identity.provider, identity.subject
+            )));
+        }
+        identities.push(identity.clone());
+        Ok(())
+    }
+
+    async fn find(&self, provider: &str, subject: &str) -> Result<Option<Identity>, AuthRepoError> {
+        Ok(self
+            .identities
+            .read()
+            .await
+            .iter()
+            .find(|i| i.provider == provider && i.subject == subject)
+            .cloned())
+    }
+
+    async fn touch(&self, id: Uuid, logged_in_at: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        if let Some(identity) = self
+            .identities
+            .write()
+            .await
+            .iter_mut()
+            .find(|i| i.id == id)
+        {
+            identity.last_login_at = logged_in_at;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::domain::value_objects::UserUuid;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_identity_repository_behavior(
+            Box::new(IdentityInMemoryRepository::default()),
+            UserUuid::new(),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
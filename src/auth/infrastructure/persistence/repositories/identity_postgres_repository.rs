@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::identity::Identity, errors::AuthRepoError,
+        repositories::identity_repository::IIdentityRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct IdentityRow {
+    id: Uuid,
+    user_id: Uuid,
+    provider: String,
+    subject: String,
+    email: String,
+    created_at: NaiveDateTime,
+    last_login_at: NaiveDateTime,
+}
+
+impl From<IdentityRow> for Identity {
+    fn from(row: IdentityRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: UserUuid::from_uuid(row.user_id),
+            provider: row.provider,
+            subject: row.subject,
+            email: row.email,
+            created_at: row.created_at.and_utc(),
+            last_login_at: row.last_login_at.and_utc(),
+        }
+    }
+}
+
+pub struct IdentityPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl IdentityPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IIdentityRepository for IdentityPostgresRepository {
+    async fn save(&self, identity: &Identity) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "INSERT INTO identities (id, user_id, provider, subject, email, created_at, last_login_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            identity.id,
+            identity.user_id.value(),
+            identity.provider,
+            identity.subject,
+            identity.email,
+            identity.created_at.naive_utc(),
+            identity.last_login_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some("23505") => {
+                AuthRepoError::IdentityAlreadyLinked(format!(
+                    "{}:{}",
+                    identity.provider, identity.subject
+                ))
+            }
+            _ => AuthRepoError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find(&self, provider: &str, subject: &str) -> Result<Option<Identity>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            IdentityRow,
+            "SELECT id, user_id, provider, subject, email, created_at, last_login_at FROM identities WHERE provider = $1 AND subject = $2",
+            provider,
+            subject
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(Identity::from))
+    }
+
+    async fn touch(&self, id: Uuid, logged_in_at: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE identities SET last_login_at = $1 WHERE id = $2",
+            logged_in_at.naive_utc(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let other_user = factory.create_random_user().await;
+        let repository = IdentityPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_identity_repository_behavior(
+            Box::new(repository),
+            user.id,
+            other_user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
@@ -0,0 +1,58 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+
+use crate::{
+    auth::domain::{
+        entities::login_attempts::{FailureOutcome, LoginAttempts, LoginLockoutPolicy},
+        errors::AuthRepoError,
+        repositories::login_attempt_repository::ILoginAttemptRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct LoginAttemptInMemoryRepository {
+    attempts: Arc<RwLock<HashMap<UserUuid, LoginAttempts>>>,
+}
+
+#[async_trait]
+impl ILoginAttemptRepository for LoginAttemptInMemoryRepository {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<LoginAttempts>, AuthRepoError> {
+        Ok(self.attempts.read().await.get(&user_id).cloned())
+    }
+
+    async fn record_failure(
+        &self,
+        user_id: UserUuid,
+        policy: &LoginLockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<FailureOutcome, AuthRepoError> {
+        let mut attempts = self.attempts.write().await;
+        let entry = attempts
+            .entry(user_id)
+            .or_insert_with(|| LoginAttempts::new(user_id));
+        Ok(policy.register_failure(entry, now))
+    }
+
+    async fn reset(&self, user_id: UserUuid) -> Result<(), AuthRepoError> {
+        self.attempts.write().await.remove(&user_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_login_attempt_repository_behavior(
+            Box::new(LoginAttemptInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
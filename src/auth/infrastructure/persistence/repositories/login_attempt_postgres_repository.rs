@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::login_attempts::{FailureOutcome, LoginAttempts, LoginLockoutPolicy},
+        errors::AuthRepoError,
+        repositories::login_attempt_repository::ILoginAttemptRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct LoginAttemptsRow {
+    user_id: Uuid,
+    failed_count: i32,
+    last_failed_at: Option<NaiveDateTime>,
+    locked_until: Option<NaiveDateTime>,
+}
+
+impl From<LoginAttemptsRow> for LoginAttempts {
+    fn from(row: LoginAttemptsRow) -> Self {
+        Self {
+            user_id: UserUuid::from_uuid(row.user_id),
+            failed_count: u32::try_from(row.failed_count).unwrap_or(0),
+            last_failed_at: row.last_failed_at.map(|at| at.and_utc()),
+            locked_until: row.locked_until.map(|at| at.and_utc()),
+        }
+    }
+}
+
+pub struct LoginAttemptPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl LoginAttemptPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ILoginAttemptRepository for LoginAttemptPostgresRepository {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<LoginAttempts>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            LoginAttemptsRow,
+            "SELECT user_id, failed_count, last_failed_at, locked_until FROM login_attempts WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(LoginAttempts::from))
+    }
+
+    async fn record_failure(
+        &self,
+        user_id: UserUuid,
+        policy: &LoginLockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<FailureOutcome, AuthRepoError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        sqlx::query!(
+            "INSERT INTO login_attempts (user_id) VALUES ($1) ON CONFLICT (user_id) DO NOTHING",
+            user_id.value()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        // Serializes concurrent failures of the same user until commit.
+        let row = sqlx::query_as!(
+            LoginAttemptsRow,
+            "SELECT user_id, failed_count, last_failed_at, locked_until FROM login_attempts WHERE user_id = $1 FOR UPDATE",
+            user_id.value()
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        let mut attempts = LoginAttempts::from(row);
+        let outcome = policy.register_failure(&mut attempts, now);
+
+        sqlx::query!(
+            "UPDATE login_attempts SET failed_count = $2, last_failed_at = $3, locked_until = $4 WHERE user_id = $1",
+            user_id.value(),
+            i32::try_from(attempts.failed_count).unwrap_or(i32::MAX),
+            attempts.last_failed_at.map(|at| at.naive_utc()),
+            attempts.locked_until.map(|at| at.naive_utc()),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(outcome)
+    }
+
+    async fn reset(&self, user_id: UserUuid) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "DELETE FROM login_attempts WHERE user_id = $1",
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = LoginAttemptPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_login_attempt_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
@@ -1,6 +1,28 @@
 pub mod dtos;
+pub mod email_change_request_in_memory_repository;
+pub mod email_change_request_postgres_repository;
+pub mod identity_in_memory_repository;
+pub mod identity_postgres_repository;
+pub mod login_attempt_in_memory_repository;
+pub mod login_attempt_postgres_repository;
+pub mod oidc_login_state_in_memory_repository;
+pub mod oidc_login_state_postgres_repository;
+pub mod password_reset_token_in_memory_repository;
+pub mod password_reset_token_postgres_repository;
+pub mod personal_access_token_in_memory_repository;
+pub mod personal_access_token_postgres_repository;
+pub mod refresh_token_in_memory_repository;
+pub mod refresh_token_postgres_repository;
+pub mod session_in_memory_repository;
+pub mod session_postgres_repository;
+pub mod token_revocation_in_memory_repository;
+pub mod token_revocation_postgres_repository;
+pub mod two_factor_in_memory_repository;
+pub mod two_factor_postgres_repository;
 pub mod user_in_memory_repository;
 pub mod user_postgres_repository;
+pub mod verification_email_in_memory_repository;
+pub mod verification_email_postgres_repository;
 
 #[cfg(test)]
 pub mod common_repository_tests;
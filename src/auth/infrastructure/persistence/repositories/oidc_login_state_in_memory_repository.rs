@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+
+use crate::auth::domain::{
+    entities::oidc_login_state::OidcLoginState, errors::AuthRepoError,
+    repositories::oidc_login_state_repository::IOidcLoginStateRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct OidcLoginStateInMemoryRepository {
+    login_states: Arc<RwLock<Vec<OidcLoginState>>>,
+}
+
+#[async_trait]
+impl IOidcLoginStateRepository for OidcLoginStateInMemoryRepository {
+    async fn save(&self, login_state: &OidcLoginState) -> Result<(), AuthRepoError> {
+        self.login_states.write().await.push(login_state.clone());
+        Ok(())
+    }
+
+    async fn take(&self, state_hash: &str) -> Result<Option<OidcLoginState>, AuthRepoError> {
+        let mut login_states = self.login_states.write().await;
+        Ok(login_states
+            .iter()
+            .position(|s| s.state_hash == state_hash)
+            .map(|index| login_states.remove(index)))
+    }
+
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        self.login_states
+            .write()
+            .await
+            .retain(|s| !s.is_expired(now));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_oidc_login_state_repository_behavior(
+            Box::new(OidcLoginStateInMemoryRepository::default()),
+        )
+        .await;
+    }
+}
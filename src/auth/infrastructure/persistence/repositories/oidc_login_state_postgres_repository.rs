@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::auth::domain::{
+    entities::oidc_login_state::OidcLoginState, errors::AuthRepoError,
+    repositories::oidc_login_state_repository::IOidcLoginStateRepository,
+};
+
+struct OidcLoginStateRow {
+    state_hash: String,
+    provider: String,
+    code_verifier: String,
+    nonce: String,
+    expires_at: NaiveDateTime,
+}
+
+impl From<OidcLoginStateRow> for OidcLoginState {
+    fn from(row: OidcLoginStateRow) -> Self {
+        Self {
+            state_hash: row.state_hash,
+            provider: row.provider,
+            code_verifier: row.code_verifier,
+            nonce: row.nonce,
+            expires_at: row.expires_at.and_utc(),
+        }
+    }
+}
+
+pub struct OidcLoginStatePostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl OidcLoginStatePostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IOidcLoginStateRepository for OidcLoginStatePostgresRepository {
+    async fn save(&self, login_state: &OidcLoginState) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "INSERT INTO oidc_login_states (state_hash, provider, code_verifier, nonce, expires_at) VALUES ($1, $2, $3, $4, $5)",
+            login_state.state_hash,
+            login_state.provider,
+            login_state.code_verifier,
+            login_state.nonce,
+            login_state.expires_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn take(&self, state_hash: &str) -> Result<Option<OidcLoginState>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            OidcLoginStateRow,
+            "DELETE FROM oidc_login_states WHERE state_hash = $1 RETURNING state_hash, provider, code_verifier, nonce, expires_at",
+            state_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(OidcLoginState::from))
+    }
+
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "DELETE FROM oidc_login_states WHERE expires_at <= $1",
+            now.naive_utc()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let factory = TestFactory::new().await;
+        let repository = OidcLoginStatePostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_oidc_login_state_repository_behavior(
+            Box::new(repository),
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    auth::{
+        domain::{
+            entities::{
+                password_reset_token::PasswordResetToken,
+                user::User,
+                verification_email_throttle::{
+                    SendDecision, THROTTLE_WINDOW, VerificationEmailThrottle,
+                },
+            },
+            errors::AuthRepoError,
+            repositories::{
+                password_reset_token_repository::IPasswordResetTokenRepository,
+                user_repository::IUserRepository,
+            },
+        },
+        infrastructure::persistence::repositories::user_in_memory_repository::UserInMemoryRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+/// Keeps a handle on the users so a reset can save the new password.
+#[derive(Clone)]
+pub struct PasswordResetTokenInMemoryRepository {
+    tokens: Arc<RwLock<Vec<PasswordResetToken>>>,
+    users: UserInMemoryRepository,
+}
+
+impl PasswordResetTokenInMemoryRepository {
+    pub fn new(users: UserInMemoryRepository) -> Self {
+        Self {
+            tokens: Arc::default(),
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl IPasswordResetTokenRepository for PasswordResetTokenInMemoryRepository {
+    async fn save_if_allowed(
+        &self,
+        token: &PasswordResetToken,
+        throttle: &VerificationEmailThrottle,
+    ) -> Result<SendDecision, AuthRepoError> {
+        let mut tokens = self.tokens.write().await;
+        let previous_sends: Vec<_> = tokens
+            .iter()
+            .filter(|t| {
+                t.user_id == token.user_id && t.created_at > token.created_at - THROTTLE_WINDOW
+            })
+            .map(|t| t.created_at)
+            .collect();
+
+        let decision = throttle.check(&previous_sends, token.created_at);
+        if decision == SendDecision::Allowed {
+            tokens.push(token.clone());
+        }
+        Ok(decision)
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PasswordResetToken>, AuthRepoError> {
+        Ok(self
+            .tokens
+            .read()
+            .await
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn mark_all_used(
+        &self,
+        user_id: UserUuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        for token in self
+            .tokens
+            .write()
+            .await
+            .iter_mut()
+            .filter(|t| t.user_id == user_id && t.used_at.is_none())
+        {
+            token.used_at = Some(used_at);
+        }
+        Ok(())
+    }
+
+    async fn reset_password(
+        &self,
+        token_id: Uuid,
+        user: &User,
+        used_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError> {
+        let mut tokens = self.tokens.write().await;
+        if !tokens
+            .iter()
+            .any(|t| t.id == token_id && t.used_at.is_none())
+        {
+            return Ok(false);
+        }
+        self.users
+            .get(user.id)
+            .await?
+            .filter(|stored| !stored.account_disabled)
+            .ok_or(AuthRepoError::NotFound(user.id))?;
+        // Saved before the tokens are burned, so a failure leaves them usable.
+        self.users.update(user).await?;
+        for token in tokens
+            .iter_mut()
+            .filter(|t| t.user_id == user.id && t.used_at.is_none())
+        {
+            token.used_at = Some(used_at);
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let users = UserInMemoryRepository::default();
+        let user = User::new(
+            &UserUuid::new().to_string(),
+            "test@example.com",
+            "S0m3V3ryStr0ngP@ssw0rd!",
+        )
+        .unwrap();
+        users.save(&user).await.unwrap();
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_password_reset_token_repository_behavior(
+            Box::new(PasswordResetTokenInMemoryRepository::new(users.clone())),
+            Box::new(users),
+            user,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,221 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::{
+            password_reset_token::PasswordResetToken,
+            user::User,
+            verification_email_throttle::{
+                SendDecision, THROTTLE_WINDOW, VerificationEmailThrottle,
+            },
+        },
+        errors::AuthRepoError,
+        repositories::password_reset_token_repository::IPasswordResetTokenRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct PasswordResetTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    created_at: NaiveDateTime,
+    expires_at: NaiveDateTime,
+    used_at: Option<NaiveDateTime>,
+}
+
+impl From<PasswordResetTokenRow> for PasswordResetToken {
+    fn from(row: PasswordResetTokenRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: UserUuid::from_uuid(row.user_id),
+            token_hash: row.token_hash,
+            created_at: row.created_at.and_utc(),
+            expires_at: row.expires_at.and_utc(),
+            used_at: row.used_at.map(|at| at.and_utc()),
+        }
+    }
+}
+
+pub struct PasswordResetTokenPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl PasswordResetTokenPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IPasswordResetTokenRepository for PasswordResetTokenPostgresRepository {
+    async fn save_if_allowed(
+        &self,
+        token: &PasswordResetToken,
+        throttle: &VerificationEmailThrottle,
+    ) -> Result<SendDecision, AuthRepoError> {
+        let now = token.created_at;
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        // Serializes concurrent requests of the same user until commit.
+        sqlx::query!(
+            "SELECT id FROM users WHERE id = $1 FOR UPDATE",
+            token.user_id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        let previous_sends: Vec<DateTime<Utc>> = sqlx::query_scalar!(
+            "SELECT created_at FROM password_reset_tokens WHERE user_id = $1 AND created_at > $2",
+            token.user_id.value(),
+            (now - THROTTLE_WINDOW).naive_utc()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?
+        .into_iter()
+        .map(|created_at| created_at.and_utc())
+        .collect();
+
+        let decision = throttle.check(&previous_sends, now);
+        if decision == SendDecision::Allowed {
+            sqlx::query!(
+                "INSERT INTO password_reset_tokens (id, user_id, token_hash, created_at, expires_at) VALUES ($1, $2, $3, $4, $5)",
+                token.id,
+                token.user_id.value(),
+                token.token_hash,
+                token.created_at.naive_utc(),
+                token.expires_at.naive_utc(),
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(decision)
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PasswordResetToken>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            PasswordResetTokenRow,
+            "SELECT id, user_id, token_hash, created_at, expires_at, used_at FROM password_reset_tokens WHERE token_hash = $1",
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(PasswordResetToken::from))
+    }
+
+    async fn mark_all_used(
+        &self,
+        user_id: UserUuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE password_reset_tokens SET used_at = $1 WHERE user_id = $2 AND used_at IS NULL",
+            used_at.naive_utc(),
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn reset_password(
+        &self,
+        token_id: Uuid,
+        user: &User,
+        used_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        let used = sqlx::query!(
+            "UPDATE password_reset_tokens SET used_at = $1 WHERE id = $2 AND user_id = $3 AND used_at IS NULL",
+            used_at.naive_utc(),
+            token_id,
+            user.id.value()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+        if used.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        let updated = sqlx::query!(
+            "UPDATE users SET password = $1, updated_at = $2 WHERE id = $3 AND NOT account_disabled",
+            user.password().value(),
+            user.updated
+                .and_hms_opt(0, 0, 0)
+                .expect("Updated date should be valid")
+                .and_utc(),
+            user.id.value(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+        if updated.rows_affected() == 0 {
+            return Err(AuthRepoError::NotFound(user.id));
+        }
+
+        sqlx::query!(
+            "UPDATE password_reset_tokens SET used_at = $1 WHERE user_id = $2 AND used_at IS NULL",
+            used_at.naive_utc(),
+            user.id.value()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::infrastructure::persistence::repositories::user_postgres_repository::UserPostgresRepository;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = PasswordResetTokenPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_password_reset_token_repository_behavior(
+            Box::new(repository),
+            Box::new(UserPostgresRepository::new(factory.pool.clone()).await),
+            user,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
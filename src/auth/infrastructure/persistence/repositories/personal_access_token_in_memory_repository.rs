@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::personal_access_token::PersonalAccessToken, errors::AuthRepoError,
+        repositories::personal_access_token_repository::IPersonalAccessTokenRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct PersonalAccessTokenInMemoryRepository {
+    tokens: Arc<RwLock<Vec<PersonalAccessToken>>>,
+}
+
+#[async_trait]
+impl IPersonalAccessTokenRepository for PersonalAccessTokenInMemoryRepository {
+    async fn save(&self, token: &PersonalAccessToken) -> Result<(), AuthRepoError> {
+        self.tokens.write().await.push(token.clone());
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalAccessToken>, AuthRepoError> {
+        Ok(self
+            .tokens
+            .read()
+            .await
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Vec<PersonalAccessToken>, AuthRepoError> {
+        let mut tokens: Vec<_> = self
+            .tokens
+            .read()
+            .await
+            .iter()
+            .filter(|t| t.user_id == user_id && t.revoked_at.is_none())
+            .cloned()
+            .collect();
+        tokens.sort_by_key(|t| std::cmp::Reverse(t.created_at));
+        Ok(tokens)
+    }
+
+    async fn revoke(
+        &self,
+        user_id: UserUuid,
+        id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError> {
+        let mut tokens = self.tokens.write().await;
+        match tokens
+            .iter_mut()
+            .find(|t| t.id == id && t.user_id == user_id && t.revoked_at.is_none())
+        {
+            Some(token) => {
+                token.revoked_at = Some(revoked_at);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        if let Some(token) = self.tokens.write().await.iter_mut().find(|t| t.id == id) {
+            token.last_used_at = Some(used_at);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_personal_access_token_repository_behavior(
+            Box::new(PersonalAccessTokenInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::personal_access_token::PersonalAccessToken,
+        errors::{AuthDomainError, AuthRepoError},
+        repositories::personal_access_token_repository::IPersonalAccessTokenRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct PersonalAccessTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    scope: String,
+    token_hash: String,
+    created_at: NaiveDateTime,
+    expires_at: Option<NaiveDateTime>,
+    last_used_at: Option<NaiveDateTime>,
+    revoked_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<PersonalAccessTokenRow> for PersonalAccessToken {
+    type Error = AuthRepoError;
+
+    fn try_from(row: PersonalAccessTokenRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            user_id: UserUuid::from_uuid(row.user_id),
+            name: row.name,
+            scope: row.scope.parse().map_err(AuthDomainError::Shared)?,
+            token_hash: row.token_hash,
+            created_at: row.created_at.and_utc(),
+            expires_at: row.expires_at.map(|at| at.and_utc()),
+            last_used_at: row.last_used_at.map(|at| at.and_utc()),
+            revoked_at: row.revoked_at.map(|at| at.and_utc()),
+        })
+    }
+}
+
+pub struct PersonalAccessTokenPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl PersonalAccessTokenPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IPersonalAccessTokenRepository for PersonalAccessTokenPostgresRepository {
+    async fn save(&self, token: &PersonalAccessToken) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "INSERT INTO personal_access_tokens (id, user_id, name, scope, token_hash, created_at, expires_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            token.id,
+            token.user_id.value(),
+            token.name,
+            token.scope.as_str(),
+            token.token_hash,
+            token.created_at.naive_utc(),
+            token.expires_at.map(|at| at.naive_utc()),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalAccessToken>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            PersonalAccessTokenRow,
+            "SELECT id, user_id, name, scope, token_hash, created_at, expires_at, last_used_at, revoked_at FROM personal_access_tokens WHERE token_hash = $1",
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        row.map(PersonalAccessToken::try_from).transpose()
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Vec<PersonalAccessToken>, AuthRepoError> {
+        let rows = sqlx::query_as!(
+            PersonalAccessTokenRow,
+            "SELECT id, user_id, name, scope, token_hash, created_at, expires_at, last_used_at, revoked_at FROM personal_access_tokens WHERE user_id = $1 AND revoked_at IS NULL ORDER BY created_at DESC",
+            user_id.value()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(PersonalAccessToken::try_from)
+            .collect()
+    }
+
+    async fn revoke(
+        &self,
+        user_id: UserUuid,
+        id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError> {
+        let result = sqlx::query!(
+            "UPDATE personal_access_tokens SET revoked_at = $1 WHERE id = $2 AND user_id = $3 AND revoked_at IS NULL",
+            revoked_at.naive_utc(),
+            id,
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE personal_access_tokens SET last_used_at = $1 WHERE id = $2",
+            used_at.naive_utc(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = PersonalAccessTokenPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_personal_access_token_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
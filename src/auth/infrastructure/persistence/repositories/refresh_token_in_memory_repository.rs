@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::refresh_token::RefreshToken, errors::AuthRepoError,
+        repositories::refresh_token_repository::IRefreshTokenRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct RefreshTokenInMemoryRepository {
+    tokens: Arc<RwLock<Vec<RefreshToken>>>,
+}
+
+#[async_trait]
+impl IRefreshTokenRepository for RefreshTokenInMemoryRepository {
+    async fn save(&self, token: &RefreshToken) -> Result<(), AuthRepoError> {
+        self.tokens.write().await.push(token.clone());
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, AuthRepoError> {
+        Ok(self
+            .tokens
+            .read()
+            .await
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn mark_used(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<bool, AuthRepoError> {
+        let mut tokens = self.tokens.write().await;
+        match tokens
+            .iter_mut()
+            .find(|t| t.id == id && t.used_at.is_none() && t.revoked_at.is_none())
+        {
+            Some(token) => {
+                token.used_at = Some(used_at);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn revoke_family(
+        &self,
+        family_id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        for token in self
+            .tokens
+            .write()
+            .await
+            .iter_mut()
+            .filter(|t| t.family_id == family_id && t.revoked_at.is_none())
+        {
+            token.revoked_at = Some(revoked_at);
+        }
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserUuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        for token in self
+            .tokens
+            .write()
+            .await
+            .iter_mut()
+            .filter(|t| t.user_id == user_id && t.revoked_at.is_none())
+        {
+            token.revoked_at = Some(revoked_at);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_refresh_token_repository_behavior(
+            Box::new(RefreshTokenInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
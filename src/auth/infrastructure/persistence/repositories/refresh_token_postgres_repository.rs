@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::refresh_token::RefreshToken, errors::AuthRepoError,
+        repositories::refresh_token_repository::IRefreshTokenRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct RefreshTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    family_id: Uuid,
+    token_hash: String,
+    created_at: NaiveDateTime,
+    expires_at: NaiveDateTime,
+    used_at: Option<NaiveDateTime>,
+    revoked_at: Option<NaiveDateTime>,
+}
+
+impl From<RefreshTokenRow> for RefreshToken {
+    fn from(row: RefreshTokenRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: UserUuid::from_uuid(row.user_id),
+            family_id: row.family_id,
+            token_hash: row.token_hash,
+            created_at: row.created_at.and_utc(),
+            expires_at: row.expires_at.and_utc(),
+            used_at: row.used_at.map(|at| at.and_utc()),
+            revoked_at: row.revoked_at.map(|at| at.and_utc()),
+        }
+    }
+}
+
+pub struct RefreshTokenPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl RefreshTokenPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IRefreshTokenRepository for RefreshTokenPostgresRepository {
+    async fn save(&self, token: &RefreshToken) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "INSERT INTO refresh_tokens (id, user_id, family_id, token_hash, created_at, expires_at) VALUES ($1, $2, $3, $4, $5, $6)",
+            token.id,
+            token.user_id.value(),
+            token.family_id,
+            token.token_hash,
+            token.created_at.naive_utc(),
+            token.expires_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            RefreshTokenRow,
+            "SELECT id, user_id, family_id, token_hash, created_at, expires_at, used_at, revoked_at FROM refresh_tokens WHERE token_hash = $1",
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(RefreshToken::from))
+    }
+
+    async fn mark_used(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<bool, AuthRepoError> {
+        let result = sqlx::query!(
+            "UPDATE refresh_tokens SET used_at = $1 WHERE id = $2 AND used_at IS NULL AND revoked_at IS NULL",
+            used_at.naive_utc(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke_family(
+        &self,
+        family_id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE refresh_tokens SET revoked_at = $1 WHERE family_id = $2 AND revoked_at IS NULL",
+            revoked_at.naive_utc(),
+            family_id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserUuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE refresh_tokens SET revoked_at = $1 WHERE user_id = $2 AND revoked_at IS NULL",
+            revoked_at.naive_utc(),
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = RefreshTokenPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_refresh_token_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::session::{Session, SignInHistory},
+        errors::AuthRepoError,
+        repositories::session_repository::ISessionRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct SessionInMemoryRepository {
+    sessions: Arc<RwLock<Vec<Session>>>,
+}
+
+#[async_trait]
+impl ISessionRepository for SessionInMemoryRepository {
+    async fn save(&self, session: &Session) -> Result<(), AuthRepoError> {
+        self.sessions.write().await.push(session.clone());
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Session>, AuthRepoError> {
+        Ok(self
+            .sessions
+            .read()
+            .await
+            .iter()
+            .find(|s| s.id == id)
+            .cloned())
+    }
+
+    async fn list_active(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, AuthRepoError> {
+        let mut sessions: Vec<Session> = self
+            .sessions
+            .read()
+            .await
+            .iter()
+            .filter(|s| s.user_id == user_id && s.is_active(now))
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.last_seen_at));
+        Ok(sessions)
+    }
+
+    async fn sign_in_history(
+        &self,
+        user_id: UserUuid,
+        user_agent: Option<&str>,
+    ) -> Result<SignInHistory, AuthRepoError> {
+        let sessions = self.sessions.read().await;
+        let mut own = sessions.iter().filter(|s| s.user_id == user_id).peekable();
+        Ok(SignInHistory {
+            has_sessions: own.peek().is_some(),
+            knows_user_agent: own.any(|s| s.user_agent.as_deref() == user_agent),
+        })
+    }
+
+    async fn extend(
+        &self,
+        id: Uuid,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        if let Some(session) = self.sessions.write().await.iter_mut().find(|s| s.id == id) {
+            session.expires_at = expires_at;
+            session.last_seen_at = now;
+        }
+        Ok(())
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        if let Some(session) = self
+            .sessions
+            .write()
+            .await
+            .iter_mut()
+            .find(|s| s.id == id && s.last_seen_at < seen_at)
+        {
+            session.last_seen_at = seen_at;
+        }
+        Ok(())
+    }
+
+    async fn revoke(
+        &self,
+        user_id: UserUuid,
+        id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError> {
+        let mut sessions = self.sessions.write().await;
+        let Some(session) = sessions
+            .iter_mut()
+            .find(|s| s.id == id && s.user_id == user_id && s.revoked_at.is_none())
+        else {
+            return Ok(false);
+        };
+        session.revoked_at = Some(revoked_at);
+        Ok(true)
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserUuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        for session in self
+            .sessions
+            .write()
+            .await
+            .iter_mut()
+            .filter(|s| s.user_id == user_id && s.revoked_at.is_none())
+        {
+            session.revoked_at = Some(revoked_at);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_session_repository_behavior(
+            Box::new(SessionInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::session::{Session, SignInHistory},
+        errors::AuthRepoError,
+        repositories::session_repository::ISessionRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct SessionRow {
+    id: Uuid,
+    user_id: Uuid,
+    device: String,
+    user_agent: Option<String>,
+    ip: Option<String>,
+    created_at: NaiveDateTime,
+    last_seen_at: NaiveDateTime,
+    expires_at: NaiveDateTime,
+    revoked_at: Option<NaiveDateTime>,
+}
+
+impl From<SessionRow> for Session {
+    fn from(row: SessionRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: UserUuid::from_uuid(row.user_id),
+            device: row.device,
+            user_agent: row.user_agent,
+            ip: row.ip,
+            created_at: row.created_at.and_utc(),
+            last_seen_at: row.last_seen_at.and_utc(),
+            expires_at: row.expires_at.and_utc(),
+            revoked_at: row.revoked_at.map(|at| at.and_utc()),
+        }
+    }
+}
+
+pub struct SessionPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl SessionPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ISessionRepository for SessionPostgresRepository {
+    async fn save(&self, session: &Session) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "INSERT INTO sessions (id, user_id, device, user_agent, ip, created_at, last_seen_at, expires_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+            session.id,
+            session.user_id.value(),
+            session.device,
+            session.user_agent,
+            session.ip,
+            session.created_at.naive_utc(),
+            session.last_seen_at.naive_utc(),
+            session.expires_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Session>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            SessionRow,
+            "SELECT id, user_id, device, user_agent, ip, created_at, last_seen_at, expires_at, revoked_at FROM sessions WHERE id = $1",
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(Session::from))
+    }
+
+    async fn list_active(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, AuthRepoError> {
+        let rows = sqlx::query_as!(
+            SessionRow,
+            "SELECT id, user_id, device, user_agent, ip, created_at, last_seen_at, expires_at, revoked_at FROM sessions WHERE user_id = $1 AND revoked_at IS NULL AND expires_at > $2 ORDER BY last_seen_at DESC, id",
+            user_id.value(),
+            now.naive_utc()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Session::from).collect())
+    }
+
+    async fn sign_in_history(
+        &self,
+        user_id: UserUuid,
+        user_agent: Option<&str>,
+    ) -> Result<SignInHistory, AuthRepoError> {
+        let row = sqlx::query!(
+            r#"SELECT EXISTS (SELECT 1 FROM sessions WHERE user_id = $1) AS "has_sessions!", EXISTS (SELECT 1 FROM sessions WHERE user_id = $1 AND user_agent IS NOT DISTINCT FROM $2) AS "knows_user_agent!""#,
+            user_id.value(),
+            user_agent
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(SignInHistory {
+            has_sessions: row.has_sessions,
+            knows_user_agent: row.knows_user_agent,
+        })
+    }
+
+    async fn extend(
+        &self,
+        id: Uuid,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE sessions SET expires_at = $1, last_seen_at = $2 WHERE id = $3",
+            expires_at.naive_utc(),
+            now.naive_utc(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE sessions SET last_seen_at = $1 WHERE id = $2 AND last_seen_at < $1",
+            seen_at.naive_utc(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn revoke(
+        &self,
+        user_id: UserUuid,
+        id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, AuthRepoError> {
+        let result = sqlx::query!(
+            "UPDATE sessions SET revoked_at = $1 WHERE id = $2 AND user_id = $3 AND revoked_at IS NULL",
+            revoked_at.naive_utc(),
+            id,
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserUuid,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "UPDATE sessions SET revoked_at = $1 WHERE user_id = $2 AND revoked_at IS NULL",
+            revoked_at.naive_utc(),
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = SessionPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_session_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
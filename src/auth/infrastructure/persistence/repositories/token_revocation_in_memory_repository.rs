@@ -0,0 +1,92 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+
+use crate::{
+    auth::domain::{
+        entities::token_revocations::TokenRevocations, errors::AuthRepoError,
+        repositories::token_revocation_repository::ITokenRevocationRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone)]
+struct RevokedToken {
+    user_id: UserUuid,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Default)]
+pub struct TokenRevocationInMemoryRepository {
+    versions: Arc<RwLock<HashMap<UserUuid, i32>>>,
+    revoked: Arc<RwLock<HashMap<String, RevokedToken>>>,
+}
+
+#[async_trait]
+impl ITokenRevocationRepository for TokenRevocationInMemoryRepository {
+    async fn revoke_token(
+        &self,
+        jti: &str,
+        user_id: UserUuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        self.revoked.write().await.insert(
+            jti.to_string(),
+            RevokedToken {
+                user_id,
+                expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    async fn revoke_all_tokens(&self, user_id: UserUuid) -> Result<i32, AuthRepoError> {
+        let mut versions = self.versions.write().await;
+        let version = versions.entry(user_id).or_default();
+        *version += 1;
+        Ok(*version)
+    }
+
+    async fn get_revocations(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+    ) -> Result<TokenRevocations, AuthRepoError> {
+        let token_version = self
+            .versions
+            .read()
+            .await
+            .get(&user_id)
+            .copied()
+            .unwrap_or_default();
+        let revoked_jtis = self
+            .revoked
+            .read()
+            .await
+            .iter()
+            .filter(|(_, token)| token.user_id == user_id && token.expires_at > now)
+            .map(|(jti, _)| jti.clone())
+            .collect();
+
+        Ok(TokenRevocations {
+            token_version,
+            revoked_jtis,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_token_revocation_repository_behavior(
+            Box::new(TokenRevocationInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    auth::domain::{
+        entities::token_revocations::TokenRevocations, errors::AuthRepoError,
+        repositories::token_revocation_repository::ITokenRevocationRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+pub struct TokenRevocationPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl TokenRevocationPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ITokenRevocationRepository for TokenRevocationPostgresRepository {
+    async fn revoke_token(
+        &self,
+        jti: &str,
+        user_id: UserUuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "INSERT INTO revoked_tokens (jti, user_id, expires_at) VALUES ($1, $2, $3) ON CONFLICT (jti) DO NOTHING",
+            jti,
+            user_id.value(),
+            expires_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        // Expired tokens are rejected by their signature check already.
+        sqlx::query!(
+            "DELETE FROM revoked_tokens WHERE user_id = $1 AND expires_at <= $2",
+            user_id.value(),
+            Utc::now().naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_tokens(&self, user_id: UserUuid) -> Result<i32, AuthRepoError> {
+        sqlx::query_scalar!(
+            "UPDATE users SET token_version = token_version + 1 WHERE id = $1 RETURNING token_version",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?
+        .ok_or(AuthRepoError::NotFound(user_id))
+    }
+
+    async fn get_revocations(
+        &self,
+        user_id: UserUuid,
+        now: DateTime<Utc>,
+    ) -> Result<TokenRevocations, AuthRepoError> {
+        let token_version = sqlx::query_scalar!(
+            "SELECT token_version FROM users WHERE id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?
+        .unwrap_or_default();
+
+        let revoked_jtis = sqlx::query_scalar!(
+            "SELECT jti FROM revoked_tokens WHERE user_id = $1 AND expires_at > $2",
+            user_id.value(),
+            now.naive_utc()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?
+        .into_iter()
+        .collect();
+
+        Ok(TokenRevocations {
+            token_version,
+            revoked_jtis,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = TokenRevocationPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_token_revocation_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
@@ -0,0 +1,79 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{
+    auth::domain::{
+        entities::two_factor::TwoFactor, errors::AuthRepoError,
+        repositories::two_factor_repository::ITwoFactorRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct TwoFactorInMemoryRepository {
+    factors: Arc<RwLock<HashMap<UserUuid, TwoFactor>>>,
+}
+
+#[async_trait]
+impl ITwoFactorRepository for TwoFactorInMemoryRepository {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<TwoFactor>, AuthRepoError> {
+        Ok(self.factors.read().await.get(&user_id).cloned())
+    }
+
+    async fn save(&self, two_factor: &TwoFactor) -> Result<(), AuthRepoError> {
+        self.factors
+            .write()
+            .await
+            .insert(two_factor.user_id, two_factor.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: UserUuid) -> Result<(), AuthRepoError> {
+        self.factors.write().await.remove(&user_id);
+        Ok(())
+    }
+
+    async fn record_used_step(&self, user_id: UserUuid, step: i64) -> Result<bool, AuthRepoError> {
+        let mut factors = self.factors.write().await;
+        match factors
+            .get_mut(&user_id)
+            .filter(|f| f.last_used_step.is_none_or(|last| last < step))
+        {
+            Some(two_factor) => {
+                two_factor.last_used_step = Some(step);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn consume_recovery_code(
+        &self,
+        user_id: UserUuid,
+        code_hash: &str,
+    ) -> Result<bool, AuthRepoError> {
+        let mut factors = self.factors.write().await;
+        let Some(two_factor) = factors.get_mut(&user_id) else {
+            return Ok(false);
+        };
+        let before = two_factor.recovery_code_hashes.len();
+        two_factor.recovery_code_hashes.retain(|h| h != code_hash);
+        Ok(two_factor.recovery_code_hashes.len() < before)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_two_factor_repository_behavior(
+            Box::new(TwoFactorInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::{totp::TotpSecret, two_factor::TwoFactor},
+        errors::AuthRepoError,
+        repositories::two_factor_repository::ITwoFactorRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct TwoFactorRow {
+    user_id: Uuid,
+    secret: Vec<u8>,
+    enabled_at: Option<NaiveDateTime>,
+    last_used_step: Option<i64>,
+    recovery_code_hashes: Vec<String>,
+    created_at: NaiveDateTime,
+}
+
+impl From<TwoFactorRow> for TwoFactor {
+    fn from(row: TwoFactorRow) -> Self {
+        Self {
+            user_id: UserUuid::from_uuid(row.user_id),
+            secret: TotpSecret::from_bytes(row.secret),
+            enabled_at: row.enabled_at.map(|at| at.and_utc()),
+            last_used_step: row.last_used_step,
+            recovery_code_hashes: row.recovery_code_hashes,
+            created_at: row.created_at.and_utc(),
+        }
+    }
+}
+
+pub struct TwoFactorPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl TwoFactorPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ITwoFactorRepository for TwoFactorPostgresRepository {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<TwoFactor>, AuthRepoError> {
+        let row = sqlx::query_as!(
+            TwoFactorRow,
+            "SELECT user_id, secret, enabled_at, last_used_step, recovery_code_hashes, created_at FROM user_two_factor WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(TwoFactor::from))
+    }
+
+    async fn save(&self, two_factor: &TwoFactor) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO user_two_factor (user_id, secret, enabled_at, last_used_step, recovery_code_hashes, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (user_id) DO UPDATE SET
+                secret = EXCLUDED.secret,
+                enabled_at = EXCLUDED.enabled_at,
+                last_used_step = EXCLUDED.last_used_step,
+                recovery_code_hashes = EXCLUDED.recovery_code_hashes,
+                created_at = EXCLUDED.created_at
+            "#,
+            two_factor.user_id.value(),
+            two_factor.secret.as_bytes(),
+            two_factor.enabled_at.map(|at| at.naive_utc()),
+            two_factor.last_used_step,
+            &two_factor.recovery_code_hashes,
+            two_factor.created_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: UserUuid) -> Result<(), AuthRepoError> {
+        sqlx::query!(
+            "DELETE FROM user_two_factor WHERE user_id = $1",
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn record_used_step(&self, user_id: UserUuid, step: i64) -> Result<bool, AuthRepoError> {
+        let result = sqlx::query!(
+            "UPDATE user_two_factor SET last_used_step = $2 WHERE user_id = $1 AND (last_used_step IS NULL OR last_used_step < $2)",
+            user_id.value(),
+            step
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn consume_recovery_code(
+        &self,
+        user_id: UserUuid,
+        code_hash: &str,
+    ) -> Result<bool, AuthRepoError> {
+        let result = sqlx::query!(
+            "UPDATE user_two_factor SET recovery_code_hashes = array_remove(recovery_code_hashes, $2) WHERE user_id = $1 AND $2 = ANY(recovery_code_hashes)",
+            user_id.value(),
+            code_hash
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = TwoFactorPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_two_factor_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
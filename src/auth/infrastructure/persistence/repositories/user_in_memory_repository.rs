@@ -53,6 +53,14 @@ impl IUserRepository for UserInMemoryRepository {
     }
     async fn update(&self, user: &User) -> Result<(), AuthRepoError> {
         let mut users = self.users.write().await;
+        if users
+            .iter()
+            .any(|u| u.id != user.id && u.email == user.email)
+        {
+            return Err(AuthRepoError::UserAlreadyExists(
+                user.email.value().to_string(),
+            ));
+        }
         let position = users.iter().position(|u| u.id == user.id);
         match position {
             Some(idx) => {
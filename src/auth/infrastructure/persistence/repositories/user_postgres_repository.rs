@@ -26,12 +26,13 @@ impl UserPostgresRepository {
 impl IUserRepository for UserPostgresRepository {
     async fn save(&self, user: &User) -> Result<UserUuid, AuthRepoError> {
         sqlx::query!(
-            "INSERT INTO users (id, email, password, email_validated, account_disabled, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            "INSERT INTO users (id, email, password, email_validated, account_disabled, role, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
             user.id.value(),
             user.email.value(),
             user.password().value(),
             user.email_validated,
             user.account_disabled,
+            user.role.as_str(),
             user.created
                 .and_hms_opt(0, 0, 0)
                 .expect("Created date should be valid")
@@ -57,7 +58,9 @@ impl IUserRepository for UserPostgresRepository {
     }
     async fn update(&self, user: &User) -> Result<(), AuthRepoError> {
         let result = sqlx::query!(
-            "UPDATE users SET email_validated = $1, account_disabled = $2, updated_at = $3 WHERE id = $4",
+            "UPDATE users SET email = $1, password = $2, email_validated = $3, account_disabled = $4, updated_at = $5 WHERE id = $6",
+            user.email.value(),
+            user.password().value(),
             user.email_validated,
             user.account_disabled,
             user.updated
@@ -68,7 +71,12 @@ impl IUserRepository for UserPostgresRepository {
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some("23505") => {
+                AuthRepoError::UserAlreadyExists(user.email.value().to_string())
+            }
+            _ => AuthRepoError::DatabaseError(e.to_string()),
+        })?;
 
         if result.rows_affected() == 0 {
             return Err(AuthRepoError::NotFound(user.id));
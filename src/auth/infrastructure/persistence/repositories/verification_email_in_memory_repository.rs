@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+
+use crate::{
+    auth::domain::{
+        entities::verification_email_throttle::{
+            SendDecision, THROTTLE_WINDOW, VerificationEmailThrottle,
+        },
+        errors::AuthRepoError,
+        repositories::verification_email_repository::IVerificationEmailRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct VerificationEmailInMemoryRepository {
+    sends: Arc<Mutex<HashMap<UserUuid, Vec<DateTime<Utc>>>>>,
+}
+
+#[async_trait]
+impl IVerificationEmailRepository for VerificationEmailInMemoryRepository {
+    async fn record_send_if_allowed(
+        &self,
+        user_id: UserUuid,
+        throttle: &VerificationEmailThrottle,
+        now: DateTime<Utc>,
+    ) -> Result<SendDecision, AuthRepoError> {
+        let mut sends = self.sends.lock().await;
+        let user_sends = sends.entry(user_id).or_default();
+        user_sends.retain(|sent_at| *sent_at > now - THROTTLE_WINDOW);
+
+        let decision = throttle.check(user_sends, now);
+        if decision == SendDecision::Allowed {
+            user_sends.push(now);
+        }
+        Ok(decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_verification_email_repository_behavior(
+            Box::new(VerificationEmailInMemoryRepository::default()),
+            UserUuid::new(),
+        )
+        .await;
+    }
+}
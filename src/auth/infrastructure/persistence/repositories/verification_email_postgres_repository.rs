@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    auth::domain::{
+        entities::verification_email_throttle::{
+            SendDecision, THROTTLE_WINDOW, VerificationEmailThrottle,
+        },
+        errors::AuthRepoError,
+        repositories::verification_email_repository::IVerificationEmailRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+pub struct VerificationEmailPostgresRepository {
+    pool: sqlx::postgres::PgPool,
+}
+
+impl VerificationEmailPostgresRepository {
+    pub async fn new(pool: sqlx::postgres::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IVerificationEmailRepository for VerificationEmailPostgresRepository {
+    async fn record_send_if_allowed(
+        &self,
+        user_id: UserUuid,
+        throttle: &VerificationEmailThrottle,
+        now: DateTime<Utc>,
+    ) -> Result<SendDecision, AuthRepoError> {
+        let window_start = (now - THROTTLE_WINDOW).naive_utc();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        // Serializes concurrent requests of the same user until commit.
+        sqlx::query!(
+            "SELECT id FROM users WHERE id = $1 FOR UPDATE",
+            user_id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        sqlx::query!(
+            "DELETE FROM verification_email_sends WHERE user_id = $1 AND sent_at <= $2",
+            user_id.value(),
+            window_start
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        let previous_sends: Vec<DateTime<Utc>> = sqlx::query_scalar!(
+            "SELECT sent_at FROM verification_email_sends WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?
+        .into_iter()
+        .map(|sent_at| sent_at.and_utc())
+        .collect();
+
+        let decision = throttle.check(&previous_sends, now);
+        if decision == SendDecision::Allowed {
+            sqlx::query!(
+                "INSERT INTO verification_email_sends (id, user_id, sent_at) VALUES ($1, $2, $3)",
+                Uuid::new_v4(),
+                user_id.value(),
+                now.naive_utc()
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AuthRepoError::DatabaseError(e.to_string()))?;
+
+        Ok(decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_repository_contract() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = VerificationEmailPostgresRepository::new(factory.pool.clone()).await;
+
+        crate::auth::infrastructure::persistence::repositories::common_repository_tests::assert_verification_email_repository_behavior(
+            Box::new(repository),
+            user.id,
+        )
+        .await;
+
+        factory.teardown().await;
+    }
+}
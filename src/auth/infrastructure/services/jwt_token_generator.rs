@@ -1,7 +1,24 @@
-use crate::auth::application::{errors::AuthError, token_generator::ITokenGenerator};
+use crate::auth::application::{
+    errors::AuthError,
+    token_generator::{AccessToken, ITokenGenerator, PurposeClaims, PurposeToken, TokenPurpose},
+};
 use crate::shared::config::Config;
-use crate::shared::infrastructure::http::auth_extractor::{create_jwt, validate_token};
+use crate::shared::domain::value_objects::UserRole;
+use crate::shared::infrastructure::http::auth_extractor::create_jwt_with_ttl;
+use chrono::{DateTime, TimeDelta, Utc};
+use jsonwebtoken::errors::ErrorKind;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PurposeJwtClaims {
+    sub: String,
+    email: String,
+    aud: String,
+    exp: usize,
+    jti: String,
+}
 
 pub struct JwtTokenGenerator {
     config: Arc<Config>,
@@ -11,20 +28,192 @@ impl JwtTokenGenerator {
     pub fn new(config: Arc<Config>) -> Self {
         Self { config }
     }
+
+    fn ttl_secs(&self, purpose: TokenPurpose) -> i64 {
+        match purpose {
+            TokenPurpose::EmailVerification => self.config.email_verification_expiration_time,
+            TokenPurpose::PasswordReset => self.config.password_reset_expiration_time,
+            TokenPurpose::EmailChange => self.config.email_change_expiration_time,
+            TokenPurpose::MfaPending => self.config.mfa_token_expiration_time,
+        }
+    }
 }
 
 impl ITokenGenerator for JwtTokenGenerator {
-    fn generate_token(&self, user_id: &str, email: &str) -> Result<String, AuthError> {
-        create_jwt(user_id, email, &self.config)
-            .map_err(|e| AuthError::InternalError(e.to_string()))
+    fn generate_access_token(
+        &self,
+        user_id: &str,
+        email: &str,
+        token_version: i32,
+        role: UserRole,
+        session_id: Uuid,
+    ) -> Result<AccessToken, AuthError> {
+        let ttl = self.config.jwt_expiration_time;
+        let expires_at = Utc::now() + TimeDelta::seconds(ttl);
+        let token = create_jwt_with_ttl(
+            user_id,
+            email,
+            ttl,
+            token_version,
+            role,
+            Some(session_id),
+            &self.config,
+        )
+        .map_err(|e| AuthError::InternalError(e.to_string()))?;
+        Ok(AccessToken { token, expires_at })
     }
 
-    fn validate_token(&self, token: &str) -> Result<String, AuthError> {
-        validate_token(token, &self.config).map_err(|e| match e {
-            crate::shared::infrastructure::http::auth_extractor::AuthExtractorError::TokenExpired => {
-                AuthError::TokenExpired
-            }
-            _ => AuthError::InvalidToken,
+    fn generate_purpose_token(
+        &self,
+        purpose: TokenPurpose,
+        user_id: &str,
+        email: &str,
+    ) -> Result<PurposeToken, AuthError> {
+        let expires_at = Utc::now() + TimeDelta::seconds(self.ttl_secs(purpose));
+        let claims = PurposeJwtClaims {
+            sub: user_id.to_string(),
+            email: email.to_string(),
+            aud: purpose.audience().to_string(),
+            exp: expires_at.timestamp() as usize,
+            jti: Uuid::new_v4().to_string(),
+        };
+        let token = self
+            .config
+            .jwt_keys()
+            .encode(&claims)
+            .map_err(|e| AuthError::InternalError(e.to_string()))?;
+
+        Ok(PurposeToken {
+            token,
+            token_id: claims.jti,
+            expires_at: DateTime::from_timestamp(claims.exp as i64, 0).unwrap_or(expires_at),
+        })
+    }
+
+    fn validate_purpose_token(
+        &self,
+        purpose: TokenPurpose,
+        token: &str,
+    ) -> Result<PurposeClaims, AuthError> {
+        let claims = self
+            .config
+            .jwt_keys()
+            .decode::<PurposeJwtClaims>(token, purpose.audience())
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => AuthError::TokenExpired,
+                _ => AuthError::InvalidToken,
+            })?;
+
+        Ok(PurposeClaims {
+            user_id: claims.sub,
+            email: claims.email,
+            token_id: claims.jti,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::http::auth_extractor::{AuthExtractorError, decode_claims};
+
+    fn generator() -> JwtTokenGenerator {
+        JwtTokenGenerator::new(Arc::new(Config::test_default()))
+    }
+
+    #[test]
+    fn test_purpose_token_roundtrip() {
+        let generator = generator();
+
+        for purpose in TokenPurpose::ALL {
+            let minted = generator
+                .generate_purpose_token(purpose, "user-id", "user@example.com")
+                .unwrap();
+
+            let claims = generator
+                .validate_purpose_token(purpose, &minted.token)
+                .unwrap();
+
+            assert_eq!(claims.user_id, "user-id");
+            assert_eq!(claims.email, "user@example.com");
+            assert_eq!(claims.token_id, minted.token_id);
+        }
+    }
+
+    #[test]
+    fn test_each_purpose_rejects_tokens_minted_for_another() {
+        let generator = generator();
+
+        for minted_for in TokenPurpose::ALL {
+            let token = generator
+                .generate_purpose_token(minted_for, "user-id", "user@example.com")
+                .unwrap()
+                .token;
+
+            for other in TokenPurpose::ALL.into_iter().filter(|p| *p != minted_for) {
+                assert_eq!(
+                    generator.validate_purpose_token(other, &token),
+                    Err(AuthError::InvalidToken),
+                    "{:?} token accepted as {:?}",
+                    minted_for,
+                    other
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_access_and_purpose_tokens_are_not_interchangeable() {
+        let generator = generator();
+        let access = generator
+            .generate_access_token(
+                "user-id",
+                "user@example.com",
+                0,
+                UserRole::User,
+                Uuid::new_v4(),
+            )
+            .unwrap();
+
+        for purpose in TokenPurpose::ALL {
+            let token = generator
+                .generate_purpose_token(purpose, "user-id", "user@example.com")
+                .unwrap()
+                .token;
+
+            assert_eq!(
+                generator.validate_purpose_token(purpose, &access.token),
+                Err(AuthError::InvalidToken)
+            );
+            assert_eq!(
+                decode_claims(&token, &generator.config).unwrap_err(),
+                AuthExtractorError::InvalidToken
+            );
+        }
+    }
+
+    #[test]
+    fn test_each_purpose_has_its_own_lifetime() {
+        let mut config = Config::test_default();
+        config.email_verification_expiration_time = 60;
+        config.password_reset_expiration_time = -3600;
+        let generator = JwtTokenGenerator::new(Arc::new(config));
+
+        let verification = generator
+            .generate_purpose_token(TokenPurpose::EmailVerification, "user-id", "a@b.com")
+            .unwrap();
+        let reset = generator
+            .generate_purpose_token(TokenPurpose::PasswordReset, "user-id", "a@b.com")
+            .unwrap();
+
+        assert!(
+            generator
+                .validate_purpose_token(TokenPurpose::EmailVerification, &verification.token)
+                .is_ok()
+        );
+        assert_eq!(
+            generator.validate_purpose_token(TokenPurpose::PasswordReset, &reset.token),
+            Err(AuthError::TokenExpired)
+        );
+    }
+}
@@ -1,2 +1,5 @@
 pub mod jwt_token_generator;
+pub mod oidc_http_provider;
+#[cfg(test)]
+pub mod oidc_stand_in_provider;
 pub mod postgres_email_queue_enqueuer;
@@ -0,0 +1,425 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use jsonwebtoken::{
+    Algorithm, DecodingKey, Validation,
+    jwk::{Jwk, JwkSet},
+};
+use serde::Deserialize;
+use tokio::sync::{OnceCell, RwLock};
+use tracing::warn;
+
+use crate::auth::application::{
+    errors::AuthError,
+    oidc_provider::{IOidcProvider, OidcAuthorizationRequest, OidcCodeExchange, OidcUserInfo},
+};
+use crate::shared::config::OidcProviderConfig;
+
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+/// HMAC would need the client secret as key and `none` is unsigned.
+const ACCEPTED_ALGORITHMS: [Algorithm; 7] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+#[derive(Debug, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    /// Some providers send it as a string.
+    email_verified: Option<serde_json::Value>,
+}
+
+/// Provider found through OpenID Connect discovery. The client authenticates
+/// with `client_secret_post`; metadata is fetched once and the signing keys
+/// again whenever an ID token names an unknown one.
+pub struct OidcHttpProvider {
+    config: OidcProviderConfig,
+    http: reqwest::Client,
+    metadata: OnceCell<ProviderMetadata>,
+    keys: RwLock<JwkSet>,
+}
+
+impl OidcHttpProvider {
+    pub fn new(config: OidcProviderConfig) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            http,
+            metadata: OnceCell::new(),
+            keys: RwLock::new(JwkSet { keys: Vec::new() }),
+        }
+    }
+
+    async fn metadata(&self) -> Result<&ProviderMetadata, AuthError> {
+        self.metadata
+            .get_or_try_init(|| async {
+                let url = format!("{}/.well-known/openid-configuration", self.config.issuer);
+                let metadata: ProviderMetadata = self.get_json(&url).await?;
+                if metadata.issuer.trim_end_matches('/') != self.config.issuer {
+                    return Err(self.provider_error(format!(
+                        "discovery document is for issuer `{}`",
+                        metadata.issuer
+                    )));
+                }
+                Ok(metadata)
+            })
+            .await
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, AuthError> {
+        self.http
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| self.provider_error(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| self.provider_error(e.to_string()))
+    }
+
+    async fn signing_key(&self, kid: Option<&str>) -> Result<Jwk, AuthError> {
+        let find = |keys: &JwkSet| match kid {
+            Some(kid) => keys.find(kid).cloned(),
+            None if keys.keys.len() == 1 => keys.keys.first().cloned(),
+            None => None,
+        };
+        if let Some(key) = find(&*self.keys.read().await) {
+            return Ok(key);
+        }
+
+        let jwks_uri = &self.metadata().await?.jwks_uri;
+        let keys: JwkSet = self.get_json(jwks_uri).await?;
+        let key = find(&keys);
+        *self.keys.write().await = keys;
+        key.ok_or(AuthError::InvalidToken)
+    }
+
+    async fn validate_id_token(
+        &self,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<OidcUserInfo, AuthError> {
+        let header = jsonwebtoken::decode_header(id_token).map_err(|_| AuthError::InvalidToken)?;
+        if !ACCEPTED_ALGORITHMS.contains(&header.alg) {
+            return Err(AuthError::InvalidToken);
+        }
+        let jwk = self.signing_key(header.kid.as_deref()).await?;
+        let key = DecodingKey::from_jwk(&jwk).map_err(|_| AuthError::InvalidToken)?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.config.issuer, &self.metadata().await?.issuer]);
+        validation.set_audience(&[&self.config.client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        let claims = jsonwebtoken::decode::<IdTokenClaims>(id_token, &key, &validation)
+            .map_err(|e| {
+                warn!(
+                    error_kind = "invalid_id_token",
+                    provider = %self.config.name,
+                    error = %e,
+                    "oidc_http_provider.validate_id_token failed"
+                );
+                AuthError::InvalidToken
+            })?
+            .claims;
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(AuthError::InvalidToken);
+        }
+
+        Ok(OidcUserInfo {
+            subject: claims.sub,
+            email: claims.email,
+            email_verified: match claims.email_verified {
+                Some(serde_json::Value::Bool(verified)) => verified,
+                Some(serde_json::Value::String(verified)) => verified == "true",
+                _ => false,
+            },
+        })
+    }
+
+    fn provider_error(&self, reason: String) -> AuthError {
+        warn!(
+            error_kind = "oidc_provider_error",
+            provider = %self.config.name,
+            error = %reason,
+            "oidc_http_provider request failed"
+        );
+        AuthError::OidcProviderError(format!("{}: {}", self.config.name, reason))
+    }
+}
+
+#[async_trait]
+impl IOidcProvider for OidcHttpProvider {
+    async fn authorization_url(
+        &self,
+        request: &OidcAuthorizationRequest,
+    ) -> Result<String, AuthError> {
+        let metadata = self.metadata().await?;
+        reqwest::Url::parse_with_params(
+            &metadata.authorization_endpoint,
+            [
+                ("response_type", "code"),
+                ("client_id", &self.config.client_id),
+                ("redirect_uri", &request.redirect_uri),
+                ("scope", &self.config.scopes),
+                ("state", &request.state),
+                ("nonce", &request.nonce),
+                ("code_challenge", &request.code_challenge),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map(String::from)
+        .map_err(|e| self.provider_error(e.to_string()))
+    }
+
+    async fn exchange_code(&self, exchange: &OidcCodeExchange) -> Result<OidcUserInfo, AuthError> {
+        let metadata = self.metadata().await?;
+        let response = self
+            .http
+            .post(&metadata.token_endpoint)
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", &exchange.code),
+                ("redirect_uri", &exchange.redirect_uri),
+                ("code_verifier", &exchange.code_verifier),
+                ("client_id", &self.config.client_id),
+                ("client_secret", &self.config.client_secret),
+            ])
+            .send()
+            .await
+            .map_err(|e| self.provider_error(e.to_string()))?;
+        // A used, expired or foreign code is the client's fault, not the
+        // provider's.
+        if response.status().is_client_error() {
+            warn!(
+                error_kind = "oidc_code_rejected",
+                provider = %self.config.name,
+                status = %response.status(),
+                "oidc_http_provider.exchange_code failed"
+            );
+            return Err(AuthError::InvalidToken);
+        }
+        let tokens: TokenResponse = response
+            .error_for_status()
+            .map_err(|e| self.provider_error(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| self.provider_error(e.to_string()))?;
+
+        self.validate_id_token(&tokens.id_token, &exchange.nonce)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::infrastructure::services::oidc_stand_in_provider::{
+        StandInOidcProvider, StandInUser,
+    };
+    use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+    use sha2::{Digest, Sha256};
+
+    const REDIRECT_URI: &str = "http://localhost:3001/auth/oidc/stand-in/callback";
+    const VERIFIER: &str = "a-code-verifier-long-enough-to-follow-rfc-7636";
+
+    fn authorization_request() -> OidcAuthorizationRequest {
+        OidcAuthorizationRequest {
+            state: "state".to_string(),
+            nonce: "nonce".to_string(),
+            code_challenge: URL_SAFE_NO_PAD.encode(Sha256::digest(VERIFIER)),
+            redirect_uri: REDIRECT_URI.to_string(),
+        }
+    }
+
+    fn exchange(code: String) -> OidcCodeExchange {
+        OidcCodeExchange {
+            code,
+            code_verifier: VERIFIER.to_string(),
+            redirect_uri: REDIRECT_URI.to_string(),
+            nonce: "nonce".to_string(),
+        }
+    }
+
+    async fn authorize(
+        stand_in: &StandInOidcProvider,
+        provider: &OidcHttpProvider,
+        user: StandInUser,
+    ) -> String {
+        let url = provider
+            .authorization_url(&authorization_request())
+            .await
+            .unwrap();
+        let (code, state) = stand_in.authorize(&url, user);
+        assert_eq!(state, "state");
+        code
+    }
+
+    #[tokio::test]
+    async fn test_code_exchange_returns_the_validated_identity() {
+        let stand_in = StandInOidcProvider::start().await;
+        let provider = OidcHttpProvider::new(stand_in.config());
+
+        let code = authorize(
+            &stand_in,
+            &provider,
+            StandInUser::verified("sub-1", "a@b.com"),
+        )
+        .await;
+        let user_info = provider.exchange_code(&exchange(code)).await.unwrap();
+
+        assert_eq!(
+            user_info,
+            OidcUserInfo {
+                subject: "sub-1".to_string(),
+                email: Some("a@b.com".to_string()),
+                email_verified: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_codes_are_single_use_and_bound_to_the_pkce_verifier() {
+        let stand_in = StandInOidcProvider::start().await;
+        let provider = OidcHttpProvider::new(stand_in.config());
+
+        let code = authorize(
+            &stand_in,
+            &provider,
+            StandInUser::verified("sub", "a@b.com"),
+        )
+        .await;
+        let mut wrong_verifier = exchange(code.clone());
+        wrong_verifier.code_verifier = "another-verifier-that-is-long-enough-too".to_string();
+        assert_eq!(
+            provider.exchange_code(&wrong_verifier).await,
+            Err(AuthError::InvalidToken)
+        );
+
+        let code = authorize(
+            &stand_in,
+            &provider,
+            StandInUser::verified("sub", "a@b.com"),
+        )
+        .await;
+        assert!(
+            provider
+                .exchange_code(&exchange(code.clone()))
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            provider.exchange_code(&exchange(code)).await,
+            Err(AuthError::InvalidToken)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_id_tokens_must_carry_the_nonce_and_a_trusted_signature() {
+        let stand_in = StandInOidcProvider::start().await;
+        let provider = OidcHttpProvider::new(stand_in.config());
+
+        let code = authorize(
+            &stand_in,
+            &provider,
+            StandInUser::verified("sub", "a@b.com"),
+        )
+        .await;
+        let mut other_nonce = exchange(code);
+        other_nonce.nonce = "other".to_string();
+        assert_eq!(
+            provider.exchange_code(&other_nonce).await,
+            Err(AuthError::InvalidToken)
+        );
+
+        stand_in.sign_with_unpublished_key();
+        let code = authorize(
+            &stand_in,
+            &provider,
+            StandInUser::verified("sub", "a@b.com"),
+        )
+        .await;
+        assert_eq!(
+            provider.exchange_code(&exchange(code)).await,
+            Err(AuthError::InvalidToken)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_id_tokens_for_another_client_are_rejected() {
+        let stand_in = StandInOidcProvider::start().await;
+        let provider = OidcHttpProvider::new(stand_in.config());
+        stand_in.issue_tokens_for_audience("another-client");
+
+        let code = authorize(
+            &stand_in,
+            &provider,
+            StandInUser::verified("sub", "a@b.com"),
+        )
+        .await;
+
+        assert_eq!(
+            provider.exchange_code(&exchange(code)).await,
+            Err(AuthError::InvalidToken)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_email_verified_may_be_a_string() {
+        let stand_in = StandInOidcProvider::start().await;
+        let provider = OidcHttpProvider::new(stand_in.config());
+        let mut user = StandInUser::verified("sub", "a@b.com");
+        user.email_verified = serde_json::json!("true");
+
+        let code = authorize(&stand_in, &provider, user).await;
+
+        assert!(
+            provider
+                .exchange_code(&exchange(code))
+                .await
+                .unwrap()
+                .email_verified
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_or_mismatched_issuers_are_provider_errors() {
+        let stand_in = StandInOidcProvider::start().await;
+        let mut config = stand_in.config();
+        config.issuer = format!("{}/tenant", config.issuer);
+        let mismatched = OidcHttpProvider::new(config);
+        let mut config = stand_in.config();
+        config.issuer = "http://127.0.0.1:1".to_string();
+        let unreachable = OidcHttpProvider::new(config);
+
+        for provider in [mismatched, unreachable] {
+            assert!(matches!(
+                provider.authorization_url(&authorization_request()).await,
+                Err(AuthError::OidcProviderError(_))
+            ));
+        }
+    }
+}
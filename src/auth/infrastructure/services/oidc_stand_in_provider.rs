@@ -0,0 +1,234 @@
+//! Local OpenID Connect provider for tests: discovery, JWKS and a token
+//! endpoint that checks PKCE, on a random port. The browser step is
+//! simulated by [`StandInOidcProvider::authorize`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    Form, Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{TimeDelta, Utc};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::shared::config::OidcProviderConfig;
+use crate::shared::infrastructure::jwt_keys::JwtKeys;
+
+const RSA_PEM: &str = include_str!("../../../shared/infrastructure/testdata/jwt_rs256.pem");
+pub const CLIENT_ID: &str = "seeker";
+const CLIENT_SECRET: &str = "stand-in-secret";
+
+#[derive(Debug, Clone)]
+pub struct StandInUser {
+    pub subject: String,
+    pub email: String,
+    pub email_verified: serde_json::Value,
+}
+
+impl StandInUser {
+    pub fn verified(subject: &str, email: &str) -> Self {
+        Self {
+            subject: subject.to_string(),
+            email: email.to_string(),
+            email_verified: serde_json::json!(true),
+        }
+    }
+
+    pub fn unverified(subject: &str, email: &str) -> Self {
+        Self {
+            email_verified: serde_json::json!(false),
+            ..Self::verified(subject, email)
+        }
+    }
+}
+
+struct PendingCode {
+    user: StandInUser,
+    redirect_uri: String,
+    code_challenge: String,
+    nonce: String,
+}
+
+struct StandInState {
+    issuer: String,
+    keys: JwtKeys,
+    /// Signs with the same `kid` as `keys`, but is not published.
+    unpublished_keys: JwtKeys,
+    sign_with_unpublished_key: bool,
+    audience: String,
+    codes: HashMap<String, PendingCode>,
+}
+
+#[derive(Clone)]
+pub struct StandInOidcProvider {
+    state: Arc<Mutex<StandInState>>,
+}
+
+impl StandInOidcProvider {
+    pub async fn start() -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Should bind the stand-in provider");
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let unpublished_pem = ed25519_dalek::pkcs8::EncodePrivateKey::to_pkcs8_pem(
+            &ed25519_dalek::SigningKey::from_bytes(&rand::random()),
+            Default::default(),
+        )
+        .unwrap();
+        let provider = Self {
+            state: Arc::new(Mutex::new(StandInState {
+                issuer,
+                keys: JwtKeys::new("stand-in", RSA_PEM, Vec::new(), TimeDelta::zero()).unwrap(),
+                unpublished_keys: JwtKeys::new(
+                    "stand-in",
+                    &unpublished_pem,
+                    Vec::new(),
+                    TimeDelta::zero(),
+                )
+                .unwrap(),
+                sign_with_unpublished_key: false,
+                audience: CLIENT_ID.to_string(),
+                codes: HashMap::new(),
+            })),
+        };
+
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/{tenant}/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/token", post(token))
+            .with_state(provider.state.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        provider
+    }
+
+    pub fn config(&self) -> OidcProviderConfig {
+        OidcProviderConfig {
+            name: "stand-in".to_string(),
+            issuer: self.state.lock().unwrap().issuer.clone(),
+            client_id: CLIENT_ID.to_string(),
+            client_secret: CLIENT_SECRET.to_string(),
+            scopes: "openid email".to_string(),
+        }
+    }
+
+    /// What the provider does when the browser arrives with the
+    /// authorization URL and the user consents: returns the `code` and
+    /// `state` it redirects back with.
+    pub fn authorize(&self, authorization_url: &str, user: StandInUser) -> (String, String) {
+        let url = reqwest::Url::parse(authorization_url).unwrap();
+        let params: HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert_eq!(params["response_type"], "code");
+        assert_eq!(params["client_id"], CLIENT_ID);
+        assert_eq!(params["code_challenge_method"], "S256");
+        assert!(params["scope"].split(' ').any(|scope| scope == "openid"));
+
+        let code = Uuid::new_v4().to_string();
+        self.state.lock().unwrap().codes.insert(
+            code.clone(),
+            PendingCode {
+                user,
+                redirect_uri: params["redirect_uri"].clone(),
+                code_challenge: params["code_challenge"].clone(),
+                nonce: params["nonce"].clone(),
+            },
+        );
+        (code, params["state"].clone())
+    }
+
+    pub fn sign_with_unpublished_key(&self) {
+        self.state.lock().unwrap().sign_with_unpublished_key = true;
+    }
+
+    pub fn issue_tokens_for_audience(&self, audience: &str) {
+        self.state.lock().unwrap().audience = audience.to_string();
+    }
+}
+
+async fn discovery(State(state): State<Arc<Mutex<StandInState>>>) -> Json<serde_json::Value> {
+    let issuer = state.lock().unwrap().issuer.clone();
+    Json(serde_json::json!({
+        "issuer": issuer,
+        "authorization_endpoint": format!("{issuer}/authorize"),
+        "token_endpoint": format!("{issuer}/token"),
+        "jwks_uri": format!("{issuer}/jwks"),
+        "response_types_supported": ["code"],
+        "subject_types_supported": ["public"],
+        "id_token_signing_alg_values_supported": ["RS256"],
+        "code_challenge_methods_supported": ["S256"],
+    }))
+}
+
+async fn jwks(State(state): State<Arc<Mutex<StandInState>>>) -> Json<serde_json::Value> {
+    let jwks = state.lock().unwrap().keys.jwks(Utc::now());
+    Json(serde_json::to_value(jwks).unwrap())
+}
+
+#[derive(Deserialize)]
+struct TokenRequest {
+    grant_type: String,
+    code: String,
+    redirect_uri: String,
+    code_verifier: String,
+    client_id: String,
+    client_secret: String,
+}
+
+async fn token(
+    State(state): State<Arc<Mutex<StandInState>>>,
+    Form(request): Form<TokenRequest>,
+) -> Response {
+    let invalid_grant = || {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": "invalid_grant" })),
+        )
+            .into_response()
+    };
+    let mut state = state.lock().unwrap();
+    if request.grant_type != "authorization_code"
+        || request.client_id != CLIENT_ID
+        || request.client_secret != CLIENT_SECRET
+    {
+        return invalid_grant();
+    }
+    let Some(pending) = state.codes.remove(&request.code) else {
+        return invalid_grant();
+    };
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(request.code_verifier.as_bytes()));
+    if pending.redirect_uri != request.redirect_uri || pending.code_challenge != challenge {
+        return invalid_grant();
+    }
+
+    let now = Utc::now().timestamp();
+    let claims = serde_json::json!({
+        "iss": state.issuer,
+        "aud": state.audience,
+        "sub": pending.user.subject,
+        "email": pending.user.email,
+        "email_verified": pending.user.email_verified,
+        "nonce": pending.nonce,
+        "iat": now,
+        "exp": now + 300,
+    });
+    let keys = if state.sign_with_unpublished_key {
+        &state.unpublished_keys
+    } else {
+        &state.keys
+    };
+    let id_token = keys.encode(&claims).unwrap();
+    Json(serde_json::json!({
+        "access_token": "stand-in-access-token",
+        "token_type": "Bearer",
+        "expires_in": 300,
+        "id_token": id_token,
+    }))
+    .into_response()
+}
@@ -1,6 +1,13 @@
 use async_trait::async_trait;
+use serde_json::json;
 
-use crate::auth::application::{email_queue_enqueuer::IEmailQueueEnqueuer, errors::AuthError};
+use crate::{
+    auth::{
+        application::{email_queue_enqueuer::IEmailQueueEnqueuer, errors::AuthError},
+        domain::entities::auth_email::AuthEmail,
+    },
+    shared::infrastructure::email_queue::email_queue_payload,
+};
 
 pub struct PostgresEmailQueueEnqueuer {
     pool: sqlx::postgres::PgPool,
@@ -12,27 +19,52 @@ impl PostgresEmailQueueEnqueuer {
     }
 }
 
+fn template_variables(email: &AuthEmail) -> serde_json::Value {
+    match email {
+        AuthEmail::VerifyEmail { link }
+        | AuthEmail::ResetPassword { link }
+        | AuthEmail::ConfirmEmailChange { link } => json!({ "link": link }),
+        AuthEmail::EmailChangeRequested { new_email, .. } => json!({ "new_email": new_email }),
+        AuthEmail::TwoFactorEnabled { .. } | AuthEmail::TwoFactorDisabled { .. } => json!({}),
+        AuthEmail::SignInsBlocked { until } => json!({ "until": until.to_rfc3339() }),
+        AuthEmail::PersonalAccessTokenCreated { name, scope, .. } => {
+            json!({ "name": name, "scope": scope.to_string() })
+        }
+        AuthEmail::NewSignIn {
+            device,
+            ip,
+            signed_in_at,
+        } => json!({
+            "device": device,
+            "ip": ip,
+            "signed_in_at": signed_in_at.to_rfc3339()
+        }),
+    }
+}
+
 #[async_trait]
 impl IEmailQueueEnqueuer for PostgresEmailQueueEnqueuer {
     async fn enqueue(
         &self,
         to: &str,
-        subject: &str,
-        body: &str,
+        email: &AuthEmail,
         user_id: uuid::Uuid,
         trace_context: Option<String>,
     ) -> Result<(), AuthError> {
-        let payload = serde_json::json!({
-            "to": to,
-            "subject": subject,
-            "body": body
-        });
+        let payload = email_queue_payload(
+            to,
+            AuthEmail::CATEGORY,
+            email.template(),
+            template_variables(email),
+        );
+        let idempotency_key = email.idempotency_key(user_id);
 
         sqlx::query!(
-            "INSERT INTO email_queue (payload, user_id, trace_id) VALUES ($1, $2, $3)",
+            "INSERT INTO email_queue (payload, user_id, trace_id, idempotency_key) VALUES ($1, $2, $3, $4) ON CONFLICT (idempotency_key) DO NOTHING",
             payload,
             user_id,
-            trace_context
+            trace_context,
+            idempotency_key.as_str()
         )
         .execute(&self.pool)
         .await
@@ -41,3 +73,78 @@ impl IEmailQueueEnqueuer for PostgresEmailQueueEnqueuer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_the_same_email_is_queued_once() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let enqueuer = PostgresEmailQueueEnqueuer::new(factory.pool.clone());
+        let email = AuthEmail::VerifyEmail {
+            link: format!("https://seeker.example/verify?token={}", user.id),
+        };
+
+        let enabled = AuthEmail::TwoFactorEnabled {
+            enabled_at: chrono::Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap(),
+        };
+
+        for email in [&email, &email, &enabled, &enabled] {
+            enqueuer
+                .enqueue(user.email.value(), email, user.id.value(), None)
+                .await
+                .unwrap();
+        }
+
+        let queued = sqlx::query_scalar!(
+            "SELECT payload->>'template' FROM email_queue WHERE user_id = $1 ORDER BY created_at",
+            user.id.value()
+        )
+        .fetch_all(&factory.pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            queued,
+            vec![
+                Some("verify_email".to_string()),
+                Some("two_factor_enabled".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payload_names_the_template_and_its_variables() {
+        let email = AuthEmail::NewSignIn {
+            device: "Firefox on Linux".to_string(),
+            ip: None,
+            signed_in_at: chrono::Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap(),
+        };
+
+        let payload = email_queue_payload(
+            "user@example.com",
+            AuthEmail::CATEGORY,
+            email.template(),
+            template_variables(&email),
+        );
+
+        assert_eq!(
+            payload,
+            json!({
+                "to": "user@example.com",
+                "locale": "en",
+                "category": "security",
+                "template": "new_sign_in",
+                "variables": {
+                    "device": "Firefox on Linux",
+                    "ip": null,
+                    "signed_in_at": "2026-03-01T09:30:00+00:00"
+                }
+            })
+        );
+    }
+}
@@ -1,11 +1,237 @@
 use utoipa::ToSchema;
 
+use crate::auth::application::auth_service::{
+    LoginOutcome, LoginResponse, MfaChallenge, MfaEnrollment,
+};
+use crate::auth::domain::entities::personal_access_token::PersonalAccessToken;
+use crate::auth::domain::entities::session::Session;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct SuccesfullLoginDto {
     pub access_token: String,
+    /// RFC 3339 timestamp
+    pub access_token_expires_at: String,
+    pub refresh_token: String,
+    /// RFC 3339 timestamp
+    pub refresh_token_expires_at: String,
     pub email_validated: bool,
 }
 
+impl From<LoginResponse> for SuccesfullLoginDto {
+    fn from(response: LoginResponse) -> Self {
+        Self {
+            access_token: response.token,
+            access_token_expires_at: response.token_expires_at.to_rfc3339(),
+            refresh_token: response.refresh_token,
+            refresh_token_expires_at: response.refresh_token_expires_at.to_rfc3339(),
+            email_validated: response.is_email_verified,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MfaRequiredDto {
+    /// Always `true`, tells this response apart from a session
+    pub mfa_required: bool,
+    /// Send it to `/auth/login/mfa` together with a code
+    pub mfa_token: String,
+    /// RFC 3339 timestamp
+    pub mfa_token_expires_at: String,
+}
+
+impl From<MfaChallenge> for MfaRequiredDto {
+    fn from(challenge: MfaChallenge) -> Self {
+        Self {
+            mfa_required: true,
+            mfa_token: challenge.mfa_token,
+            mfa_token_expires_at: challenge.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Either a session or, for users with two-factor authentication, the
+/// challenge to finish the login with.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum LoginResultDto {
+    Session(SuccesfullLoginDto),
+    MfaRequired(MfaRequiredDto),
+}
+
+impl From<LoginOutcome> for LoginResultDto {
+    fn from(outcome: LoginOutcome) -> Self {
+        match outcome {
+            LoginOutcome::Authenticated(response) => Self::Session(response.into()),
+            LoginOutcome::MfaRequired(challenge) => Self::MfaRequired(challenge.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MfaLoginDto {
+    pub mfa_token: String,
+    /// Authenticator code or one of the recovery codes
+    pub code: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MfaEnrollDto {
+    /// Current password of the account
+    pub password: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MfaEnrollmentDto {
+    /// Base32 secret, for apps that cannot scan the URI
+    pub secret: String,
+    /// `otpauth://` URI to show as a QR code
+    pub otpauth_uri: String,
+}
+
+impl From<MfaEnrollment> for MfaEnrollmentDto {
+    fn from(enrollment: MfaEnrollment) -> Self {
+        Self {
+            secret: enrollment.secret,
+            otpauth_uri: enrollment.otpauth_uri,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MfaCodeDto {
+    /// Code currently shown by the authenticator app
+    pub code: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RecoveryCodesDto {
+    /// Each code works once in place of an authenticator code. They are not
+    /// shown again.
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MfaDisableDto {
+    pub password: String,
+    /// Authenticator code or one of the recovery codes
+    pub code: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CreatePersonalAccessTokenDto {
+    pub name: String,
+    /// `read-only` or `positions-write`
+    pub scope: String,
+    /// Omit for a token that works until revoked, at most 365
+    pub expires_in_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PersonalAccessTokenDto {
+    pub id: String,
+    pub name: String,
+    pub scope: String,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+    /// RFC 3339 timestamp, `null` for tokens that do not expire
+    pub expires_at: Option<String>,
+    /// RFC 3339 timestamp, precise to the minute
+    pub last_used_at: Option<String>,
+}
+
+impl From<PersonalAccessToken> for PersonalAccessTokenDto {
+    fn from(token: PersonalAccessToken) -> Self {
+        Self {
+            id: token.id.to_string(),
+            name: token.name,
+            scope: token.scope.to_string(),
+            created_at: token.created_at.to_rfc3339(),
+            expires_at: token.expires_at.map(|at| at.to_rfc3339()),
+            last_used_at: token.last_used_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CreatedPersonalAccessTokenDto {
+    /// Send it as `Authorization: Bearer <token>`. It is not shown again.
+    pub token: String,
+    #[serde(flatten)]
+    pub details: PersonalAccessTokenDto,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SessionDto {
+    pub id: String,
+    /// Readable summary of the user agent, such as "Firefox on Linux"
+    pub device: String,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+    /// RFC 3339 timestamp, precise to the minute
+    pub last_seen_at: String,
+    /// Whether the request was made with a token of this session
+    pub current: bool,
+}
+
+impl SessionDto {
+    pub fn new(session: Session, current_session_id: Option<&str>) -> Self {
+        let id = session.id.to_string();
+        Self {
+            current: current_session_id == Some(id.as_str()),
+            id,
+            device: session.device,
+            user_agent: session.user_agent,
+            ip: session.ip,
+            created_at: session.created_at.to_rfc3339(),
+            last_seen_at: session.last_seen_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RefreshTokenDto {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct LogoutDto {
+    /// Refresh token of the session being closed, revoked along with it
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ForgotPasswordDto {
+    pub email: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ResetPasswordDto {
+    /// Token from the link of the reset email
+    pub token: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ChangePasswordDto {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ChangeEmailDto {
+    /// Current password of the account
+    pub password: String,
+    pub new_email: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConfirmEmailChangeDto {
+    /// Token from the link mailed to the new address
+    pub token: String,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct FailedLoginDto {
     pub message: String,
@@ -32,3 +258,16 @@ pub struct UserUuidDto {
 pub struct VerifyEmailQuery {
     pub token: String,
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct OidcProvidersDto {
+    /// Names to use in `/auth/oidc/{provider}/...`
+    pub providers: Vec<String>,
+}
+
+/// What the provider appended to the redirect URI.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct OidcCallbackDto {
+    pub code: String,
+    pub state: String,
+}
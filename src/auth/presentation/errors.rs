@@ -1,4 +1,8 @@
-use axum::{Json, http::StatusCode, response::IntoResponse};
+use axum::{
+    Json,
+    http::{HeaderValue, StatusCode, header::RETRY_AFTER},
+    response::IntoResponse,
+};
 use thiserror::Error;
 
 use crate::auth::application::errors::AuthError;
@@ -12,11 +16,44 @@ pub enum AuthApiError {
 
 impl IntoResponse for AuthApiError {
     fn into_response(self) -> axum::response::Response {
+        let retry_after = match &self {
+            AuthApiError::AuthError(AuthError::TooManyRequests { retry_after_secs }) => {
+                Some(*retry_after_secs)
+            }
+            _ => None,
+        };
         let (status, message) = match self {
+            AuthApiError::AuthError(
+                e @ (AuthError::InvalidToken | AuthError::TokenExpired | AuthError::InvalidMfaCode),
+            ) => (StatusCode::UNAUTHORIZED, e.to_string()),
+            AuthApiError::AuthError(
+                e @ (AuthError::EmailTaken
+                | AuthError::EmailAlreadyVerified
+                | AuthError::MfaAlreadyEnabled
+                | AuthError::MfaNotEnabled
+                | AuthError::IdentityNotLinkable),
+            ) => (StatusCode::CONFLICT, e.to_string()),
+            AuthApiError::AuthError(
+                e @ (AuthError::TokenNotFound
+                | AuthError::SessionNotFound
+                | AuthError::UnknownOidcProvider),
+            ) => (StatusCode::NOT_FOUND, e.to_string()),
+            AuthApiError::AuthError(e @ AuthError::OidcProviderError(_)) => {
+                (StatusCode::BAD_GATEWAY, e.to_string())
+            }
+            AuthApiError::AuthError(e @ AuthError::TooManyRequests { .. }) => {
+                (StatusCode::TOO_MANY_REQUESTS, e.to_string())
+            }
             AuthApiError::AuthError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
         };
 
-        (status, Json(ApiErrorResponse { message })).into_response()
+        let mut response = (status, Json(ApiErrorResponse { message })).into_response();
+        if let Some(value) =
+            retry_after.and_then(|secs| HeaderValue::from_str(&secs.to_string()).ok())
+        {
+            response.headers_mut().insert(RETRY_AFTER, value);
+        }
+        response
     }
 }
 
@@ -38,6 +75,30 @@ mod tests {
         assert_eq!(response_status(response), StatusCode::BAD_REQUEST);
     }
 
+    #[test]
+    fn test_token_errors_are_unauthorized() {
+        let response = AuthApiError::from(AuthError::TokenExpired).into_response();
+        assert_eq!(response_status(response), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_throttled_requests_carry_retry_after() {
+        let response = AuthApiError::from(AuthError::TooManyRequests {
+            retry_after_secs: 42,
+        })
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "42");
+    }
+
+    #[test]
+    fn test_identity_provider_failures_are_bad_gateway() {
+        let response =
+            AuthApiError::from(AuthError::OidcProviderError("timeout".to_string())).into_response();
+        assert_eq!(response_status(response), StatusCode::BAD_GATEWAY);
+    }
+
     #[test]
     fn test_auth_api_error_display() {
         let auth_error = AuthError::InvalidCredentials;
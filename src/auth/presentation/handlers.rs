@@ -2,14 +2,31 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Query, State},
+    extract::{Path, Query, State},
+    http::StatusCode,
 };
+use chrono::DateTime;
+use uuid::Uuid;
 
-use crate::auth::{
-    application::auth_service::AuthService,
-    presentation::{
-        dtos::{LoginDto, SignupDto, SuccesfullLoginDto, UserUuidDto, VerifyEmailQuery},
-        errors::AuthApiError,
+use crate::{
+    auth::{
+        application::{auth_service::AuthService, errors::AuthError},
+        domain::errors::AuthDomainError,
+        presentation::{
+            dtos::{
+                ChangeEmailDto, ChangePasswordDto, ConfirmEmailChangeDto,
+                CreatePersonalAccessTokenDto, CreatedPersonalAccessTokenDto, ForgotPasswordDto,
+                LoginDto, LoginResultDto, LogoutDto, MfaCodeDto, MfaDisableDto, MfaEnrollDto,
+                MfaEnrollmentDto, MfaLoginDto, PersonalAccessTokenDto, RecoveryCodesDto,
+                RefreshTokenDto, ResetPasswordDto, SessionDto, SignupDto, SuccesfullLoginDto,
+                UserUuidDto, VerifyEmailQuery,
+            },
+            errors::AuthApiError,
+        },
+    },
+    shared::infrastructure::http::{
+        auth_extractor::{AuthenticatedClaims, AuthenticatedUser},
+        request_context::current_request_origin,
     },
 };
 
@@ -18,7 +35,7 @@ use crate::auth::{
     path = "/auth/login",
     request_body = LoginDto,
     responses(
-        (status = 200, description = "Login successful", body = SuccesfullLoginDto),
+        (status = 200, description = "Login successful, or a second factor is required", body = LoginResultDto),
         (status = 401, description = "Invalid credentials")
     ),
     tag = "Auth"
@@ -26,12 +43,312 @@ use crate::auth::{
 pub async fn login(
     State(service): State<Arc<AuthService>>,
     Json(payload): Json<LoginDto>,
+) -> Result<Json<LoginResultDto>, AuthApiError> {
+    let outcome = service
+        .login(&payload.email, &payload.password, &current_request_origin())
+        .await?;
+    Ok(Json(LoginResultDto::from(outcome)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/login/mfa",
+    request_body = MfaLoginDto,
+    responses(
+        (status = 200, description = "Login successful", body = SuccesfullLoginDto),
+        (status = 401, description = "Invalid or expired MFA token, or wrong code")
+    ),
+    tag = "Auth"
+)]
+pub async fn login_mfa(
+    State(service): State<Arc<AuthService>>,
+    Json(payload): Json<MfaLoginDto>,
 ) -> Result<Json<SuccesfullLoginDto>, AuthApiError> {
-    let response = service.login(&payload.email, &payload.password).await?;
-    Ok(Json(SuccesfullLoginDto {
-        access_token: response.token,
-        email_validated: response.is_email_verified,
-    }))
+    let response = service
+        .complete_mfa_login(&payload.mfa_token, &payload.code, &current_request_origin())
+        .await?;
+    Ok(Json(SuccesfullLoginDto::from(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/enroll",
+    request_body = MfaEnrollDto,
+    responses(
+        (status = 200, description = "Secret created, confirm it with a first code", body = MfaEnrollmentDto),
+        (status = 400, description = "Wrong password"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Two-factor authentication already enabled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn mfa_enroll(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Json(payload): Json<MfaEnrollDto>,
+) -> Result<Json<MfaEnrollmentDto>, AuthApiError> {
+    let enrollment = service
+        .start_mfa_enrollment(&user_id, &payload.password)
+        .await?;
+    Ok(Json(MfaEnrollmentDto::from(enrollment)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/confirm",
+    request_body = MfaCodeDto,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled", body = RecoveryCodesDto),
+        (status = 401, description = "Unauthorized or wrong code"),
+        (status = 409, description = "Not enrolled, or already enabled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn mfa_confirm(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Json(payload): Json<MfaCodeDto>,
+) -> Result<Json<RecoveryCodesDto>, AuthApiError> {
+    let recovery_codes = service
+        .confirm_mfa_enrollment(&user_id, &payload.code)
+        .await?;
+    Ok(Json(RecoveryCodesDto { recovery_codes }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/disable",
+    request_body = MfaDisableDto,
+    responses(
+        (status = 204, description = "Two-factor authentication disabled"),
+        (status = 400, description = "Wrong password"),
+        (status = 401, description = "Unauthorized or wrong code"),
+        (status = 409, description = "Two-factor authentication not enabled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn mfa_disable(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Json(payload): Json<MfaDisableDto>,
+) -> Result<StatusCode, AuthApiError> {
+    service
+        .disable_mfa(&user_id, &payload.password, &payload.code)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/refresh",
+    request_body = RefreshTokenDto,
+    responses(
+        (status = 200, description = "Tokens rotated", body = SuccesfullLoginDto),
+        (status = 401, description = "Invalid, expired or already used refresh token")
+    ),
+    tag = "Auth"
+)]
+pub async fn refresh(
+    State(service): State<Arc<AuthService>>,
+    Json(payload): Json<RefreshTokenDto>,
+) -> Result<Json<SuccesfullLoginDto>, AuthApiError> {
+    let response = service.refresh(&payload.refresh_token).await?;
+    Ok(Json(SuccesfullLoginDto::from(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout",
+    request_body(content = Option<LogoutDto>, description = "Refresh token of the current session"),
+    responses(
+        (status = 204, description = "Access token and session revoked"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn logout(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    payload: Option<Json<LogoutDto>>,
+) -> Result<StatusCode, AuthApiError> {
+    let expires_at = DateTime::from_timestamp(claims.exp as i64, 0).unwrap_or_default();
+    let refresh_token = payload.and_then(|Json(dto)| dto.refresh_token);
+    service
+        .logout(
+            &claims.sub,
+            &claims.jti,
+            expires_at,
+            claims.sid.as_deref(),
+            refresh_token.as_deref(),
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout-all",
+    responses(
+        (status = 204, description = "Every access and refresh token of the user revoked"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn logout_all(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+) -> Result<StatusCode, AuthApiError> {
+    service.logout_all(&user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/tokens",
+    request_body = CreatePersonalAccessTokenDto,
+    responses(
+        (status = 201, description = "Token created, the secret is shown this once", body = CreatedPersonalAccessTokenDto),
+        (status = 400, description = "Invalid name, scope or expiry"),
+        (status = 401, description = "Unauthorized, or not a session token")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn create_personal_access_token(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    Json(payload): Json<CreatePersonalAccessTokenDto>,
+) -> Result<(StatusCode, Json<CreatedPersonalAccessTokenDto>), AuthApiError> {
+    let scope = payload
+        .scope
+        .parse()
+        .map_err(|e| AuthError::DomainError(AuthDomainError::Shared(e)))?;
+    let (token, secret) = service
+        .create_personal_access_token(&claims.sub, &payload.name, scope, payload.expires_in_days)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedPersonalAccessTokenDto {
+            token: secret,
+            details: token.into(),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth/tokens",
+    responses(
+        (status = 200, description = "Personal access tokens that were not revoked, newest first", body = [PersonalAccessTokenDto]),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn list_personal_access_tokens(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+) -> Result<Json<Vec<PersonalAccessTokenDto>>, AuthApiError> {
+    let tokens = service.list_personal_access_tokens(&user_id).await?;
+    Ok(Json(tokens.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/auth/tokens/{id}",
+    params(
+        ("id" = String, Path, description = "Personal access token ID")
+    ),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Token not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn revoke_personal_access_token(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Path(token_id): Path<String>,
+) -> Result<StatusCode, AuthApiError> {
+    let token_id = Uuid::parse_str(&token_id).map_err(|_| AuthError::TokenNotFound)?;
+    service
+        .revoke_personal_access_token(&user_id, token_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth/sessions",
+    responses(
+        (status = 200, description = "Signed-in devices, most recently seen first", body = [SessionDto]),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn list_sessions(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+) -> Result<Json<Vec<SessionDto>>, AuthApiError> {
+    let sessions = service.list_sessions(&claims.sub).await?;
+    Ok(Json(
+        sessions
+            .into_iter()
+            .map(|session| SessionDto::new(session, claims.sid.as_deref()))
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/auth/sessions/{id}",
+    params(
+        ("id" = String, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 204, description = "Session signed out, its tokens no longer work"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Session not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn revoke_session(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    Path(session_id): Path<String>,
+) -> Result<StatusCode, AuthApiError> {
+    let session_id = Uuid::parse_str(&session_id).map_err(|_| AuthError::SessionNotFound)?;
+    service.revoke_session(&claims.sub, session_id).await?;
+    Ok(StatusCode::NO_CONTENT)
 }
 
 #[utoipa::path(
@@ -76,3 +393,141 @@ pub async fn verify_email(
         serde_json::json!({ "message": "Email verified successfully" }),
     ))
 }
+
+#[utoipa::path(
+    post,
+    path = "/auth/resend-verification",
+    responses(
+        (status = 202, description = "A new verification link is emailed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Email already verified"),
+        (status = 429, description = "Cooldown or daily cap reached, see the Retry-After header")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn resend_verification(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+) -> Result<StatusCode, AuthApiError> {
+    service.resend_verification_email(&user_id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/forgot-password",
+    request_body = ForgotPasswordDto,
+    responses(
+        (status = 202, description = "A reset link is emailed if the account exists")
+    ),
+    tag = "Auth"
+)]
+pub async fn forgot_password(
+    State(service): State<Arc<AuthService>>,
+    Json(payload): Json<ForgotPasswordDto>,
+) -> StatusCode {
+    // Always accepted, so the response does not reveal whether the email exists.
+    if let Err(e) = service.request_password_reset(&payload.email).await {
+        tracing::error!(error = %e, "auth.forgot_password failed");
+    }
+    StatusCode::ACCEPTED
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/reset-password",
+    request_body = ResetPasswordDto,
+    responses(
+        (status = 204, description = "Password changed, every session revoked"),
+        (status = 400, description = "Password too weak"),
+        (status = 401, description = "Invalid, expired or already used token")
+    ),
+    tag = "Auth"
+)]
+pub async fn reset_password(
+    State(service): State<Arc<AuthService>>,
+    Json(payload): Json<ResetPasswordDto>,
+) -> Result<StatusCode, AuthApiError> {
+    service
+        .reset_password(&payload.token, &payload.new_password)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/change-password",
+    request_body = ChangePasswordDto,
+    responses(
+        (status = 200, description = "Password changed, other sessions revoked", body = SuccesfullLoginDto),
+        (status = 400, description = "Wrong current password or new password too weak"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn change_password(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Json(payload): Json<ChangePasswordDto>,
+) -> Result<Json<SuccesfullLoginDto>, AuthApiError> {
+    let response = service
+        .change_password(
+            &user_id,
+            &payload.current_password,
+            &payload.new_password,
+            &current_request_origin(),
+        )
+        .await?;
+    Ok(Json(SuccesfullLoginDto::from(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/change-email",
+    request_body = ChangeEmailDto,
+    responses(
+        (status = 202, description = "Confirmation link mailed to the new address"),
+        (status = 400, description = "Wrong password or invalid email"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Email already in use")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn change_email(
+    State(service): State<Arc<AuthService>>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Json(payload): Json<ChangeEmailDto>,
+) -> Result<StatusCode, AuthApiError> {
+    service
+        .request_email_change(&user_id, &payload.password, &payload.new_email)
+        .await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/confirm-email-change",
+    request_body = ConfirmEmailChangeDto,
+    responses(
+        (status = 204, description = "Email changed"),
+        (status = 401, description = "Invalid, expired or already used token"),
+        (status = 409, description = "Email already in use")
+    ),
+    tag = "Auth"
+)]
+pub async fn confirm_email_change(
+    State(service): State<Arc<AuthService>>,
+    Json(payload): Json<ConfirmEmailChangeDto>,
+) -> Result<StatusCode, AuthApiError> {
+    service.confirm_email_change(&payload.token).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
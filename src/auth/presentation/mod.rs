@@ -1,4 +1,6 @@
 pub mod dtos;
 pub mod errors;
 pub mod handlers;
+pub mod oidc_handlers;
+pub mod oidc_routes;
 pub mod routes;
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::Redirect,
+};
+
+use crate::auth::{
+    application::oidc_service::OidcService,
+    presentation::{
+        dtos::{LoginResultDto, OidcCallbackDto, OidcProvidersDto},
+        errors::AuthApiError,
+    },
+};
+use crate::shared::infrastructure::http::request_context::current_request_origin;
+
+#[utoipa::path(
+    get,
+    path = "/auth/oidc/providers",
+    responses(
+        (status = 200, description = "Identity providers users can sign in with", body = OidcProvidersDto)
+    ),
+    tag = "Auth"
+)]
+pub async fn list_oidc_providers(
+    State(service): State<Arc<OidcService>>,
+) -> Json<OidcProvidersDto> {
+    Json(OidcProvidersDto {
+        providers: service.provider_names(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth/oidc/{provider}/authorize",
+    params(
+        ("provider" = String, Path, description = "Identity provider name")
+    ),
+    responses(
+        (status = 303, description = "Redirect to the identity provider"),
+        (status = 404, description = "Unknown identity provider"),
+        (status = 502, description = "Identity provider unavailable")
+    ),
+    tag = "Auth"
+)]
+pub async fn oidc_authorize(
+    State(service): State<Arc<OidcService>>,
+    Path(provider): Path<String>,
+) -> Result<Redirect, AuthApiError> {
+    let url = service.start_login(&provider).await?;
+    Ok(Redirect::to(&url))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/oidc/{provider}/callback",
+    params(
+        ("provider" = String, Path, description = "Identity provider name")
+    ),
+    request_body = OidcCallbackDto,
+    responses(
+        (status = 200, description = "Login successful, or a second factor is required", body = LoginResultDto),
+        (status = 401, description = "Unknown, used or expired state, or rejected code"),
+        (status = 404, description = "Unknown identity provider"),
+        (status = 409, description = "The email is not verified on both sides, cannot link the accounts"),
+        (status = 502, description = "Identity provider unavailable")
+    ),
+    tag = "Auth"
+)]
+pub async fn oidc_callback(
+    State(service): State<Arc<OidcService>>,
+    Path(provider): Path<String>,
+    Json(payload): Json<OidcCallbackDto>,
+) -> Result<Json<LoginResultDto>, AuthApiError> {
+    let outcome = service
+        .complete_login(
+            &provider,
+            &payload.code,
+            &payload.state,
+            &current_request_origin(),
+        )
+        .await?;
+    Ok(Json(LoginResultDto::from(outcome)))
+}
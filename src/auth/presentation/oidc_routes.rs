@@ -0,0 +1,16 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::auth::presentation::oidc_handlers::{
+    list_oidc_providers, oidc_authorize, oidc_callback,
+};
+use crate::auth::presentation::routes::AuthState;
+
+pub fn create_oidc_routes() -> Router<AuthState> {
+    Router::new()
+        .route("/providers", get(list_oidc_providers))
+        .route("/{provider}/authorize", get(oidc_authorize))
+        .route("/{provider}/callback", post(oidc_callback))
+}
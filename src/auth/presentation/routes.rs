@@ -1,22 +1,30 @@
 use axum::{
     Router,
     extract::FromRef,
-    routing::{get, post},
+    routing::{delete, get, post},
 };
 use std::sync::Arc;
 
 use crate::{
     auth::{
-        application::auth_service::AuthService,
-        presentation::handlers::{login, signup, verify_email},
+        application::{auth_service::AuthService, oidc_service::OidcService},
+        presentation::handlers::{
+            change_email, change_password, confirm_email_change, create_personal_access_token,
+            forgot_password, list_personal_access_tokens, list_sessions, login, login_mfa, logout,
+            logout_all, mfa_confirm, mfa_disable, mfa_enroll, refresh, resend_verification,
+            reset_password, revoke_personal_access_token, revoke_session, signup, verify_email,
+        },
+        presentation::oidc_routes::create_oidc_routes,
     },
-    shared::config::Config,
+    shared::{config::Config, infrastructure::http::auth_extractor::UserStatusChecker},
 };
 
 #[derive(Clone)]
-struct AuthState {
+pub struct AuthState {
     service: Arc<AuthService>,
+    oidc_service: Arc<OidcService>,
     config: Arc<Config>,
+    user_checker: Arc<dyn UserStatusChecker>,
 }
 
 impl FromRef<AuthState> for Arc<AuthService> {
@@ -25,18 +33,61 @@ impl FromRef<AuthState> for Arc<AuthService> {
     }
 }
 
+impl FromRef<AuthState> for Arc<OidcService> {
+    fn from_ref(state: &AuthState) -> Self {
+        state.oidc_service.clone()
+    }
+}
+
 impl FromRef<AuthState> for Arc<Config> {
     fn from_ref(state: &AuthState) -> Self {
         state.config.clone()
     }
 }
 
-pub fn create_auth_routes(service: Arc<AuthService>, config: Arc<Config>) -> Router {
-    let state = AuthState { service, config };
+impl FromRef<AuthState> for Arc<dyn UserStatusChecker> {
+    fn from_ref(state: &AuthState) -> Self {
+        state.user_checker.clone()
+    }
+}
+
+pub fn create_auth_routes(
+    service: Arc<AuthService>,
+    oidc_service: Arc<OidcService>,
+    config: Arc<Config>,
+    user_checker: Arc<dyn UserStatusChecker>,
+) -> Router {
+    let state = AuthState {
+        service,
+        oidc_service,
+        config,
+        user_checker,
+    };
     Router::new()
         .route("/login", post(login))
+        .route("/login/mfa", post(login_mfa))
+        .route("/refresh", post(refresh))
+        .route("/logout", post(logout))
+        .route("/logout-all", post(logout_all))
+        .route("/forgot-password", post(forgot_password))
+        .route("/reset-password", post(reset_password))
         .route("/signup", post(signup))
         .route("/verify-email", get(verify_email))
+        .route("/resend-verification", post(resend_verification))
+        .route("/change-password", post(change_password))
+        .route("/change-email", post(change_email))
+        .route("/confirm-email-change", post(confirm_email_change))
+        .route("/mfa/enroll", post(mfa_enroll))
+        .route("/mfa/confirm", post(mfa_confirm))
+        .route("/mfa/disable", post(mfa_disable))
+        .route(
+            "/tokens",
+            post(create_personal_access_token).get(list_personal_access_tokens),
+        )
+        .route("/tokens/{id}", delete(revoke_personal_access_token))
+        .route("/sessions", get(list_sessions))
+        .route("/sessions/{id}", delete(revoke_session))
+        .nest("/oidc", create_oidc_routes())
         .with_state(state)
 }
 
@@ -56,27 +107,34 @@ mod tests {
     use tower::ServiceExt;
     use uuid::Uuid;
 
-    use crate::auth::application::auth_service::AuthService;
+    use crate::auth::application::auth_service::{AuthRepositories, AuthService, AuthSettings};
     use crate::auth::application::email_queue_enqueuer::IEmailQueueEnqueuer;
     use crate::auth::application::errors::AuthError;
-    use crate::auth::application::token_generator::ITokenGenerator;
+    use crate::auth::application::oidc_service::{OidcRepositories, OidcSettings};
+    use crate::auth::application::token_revocation_service::TokenRevocationService;
+    use crate::auth::application::user_status_checker::UserStatusCheckerImpl;
+    use crate::auth::domain::entities::auth_email::AuthEmail;
+    use crate::auth::domain::entities::login_attempts::LoginLockoutPolicy;
+    use crate::auth::domain::entities::verification_email_throttle::VerificationEmailThrottle;
+    use crate::auth::presentation::dtos::{
+        ChangePasswordDto, CreatePersonalAccessTokenDto, CreatedPersonalAccessTokenDto,
+        ForgotPasswordDto, LogoutDto, MfaCodeDto, MfaEnrollDto, MfaEnrollmentDto, MfaLoginDto,
+        OidcCallbackDto, OidcProvidersDto, PersonalAccessTokenDto, RefreshTokenDto,
+        ResetPasswordDto, SessionDto,
+    };
     use crate::auth::presentation::dtos::{SignupDto, UserUuidDto};
     use crate::composition_root::create_user_in_memory_repository;
     use crate::shared::fixtures::{valid_email, valid_password};
-    use std::sync::Mutex;
-
-    struct MockTokenGenerator {
-        last_user_id: Mutex<Option<String>>,
-    }
+    use std::collections::BTreeMap;
+    use std::time::Duration;
 
     struct MockEmailQueue;
     #[async_trait::async_trait]
     impl IEmailQueueEnqueuer for MockEmailQueue {
         async fn enqueue(
             &self,
-            _email: &str,
-            _subject: &str,
-            _body: &str,
+            _to: &str,
+            _email: &AuthEmail,
             _user_id: uuid::Uuid,
             _trace_id: Option<String>,
         ) -> Result<(), AuthError> {
@@ -84,45 +142,112 @@ mod tests {
         }
     }
 
-    impl MockTokenGenerator {
-        fn new() -> Self {
-            Self {
-                last_user_id: Mutex::new(None),
-            }
-        }
-    }
-
-    impl ITokenGenerator for MockTokenGenerator {
-        fn generate_token(&self, user_id: &str, _email: &str) -> Result<String, AuthError> {
-            *self.last_user_id.lock().unwrap() = Some(user_id.to_string());
-            Ok("mock-token".to_string())
-        }
-
-        fn validate_token(&self, token: &str) -> Result<String, AuthError> {
-            if token == "mock-token" {
-                let user_id = self.last_user_id.lock().unwrap();
-                Ok(user_id.clone().unwrap_or_default())
-            } else {
-                Err(AuthError::InvalidToken)
-            }
-        }
-    }
-
     async fn setup_router() -> Router {
         let repo = create_user_in_memory_repository().await;
         let user = User::new(&Uuid::new_v4().to_string(), valid_email(), valid_password()).unwrap();
         repo.save(&user).await.unwrap();
 
         let config = Arc::new(Config::test_default());
-        let token_generator = Box::new(MockTokenGenerator::new());
+        let token_generator = Box::new(
+            crate::auth::infrastructure::services::jwt_token_generator::JwtTokenGenerator::new(
+                config.clone(),
+            ),
+        );
+        let token_revocations = Arc::new(TokenRevocationService::new(
+            Box::new(
+                crate::auth::infrastructure::persistence::repositories::token_revocation_in_memory_repository::TokenRevocationInMemoryRepository::default(),
+            ),
+            Duration::from_secs(30),
+        ));
         let email_queue = Box::new(MockEmailQueue);
+        let personal_access_tokens =
+            crate::auth::infrastructure::persistence::repositories::personal_access_token_in_memory_repository::PersonalAccessTokenInMemoryRepository::default();
+        let sessions =
+            crate::auth::infrastructure::persistence::repositories::session_in_memory_repository::SessionInMemoryRepository::default();
+        let user_checker = Arc::new(UserStatusCheckerImpl::new(
+            Arc::new(Box::new(repo.clone())),
+            token_revocations.clone(),
+            Arc::new(Box::new(personal_access_tokens.clone())),
+            Arc::new(Box::new(sessions.clone())),
+        ));
         let service = Arc::new(AuthService::new(
-            Box::new(repo),
+            AuthRepositories {
+                users: Box::new(repo.clone()),
+                refresh_tokens: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::refresh_token_in_memory_repository::RefreshTokenInMemoryRepository::default(),
+                ),
+                password_reset_tokens: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::password_reset_token_in_memory_repository::PasswordResetTokenInMemoryRepository::new(repo.clone()),
+                ),
+                verification_emails: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::verification_email_in_memory_repository::VerificationEmailInMemoryRepository::default(),
+                ),
+                email_change_requests: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::email_change_request_in_memory_repository::EmailChangeRequestInMemoryRepository::default(),
+                ),
+                two_factor: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::two_factor_in_memory_repository::TwoFactorInMemoryRepository::default(),
+                ),
+                login_attempts: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::login_attempt_in_memory_repository::LoginAttemptInMemoryRepository::default(),
+                ),
+                personal_access_tokens: Box::new(personal_access_tokens),
+                sessions: Box::new(sessions),
+            },
             token_generator,
+            token_revocations,
             email_queue,
-            "http://localhost:3000".to_string(),
+            Arc::new(
+                crate::shared::infrastructure::audit::audit_in_memory_log::AuditInMemoryLog::new(),
+            ),
+            AuthSettings {
+                frontend_url: "http://localhost:3000".to_string(),
+                refresh_token_ttl_secs: 3600,
+                verification_email_throttle: VerificationEmailThrottle {
+                    cooldown_secs: 60,
+                    daily_cap: 5,
+                },
+                totp_issuer: "Seeker".to_string(),
+                login_lockout: LoginLockoutPolicy {
+                    delay_after: 3,
+                    base_delay_secs: 1,
+                    max_delay_secs: 30,
+                    lockout_threshold: 10,
+                    lockout_secs: 900,
+                },
+            },
+        ));
+        let oidc_service = Arc::new(OidcService::new(
+            BTreeMap::new(),
+            OidcRepositories {
+                users: Box::new(repo),
+                identities: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::identity_in_memory_repository::IdentityInMemoryRepository::default(),
+                ),
+                login_states: Box::new(
+                    crate::auth::infrastructure::persistence::repositories::oidc_login_state_in_memory_repository::OidcLoginStateInMemoryRepository::default(),
+                ),
+            },
+            service.clone(),
+            OidcSettings {
+                redirect_base_url: "http://localhost:3000".to_string(),
+                login_state_ttl_secs: 600,
+            },
         ));
-        create_auth_routes(service, config)
+        create_auth_routes(service, oidc_service, config, user_checker)
+    }
+
+    fn authorized_request(
+        uri: &str,
+        access_token: &str,
+        body: impl serde::Serialize,
+    ) -> Request<Body> {
+        let mut request = json_request(uri, "POST", body);
+        request.headers_mut().insert(
+            header::AUTHORIZATION,
+            format!("Bearer {}", access_token).parse().unwrap(),
+        );
+        request
     }
 
     fn json_request(uri: &str, method: &str, body: impl serde::Serialize) -> Request<Body> {
@@ -245,4 +370,505 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
+
+    async fn login(app: &Router) -> SuccesfullLoginDto {
+        let login_dto = LoginDto {
+            email: valid_email().to_string(),
+            password: valid_password().to_string(),
+        };
+        let response = app
+            .clone()
+            .oneshot(json_request("/login", "POST", login_dto))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<SuccesfullLoginDto>(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_tokens() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "/refresh",
+                "POST",
+                RefreshTokenDto {
+                    refresh_token: tokens.refresh_token.clone(),
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let refreshed = serde_json::from_slice::<SuccesfullLoginDto>(&body).unwrap();
+        assert_ne!(refreshed.refresh_token, tokens.refresh_token);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_with_reused_token_is_unauthorized() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+        let request = || {
+            json_request(
+                "/refresh",
+                "POST",
+                RefreshTokenDto {
+                    refresh_token: tokens.refresh_token.clone(),
+                },
+            )
+        };
+
+        let first = app.clone().oneshot(request()).await.unwrap();
+        let second = app.oneshot(request()).await.unwrap();
+
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(second.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_logout_revokes_access_and_refresh_tokens() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+        let logout = || {
+            authorized_request(
+                "/logout",
+                &tokens.access_token,
+                LogoutDto {
+                    refresh_token: Some(tokens.refresh_token.clone()),
+                },
+            )
+        };
+
+        let first = app.clone().oneshot(logout()).await.unwrap();
+        let second = app.clone().oneshot(logout()).await.unwrap();
+        let refresh = app
+            .oneshot(json_request(
+                "/refresh",
+                "POST",
+                RefreshTokenDto {
+                    refresh_token: tokens.refresh_token.clone(),
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(first.status(), StatusCode::NO_CONTENT);
+        assert_eq!(second.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(refresh.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_logout_all_revokes_other_sessions() {
+        let app = setup_router().await;
+        let first_session = login(&app).await;
+        let second_session = login(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(authorized_request(
+                "/logout-all",
+                &first_session.access_token,
+                LogoutDto {
+                    refresh_token: None,
+                },
+            ))
+            .await
+            .unwrap();
+        let other = app
+            .clone()
+            .oneshot(authorized_request(
+                "/logout",
+                &second_session.access_token,
+                LogoutDto {
+                    refresh_token: None,
+                },
+            ))
+            .await
+            .unwrap();
+        let new_session = login(&app).await;
+        let after_new_login = app
+            .oneshot(authorized_request(
+                "/logout",
+                &new_session.access_token,
+                LogoutDto {
+                    refresh_token: None,
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(other.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(after_new_login.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_is_accepted_for_any_email() {
+        let app = setup_router().await;
+
+        for email in [valid_email(), "unknown@example.com"] {
+            let response = app
+                .clone()
+                .oneshot(json_request(
+                    "/forgot-password",
+                    "POST",
+                    ForgotPasswordDto {
+                        email: email.to_string(),
+                    },
+                ))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::ACCEPTED);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_with_invalid_token_is_unauthorized() {
+        let app = setup_router().await;
+
+        let response = app
+            .oneshot(json_request(
+                "/reset-password",
+                "POST",
+                ResetPasswordDto {
+                    token: "invalid".to_string(),
+                    new_password: valid_password().to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_login_token_cannot_verify_an_email() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/verify-email?token={}", tokens.access_token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_is_throttled() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+        let resend = || authorized_request("/resend-verification", &tokens.access_token, ());
+
+        let first = app.clone().oneshot(resend()).await.unwrap();
+        let second = app.oneshot(resend()).await.unwrap();
+
+        assert_eq!(first.status(), StatusCode::ACCEPTED);
+        assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(second.headers().contains_key(header::RETRY_AFTER));
+    }
+
+    #[tokio::test]
+    async fn test_change_password_returns_a_new_session() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+        let change = |current_password: &str| {
+            authorized_request(
+                "/change-password",
+                &tokens.access_token,
+                ChangePasswordDto {
+                    current_password: current_password.to_string(),
+                    new_password: "An0ther-V3ry-Str0ng-P@ss".to_string(),
+                },
+            )
+        };
+
+        let wrong = app.clone().oneshot(change("wrong")).await.unwrap();
+        let changed = app.clone().oneshot(change(valid_password())).await.unwrap();
+        let stale_session = app.oneshot(change(valid_password())).await.unwrap();
+
+        assert_eq!(wrong.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(changed.status(), StatusCode::OK);
+        assert_eq!(stale_session.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_mfa_enrollment_and_login_reject_bad_input() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+
+        let enroll = app
+            .clone()
+            .oneshot(authorized_request(
+                "/mfa/enroll",
+                &tokens.access_token,
+                MfaEnrollDto {
+                    password: valid_password().to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(enroll.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(enroll.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let enrollment: MfaEnrollmentDto = serde_json::from_slice(&body).unwrap();
+        assert!(enrollment.otpauth_uri.contains(&enrollment.secret));
+
+        let confirm = app
+            .clone()
+            .oneshot(authorized_request(
+                "/mfa/confirm",
+                &tokens.access_token,
+                MfaCodeDto {
+                    code: "not-a-code".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+        let mfa_login = app
+            .oneshot(json_request(
+                "/login/mfa",
+                "POST",
+                MfaLoginDto {
+                    mfa_token: tokens.access_token.clone(),
+                    code: "123456".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(confirm.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(mfa_login.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    fn bearer_request(uri: &str, method: &str, token: &str) -> Request<Body> {
+        Request::builder()
+            .uri(uri)
+            .method(method)
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_personal_access_token_lifecycle() {
+        let app = setup_router().await;
+        let tokens = login(&app).await;
+
+        let created = app
+            .clone()
+            .oneshot(authorized_request(
+                "/tokens",
+                &tokens.access_token,
+                CreatePersonalAccessTokenDto {
+                    name: "Bookmarklet".to_string(),
+                    scope: "read-only".to_string(),
+                    expires_in_days: Some(7),
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(created.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: CreatedPersonalAccessTokenDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(created.details.scope, "read-only");
+
+        let listed = app
+            .clone()
+            .oneshot(bearer_request("/tokens", "GET", &created.token))
+            .await
+            .unwrap();
+        assert_eq!(listed.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(listed.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: Vec<PersonalAccessTokenDto> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, created.details.id);
+        assert!(listed[0].last_used_at.is_some());
+
+        let write_with_token = app
+            .clone()
+            .oneshot(authorized_request(
+                "/change-password",
+                &created.token,
+                ChangePasswordDto {
+                    current_password: valid_password().to_string(),
+                    new_password: "An0ther-V3ry-Str0ng-P@ss".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+        let create_with_token = app
+            .clone()
+            .oneshot(authorized_request(
+                "/tokens",
+                &created.token,
+                CreatePersonalAccessTokenDto {
+                    name: "Another".to_string(),
+                    scope: "read-only".to_string(),
+                    expires_in_days: None,
+                },
+            ))
+            .await
+            .unwrap();
+        let bad_scope = app
+            .clone()
+            .oneshot(authorized_request(
+                "/tokens",
+                &tokens.access_token,
+                CreatePersonalAccessTokenDto {
+                    name: "Admin".to_string(),
+                    scope: "admin".to_string(),
+                    expires_in_days: None,
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(write_with_token.status(), StatusCode::FORBIDDEN);
+        assert_eq!(create_with_token.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(bad_scope.status(), StatusCode::BAD_REQUEST);
+
+        let uri = format!("/tokens/{}", created.details.id);
+        let revoked = app
+            .clone()
+            .oneshot(bearer_request(&uri, "DELETE", &tokens.access_token))
+            .await
+            .unwrap();
+        let revoked_again = app
+            .clone()
+            .oneshot(bearer_request(&uri, "DELETE", &tokens.access_token))
+            .await
+            .unwrap();
+        let after_revoke = app
+            .oneshot(bearer_request("/tokens", "GET", &created.token))
+            .await
+            .unwrap();
+        assert_eq!(revoked.status(), StatusCode::NO_CONTENT);
+        assert_eq!(revoked_again.status(), StatusCode::NOT_FOUND);
+        assert_eq!(after_revoke.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_revoked_sessions_are_signed_out_immediately() {
+        let app = setup_router().await;
+        let laptop = login(&app).await;
+        let phone = login(&app).await;
+
+        let listed = app
+            .clone()
+            .oneshot(bearer_request("/sessions", "GET", &laptop.access_token))
+            .await
+            .unwrap();
+        assert_eq!(listed.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(listed.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: Vec<SessionDto> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed.iter().filter(|session| session.current).count(), 1);
+        let phone_session = listed.iter().find(|session| !session.current).unwrap();
+
+        let uri = format!("/sessions/{}", phone_session.id);
+        let revoked = app
+            .clone()
+            .oneshot(bearer_request(&uri, "DELETE", &laptop.access_token))
+            .await
+            .unwrap();
+        let revoked_again = app
+            .clone()
+            .oneshot(bearer_request(&uri, "DELETE", &laptop.access_token))
+            .await
+            .unwrap();
+        let phone_request = app
+            .clone()
+            .oneshot(bearer_request("/sessions", "GET", &phone.access_token))
+            .await
+            .unwrap();
+        let phone_refresh = app
+            .clone()
+            .oneshot(json_request(
+                "/refresh",
+                "POST",
+                RefreshTokenDto {
+                    refresh_token: phone.refresh_token.clone(),
+                },
+            ))
+            .await
+            .unwrap();
+        let laptop_request = app
+            .oneshot(bearer_request("/sessions", "GET", &laptop.access_token))
+            .await
+            .unwrap();
+
+        assert_eq!(revoked.status(), StatusCode::NO_CONTENT);
+        assert_eq!(revoked_again.status(), StatusCode::NOT_FOUND);
+        assert_eq!(phone_request.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(phone_refresh.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(laptop_request.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_oidc_lists_configured_providers_and_rejects_unknown_ones() {
+        let app = setup_router().await;
+
+        let providers = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/oidc/providers")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(providers.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(providers.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let providers: OidcProvidersDto = serde_json::from_slice(&body).unwrap();
+        assert!(providers.providers.is_empty());
+
+        let authorize = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/oidc/github/authorize")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(authorize.status(), StatusCode::NOT_FOUND);
+
+        let callback = app
+            .oneshot(json_request(
+                "/oidc/github/callback",
+                "POST",
+                OidcCallbackDto {
+                    code: "code".to_string(),
+                    state: "state".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(callback.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,23 +1,62 @@
-use crate::auth::application::auth_service::AuthService;
+use crate::account::application::account_service::{AccountService, AccountSettings};
+use crate::account::domain::repositories::account_repository::IAccountRepository;
+use crate::account::infrastructure::persistence::repositories::account_postgres_repository::AccountPostgresRepository;
+use crate::admin::application::admin_service::AdminService;
+use crate::admin::domain::repositories::admin_repository::IAdminRepository;
+use crate::admin::infrastructure::persistence::repositories::admin_postgres_repository::AdminPostgresRepository;
+use crate::auth::application::auth_service::{AuthRepositories, AuthService, AuthSettings};
+use crate::auth::application::oidc_provider::IOidcProvider;
+use crate::auth::application::oidc_service::{OidcRepositories, OidcService, OidcSettings};
+use crate::auth::application::token_revocation_service::TokenRevocationService;
+use crate::auth::domain::entities::login_attempts::LoginLockoutPolicy;
+use crate::auth::domain::entities::verification_email_throttle::VerificationEmailThrottle;
 use crate::auth::domain::repositories::user_repository::IUserRepository;
+use crate::auth::infrastructure::persistence::repositories::email_change_request_postgres_repository::EmailChangeRequestPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::identity_postgres_repository::IdentityPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::login_attempt_postgres_repository::LoginAttemptPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::oidc_login_state_postgres_repository::OidcLoginStatePostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::password_reset_token_postgres_repository::PasswordResetTokenPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::personal_access_token_postgres_repository::PersonalAccessTokenPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::refresh_token_postgres_repository::RefreshTokenPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::session_postgres_repository::SessionPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::token_revocation_postgres_repository::TokenRevocationPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::two_factor_postgres_repository::TwoFactorPostgresRepository;
 use crate::auth::infrastructure::persistence::repositories::user_in_memory_repository::UserInMemoryRepository;
 use crate::auth::infrastructure::persistence::repositories::user_postgres_repository::UserPostgresRepository;
+use crate::auth::infrastructure::persistence::repositories::verification_email_postgres_repository::VerificationEmailPostgresRepository;
 use crate::auth::infrastructure::services::jwt_token_generator::JwtTokenGenerator;
+use crate::auth::infrastructure::services::oidc_http_provider::OidcHttpProvider;
 use crate::auth::infrastructure::services::postgres_email_queue_enqueuer::PostgresEmailQueueEnqueuer;
+use crate::digest::application::digest_service::DigestService;
+use crate::digest::domain::repositories::digest_repository::IDigestRepository;
+use crate::digest::infrastructure::persistence::repositories::digest_postgres_repository::DigestPostgresRepository;
+use crate::goals::application::goal_service::GoalService;
+use crate::goals::domain::repositories::goal_repository::IGoalRepository;
+use crate::goals::infrastructure::persistence::repositories::goal_postgres_repository::GoalPostgresRepository;
 use crate::positions::application::comment_service::CommentService;
 use crate::positions::application::position_service::PositionService;
+use crate::positions::application::staleness_service::StalenessService;
 use crate::positions::domain::repositories::comment_repository::ICommentRepository;
 use crate::positions::domain::repositories::position_repository::IPositionRepository;
+use crate::positions::domain::repositories::staleness_settings_repository::IStalenessSettingsRepository;
 use crate::positions::infrastructure::persistence::repositories::comment_postgres_repository::CommentPostgresRepository;
 use crate::positions::infrastructure::persistence::repositories::position_postgres_repository::PositionPostgresRepository;
+use crate::positions::infrastructure::persistence::repositories::staleness_settings_postgres_repository::StalenessSettingsPostgresRepository;
 use crate::shared::config::Config;
+use crate::shared::domain::audit::IAuditLog;
+use crate::shared::infrastructure::audit::audit_postgres_log::AuditPostgresLog;
 use crate::shared::infrastructure::postgres_conn::get_or_create_pool;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub async fn get_or_create_postgres_pool(config: &Config) -> sqlx::postgres::PgPool {
     get_or_create_pool(config).await
 }
 
+pub async fn create_audit_postgres_log(pool: sqlx::postgres::PgPool) -> AuditPostgresLog {
+    AuditPostgresLog::new(pool).await
+}
+
 pub async fn create_position_postgres_repository(
     pool: sqlx::postgres::PgPool,
 ) -> PositionPostgresRepository {
@@ -30,6 +69,36 @@ pub async fn create_comment_postgres_repository(
     CommentPostgresRepository::new(pool).await
 }
 
+pub async fn create_staleness_settings_postgres_repository(
+    pool: sqlx::postgres::PgPool,
+) -> StalenessSettingsPostgresRepository {
+    StalenessSettingsPostgresRepository::new(pool).await
+}
+
+pub async fn create_digest_postgres_repository(
+    pool: sqlx::postgres::PgPool,
+) -> DigestPostgresRepository {
+    DigestPostgresRepository::new(pool).await
+}
+
+pub async fn create_goal_postgres_repository(
+    pool: sqlx::postgres::PgPool,
+) -> GoalPostgresRepository {
+    GoalPostgresRepository::new(pool).await
+}
+
+pub async fn create_account_postgres_repository(
+    pool: sqlx::postgres::PgPool,
+) -> AccountPostgresRepository {
+    AccountPostgresRepository::new(pool).await
+}
+
+pub async fn create_admin_postgres_repository(
+    pool: sqlx::postgres::PgPool,
+) -> AdminPostgresRepository {
+    AdminPostgresRepository::new(pool).await
+}
+
 pub async fn create_user_in_memory_repository() -> UserInMemoryRepository {
     UserInMemoryRepository::default()
 }
@@ -40,6 +109,18 @@ pub async fn create_user_postgres_repository(
     UserPostgresRepository::new(pool).await
 }
 
+pub async fn create_personal_access_token_postgres_repository(
+    pool: sqlx::postgres::PgPool,
+) -> PersonalAccessTokenPostgresRepository {
+    PersonalAccessTokenPostgresRepository::new(pool).await
+}
+
+pub async fn create_session_postgres_repository(
+    pool: sqlx::postgres::PgPool,
+) -> SessionPostgresRepository {
+    SessionPostgresRepository::new(pool).await
+}
+
 pub async fn create_position_service(repo: Box<dyn IPositionRepository>) -> PositionService {
     PositionService::new(repo)
 }
@@ -48,17 +129,142 @@ pub async fn create_comment_service(repo: Box<dyn ICommentRepository>) -> Commen
     CommentService::new(repo)
 }
 
+pub async fn create_staleness_service(
+    settings_repo: Box<dyn IStalenessSettingsRepository>,
+    position_repo: Box<dyn IPositionRepository>,
+    config: Arc<Config>,
+) -> StalenessService {
+    StalenessService::new(settings_repo, position_repo, config.stale_after_days)
+}
+
+pub async fn create_digest_service(
+    repo: Box<dyn IDigestRepository>,
+    config: Arc<Config>,
+) -> DigestService {
+    DigestService::new(repo, config.frontend_url.clone(), config.stale_after_days)
+}
+
+pub async fn create_goal_service(repo: Box<dyn IGoalRepository>) -> GoalService {
+    GoalService::new(repo)
+}
+
+pub async fn create_account_service(
+    repo: Box<dyn IAccountRepository>,
+    audit_log: Arc<dyn IAuditLog>,
+    config: Arc<Config>,
+) -> AccountService {
+    AccountService::new(
+        repo,
+        audit_log,
+        AccountSettings {
+            frontend_url: config.frontend_url.clone(),
+            deletion_grace_period_secs: config.account_deletion_grace_period_secs,
+            unsubscribe_secret: config.unsubscribe_secret.clone(),
+        },
+    )
+}
+
+pub async fn create_admin_service(
+    repo: Box<dyn IAdminRepository>,
+    audit_log: Arc<dyn IAuditLog>,
+) -> AdminService {
+    AdminService::new(repo, audit_log)
+}
+
+pub async fn create_token_revocation_service(
+    pool: sqlx::postgres::PgPool,
+    config: Arc<Config>,
+) -> TokenRevocationService {
+    TokenRevocationService::new(
+        Box::new(TokenRevocationPostgresRepository::new(pool).await),
+        Duration::from_secs(config.token_revocation_cache_ttl_secs),
+    )
+}
+
 pub async fn create_auth_service(
     repo: Box<dyn IUserRepository>,
+    token_revocations: Arc<TokenRevocationService>,
+    audit_log: Arc<dyn IAuditLog>,
     pool: sqlx::postgres::PgPool,
     config: Arc<Config>,
 ) -> AuthService {
     let token_generator = Box::new(JwtTokenGenerator::new(config.clone()));
+    let refresh_token_repository =
+        Box::new(RefreshTokenPostgresRepository::new(pool.clone()).await);
+    let password_reset_token_repository =
+        Box::new(PasswordResetTokenPostgresRepository::new(pool.clone()).await);
+    let verification_email_repository =
+        Box::new(VerificationEmailPostgresRepository::new(pool.clone()).await);
+    let email_change_request_repository =
+        Box::new(EmailChangeRequestPostgresRepository::new(pool.clone()).await);
+    let two_factor_repository = Box::new(TwoFactorPostgresRepository::new(pool.clone()).await);
+    let login_attempt_repository =
+        Box::new(LoginAttemptPostgresRepository::new(pool.clone()).await);
+    let personal_access_token_repository =
+        Box::new(PersonalAccessTokenPostgresRepository::new(pool.clone()).await);
+    let session_repository = Box::new(SessionPostgresRepository::new(pool.clone()).await);
     let email_queue = Box::new(PostgresEmailQueueEnqueuer::new(pool));
     AuthService::new(
-        repo,
+        AuthRepositories {
+            users: repo,
+            refresh_tokens: refresh_token_repository,
+            password_reset_tokens: password_reset_token_repository,
+            verification_emails: verification_email_repository,
+            email_change_requests: email_change_request_repository,
+            two_factor: two_factor_repository,
+            login_attempts: login_attempt_repository,
+            personal_access_tokens: personal_access_token_repository,
+            sessions: session_repository,
+        },
         token_generator,
+        token_revocations,
         email_queue,
-        config.frontend_url.clone(),
+        audit_log,
+        AuthSettings {
+            frontend_url: config.frontend_url.clone(),
+            refresh_token_ttl_secs: config.refresh_token_expiration_time,
+            verification_email_throttle: VerificationEmailThrottle {
+                cooldown_secs: config.verification_email_cooldown_secs,
+                daily_cap: config.verification_email_daily_cap,
+            },
+            totp_issuer: config.totp_issuer.clone(),
+            login_lockout: LoginLockoutPolicy {
+                delay_after: config.login_delay_after_failures,
+                base_delay_secs: config.login_delay_base_secs,
+                max_delay_secs: config.login_delay_max_secs,
+                lockout_threshold: config.login_lockout_threshold,
+                lockout_secs: config.login_lockout_secs,
+            },
+        },
+    )
+}
+
+pub async fn create_oidc_service(
+    auth_service: Arc<AuthService>,
+    pool: sqlx::postgres::PgPool,
+    config: Arc<Config>,
+) -> OidcService {
+    let providers = config
+        .oidc_providers
+        .iter()
+        .map(|provider| {
+            (
+                provider.name.clone(),
+                Box::new(OidcHttpProvider::new(provider.clone())) as Box<dyn IOidcProvider>,
+            )
+        })
+        .collect();
+    OidcService::new(
+        providers,
+        OidcRepositories {
+            users: Box::new(UserPostgresRepository::new(pool.clone()).await),
+            identities: Box::new(IdentityPostgresRepository::new(pool.clone()).await),
+            login_states: Box::new(OidcLoginStatePostgresRepository::new(pool).await),
+        },
+        auth_service,
+        OidcSettings {
+            redirect_base_url: config.frontend_url.clone(),
+            login_state_ttl_secs: config.oidc_login_state_ttl_secs,
+        },
     )
 }
@@ -0,0 +1,165 @@
+use chrono::NaiveDate;
+use tracing::{error, info};
+
+use crate::{
+    digest::{
+        application::errors::DigestServiceError,
+        domain::{
+            entities::{
+                digest::{DigestEmail, DigestWeek, WeeklyDigest},
+                subscription::{DigestRecipient, DigestSubscription},
+            },
+            repositories::digest_repository::IDigestRepository,
+        },
+    },
+    shared::{
+        domain::value_objects::UserUuid, infrastructure::observability::current_trace_context,
+    },
+};
+
+pub struct DigestService {
+    repo: Box<dyn IDigestRepository>,
+    frontend_url: String,
+    default_stale_after_days: i64,
+}
+
+impl DigestService {
+    pub fn new(
+        repo: Box<dyn IDigestRepository>,
+        frontend_url: String,
+        default_stale_after_days: i64,
+    ) -> Self {
+        Self {
+            repo,
+            frontend_url,
+            default_stale_after_days,
+        }
+    }
+
+    pub async fn get_subscription(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<DigestSubscription, DigestServiceError> {
+        let subscription = self.repo.get_subscription(user_id).await?;
+        Ok(subscription.unwrap_or_else(|| DigestSubscription::new(user_id, false)))
+    }
+
+    pub async fn update_subscription(
+        &self,
+        subscription: DigestSubscription,
+    ) -> Result<(), DigestServiceError> {
+        self.repo.save_subscription(subscription).await?;
+        Ok(())
+    }
+
+    /// Enqueues the digest of the last complete week for every subscribed
+    /// user that has not received it yet. Safe to run as often as needed.
+    pub async fn send_weekly_digests(&self, today: NaiveDate) -> Result<usize, DigestServiceError> {
+        let week = DigestWeek::previous(today);
+        let recipients = self.repo.get_pending_recipients(week).await?;
+
+        let mut sent = 0;
+        for recipient in recipients {
+            match self.send_digest(&recipient, week, today).await {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    error!(
+                        user_id = %recipient.user_id,
+                        error = %e,
+                        "digest_service.send_weekly_digests failed"
+                    );
+                }
+            }
+        }
+
+        Ok(sent)
+    }
+
+    async fn send_digest(
+        &self,
+        recipient: &DigestRecipient,
+        week: DigestWeek,
+        today: NaiveDate,
+    ) -> Result<bool, DigestServiceError> {
+        let activity = self.repo.get_activity(recipient.user_id, week).await?;
+        let digest = WeeklyDigest::compose(week, activity, self.default_stale_after_days, today);
+        let email = DigestEmail::new(digest, &self.frontend_url);
+
+        let enqueued = self
+            .repo
+            .enqueue_digest(recipient, week, email, current_trace_context())
+            .await?;
+        if enqueued {
+            info!(
+                user_id = %recipient.user_id,
+                week_start = %week.start(),
+                "Weekly digest enqueued"
+            );
+        }
+        Ok(enqueued)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        digest::infrastructure::persistence::repositories::digest_in_memory_repository::DigestInMemoryRepository,
+        shared::{domain::value_objects::UserUuid, fixtures::date},
+    };
+
+    fn create_service(repo: DigestInMemoryRepository) -> DigestService {
+        DigestService::new(Box::new(repo), "http://localhost:3001".to_string(), 21)
+    }
+
+    #[tokio::test]
+    async fn test_subscription_defaults_to_disabled() {
+        let service = create_service(DigestInMemoryRepository::default());
+
+        let subscription = service.get_subscription(UserUuid::new()).await.unwrap();
+
+        assert!(!subscription.enabled);
+    }
+
+    #[tokio::test]
+    async fn test_send_weekly_digests_once_per_week() {
+        let repo = DigestInMemoryRepository::default();
+        let subscribed = UserUuid::new();
+        let unsubscribed = UserUuid::new();
+        repo.add_user(subscribed, "subscribed@example.com").await;
+        repo.add_user(unsubscribed, "unsubscribed@example.com")
+            .await;
+        let service = create_service(repo.clone());
+        service
+            .update_subscription(DigestSubscription::new(subscribed, true))
+            .await
+            .unwrap();
+        service
+            .update_subscription(DigestSubscription::new(unsubscribed, false))
+            .await
+            .unwrap();
+
+        let first = service
+            .send_weekly_digests(date("2026-01-12"))
+            .await
+            .unwrap();
+        let second = service
+            .send_weekly_digests(date("2026-01-14"))
+            .await
+            .unwrap();
+        let next_week = service
+            .send_weekly_digests(date("2026-01-19"))
+            .await
+            .unwrap();
+
+        assert_eq!(first, 1);
+        assert_eq!(second, 0);
+        assert_eq!(next_week, 1);
+        let sent = repo.sent().await;
+        assert_eq!(sent.len(), 2);
+        assert!(sent.iter().all(|(to, _, _)| to == "subscribed@example.com"));
+        assert_eq!(sent[0].1, date("2026-01-05"));
+        assert_eq!(sent[1].1, date("2026-01-12"));
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+use crate::digest::domain::errors::DigestRepoError;
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum DigestServiceError {
+    #[error("Repository error: `{0}`")]
+    RepositoryError(#[from] DigestRepoError),
+
+    #[error("Internal error: `{0}`")]
+    InternalError(String),
+}
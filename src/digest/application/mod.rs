@@ -0,0 +1,2 @@
+pub mod digest_service;
+pub mod errors;
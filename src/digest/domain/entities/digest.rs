@@ -0,0 +1,275 @@
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::shared::domain::{
+    email::{EmailCategory, IdempotencyKey},
+    position_status::PositionStatus,
+    staleness::Staleness,
+    value_objects::UserUuid,
+};
+
+/// Days without news after an interview before we suggest following up.
+pub const FOLLOW_UP_AFTER_DAYS: i64 = 7;
+
+/// A Monday-to-Sunday week.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigestWeek {
+    start: NaiveDate,
+}
+
+impl DigestWeek {
+    pub fn containing(date: NaiveDate) -> Self {
+        let offset = u64::from(date.weekday().num_days_from_monday());
+        Self {
+            start: date - Days::new(offset),
+        }
+    }
+
+    /// The last complete week before `today`, which is the one a digest sent
+    /// today summarizes.
+    pub fn previous(today: NaiveDate) -> Self {
+        Self::containing(today - Days::new(7))
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.start + Days::new(6)
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && date <= self.end()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigestPosition {
+    pub company: String,
+    pub role_title: String,
+    pub status: PositionStatus,
+    pub applied_on: NaiveDate,
+    pub last_activity_on: NaiveDate,
+}
+
+impl DigestPosition {
+    fn is_interviewing(&self) -> bool {
+        self.status.is_interview()
+    }
+
+    fn staleness(&self, stale_after_days: i64, today: NaiveDate) -> Staleness {
+        Staleness::of(
+            self.status.application_state(),
+            self.last_activity_on,
+            stale_after_days,
+            today,
+        )
+    }
+
+    fn days_idle(&self, today: NaiveDate) -> i64 {
+        (today - self.last_activity_on).num_days()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigestStatusChange {
+    pub company: String,
+    pub role_title: String,
+    pub from_status: String,
+    pub to_status: String,
+    pub changed_on: NaiveDate,
+}
+
+/// Raw data of a user the digest is composed from.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DigestActivity {
+    pub positions: Vec<DigestPosition>,
+    pub status_changes: Vec<DigestStatusChange>,
+    pub stale_after_days: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct WeeklyDigest {
+    pub week: DigestWeek,
+    pub new_applications: Vec<DigestPosition>,
+    pub status_changes: Vec<DigestStatusChange>,
+    pub upcoming_interviews: Vec<DigestPosition>,
+    pub stale_applications: Vec<DigestPosition>,
+    pub pending_follow_ups: Vec<DigestPosition>,
+}
+
+impl WeeklyDigest {
+    pub fn compose(
+        week: DigestWeek,
+        activity: DigestActivity,
+        default_stale_after_days: i64,
+        today: NaiveDate,
+    ) -> Self {
+        let stale_after_days = activity
+            .stale_after_days
+            .unwrap_or(default_stale_after_days);
+
+        let mut positions = activity.positions;
+        positions.sort_by(|a, b| {
+            a.applied_on
+                .cmp(&b.applied_on)
+                .then_with(|| a.company.cmp(&b.company))
+        });
+
+        let mut status_changes: Vec<DigestStatusChange> = activity
+            .status_changes
+            .into_iter()
+            .filter(|change| week.contains(change.changed_on))
+            .collect();
+        status_changes.sort_by_key(|change| change.changed_on);
+
+        let new_applications = positions
+            .iter()
+            .filter(|p| week.contains(p.applied_on))
+            .cloned()
+            .collect();
+        let upcoming_interviews = positions
+            .iter()
+            .filter(|p| p.is_interviewing())
+            .cloned()
+            .collect();
+        let stale_applications = positions
+            .iter()
+            .filter(|p| p.staleness(stale_after_days, today) == Staleness::Stale)
+            .cloned()
+            .collect();
+        let pending_follow_ups = positions
+            .iter()
+            .filter(|p| {
+                p.is_interviewing()
+                    && p.days_idle(today) >= FOLLOW_UP_AFTER_DAYS
+                    && p.staleness(stale_after_days, today) != Staleness::Stale
+            })
+            .cloned()
+            .collect();
+
+        Self {
+            week,
+            new_applications,
+            status_changes,
+            upcoming_interviews,
+            stale_applications,
+            pending_follow_ups,
+        }
+    }
+}
+
+/// A digest as queued for the email worker, which renders it with its
+/// `weekly_digest` template.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigestEmail {
+    pub digest: WeeklyDigest,
+    pub dashboard_link: String,
+}
+
+impl DigestEmail {
+    pub const TEMPLATE: &str = "weekly_digest";
+    pub const CATEGORY: EmailCategory = EmailCategory::Digests;
+
+    pub fn new(digest: WeeklyDigest, frontend_url: &str) -> Self {
+        Self {
+            digest,
+            dashboard_link: format!("{}/dashboard", frontend_url),
+        }
+    }
+
+    /// A user gets one digest per week.
+    pub fn idempotency_key(user_id: UserUuid, week: DigestWeek) -> IdempotencyKey {
+        IdempotencyKey::new(
+            Self::TEMPLATE,
+            &[&user_id.value().to_string(), &week.start().to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::fixtures::date;
+
+    fn position(
+        company: &str,
+        status: PositionStatus,
+        applied_on: &str,
+        last: &str,
+    ) -> DigestPosition {
+        DigestPosition {
+            company: company.to_string(),
+            role_title: "Engineer".to_string(),
+            status,
+            applied_on: date(applied_on),
+            last_activity_on: date(last),
+        }
+    }
+
+    #[test]
+    fn test_week_starts_on_monday() {
+        let week = DigestWeek::containing(date("2026-01-08"));
+
+        assert_eq!(week.start(), date("2026-01-05"));
+        assert_eq!(week.end(), date("2026-01-11"));
+        assert_eq!(
+            DigestWeek::previous(date("2026-01-12")).start(),
+            date("2026-01-05")
+        );
+    }
+
+    #[test]
+    fn test_compose_sections() {
+        let week = DigestWeek::containing(date("2026-01-05"));
+        let activity = DigestActivity {
+            positions: vec![
+                position("New", PositionStatus::CvSent, "2026-01-06", "2026-01-06"),
+                position(
+                    "Interview",
+                    PositionStatus::TechnicalInterview,
+                    "2025-12-20",
+                    "2026-01-09",
+                ),
+                position(
+                    "Waiting",
+                    PositionStatus::PhoneScreenScheduled,
+                    "2025-12-15",
+                    "2026-01-02",
+                ),
+                position("Silent", PositionStatus::CvSent, "2025-11-01", "2025-11-01"),
+                position(
+                    "Closed",
+                    PositionStatus::Rejected,
+                    "2025-11-01",
+                    "2025-11-01",
+                ),
+            ],
+            status_changes: vec![DigestStatusChange {
+                company: "Interview".to_string(),
+                role_title: "Engineer".to_string(),
+                from_status: "CvSent".to_string(),
+                to_status: "TechnicalInterview".to_string(),
+                changed_on: date("2026-01-09"),
+            }],
+            stale_after_days: None,
+        };
+
+        let digest = WeeklyDigest::compose(week, activity, 21, date("2026-01-12"));
+
+        let companies = |positions: &[DigestPosition]| {
+            positions
+                .iter()
+                .map(|p| p.company.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(companies(&digest.new_applications), vec!["New"]);
+        assert_eq!(digest.status_changes.len(), 1);
+        assert_eq!(
+            companies(&digest.upcoming_interviews),
+            vec!["Waiting", "Interview"]
+        );
+        assert_eq!(companies(&digest.stale_applications), vec!["Silent"]);
+        assert_eq!(companies(&digest.pending_follow_ups), vec!["Waiting"]);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::shared::domain::value_objects::UserUuid;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigestSubscription {
+    pub user_id: UserUuid,
+    pub enabled: bool,
+}
+
+impl DigestSubscription {
+    pub fn new(user_id: UserUuid, enabled: bool) -> Self {
+        Self { user_id, enabled }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigestRecipient {
+    pub user_id: UserUuid,
+    pub email: String,
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::digest::domain::{
+    entities::{
+        digest::{DigestActivity, DigestEmail, DigestWeek},
+        subscription::{DigestRecipient, DigestSubscription},
+    },
+    errors::DigestRepoError,
+};
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IDigestRepository: Send + Sync {
+    async fn get_subscription(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<DigestSubscription>, DigestRepoError>;
+    async fn save_subscription(
+        &self,
+        subscription: DigestSubscription,
+    ) -> Result<(), DigestRepoError>;
+    /// Subscribed users that have not been sent the digest for `week` yet.
+    async fn get_pending_recipients(
+        &self,
+        week: DigestWeek,
+    ) -> Result<Vec<DigestRecipient>, DigestRepoError>;
+    async fn get_activity(
+        &self,
+        user_id: UserUuid,
+        week: DigestWeek,
+    ) -> Result<DigestActivity, DigestRepoError>;
+    /// Records the digest of `week` as sent and enqueues the email atomically.
+    /// Returns `false` when the user already had a digest for that week.
+    async fn enqueue_digest(
+        &self,
+        recipient: &DigestRecipient,
+        week: DigestWeek,
+        email: DigestEmail,
+        trace_context: Option<String>,
+    ) -> Result<bool, DigestRepoError>;
+}
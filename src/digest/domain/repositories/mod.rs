@@ -0,0 +1 @@
+pub mod digest_repository;
@@ -0,0 +1,129 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio::sync::RwLock;
+
+use crate::{
+    digest::domain::{
+        entities::{
+            digest::{DigestActivity, DigestEmail, DigestWeek},
+            subscription::{DigestRecipient, DigestSubscription},
+        },
+        errors::DigestRepoError,
+        repositories::digest_repository::IDigestRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct DigestInMemoryRepository {
+    emails: Arc<RwLock<HashMap<uuid::Uuid, String>>>,
+    subscriptions: Arc<RwLock<Vec<DigestSubscription>>>,
+    activity: Arc<RwLock<HashMap<uuid::Uuid, DigestActivity>>>,
+    sent: Arc<RwLock<Vec<(String, NaiveDate, DigestEmail)>>>,
+}
+
+impl DigestInMemoryRepository {
+    pub async fn add_user(&self, user_id: UserUuid, email: &str) {
+        self.emails
+            .write()
+            .await
+            .insert(user_id.value(), email.to_string());
+    }
+
+    pub async fn set_activity(&self, user_id: UserUuid, activity: DigestActivity) {
+        self.activity
+            .write()
+            .await
+            .insert(user_id.value(), activity);
+    }
+
+    /// Enqueued digests as (recipient email, week start, email).
+    pub async fn sent(&self) -> Vec<(String, NaiveDate, DigestEmail)> {
+        self.sent.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl IDigestRepository for DigestInMemoryRepository {
+    async fn get_subscription(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<DigestSubscription>, DigestRepoError> {
+        Ok(self
+            .subscriptions
+            .read()
+            .await
+            .iter()
+            .find(|s| s.user_id == user_id)
+            .cloned())
+    }
+
+    async fn save_subscription(
+        &self,
+        subscription: DigestSubscription,
+    ) -> Result<(), DigestRepoError> {
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.retain(|s| s.user_id != subscription.user_id);
+        subscriptions.push(subscription);
+        Ok(())
+    }
+
+    async fn get_pending_recipients(
+        &self,
+        week: DigestWeek,
+    ) -> Result<Vec<DigestRecipient>, DigestRepoError> {
+        let emails = self.emails.read().await;
+        let sent = self.sent.read().await;
+        Ok(self
+            .subscriptions
+            .read()
+            .await
+            .iter()
+            .filter(|s| s.enabled)
+            .filter_map(|s| {
+                let email = emails.get(&s.user_id.value())?;
+                let already_sent = sent
+                    .iter()
+                    .any(|(to, week_start, _)| to == email && *week_start == week.start());
+                (!already_sent).then(|| DigestRecipient {
+                    user_id: s.user_id,
+                    email: email.clone(),
+                })
+            })
+            .collect())
+    }
+
+    async fn get_activity(
+        &self,
+        user_id: UserUuid,
+        _week: DigestWeek,
+    ) -> Result<DigestActivity, DigestRepoError> {
+        Ok(self
+            .activity
+            .read()
+            .await
+            .get(&user_id.value())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn enqueue_digest(
+        &self,
+        recipient: &DigestRecipient,
+        week: DigestWeek,
+        email: DigestEmail,
+        _trace_context: Option<String>,
+    ) -> Result<bool, DigestRepoError> {
+        let mut sent = self.sent.write().await;
+        if sent
+            .iter()
+            .any(|(to, week_start, _)| *to == recipient.email && *week_start == week.start())
+        {
+            return Ok(false);
+        }
+        sent.push((recipient.email.clone(), week.start(), email));
+        Ok(true)
+    }
+}
@@ -0,0 +1,423 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use sqlx::postgres::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    digest::domain::{
+        entities::{
+            digest::{DigestActivity, DigestEmail, DigestPosition, DigestStatusChange, DigestWeek},
+            subscription::{DigestRecipient, DigestSubscription},
+        },
+        errors::DigestRepoError,
+        repositories::digest_repository::IDigestRepository,
+    },
+    shared::{
+        domain::{position_status::PositionStatus, value_objects::UserUuid},
+        infrastructure::email_queue::email_queue_payload,
+    },
+};
+
+struct DigestPositionRow {
+    company: String,
+    role_title: String,
+    status: String,
+    applied_on: NaiveDate,
+    last_activity_on: NaiveDate,
+}
+
+impl TryFrom<DigestPositionRow> for DigestPosition {
+    type Error = DigestRepoError;
+
+    fn try_from(row: DigestPositionRow) -> Result<Self, Self::Error> {
+        Ok(DigestPosition {
+            company: row.company,
+            role_title: row.role_title,
+            status: PositionStatus::from_str(&row.status)
+                .map_err(|e| DigestRepoError::DatabaseError(e.to_string()))?,
+            applied_on: row.applied_on,
+            last_activity_on: row.last_activity_on,
+        })
+    }
+}
+
+struct DigestStatusChangeRow {
+    company: String,
+    role_title: String,
+    from_status: String,
+    to_status: String,
+    changed_at: NaiveDateTime,
+}
+
+pub struct DigestPostgresRepository {
+    pool: PgPool,
+}
+
+impl DigestPostgresRepository {
+    pub async fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn database_error(operation: &'static str, e: sqlx::Error) -> DigestRepoError {
+    error!(
+        error_kind = "database_error",
+        error = %e,
+        "digest_repo.{} failed",
+        operation
+    );
+    DigestRepoError::DatabaseError(e.to_string())
+}
+
+fn position_variables(positions: &[DigestPosition]) -> serde_json::Value {
+    positions
+        .iter()
+        .map(|p| {
+            serde_json::json!({
+                "company": p.company,
+                "role_title": p.role_title,
+                "status": p.status.to_string(),
+                "applied_on": p.applied_on.to_string(),
+                "last_activity_on": p.last_activity_on.to_string()
+            })
+        })
+        .collect()
+}
+
+fn template_variables(email: &DigestEmail) -> serde_json::Value {
+    let digest = &email.digest;
+    let status_changes: Vec<serde_json::Value> = digest
+        .status_changes
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "company": c.company,
+                "role_title": c.role_title,
+                "from_status": c.from_status,
+                "to_status": c.to_status,
+                "changed_on": c.changed_on.to_string()
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "week_start": digest.week.start().to_string(),
+        "week_end": digest.week.end().to_string(),
+        "dashboard_link": email.dashboard_link,
+        "new_applications": position_variables(&digest.new_applications),
+        "status_changes": status_changes,
+        "upcoming_interviews": position_variables(&digest.upcoming_interviews),
+        "stale_applications": position_variables(&digest.stale_applications),
+        "pending_follow_ups": position_variables(&digest.pending_follow_ups)
+    })
+}
+
+#[async_trait]
+impl IDigestRepository for DigestPostgresRepository {
+    async fn get_subscription(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<Option<DigestSubscription>, DigestRepoError> {
+        let enabled = sqlx::query_scalar!(
+            "SELECT enabled FROM digest_subscriptions WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get_subscription", e))?;
+
+        Ok(enabled.map(|enabled| DigestSubscription::new(user_id, enabled)))
+    }
+
+    async fn save_subscription(
+        &self,
+        subscription: DigestSubscription,
+    ) -> Result<(), DigestRepoError> {
+        sqlx::query!(
+            "INSERT INTO digest_subscriptions (user_id, enabled) VALUES ($1, $2) ON CONFLICT (user_id) DO UPDATE SET enabled = EXCLUDED.enabled, updated_at = NOW()",
+            subscription.user_id.value(),
+            subscription.enabled,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("save_subscription", e))?;
+
+        Ok(())
+    }
+
+    async fn get_pending_recipients(
+        &self,
+        week: DigestWeek,
+    ) -> Result<Vec<DigestRecipient>, DigestRepoError> {
+        // Users who turned digest emails off keep their subscription, but
+        // get nothing until they turn them back on.
+        let rows = sqlx::query!(
+            "SELECT u.id, u.email FROM digest_subscriptions s JOIN users u ON u.id = s.user_id WHERE s.enabled AND NOT u.account_disabled AND NOT EXISTS (SELECT 1 FROM weekly_digests d WHERE d.user_id = s.user_id AND d.week_start = $1) AND NOT EXISTS (SELECT 1 FROM notification_preferences n WHERE n.user_id = s.user_id AND n.category = $2 AND NOT n.enabled)",
+            week.start(),
+            DigestEmail::CATEGORY.as_str()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get_pending_recipients", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DigestRecipient {
+                user_id: UserUuid::from_uuid(row.id),
+                email: row.email,
+            })
+            .collect())
+    }
+
+    async fn get_activity(
+        &self,
+        user_id: UserUuid,
+        week: DigestWeek,
+    ) -> Result<DigestActivity, DigestRepoError> {
+        let positions = sqlx::query_as!(
+            DigestPositionRow,
+            r#"SELECT p.company, p.role_title, p.status, p.applied_on, GREATEST(p.applied_on, (SELECT MAX(h.changed_at)::date FROM position_status_history h WHERE h.position_id = p.id), (SELECT MAX(c.created_at)::date FROM comments c WHERE c.position_id = p.id)) AS "last_activity_on!" FROM positions p WHERE p.user_id = $1 AND NOT p.deleted"#,
+            user_id.value()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get_activity", e))?;
+
+        let week_start = week.start().and_time(NaiveTime::MIN);
+        let next_week_start = (week.end() + chrono::Days::new(1)).and_time(NaiveTime::MIN);
+        let status_changes = sqlx::query_as!(
+            DigestStatusChangeRow,
+            "SELECT p.company, p.role_title, h.from_status, h.to_status, h.changed_at FROM position_status_history h JOIN positions p ON p.id = h.position_id WHERE p.user_id = $1 AND NOT p.deleted AND h.changed_at >= $2 AND h.changed_at < $3 ORDER BY h.changed_at",
+            user_id.value(),
+            week_start,
+            next_week_start,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get_activity", e))?;
+
+        let stale_after_days = sqlx::query_scalar!(
+            "SELECT stale_after_days FROM staleness_settings WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get_activity", e))?;
+
+        Ok(DigestActivity {
+            positions: positions
+                .into_iter()
+                .map(DigestPosition::try_from)
+                .collect::<Result<_, _>>()?,
+            status_changes: status_changes
+                .into_iter()
+                .map(|row| DigestStatusChange {
+                    company: row.company,
+                    role_title: row.role_title,
+                    from_status: row.from_status,
+                    to_status: row.to_status,
+                    changed_on: row.changed_at.date(),
+                })
+                .collect(),
+            stale_after_days: stale_after_days.map(i64::from),
+        })
+    }
+
+    async fn enqueue_digest(
+        &self,
+        recipient: &DigestRecipient,
+        week: DigestWeek,
+        email: DigestEmail,
+        trace_context: Option<String>,
+    ) -> Result<bool, DigestRepoError> {
+        let payload = email_queue_payload(
+            &recipient.email,
+            DigestEmail::CATEGORY,
+            DigestEmail::TEMPLATE,
+            template_variables(&email),
+        );
+        let idempotency_key = DigestEmail::idempotency_key(recipient.user_id, week);
+        // The weekly_digests row decides whether the digest goes out: the
+        // email is queued only when this run claimed the user's week.
+        let queued = sqlx::query!(
+            "WITH claimed AS (INSERT INTO weekly_digests (user_id, week_start, email_queue_id) VALUES ($1, $2, $3) ON CONFLICT (user_id, week_start) DO NOTHING RETURNING email_queue_id) INSERT INTO email_queue (id, payload, user_id, trace_id, idempotency_key) SELECT email_queue_id, $4, $1, $5, $6 FROM claimed",
+            recipient.user_id.value(),
+            week.start(),
+            Uuid::new_v4(),
+            payload,
+            trace_context,
+            idempotency_key.as_str()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("enqueue_digest", e))?
+        .rows_affected()
+            == 1;
+
+        Ok(queued)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        digest::domain::entities::digest::WeeklyDigest,
+        shared::infrastructure::test_factory::TestFactory,
+    };
+
+    fn week() -> DigestWeek {
+        DigestWeek::containing(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+    }
+
+    fn email() -> DigestEmail {
+        let activity = DigestActivity {
+            positions: vec![DigestPosition {
+                company: "Rust Corp".to_string(),
+                role_title: "Engineer".to_string(),
+                status: PositionStatus::CvSent,
+                applied_on: NaiveDate::from_ymd_opt(2026, 1, 6).unwrap(),
+                last_activity_on: NaiveDate::from_ymd_opt(2026, 1, 6).unwrap(),
+            }],
+            ..DigestActivity::default()
+        };
+        let digest = WeeklyDigest::compose(
+            week(),
+            activity,
+            21,
+            NaiveDate::from_ymd_opt(2026, 1, 12).unwrap(),
+        );
+        DigestEmail::new(digest, "https://seeker.example")
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_digest_only_once_per_week() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = DigestPostgresRepository::new(factory.pool.clone()).await;
+
+        repository
+            .save_subscription(DigestSubscription::new(user.id, true))
+            .await
+            .expect("Should save subscription");
+        let recipients = repository.get_pending_recipients(week()).await.unwrap();
+        let recipient = recipients
+            .into_iter()
+            .find(|r| r.user_id == user.id)
+            .expect("Subscribed user should be pending");
+
+        let first = repository
+            .enqueue_digest(&recipient, week(), email(), None)
+            .await
+            .unwrap();
+        let second = repository
+            .enqueue_digest(&recipient, week(), email(), None)
+            .await
+            .unwrap();
+
+        assert!(first);
+        assert!(!second);
+        let queued = sqlx::query_scalar!(
+            "SELECT payload FROM email_queue WHERE user_id = $1",
+            user.id.value()
+        )
+        .fetch_all(&factory.pool)
+        .await
+        .unwrap();
+        assert_eq!(queued.len(), 1);
+        let idempotency_key = sqlx::query_scalar!(
+            "SELECT idempotency_key FROM email_queue WHERE user_id = $1",
+            user.id.value()
+        )
+        .fetch_one(&factory.pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            idempotency_key,
+            Some(format!("weekly_digest:{}:2026-01-05", user.id.value()))
+        );
+        assert_eq!(queued[0]["template"], "weekly_digest");
+        assert_eq!(queued[0]["category"], "digests");
+        assert_eq!(queued[0]["variables"]["week_start"], "2026-01-05");
+        assert_eq!(
+            queued[0]["variables"]["dashboard_link"],
+            "https://seeker.example/dashboard"
+        );
+        assert_eq!(
+            queued[0]["variables"]["new_applications"][0]["company"],
+            "Rust Corp"
+        );
+        assert!(
+            !repository
+                .get_pending_recipients(week())
+                .await
+                .unwrap()
+                .iter()
+                .any(|r| r.user_id == user.id)
+        );
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_users_who_turned_digest_emails_off_are_not_pending() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = DigestPostgresRepository::new(factory.pool.clone()).await;
+        repository
+            .save_subscription(DigestSubscription::new(user.id, true))
+            .await
+            .expect("Should save subscription");
+
+        sqlx::query!(
+            "INSERT INTO notification_preferences (user_id, category, enabled) VALUES ($1, 'digests', false)",
+            user.id.value(),
+        )
+        .execute(&factory.pool)
+        .await
+        .unwrap();
+
+        assert!(
+            !repository
+                .get_pending_recipients(week())
+                .await
+                .unwrap()
+                .iter()
+                .any(|r| r.user_id == user.id)
+        );
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_for_user() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = DigestPostgresRepository::new(factory.pool.clone()).await;
+
+        sqlx::query!(
+            "INSERT INTO positions (id, user_id, company, role_title, description, applied_on, url, status) VALUES ($1, $2, 'Rust Corp', 'Engineer', '', $3, '', 'CvSent')",
+            Uuid::new_v4(),
+            user.id.value(),
+            NaiveDate::from_ymd_opt(2026, 1, 6).unwrap(),
+        )
+        .execute(&factory.pool)
+        .await
+        .unwrap();
+
+        let activity = repository.get_activity(user.id, week()).await.unwrap();
+
+        assert_eq!(activity.positions.len(), 1);
+        assert_eq!(activity.positions[0].company, "Rust Corp");
+        assert_eq!(
+            activity.positions[0].last_activity_on,
+            NaiveDate::from_ymd_opt(2026, 1, 6).unwrap()
+        );
+        assert!(activity.status_changes.is_empty());
+        assert_eq!(activity.stale_after_days, None);
+
+        factory.teardown().await;
+    }
+}
@@ -0,0 +1,2 @@
+pub mod digest_in_memory_repository;
+pub mod digest_postgres_repository;
@@ -0,0 +1,25 @@
+use utoipa::ToSchema;
+
+use crate::{
+    digest::domain::entities::subscription::DigestSubscription,
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct DigestSubscriptionDto {
+    pub enabled: bool,
+}
+
+impl From<&DigestSubscription> for DigestSubscriptionDto {
+    fn from(subscription: &DigestSubscription) -> Self {
+        Self {
+            enabled: subscription.enabled,
+        }
+    }
+}
+
+impl DigestSubscriptionDto {
+    pub fn to_subscription(&self, user_id: UserUuid) -> DigestSubscription {
+        DigestSubscription::new(user_id, self.enabled)
+    }
+}
@@ -0,0 +1,45 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use thiserror::Error;
+
+use crate::{
+    digest::application::errors::DigestServiceError,
+    shared::{domain::errors::SharedDomainError, presentation::ApiErrorResponse},
+};
+
+#[derive(Error, Debug)]
+pub enum DigestApiError {
+    #[error("Service error: `{0}`")]
+    ServiceError(#[from] DigestServiceError),
+
+    #[error("Domain error: `{0}`")]
+    SharedDomainError(#[from] SharedDomainError),
+}
+
+impl IntoResponse for DigestApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            DigestApiError::ServiceError(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+            DigestApiError::SharedDomainError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
+        };
+
+        (status, Json(ApiErrorResponse { message })).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_error_response() {
+        let error = DigestApiError::from(DigestServiceError::InternalError("boom".to_string()));
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use axum::{Json, extract::State};
+
+use crate::{
+    digest::presentation::{
+        dtos::DigestSubscriptionDto, errors::DigestApiError, routes::DigestState,
+    },
+    shared::{
+        domain::value_objects::UserUuid, infrastructure::http::auth_extractor::AuthenticatedUser,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/digest/subscription",
+    responses(
+        (status = 200, description = "Weekly digest subscription of the current user", body = DigestSubscriptionDto),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Digest"
+)]
+pub async fn get_subscription(
+    user: AuthenticatedUser,
+    State(state): State<DigestState>,
+) -> Result<Json<DigestSubscriptionDto>, DigestApiError> {
+    let user_id = UserUuid::from_str(&user.0)?;
+    let subscription = state.service.get_subscription(user_id).await?;
+    Ok(Json(DigestSubscriptionDto::from(&subscription)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/digest/subscription",
+    request_body = DigestSubscriptionDto,
+    responses(
+        (status = 200, description = "Weekly digest subscription updated", body = DigestSubscriptionDto),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Digest"
+)]
+pub async fn update_subscription(
+    user: AuthenticatedUser,
+    State(state): State<DigestState>,
+    Json(payload): Json<DigestSubscriptionDto>,
+) -> Result<Json<DigestSubscriptionDto>, DigestApiError> {
+    let user_id = UserUuid::from_str(&user.0)?;
+    let subscription = payload.to_subscription(user_id);
+    state
+        .service
+        .update_subscription(subscription.clone())
+        .await?;
+    Ok(Json(DigestSubscriptionDto::from(&subscription)))
+}
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use axum::{Router, extract::FromRef, routing::get};
+
+use crate::{
+    digest::{
+        application::digest_service::DigestService,
+        presentation::handlers::{get_subscription, update_subscription},
+    },
+    shared::{config::Config, infrastructure::http::auth_extractor::UserStatusChecker},
+};
+
+#[derive(Clone)]
+pub struct DigestState {
+    pub service: Arc<DigestService>,
+    pub config: Arc<Config>,
+    pub user_checker: Arc<dyn UserStatusChecker>,
+}
+
+impl FromRef<DigestState> for Arc<Config> {
+    fn from_ref(state: &DigestState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<DigestState> for Arc<dyn UserStatusChecker> {
+    fn from_ref(state: &DigestState) -> Self {
+        state.user_checker.clone()
+    }
+}
+
+pub fn create_digest_routes(
+    service: Arc<DigestService>,
+    config: Arc<Config>,
+    user_checker: Arc<dyn UserStatusChecker>,
+) -> Router {
+    let state = DigestState {
+        service,
+        config,
+        user_checker,
+    };
+    Router::new()
+        .route(
+            "/subscription",
+            get(get_subscription).put(update_subscription),
+        )
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    struct MockUserStatusChecker;
+
+    #[async_trait::async_trait]
+    impl UserStatusChecker for MockUserStatusChecker {
+        async fn is_account_disabled(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn is_token_revoked(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            false
+        }
+
+        async fn is_session_active(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            true
+        }
+
+        async fn is_email_verified(&self, _user_id: &str) -> bool {
+            true
+        }
+
+        async fn is_admin(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn authenticate_personal_access_token(
+            &self,
+            _token: &str,
+        ) -> Option<crate::shared::infrastructure::http::auth_extractor::PersonalAccessTokenGrant>
+        {
+            None
+        }
+    }
+
+    fn setup_router() -> (Router, Config) {
+        let service = Arc::new(DigestService::new(
+            Box::new(
+                crate::digest::infrastructure::persistence::repositories::digest_in_memory_repository::DigestInMemoryRepository::default(),
+            ),
+            "http://localhost:3001".to_string(),
+            21,
+        ));
+        let config = Config::test_default();
+        (
+            create_digest_routes(
+                service,
+                Arc::new(config.clone()),
+                Arc::new(MockUserStatusChecker),
+            ),
+            config,
+        )
+    }
+
+    fn get_auth_header(config: &Config) -> String {
+        let token = crate::shared::infrastructure::http::auth_extractor::create_jwt(
+            &Uuid::new_v4().to_string(),
+            "test@example.com",
+            config,
+        )
+        .unwrap();
+        format!("Bearer {}", token)
+    }
+
+    #[tokio::test]
+    async fn test_opt_in_and_out_of_digest() {
+        let (app, config) = setup_router();
+        let auth_header = get_auth_header(&config);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/subscription")
+                    .header("content-type", "application/json")
+                    .header("Authorization", &auth_header)
+                    .body(Body::from(r#"{"enabled": true}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/subscription")
+                    .header("Authorization", &auth_header)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], br#"{"enabled":true}"#);
+    }
+
+    #[tokio::test]
+    async fn test_subscription_requires_authentication() {
+        let (app, _) = setup_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/subscription")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
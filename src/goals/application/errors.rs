@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+use crate::goals::domain::errors::GoalRepoError;
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum GoalServiceError {
+    #[error("Repository error: `{0}`")]
+    RepositoryError(#[from] GoalRepoError),
+}
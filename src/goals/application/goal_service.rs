@@ -0,0 +1,126 @@
+use chrono::NaiveDate;
+
+use crate::{
+    goals::{
+        application::errors::GoalServiceError,
+        domain::{
+            entities::{
+                goal::{Goal, GoalEvent, GoalUuid},
+                progress::GoalProgress,
+            },
+            repositories::goal_repository::IGoalRepository,
+        },
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+pub struct GoalService {
+    repo: Box<dyn IGoalRepository>,
+}
+
+impl GoalService {
+    pub fn new(repo: Box<dyn IGoalRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn create_goal(&self, goal: Goal) -> Result<(), GoalServiceError> {
+        self.repo.save(&goal).await?;
+        Ok(())
+    }
+
+    pub async fn update_goal(&self, goal: Goal) -> Result<(), GoalServiceError> {
+        self.repo.update(&goal).await?;
+        Ok(())
+    }
+
+    pub async fn get_goal(
+        &self,
+        id: GoalUuid,
+        user_id: UserUuid,
+    ) -> Result<Option<Goal>, GoalServiceError> {
+        Ok(self.repo.get(id, user_id).await?)
+    }
+
+    pub async fn get_goals(&self, user_id: UserUuid) -> Result<Vec<Goal>, GoalServiceError> {
+        Ok(self.repo.get_all(user_id).await?)
+    }
+
+    pub async fn remove_goal(
+        &self,
+        id: GoalUuid,
+        user_id: UserUuid,
+    ) -> Result<bool, GoalServiceError> {
+        Ok(self.repo.remove(id, user_id).await?)
+    }
+
+    pub async fn log_event(&self, event: GoalEvent) -> Result<(), GoalServiceError> {
+        self.repo.save_event(&event).await?;
+        Ok(())
+    }
+
+    pub async fn get_progress(
+        &self,
+        goal: &Goal,
+        today: NaiveDate,
+    ) -> Result<GoalProgress, GoalServiceError> {
+        let from = goal.first_period_start().min(goal.period.start_of(today));
+        let counts = self.repo.get_daily_counts(goal, from, today).await?;
+        Ok(GoalProgress::compute(goal, &counts, today))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::domain::entities::goal::{GoalMetric, GoalPeriod};
+    use crate::{
+        goals::infrastructure::persistence::repositories::goal_in_memory_repository::GoalInMemoryRepository,
+        shared::domain::value_objects::UserUuid,
+    };
+    use chrono::Local;
+
+    #[tokio::test]
+    async fn test_goals_are_scoped_to_their_owner() {
+        let service = GoalService::new(Box::new(GoalInMemoryRepository::default()));
+        let owner = UserUuid::new();
+        let Ok(goal) = Goal::new(owner, GoalMetric::Applications, GoalPeriod::Weekly, 10) else {
+            panic!("Expected valid goal");
+        };
+        service.create_goal(goal.clone()).await.unwrap();
+
+        assert_eq!(service.get_goals(owner).await.unwrap(), vec![goal.clone()]);
+        assert_eq!(
+            service.get_goal(goal.id, UserUuid::new()).await.unwrap(),
+            None
+        );
+        assert!(!service.remove_goal(goal.id, UserUuid::new()).await.unwrap());
+        assert!(service.remove_goal(goal.id, owner).await.unwrap());
+        assert!(service.get_goals(owner).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_progress_counts_logged_events() {
+        let service = GoalService::new(Box::new(GoalInMemoryRepository::default()));
+        let today = Local::now().date_naive();
+        let Ok(goal) = Goal::new(
+            UserUuid::new(),
+            GoalMetric::Networking,
+            GoalPeriod::Daily,
+            2,
+        ) else {
+            panic!("Expected valid goal");
+        };
+        service.create_goal(goal.clone()).await.unwrap();
+
+        for _ in 0..2 {
+            let event = GoalEvent::new(&goal, today, Some("Coffee chat".to_string())).unwrap();
+            service.log_event(event).await.unwrap();
+        }
+
+        let progress = service.get_progress(&goal, today).await.unwrap();
+        let current = progress.current().unwrap();
+        assert_eq!(current.count, 2);
+        assert!(current.is_met());
+        assert_eq!(progress.streak().current, 1);
+    }
+}
@@ -0,0 +1,349 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate};
+use uuid::Uuid;
+
+use crate::{goals::domain::errors::GoalDomainError, shared::domain::value_objects::UserUuid};
+
+pub const MIN_GOAL_TARGET: i64 = 1;
+pub const MAX_GOAL_TARGET: i64 = 1000;
+
+#[derive(PartialEq, Clone, Debug, Copy)]
+pub struct GoalUuid {
+    id: Uuid,
+}
+
+impl Default for GoalUuid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GoalUuid {
+    pub fn value(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn new() -> Self {
+        GoalUuid { id: Uuid::new_v4() }
+    }
+
+    pub fn from_uuid(id: Uuid) -> Self {
+        GoalUuid { id }
+    }
+}
+
+impl FromStr for GoalUuid {
+    type Err = GoalDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = Uuid::parse_str(s)?;
+        Ok(GoalUuid { id })
+    }
+}
+
+impl std::fmt::Display for GoalUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+/// What a goal counts. Everything but `Networking` is derived from the
+/// user's positions; networking contacts are logged by hand as goal events.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GoalMetric {
+    Applications,
+    Interviews,
+    Offers,
+    Networking,
+}
+
+impl GoalMetric {
+    pub fn accepts_events(&self) -> bool {
+        matches!(self, GoalMetric::Networking)
+    }
+}
+
+impl FromStr for GoalMetric {
+    type Err = GoalDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "applications" => Ok(GoalMetric::Applications),
+            "interviews" => Ok(GoalMetric::Interviews),
+            "offers" => Ok(GoalMetric::Offers),
+            "networking" => Ok(GoalMetric::Networking),
+            _ => Err(GoalDomainError::InvalidMetric(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for GoalMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            GoalMetric::Applications => "applications",
+            GoalMetric::Interviews => "interviews",
+            GoalMetric::Offers => "offers",
+            GoalMetric::Networking => "networking",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GoalPeriod {
+    Daily,
+    Weekly,
+}
+
+impl GoalPeriod {
+    /// First day of the period containing `date`. Weeks start on Monday.
+    pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            GoalPeriod::Daily => date,
+            GoalPeriod::Weekly => {
+                date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+            }
+        }
+    }
+
+    pub fn length_in_days(&self) -> u64 {
+        match self {
+            GoalPeriod::Daily => 1,
+            GoalPeriod::Weekly => 7,
+        }
+    }
+}
+
+impl FromStr for GoalPeriod {
+    type Err = GoalDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "daily" => Ok(GoalPeriod::Daily),
+            "weekly" => Ok(GoalPeriod::Weekly),
+            _ => Err(GoalDomainError::InvalidPeriod(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for GoalPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            GoalPeriod::Daily => "daily",
+            GoalPeriod::Weekly => "weekly",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Goal {
+    pub id: GoalUuid,
+    pub user_id: UserUuid,
+    pub metric: GoalMetric,
+    pub period: GoalPeriod,
+    target: i64,
+    pub created_at: DateTime<Local>,
+    pub updated_at: DateTime<Local>,
+}
+
+fn validate_target(target: i64) -> Result<i64, GoalDomainError> {
+    if !(MIN_GOAL_TARGET..=MAX_GOAL_TARGET).contains(&target) {
+        return Err(GoalDomainError::InvalidTarget(target));
+    }
+    Ok(target)
+}
+
+impl Goal {
+    pub fn new(
+        user_id: UserUuid,
+        metric: GoalMetric,
+        period: GoalPeriod,
+        target: i64,
+    ) -> Result<Self, GoalDomainError> {
+        let now = Local::now();
+        Ok(Self {
+            id: GoalUuid::new(),
+            user_id,
+            metric,
+            period,
+            target: validate_target(target)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Rebuilds a goal that was already validated when it was stored.
+    pub fn restore(
+        id: GoalUuid,
+        user_id: UserUuid,
+        metric: GoalMetric,
+        period: GoalPeriod,
+        target: i64,
+        created_at: DateTime<Local>,
+        updated_at: DateTime<Local>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            metric,
+            period,
+            target,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn target(&self) -> i64 {
+        self.target
+    }
+
+    pub fn change(
+        &mut self,
+        metric: GoalMetric,
+        period: GoalPeriod,
+        target: i64,
+    ) -> Result<(), GoalDomainError> {
+        self.target = validate_target(target)?;
+        self.metric = metric;
+        self.period = period;
+        self.updated_at = Local::now();
+        Ok(())
+    }
+
+    /// First period the goal is tracked for: the one it was created in.
+    pub fn first_period_start(&self) -> NaiveDate {
+        self.period.start_of(self.created_at.date_naive())
+    }
+}
+
+/// A manually logged occurrence for goals whose metric is not derived from
+/// positions, e.g. a networking contact.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GoalEvent {
+    pub id: Uuid,
+    pub goal_id: GoalUuid,
+    pub occurred_on: NaiveDate,
+    pub note: Option<String>,
+}
+
+impl GoalEvent {
+    pub fn new(
+        goal: &Goal,
+        occurred_on: NaiveDate,
+        note: Option<String>,
+    ) -> Result<Self, GoalDomainError> {
+        if !goal.metric.accepts_events() {
+            return Err(GoalDomainError::EventsNotSupported(goal.metric.to_string()));
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            goal_id: goal.id,
+            occurred_on,
+            note,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::fixtures::date;
+
+    #[test]
+    fn test_target_out_of_range() {
+        let result = Goal::new(
+            UserUuid::new(),
+            GoalMetric::Applications,
+            GoalPeriod::Weekly,
+            0,
+        );
+
+        assert_eq!(result, Err(GoalDomainError::InvalidTarget(0)));
+    }
+
+    #[test]
+    fn test_metric_and_period_round_trip() {
+        for metric in ["applications", "interviews", "offers", "networking"] {
+            let Ok(parsed) = GoalMetric::from_str(metric) else {
+                panic!("Expected valid metric");
+            };
+            assert_eq!(parsed.to_string(), metric);
+        }
+        assert_eq!(GoalPeriod::from_str("weekly"), Ok(GoalPeriod::Weekly));
+        assert_eq!(
+            GoalPeriod::from_str("yearly"),
+            Err(GoalDomainError::InvalidPeriod("yearly".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_weekly_period_starts_on_monday() {
+        // 2026-03-05 is a Thursday
+        assert_eq!(
+            GoalPeriod::Weekly.start_of(date("2026-03-05")),
+            date("2026-03-02")
+        );
+        assert_eq!(
+            GoalPeriod::Weekly.start_of(date("2026-03-02")),
+            date("2026-03-02")
+        );
+        assert_eq!(
+            GoalPeriod::Daily.start_of(date("2026-03-05")),
+            date("2026-03-05")
+        );
+    }
+
+    #[test]
+    fn test_change_validates_target() {
+        let Ok(mut goal) = Goal::new(
+            UserUuid::new(),
+            GoalMetric::Applications,
+            GoalPeriod::Weekly,
+            10,
+        ) else {
+            panic!("Expected valid goal");
+        };
+
+        assert_eq!(
+            goal.change(GoalMetric::Offers, GoalPeriod::Weekly, 5000),
+            Err(GoalDomainError::InvalidTarget(5000))
+        );
+        assert_eq!(goal.target(), 10);
+        assert_eq!(
+            goal.change(GoalMetric::Offers, GoalPeriod::Daily, 1),
+            Ok(())
+        );
+        assert_eq!(goal.metric, GoalMetric::Offers);
+        assert_eq!(goal.period, GoalPeriod::Daily);
+    }
+
+    #[test]
+    fn test_events_only_for_networking_goals() {
+        let Ok(applications) = Goal::new(
+            UserUuid::new(),
+            GoalMetric::Applications,
+            GoalPeriod::Weekly,
+            10,
+        ) else {
+            panic!("Expected valid goal");
+        };
+        let Ok(networking) = Goal::new(
+            UserUuid::new(),
+            GoalMetric::Networking,
+            GoalPeriod::Weekly,
+            2,
+        ) else {
+            panic!("Expected valid goal");
+        };
+
+        assert_eq!(
+            GoalEvent::new(&applications, date("2026-03-05"), None),
+            Err(GoalDomainError::EventsNotSupported(
+                "applications".to_string()
+            ))
+        );
+        assert!(GoalEvent::new(&networking, date("2026-03-05"), None).is_ok());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod goal;
+pub mod progress;
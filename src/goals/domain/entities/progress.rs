@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use chrono::{Days, NaiveDate};
+
+use crate::goals::domain::entities::goal::Goal;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DailyCount {
+    pub day: NaiveDate,
+    pub count: i64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PeriodProgress {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub count: i64,
+    pub target: i64,
+}
+
+impl PeriodProgress {
+    pub fn is_met(&self) -> bool {
+        self.count >= self.target
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct GoalStreak {
+    pub current: usize,
+    pub longest: usize,
+}
+
+/// Progress of a goal for every period since it was created, oldest first.
+/// The last period is the one in progress. Past periods are measured
+/// against the goal's current target.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GoalProgress {
+    periods: Vec<PeriodProgress>,
+}
+
+impl GoalProgress {
+    pub fn compute(goal: &Goal, counts: &[DailyCount], today: NaiveDate) -> Self {
+        let period = goal.period;
+        let mut per_period: HashMap<NaiveDate, i64> = HashMap::new();
+        for daily in counts {
+            *per_period.entry(period.start_of(daily.day)).or_default() += daily.count;
+        }
+
+        let current_start = period.start_of(today);
+        let mut start = goal.first_period_start().min(current_start);
+        let mut periods = Vec::new();
+        while start <= current_start {
+            let next_start = start + Days::new(period.length_in_days());
+            periods.push(PeriodProgress {
+                period_start: start,
+                period_end: next_start - Days::new(1),
+                count: per_period.get(&start).copied().unwrap_or_default(),
+                target: goal.target(),
+            });
+            start = next_start;
+        }
+
+        Self { periods }
+    }
+
+    pub fn current(&self) -> Option<&PeriodProgress> {
+        self.periods.last()
+    }
+
+    /// The latest `limit` periods, newest first.
+    pub fn history(&self, limit: usize) -> Vec<PeriodProgress> {
+        self.periods.iter().rev().take(limit).copied().collect()
+    }
+
+    /// Consecutive periods in which the goal was met. The period in progress
+    /// extends the current streak once met but never breaks it.
+    pub fn streak(&self) -> GoalStreak {
+        let mut longest = 0;
+        let mut run = 0;
+        for progress in &self.periods {
+            if progress.is_met() {
+                run += 1;
+                longest = longest.max(run);
+            } else {
+                run = 0;
+            }
+        }
+
+        let mut newest_first = self.periods.iter().rev().peekable();
+        if newest_first
+            .peek()
+            .is_some_and(|progress| !progress.is_met())
+        {
+            newest_first.next();
+        }
+        let current = newest_first
+            .take_while(|progress| progress.is_met())
+            .count();
+
+        GoalStreak { current, longest }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        goals::domain::entities::goal::{GoalMetric, GoalPeriod, GoalUuid},
+        shared::{domain::value_objects::UserUuid, fixtures::date},
+    };
+    use chrono::{Local, NaiveTime, TimeDelta, TimeZone};
+
+    fn weekly_goal(created_on: NaiveDate, target: i64) -> Goal {
+        let noon = created_on.and_time(NaiveTime::MIN) + TimeDelta::hours(12);
+        let created_at = Local.from_utc_datetime(&noon);
+        Goal::restore(
+            GoalUuid::new(),
+            UserUuid::new(),
+            GoalMetric::Applications,
+            GoalPeriod::Weekly,
+            target,
+            created_at,
+            created_at,
+        )
+    }
+
+    fn count(day: NaiveDate, count: i64) -> DailyCount {
+        DailyCount { day, count }
+    }
+
+    #[test]
+    fn test_counts_are_grouped_by_period() {
+        // Goal created on Wednesday 2026-03-04, today is Tuesday 2026-03-17
+        let goal = weekly_goal(date("2026-03-04"), 3);
+        let counts = [
+            count(date("2026-03-02"), 2),
+            count(date("2026-03-08"), 1),
+            count(date("2026-03-10"), 1),
+            count(date("2026-03-16"), 4),
+        ];
+
+        let progress = GoalProgress::compute(&goal, &counts, date("2026-03-17"));
+        let history = progress.history(10);
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].period_start, date("2026-03-16"));
+        assert_eq!(history[0].period_end, date("2026-03-22"));
+        assert_eq!(history[0].count, 4);
+        assert_eq!(history[1].count, 1);
+        assert_eq!(history[2].count, 3);
+        assert!(history[2].is_met());
+        assert_eq!(progress.current(), history.first());
+    }
+
+    #[test]
+    fn test_history_is_limited() {
+        let goal = weekly_goal(date("2026-01-05"), 1);
+
+        let progress = GoalProgress::compute(&goal, &[], date("2026-03-17"));
+
+        assert_eq!(progress.history(4).len(), 4);
+        assert_eq!(progress.history(4)[3].period_start, date("2026-02-23"));
+    }
+
+    #[test]
+    fn test_unfinished_current_period_does_not_break_streak() {
+        let goal = weekly_goal(date("2026-02-02"), 2);
+        let counts = [
+            count(date("2026-02-02"), 2),
+            count(date("2026-02-17"), 1),
+            count(date("2026-02-23"), 2),
+            count(date("2026-03-02"), 3),
+            count(date("2026-03-09"), 2),
+        ];
+
+        let progress = GoalProgress::compute(&goal, &counts, date("2026-03-17"));
+
+        assert_eq!(
+            progress.streak(),
+            GoalStreak {
+                current: 3,
+                longest: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_met_current_period_extends_streak() {
+        let goal = weekly_goal(date("2026-03-02"), 1);
+        let counts = [
+            count(date("2026-03-02"), 1),
+            count(date("2026-03-09"), 1),
+            count(date("2026-03-16"), 1),
+        ];
+
+        let progress = GoalProgress::compute(&goal, &counts, date("2026-03-17"));
+
+        assert_eq!(
+            progress.streak(),
+            GoalStreak {
+                current: 3,
+                longest: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_missed_period_resets_current_streak() {
+        let goal = weekly_goal(date("2026-02-02"), 1);
+        let counts = [
+            count(date("2026-02-02"), 1),
+            count(date("2026-02-09"), 1),
+            count(date("2026-02-16"), 1),
+            count(date("2026-03-02"), 1),
+        ];
+
+        let progress = GoalProgress::compute(&goal, &counts, date("2026-03-17"));
+
+        assert_eq!(
+            progress.streak(),
+            GoalStreak {
+                current: 0,
+                longest: 3
+            }
+        );
+    }
+}
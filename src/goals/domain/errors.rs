@@ -0,0 +1,60 @@
+use thiserror::Error;
+
+use crate::goals::domain::entities::goal::GoalUuid;
+use crate::shared::domain::errors::SharedDomainError;
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum GoalDomainError {
+    #[error(transparent)]
+    Shared(#[from] SharedDomainError),
+
+    #[error("Invalid goal metric: `{0}`")]
+    InvalidMetric(String),
+
+    #[error("Invalid goal period: `{0}`")]
+    InvalidPeriod(String),
+
+    #[error("Invalid goal target: `{0}`")]
+    InvalidTarget(i64),
+
+    #[error("Goals tracking `{0}` are computed from positions and cannot log events")]
+    EventsNotSupported(String),
+}
+
+impl From<uuid::Error> for GoalDomainError {
+    fn from(e: uuid::Error) -> Self {
+        Self::Shared(SharedDomainError::InvalidUuid(e))
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum GoalRepoError {
+    #[error("Database error: `{0}`")]
+    DatabaseError(String),
+
+    #[error("Goal not found: `{0}`")]
+    NotFound(GoalUuid),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goal_repo_database_error() {
+        let error = GoalRepoError::DatabaseError("connection failed".to_string());
+        assert_eq!(error.to_string(), "Database error: `connection failed`");
+    }
+
+    #[test]
+    fn test_goal_repo_not_found_error() {
+        let error = GoalRepoError::NotFound(GoalUuid::new());
+        assert!(error.to_string().contains("Goal not found"));
+    }
+
+    #[test]
+    fn test_invalid_target_error() {
+        let error = GoalDomainError::InvalidTarget(0);
+        assert_eq!(error.to_string(), "Invalid goal target: `0`");
+    }
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::goals::domain::{
+    entities::{
+        goal::{Goal, GoalEvent, GoalUuid},
+        progress::DailyCount,
+    },
+    errors::GoalRepoError,
+};
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IGoalRepository: Send + Sync {
+    async fn save(&self, goal: &Goal) -> Result<(), GoalRepoError>;
+    async fn update(&self, goal: &Goal) -> Result<(), GoalRepoError>;
+    async fn get(&self, id: GoalUuid, user_id: UserUuid) -> Result<Option<Goal>, GoalRepoError>;
+    async fn get_all(&self, user_id: UserUuid) -> Result<Vec<Goal>, GoalRepoError>;
+    /// Returns `false` when the user has no goal with that id.
+    async fn remove(&self, id: GoalUuid, user_id: UserUuid) -> Result<bool, GoalRepoError>;
+    async fn save_event(&self, event: &GoalEvent) -> Result<(), GoalRepoError>;
+    /// Per-day count of whatever `goal.metric` measures, between `from` and
+    /// `to` inclusive. Days without activity are omitted.
+    async fn get_daily_counts(
+        &self,
+        goal: &Goal,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyCount>, GoalRepoError>;
+}
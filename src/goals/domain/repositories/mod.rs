@@ -0,0 +1 @@
+pub mod goal_repository;
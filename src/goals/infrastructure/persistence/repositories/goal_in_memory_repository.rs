@@ -0,0 +1,125 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio::sync::RwLock;
+
+use crate::{
+    goals::domain::{
+        entities::{
+            goal::{Goal, GoalEvent, GoalMetric, GoalUuid},
+            progress::DailyCount,
+        },
+        errors::GoalRepoError,
+        repositories::goal_repository::IGoalRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct GoalInMemoryRepository {
+    goals: Arc<RwLock<Vec<Goal>>>,
+    events: Arc<RwLock<Vec<GoalEvent>>>,
+    activity: Arc<RwLock<Vec<(UserUuid, GoalMetric, NaiveDate)>>>,
+}
+
+impl GoalInMemoryRepository {
+    /// Records an occurrence of a position-derived metric, standing in for
+    /// the positions and status history tables.
+    pub async fn record_activity(&self, user_id: UserUuid, metric: GoalMetric, day: NaiveDate) {
+        self.activity.write().await.push((user_id, metric, day));
+    }
+}
+
+#[async_trait]
+impl IGoalRepository for GoalInMemoryRepository {
+    async fn save(&self, goal: &Goal) -> Result<(), GoalRepoError> {
+        self.goals.write().await.push(goal.clone());
+        Ok(())
+    }
+
+    async fn update(&self, goal: &Goal) -> Result<(), GoalRepoError> {
+        let mut goals = self.goals.write().await;
+        match goals
+            .iter_mut()
+            .find(|g| g.id == goal.id && g.user_id == goal.user_id)
+        {
+            Some(existing) => {
+                *existing = goal.clone();
+                Ok(())
+            }
+            None => Err(GoalRepoError::NotFound(goal.id)),
+        }
+    }
+
+    async fn get(&self, id: GoalUuid, user_id: UserUuid) -> Result<Option<Goal>, GoalRepoError> {
+        Ok(self
+            .goals
+            .read()
+            .await
+            .iter()
+            .find(|g| g.id == id && g.user_id == user_id)
+            .cloned())
+    }
+
+    async fn get_all(&self, user_id: UserUuid) -> Result<Vec<Goal>, GoalRepoError> {
+        Ok(self
+            .goals
+            .read()
+            .await
+            .iter()
+            .filter(|g| g.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn remove(&self, id: GoalUuid, user_id: UserUuid) -> Result<bool, GoalRepoError> {
+        let mut goals = self.goals.write().await;
+        let before = goals.len();
+        goals.retain(|g| !(g.id == id && g.user_id == user_id));
+        let removed = goals.len() != before;
+        if removed {
+            self.events.write().await.retain(|e| e.goal_id != id);
+        }
+        Ok(removed)
+    }
+
+    async fn save_event(&self, event: &GoalEvent) -> Result<(), GoalRepoError> {
+        self.events.write().await.push(event.clone());
+        Ok(())
+    }
+
+    async fn get_daily_counts(
+        &self,
+        goal: &Goal,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyCount>, GoalRepoError> {
+        let days: Vec<NaiveDate> = if goal.metric.accepts_events() {
+            self.events
+                .read()
+                .await
+                .iter()
+                .filter(|e| e.goal_id == goal.id)
+                .map(|e| e.occurred_on)
+                .collect()
+        } else {
+            self.activity
+                .read()
+                .await
+                .iter()
+                .filter(|(user_id, metric, _)| *user_id == goal.user_id && *metric == goal.metric)
+                .map(|(_, _, day)| *day)
+                .collect()
+        };
+
+        let mut counts: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+        for day in days.into_iter().filter(|day| (from..=to).contains(day)) {
+            *counts.entry(day).or_default() += 1;
+        }
+        Ok(counts
+            .into_iter()
+            .map(|(day, count)| DailyCount { day, count })
+            .collect())
+    }
+}
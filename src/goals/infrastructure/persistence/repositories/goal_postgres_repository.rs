@@ -0,0 +1,371 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Days, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use sqlx::postgres::PgPool;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::{
+    goals::domain::{
+        entities::{
+            goal::{Goal, GoalEvent, GoalMetric, GoalPeriod, GoalUuid},
+            progress::DailyCount,
+        },
+        errors::GoalRepoError,
+        repositories::goal_repository::IGoalRepository,
+    },
+    shared::domain::{position_status::PositionStatus, value_objects::UserUuid},
+};
+
+struct GoalRow {
+    id: Uuid,
+    user_id: Uuid,
+    metric: String,
+    period: String,
+    target: i32,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+impl TryFrom<GoalRow> for Goal {
+    type Error = GoalRepoError;
+
+    fn try_from(row: GoalRow) -> Result<Self, Self::Error> {
+        let metric = GoalMetric::from_str(&row.metric)
+            .map_err(|e| GoalRepoError::DatabaseError(e.to_string()))?;
+        let period = GoalPeriod::from_str(&row.period)
+            .map_err(|e| GoalRepoError::DatabaseError(e.to_string()))?;
+        Ok(Goal::restore(
+            GoalUuid::from_uuid(row.id),
+            UserUuid::from_uuid(row.user_id),
+            metric,
+            period,
+            i64::from(row.target),
+            DateTime::<Local>::from(Utc.from_utc_datetime(&row.created_at)),
+            DateTime::<Local>::from(Utc.from_utc_datetime(&row.updated_at)),
+        ))
+    }
+}
+
+struct DailyCountRow {
+    day: NaiveDate,
+    count: i64,
+}
+
+pub struct GoalPostgresRepository {
+    pool: PgPool,
+}
+
+impl GoalPostgresRepository {
+    pub async fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn status_change_counts(
+        &self,
+        user_id: UserUuid,
+        statuses: &[PositionStatus],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyCountRow>, sqlx::Error> {
+        let statuses: Vec<String> = statuses.iter().map(PositionStatus::to_string).collect();
+        let from = from.and_time(NaiveTime::MIN);
+        let until = (to + Days::new(1)).and_time(NaiveTime::MIN);
+        sqlx::query_as!(
+            DailyCountRow,
+            r#"SELECT h.changed_at::date AS "day!", COUNT(*) AS "count!" FROM position_status_history h JOIN positions p ON p.id = h.position_id WHERE p.user_id = $1 AND NOT p.deleted AND h.to_status = ANY($2::text[]) AND h.changed_at >= $3 AND h.changed_at < $4 GROUP BY 1 ORDER BY 1"#,
+            user_id.value(),
+            &statuses,
+            from,
+            until,
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+}
+
+fn database_error(operation: &'static str, e: sqlx::Error) -> GoalRepoError {
+    error!(
+        error_kind = "database_error",
+        error = %e,
+        "goal_repo.{} failed",
+        operation
+    );
+    GoalRepoError::DatabaseError(e.to_string())
+}
+
+#[async_trait]
+impl IGoalRepository for GoalPostgresRepository {
+    async fn save(&self, goal: &Goal) -> Result<(), GoalRepoError> {
+        sqlx::query!(
+            "INSERT INTO goals (id, user_id, metric, period, target, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            goal.id.value(),
+            goal.user_id.value(),
+            goal.metric.to_string(),
+            goal.period.to_string(),
+            goal.target() as i32,
+            goal.created_at.naive_utc(),
+            goal.updated_at.naive_utc(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("save", e))?;
+
+        Ok(())
+    }
+
+    async fn update(&self, goal: &Goal) -> Result<(), GoalRepoError> {
+        let result = sqlx::query!(
+            "UPDATE goals SET metric = $1, period = $2, target = $3, updated_at = $4 WHERE id = $5 AND user_id = $6",
+            goal.metric.to_string(),
+            goal.period.to_string(),
+            goal.target() as i32,
+            goal.updated_at.naive_utc(),
+            goal.id.value(),
+            goal.user_id.value(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("update", e))?;
+
+        if result.rows_affected() == 0 {
+            warn!(
+                goal_id = %goal.id,
+                error_kind = "not_found",
+                "goal_repo.update failed"
+            );
+            return Err(GoalRepoError::NotFound(goal.id));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, id: GoalUuid, user_id: UserUuid) -> Result<Option<Goal>, GoalRepoError> {
+        let row = sqlx::query_as!(
+            GoalRow,
+            "SELECT id, user_id, metric, period, target, created_at, updated_at FROM goals WHERE id = $1 AND user_id = $2",
+            id.value(),
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get", e))?;
+
+        row.map(Goal::try_from).transpose()
+    }
+
+    async fn get_all(&self, user_id: UserUuid) -> Result<Vec<Goal>, GoalRepoError> {
+        let rows = sqlx::query_as!(
+            GoalRow,
+            "SELECT id, user_id, metric, period, target, created_at, updated_at FROM goals WHERE user_id = $1 ORDER BY created_at",
+            user_id.value()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get_all", e))?;
+
+        rows.into_iter().map(Goal::try_from).collect()
+    }
+
+    async fn remove(&self, id: GoalUuid, user_id: UserUuid) -> Result<bool, GoalRepoError> {
+        let result = sqlx::query!(
+            "DELETE FROM goals WHERE id = $1 AND user_id = $2",
+            id.value(),
+            user_id.value()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("remove", e))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn save_event(&self, event: &GoalEvent) -> Result<(), GoalRepoError> {
+        sqlx::query!(
+            "INSERT INTO goal_events (id, goal_id, occurred_on, note) VALUES ($1, $2, $3, $4)",
+            event.id,
+            event.goal_id.value(),
+            event.occurred_on,
+            event.note,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("save_event", e))?;
+
+        Ok(())
+    }
+
+    async fn get_daily_counts(
+        &self,
+        goal: &Goal,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyCount>, GoalRepoError> {
+        let rows = match goal.metric {
+            GoalMetric::Applications => {
+                sqlx::query_as!(
+                    DailyCountRow,
+                    r#"SELECT applied_on AS "day!", COUNT(*) AS "count!" FROM positions WHERE user_id = $1 AND NOT deleted AND applied_on BETWEEN $2 AND $3 GROUP BY applied_on ORDER BY applied_on"#,
+                    goal.user_id.value(),
+                    from,
+                    to,
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            GoalMetric::Interviews => {
+                self.status_change_counts(goal.user_id, &PositionStatus::INTERVIEW, from, to)
+                    .await
+            }
+            GoalMetric::Offers => {
+                self.status_change_counts(goal.user_id, &[PositionStatus::OfferReceived], from, to)
+                    .await
+            }
+            GoalMetric::Networking => {
+                sqlx::query_as!(
+                    DailyCountRow,
+                    r#"SELECT occurred_on AS "day!", COUNT(*) AS "count!" FROM goal_events WHERE goal_id = $1 AND occurred_on BETWEEN $2 AND $3 GROUP BY occurred_on ORDER BY occurred_on"#,
+                    goal.id.value(),
+                    from,
+                    to,
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
+        .map_err(|e| database_error("get_daily_counts", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DailyCount {
+                day: row.day,
+                count: row.count,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::fixtures::date;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    async fn insert_position(pool: &PgPool, user_id: UserUuid, applied_on: NaiveDate) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query!(
+            "INSERT INTO positions (id, user_id, company, role_title, description, applied_on, url, status) VALUES ($1, $2, 'Rust Corp', 'Engineer', '', $3, '', 'CvSent')",
+            id,
+            user_id.value(),
+            applied_on,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_goal_crud_is_scoped_to_owner() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = GoalPostgresRepository::new(factory.pool.clone()).await;
+        let mut goal =
+            Goal::new(user.id, GoalMetric::Applications, GoalPeriod::Weekly, 10).unwrap();
+
+        repository.save(&goal).await.unwrap();
+        goal.change(GoalMetric::Interviews, GoalPeriod::Daily, 2)
+            .unwrap();
+        repository.update(&goal).await.unwrap();
+
+        let stored = repository.get(goal.id, user.id).await.unwrap().unwrap();
+        assert_eq!(stored.metric, GoalMetric::Interviews);
+        assert_eq!(stored.period, GoalPeriod::Daily);
+        assert_eq!(stored.target(), 2);
+        assert_eq!(
+            repository.get(goal.id, UserUuid::new()).await.unwrap(),
+            None
+        );
+        assert_eq!(repository.get_all(user.id).await.unwrap().len(), 1);
+        assert!(!repository.remove(goal.id, UserUuid::new()).await.unwrap());
+        assert!(repository.remove(goal.id, user.id).await.unwrap());
+        assert!(repository.get_all(user.id).await.unwrap().is_empty());
+        assert_eq!(
+            repository.update(&goal).await,
+            Err(GoalRepoError::NotFound(goal.id))
+        );
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_daily_counts_per_metric() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = GoalPostgresRepository::new(factory.pool.clone()).await;
+
+        insert_position(&factory.pool, user.id, date("2026-03-02")).await;
+        insert_position(&factory.pool, user.id, date("2026-03-02")).await;
+        let position_id = insert_position(&factory.pool, user.id, date("2026-03-04")).await;
+        insert_position(&factory.pool, user.id, date("2026-02-20")).await;
+        sqlx::query!(
+            "INSERT INTO position_status_history (position_id, from_status, to_status, changed_at) VALUES ($1, 'CvSent', 'TechnicalInterview', $2)",
+            position_id,
+            date("2026-03-05").and_hms_opt(10, 0, 0).unwrap(),
+        )
+        .execute(&factory.pool)
+        .await
+        .unwrap();
+
+        let applications =
+            Goal::new(user.id, GoalMetric::Applications, GoalPeriod::Weekly, 3).unwrap();
+        let interviews = Goal::new(user.id, GoalMetric::Interviews, GoalPeriod::Weekly, 1).unwrap();
+        let networking = Goal::new(user.id, GoalMetric::Networking, GoalPeriod::Weekly, 2).unwrap();
+        repository.save(&networking).await.unwrap();
+        repository
+            .save_event(&GoalEvent::new(&networking, date("2026-03-03"), None).unwrap())
+            .await
+            .unwrap();
+
+        let from = date("2026-03-01");
+        let to = date("2026-03-07");
+        assert_eq!(
+            repository
+                .get_daily_counts(&applications, from, to)
+                .await
+                .unwrap(),
+            vec![
+                DailyCount {
+                    day: date("2026-03-02"),
+                    count: 2
+                },
+                DailyCount {
+                    day: date("2026-03-04"),
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            repository
+                .get_daily_counts(&interviews, from, to)
+                .await
+                .unwrap(),
+            vec![DailyCount {
+                day: date("2026-03-05"),
+                count: 1
+            }]
+        );
+        assert_eq!(
+            repository
+                .get_daily_counts(&networking, from, to)
+                .await
+                .unwrap(),
+            vec![DailyCount {
+                day: date("2026-03-03"),
+                count: 1
+            }]
+        );
+
+        factory.teardown().await;
+    }
+}
@@ -0,0 +1,2 @@
+pub mod goal_in_memory_repository;
+pub mod goal_postgres_repository;
@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use utoipa::ToSchema;
+
+use crate::{
+    goals::domain::{
+        entities::{
+            goal::{Goal, GoalEvent, GoalMetric, GoalPeriod, GoalUuid},
+            progress::{GoalProgress, GoalStreak, PeriodProgress},
+        },
+        errors::GoalDomainError,
+    },
+    shared::domain::{errors::SharedDomainError, value_objects::UserUuid},
+};
+
+pub const DEFAULT_HISTORY_PERIODS: usize = 12;
+pub const MAX_HISTORY_PERIODS: usize = 104;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct GoalUuidDto {
+    id: String,
+}
+
+impl TryFrom<GoalUuidDto> for GoalUuid {
+    type Error = GoalDomainError;
+
+    fn try_from(val: GoalUuidDto) -> Result<Self, Self::Error> {
+        GoalUuid::from_str(&val.id)
+    }
+}
+
+/// Body of both goal creation and update.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SaveGoalRequestDto {
+    /// One of `applications`, `interviews`, `offers` or `networking`
+    pub metric: String,
+    /// One of `daily` or `weekly`
+    pub period: String,
+    pub target: i64,
+}
+
+impl SaveGoalRequestDto {
+    pub fn to_new_goal(&self, user_id: UserUuid) -> Result<Goal, GoalDomainError> {
+        Goal::new(
+            user_id,
+            GoalMetric::from_str(&self.metric)?,
+            GoalPeriod::from_str(&self.period)?,
+            self.target,
+        )
+    }
+
+    pub fn to_updated_goal(&self, mut goal: Goal) -> Result<Goal, GoalDomainError> {
+        goal.change(
+            GoalMetric::from_str(&self.metric)?,
+            GoalPeriod::from_str(&self.period)?,
+            self.target,
+        )?;
+        Ok(goal)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct LogGoalEventRequestDto {
+    /// `YYYY-MM-DD`, defaults to today
+    pub occurred_on: Option<String>,
+    pub note: Option<String>,
+}
+
+impl LogGoalEventRequestDto {
+    pub fn to_event(&self, goal: &Goal, today: NaiveDate) -> Result<GoalEvent, GoalDomainError> {
+        let occurred_on = match &self.occurred_on {
+            Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| SharedDomainError::InvalidDate(date.clone()))?,
+            None => today,
+        };
+        GoalEvent::new(goal, occurred_on, self.note.clone())
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct GoalHistoryQuery {
+    pub periods: Option<usize>,
+}
+
+impl GoalHistoryQuery {
+    pub fn limit(&self) -> usize {
+        self.periods
+            .unwrap_or(DEFAULT_HISTORY_PERIODS)
+            .clamp(1, MAX_HISTORY_PERIODS)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PeriodProgressDto {
+    pub period_start: String,
+    pub period_end: String,
+    pub count: i64,
+    pub target: i64,
+    pub met: bool,
+}
+
+impl From<&PeriodProgress> for PeriodProgressDto {
+    fn from(progress: &PeriodProgress) -> Self {
+        Self {
+            period_start: progress.period_start.to_string(),
+            period_end: progress.period_end.to_string(),
+            count: progress.count,
+            target: progress.target,
+            met: progress.is_met(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct GoalStreakDto {
+    pub current: usize,
+    pub longest: usize,
+}
+
+impl From<GoalStreak> for GoalStreakDto {
+    fn from(streak: GoalStreak) -> Self {
+        Self {
+            current: streak.current,
+            longest: streak.longest,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct GoalResponseDto {
+    pub id: String,
+    pub metric: String,
+    pub period: String,
+    pub target: i64,
+    pub created_at: String,
+    pub updated_at: String,
+    pub current_period: Option<PeriodProgressDto>,
+    pub streak: GoalStreakDto,
+}
+
+impl GoalResponseDto {
+    pub fn new(goal: &Goal, progress: &GoalProgress) -> Self {
+        Self {
+            id: goal.id.to_string(),
+            metric: goal.metric.to_string(),
+            period: goal.period.to_string(),
+            target: goal.target(),
+            created_at: goal.created_at.to_string(),
+            updated_at: goal.updated_at.to_string(),
+            current_period: progress.current().map(PeriodProgressDto::from),
+            streak: progress.streak().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_limit_is_clamped() {
+        assert_eq!(GoalHistoryQuery::default().limit(), DEFAULT_HISTORY_PERIODS);
+        assert_eq!(GoalHistoryQuery { periods: Some(0) }.limit(), 1);
+        assert_eq!(
+            GoalHistoryQuery {
+                periods: Some(10_000)
+            }
+            .limit(),
+            MAX_HISTORY_PERIODS
+        );
+    }
+
+    #[test]
+    fn test_invalid_event_date() {
+        let goal = Goal::new(
+            UserUuid::new(),
+            GoalMetric::Networking,
+            GoalPeriod::Weekly,
+            2,
+        )
+        .unwrap();
+        let dto = LogGoalEventRequestDto {
+            occurred_on: Some("yesterday".to_string()),
+            note: None,
+        };
+
+        let result = dto.to_event(&goal, chrono::Local::now().date_naive());
+
+        assert_eq!(
+            result,
+            Err(GoalDomainError::Shared(SharedDomainError::InvalidDate(
+                "yesterday".to_string()
+            )))
+        );
+    }
+}
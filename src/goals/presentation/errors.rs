@@ -0,0 +1,73 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use thiserror::Error;
+
+use crate::{
+    goals::{
+        application::errors::GoalServiceError,
+        domain::{
+            entities::goal::GoalUuid,
+            errors::{GoalDomainError, GoalRepoError},
+        },
+    },
+    shared::{domain::errors::SharedDomainError, presentation::ApiErrorResponse},
+};
+
+#[derive(Error, Debug)]
+pub enum GoalApiError {
+    #[error("Service error: `{0}`")]
+    ServiceError(#[from] GoalServiceError),
+
+    #[error("Goal not found: `{0}`")]
+    GoalNotFound(GoalUuid),
+
+    #[error("Invalid goal value: `{0}`")]
+    GoalDomainError(#[from] GoalDomainError),
+
+    #[error("Domain error: `{0}`")]
+    SharedDomainError(#[from] SharedDomainError),
+}
+
+impl IntoResponse for GoalApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            GoalApiError::GoalNotFound(uuid)
+            | GoalApiError::ServiceError(GoalServiceError::RepositoryError(
+                GoalRepoError::NotFound(uuid),
+            )) => (StatusCode::NOT_FOUND, format!("Goal not found: {}", uuid)),
+            GoalApiError::ServiceError(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+            GoalApiError::GoalDomainError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
+            GoalApiError::SharedDomainError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
+        };
+
+        (status, Json(ApiErrorResponse { message })).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goal_not_found_response() {
+        let error = GoalApiError::GoalNotFound(GoalUuid::new());
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_goal_gone_during_update_response() {
+        let error = GoalApiError::from(GoalServiceError::from(GoalRepoError::NotFound(
+            GoalUuid::new(),
+        )));
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_domain_error_response() {
+        let error = GoalApiError::from(GoalDomainError::InvalidTarget(0));
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,277 @@
+use std::str::FromStr;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::Local;
+
+use crate::{
+    goals::{
+        domain::entities::goal::{Goal, GoalUuid},
+        presentation::{
+            dtos::{
+                GoalHistoryQuery, GoalResponseDto, GoalStreakDto, GoalUuidDto,
+                LogGoalEventRequestDto, PeriodProgressDto, SaveGoalRequestDto,
+            },
+            errors::GoalApiError,
+            routes::GoalState,
+        },
+    },
+    shared::{
+        domain::value_objects::UserUuid, infrastructure::http::auth_extractor::AuthenticatedUser,
+    },
+};
+
+async fn load_goal(
+    state: &GoalState,
+    user: &AuthenticatedUser,
+    goal_id: GoalUuidDto,
+) -> Result<Goal, GoalApiError> {
+    let id: GoalUuid = goal_id.try_into()?;
+    let user_id = UserUuid::from_str(&user.0)?;
+    state
+        .service
+        .get_goal(id, user_id)
+        .await?
+        .ok_or(GoalApiError::GoalNotFound(id))
+}
+
+async fn goal_response(state: &GoalState, goal: &Goal) -> Result<GoalResponseDto, GoalApiError> {
+    let progress = state
+        .service
+        .get_progress(goal, Local::now().date_naive())
+        .await?;
+    Ok(GoalResponseDto::new(goal, &progress))
+}
+
+#[utoipa::path(
+    get,
+    path = "/goals",
+    responses(
+        (status = 200, description = "Goals of the current user with their progress", body = [GoalResponseDto]),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn get_goals(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+) -> Result<Json<Vec<GoalResponseDto>>, GoalApiError> {
+    let user_id = UserUuid::from_str(&user.0)?;
+    let goals = state.service.get_goals(user_id).await?;
+    let mut response = Vec::with_capacity(goals.len());
+    for goal in &goals {
+        response.push(goal_response(&state, goal).await?);
+    }
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/goals/{id}",
+    params(
+        ("id" = String, Path, description = "Goal ID")
+    ),
+    responses(
+        (status = 200, description = "Goal found", body = GoalResponseDto),
+        (status = 404, description = "Goal not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn get_goal(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+    Path(goal_id): Path<GoalUuidDto>,
+) -> Result<Json<GoalResponseDto>, GoalApiError> {
+    let goal = load_goal(&state, &user, goal_id).await?;
+    Ok(Json(goal_response(&state, &goal).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/goals",
+    request_body = SaveGoalRequestDto,
+    responses(
+        (status = 200, description = "Goal created", body = GoalResponseDto),
+        (status = 400, description = "Invalid metric, period or target"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn save_goal(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+    Json(payload): Json<SaveGoalRequestDto>,
+) -> Result<Json<GoalResponseDto>, GoalApiError> {
+    let user_id = UserUuid::from_str(&user.0)?;
+    let goal = payload.to_new_goal(user_id)?;
+    state.service.create_goal(goal.clone()).await?;
+    Ok(Json(goal_response(&state, &goal).await?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/goals/{id}",
+    params(
+        ("id" = String, Path, description = "Goal ID")
+    ),
+    request_body = SaveGoalRequestDto,
+    responses(
+        (status = 200, description = "Goal updated", body = GoalResponseDto),
+        (status = 400, description = "Invalid metric, period or target"),
+        (status = 404, description = "Goal not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn update_goal(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+    Path(goal_id): Path<GoalUuidDto>,
+    Json(payload): Json<SaveGoalRequestDto>,
+) -> Result<Json<GoalResponseDto>, GoalApiError> {
+    let existing = load_goal(&state, &user, goal_id).await?;
+    let updated = payload.to_updated_goal(existing)?;
+    state.service.update_goal(updated.clone()).await?;
+    Ok(Json(goal_response(&state, &updated).await?))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/goals/{id}",
+    params(
+        ("id" = String, Path, description = "Goal ID")
+    ),
+    responses(
+        (status = 204, description = "Goal removed"),
+        (status = 404, description = "Goal not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn remove_goal(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+    Path(goal_id): Path<GoalUuidDto>,
+) -> Result<StatusCode, GoalApiError> {
+    let id: GoalUuid = goal_id.try_into()?;
+    let user_id = UserUuid::from_str(&user.0)?;
+    if !state.service.remove_goal(id, user_id).await? {
+        return Err(GoalApiError::GoalNotFound(id));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/goals/{id}/history",
+    params(
+        ("id" = String, Path, description = "Goal ID"),
+        ("periods" = Option<usize>, Query, description = "Number of periods to return, newest first (default 12, max 104)")
+    ),
+    responses(
+        (status = 200, description = "Progress of the goal per period", body = [PeriodProgressDto]),
+        (status = 404, description = "Goal not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn get_goal_history(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+    Path(goal_id): Path<GoalUuidDto>,
+    Query(query): Query<GoalHistoryQuery>,
+) -> Result<Json<Vec<PeriodProgressDto>>, GoalApiError> {
+    let goal = load_goal(&state, &user, goal_id).await?;
+    let progress = state
+        .service
+        .get_progress(&goal, Local::now().date_naive())
+        .await?;
+    Ok(Json(
+        progress
+            .history(query.limit())
+            .iter()
+            .map(PeriodProgressDto::from)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/goals/{id}/streak",
+    params(
+        ("id" = String, Path, description = "Goal ID")
+    ),
+    responses(
+        (status = 200, description = "Current and longest streak of met periods", body = GoalStreakDto),
+        (status = 404, description = "Goal not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn get_goal_streak(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+    Path(goal_id): Path<GoalUuidDto>,
+) -> Result<Json<GoalStreakDto>, GoalApiError> {
+    let goal = load_goal(&state, &user, goal_id).await?;
+    let progress = state
+        .service
+        .get_progress(&goal, Local::now().date_naive())
+        .await?;
+    Ok(Json(progress.streak().into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/goals/{id}/events",
+    params(
+        ("id" = String, Path, description = "Goal ID")
+    ),
+    request_body = LogGoalEventRequestDto,
+    responses(
+        (status = 200, description = "Event logged, returns the updated goal", body = GoalResponseDto),
+        (status = 400, description = "The goal metric is computed from positions or the date is invalid"),
+        (status = 404, description = "Goal not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Goals"
+)]
+pub async fn log_goal_event(
+    user: AuthenticatedUser,
+    State(state): State<GoalState>,
+    Path(goal_id): Path<GoalUuidDto>,
+    Json(payload): Json<LogGoalEventRequestDto>,
+) -> Result<Json<GoalResponseDto>, GoalApiError> {
+    let goal = load_goal(&state, &user, goal_id).await?;
+    let event = payload.to_event(&goal, Local::now().date_naive())?;
+    state.service.log_event(event).await?;
+    Ok(Json(goal_response(&state, &goal).await?))
+}
@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::FromRef,
+    routing::{get, post},
+};
+
+use crate::{
+    goals::{
+        application::goal_service::GoalService,
+        presentation::handlers::{
+            get_goal, get_goal_history, get_goal_streak, get_goals, log_goal_event, remove_goal,
+            save_goal, update_goal,
+        },
+    },
+    shared::{config::Config, infrastructure::http::auth_extractor::UserStatusChecker},
+};
+
+#[derive(Clone)]
+pub struct GoalState {
+    pub service: Arc<GoalService>,
+    pub config: Arc<Config>,
+    pub user_checker: Arc<dyn UserStatusChecker>,
+}
+
+impl FromRef<GoalState> for Arc<Config> {
+    fn from_ref(state: &GoalState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<GoalState> for Arc<dyn UserStatusChecker> {
+    fn from_ref(state: &GoalState) -> Self {
+        state.user_checker.clone()
+    }
+}
+
+pub fn create_goal_routes(
+    service: Arc<GoalService>,
+    config: Arc<Config>,
+    user_checker: Arc<dyn UserStatusChecker>,
+) -> Router {
+    let state = GoalState {
+        service,
+        config,
+        user_checker,
+    };
+    Router::new()
+        .route("/", get(get_goals).post(save_goal))
+        .route("/{id}", get(get_goal).put(update_goal).delete(remove_goal))
+        .route("/{id}/history", get(get_goal_history))
+        .route("/{id}/streak", get(get_goal_streak))
+        .route("/{id}/events", post(log_goal_event))
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    struct MockUserStatusChecker;
+
+    #[async_trait::async_trait]
+    impl UserStatusChecker for MockUserStatusChecker {
+        async fn is_account_disabled(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn is_token_revoked(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            false
+        }
+
+        async fn is_session_active(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            true
+        }
+
+        async fn is_email_verified(&self, _user_id: &str) -> bool {
+            true
+        }
+
+        async fn is_admin(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn authenticate_personal_access_token(
+            &self,
+            _token: &str,
+        ) -> Option<crate::shared::infrastructure::http::auth_extractor::PersonalAccessTokenGrant>
+        {
+            None
+        }
+    }
+
+    fn setup_router() -> (Router, Config) {
+        let service = Arc::new(GoalService::new(Box::new(
+            crate::goals::infrastructure::persistence::repositories::goal_in_memory_repository::GoalInMemoryRepository::default(),
+        )));
+        let config = Config::test_default();
+        (
+            create_goal_routes(
+                service,
+                Arc::new(config.clone()),
+                Arc::new(MockUserStatusChecker),
+            ),
+            config,
+        )
+    }
+
+    fn get_auth_header(config: &Config) -> String {
+        let token = crate::shared::infrastructure::http::auth_extractor::create_jwt(
+            &Uuid::new_v4().to_string(),
+            "test@example.com",
+            config,
+        )
+        .unwrap();
+        format!("Bearer {}", token)
+    }
+
+    async fn send(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        auth: &str,
+        body: &str,
+    ) -> (StatusCode, Value) {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .header("Authorization", auth)
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_networking_goal_progress_and_streak() {
+        let (app, config) = setup_router();
+        let auth = get_auth_header(&config);
+
+        let (status, goal) = send(
+            &app,
+            "POST",
+            "/",
+            &auth,
+            r#"{"metric": "networking", "period": "weekly", "target": 2}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(goal["current_period"]["count"], 0);
+        let id = goal["id"].as_str().unwrap().to_string();
+
+        for _ in 0..2 {
+            let (status, _) = send(
+                &app,
+                "POST",
+                &format!("/{}/events", id),
+                &auth,
+                r#"{"note": "Coffee with a recruiter"}"#,
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        let (status, streak) = send(&app, "GET", &format!("/{}/streak", id), &auth, "").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(streak["current"], 1);
+
+        let (status, history) = send(
+            &app,
+            "GET",
+            &format!("/{}/history?periods=5", id),
+            &auth,
+            "",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(history[0]["count"], 2);
+        assert_eq!(history[0]["met"], true);
+
+        let (status, goals) = send(&app, "GET", "/", &auth, "").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(goals.as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_events_rejected_for_position_metrics() {
+        let (app, config) = setup_router();
+        let auth = get_auth_header(&config);
+
+        let (_, goal) = send(
+            &app,
+            "POST",
+            "/",
+            &auth,
+            r#"{"metric": "applications", "period": "weekly", "target": 10}"#,
+        )
+        .await;
+        let id = goal["id"].as_str().unwrap().to_string();
+
+        let (status, _) = send(&app, "POST", &format!("/{}/events", id), &auth, "{}").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_goal_is_rejected() {
+        let (app, config) = setup_router();
+        let auth = get_auth_header(&config);
+
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/",
+            &auth,
+            r#"{"metric": "applications", "period": "yearly", "target": 10}"#,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_goals_of_other_users_are_not_found() {
+        let (app, config) = setup_router();
+        let owner = get_auth_header(&config);
+        let other = get_auth_header(&config);
+
+        let (_, goal) = send(
+            &app,
+            "POST",
+            "/",
+            &owner,
+            r#"{"metric": "applications", "period": "weekly", "target": 10}"#,
+        )
+        .await;
+        let id = goal["id"].as_str().unwrap().to_string();
+
+        let (status, _) = send(&app, "GET", &format!("/{}", id), &other, "").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "DELETE", &format!("/{}", id), &other, "").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "DELETE", &format!("/{}", id), &owner, "").await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{net::SocketAddr, sync::Arc};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use utoipa::OpenApi;
 
 use axum::http::HeaderName;
@@ -9,8 +9,12 @@ use tower_http::{
     trace::{DefaultOnResponse, TraceLayer},
 };
 
+pub mod account;
+pub mod admin;
 pub mod auth;
 pub mod composition_root;
+pub mod digest;
+pub mod goals;
 pub mod positions;
 pub mod shared;
 
@@ -20,14 +24,44 @@ async fn main() {
     let config = Arc::new(shared::config::Config::default());
     let pool = composition_root::get_or_create_postgres_pool(&config).await;
     let user_repo = Box::new(composition_root::create_user_postgres_repository(pool.clone()).await);
-    let auth_service =
-        composition_root::create_auth_service(user_repo, pool.clone(), config.clone()).await;
+    let audit_log: Arc<dyn shared::domain::audit::IAuditLog> =
+        Arc::new(composition_root::create_audit_postgres_log(pool.clone()).await);
+    let token_revocations = Arc::new(
+        composition_root::create_token_revocation_service(pool.clone(), config.clone()).await,
+    );
+    let auth_service = Arc::new(
+        composition_root::create_auth_service(
+            user_repo,
+            token_revocations.clone(),
+            audit_log.clone(),
+            pool.clone(),
+            config.clone(),
+        )
+        .await,
+    );
+    let oidc_service = Arc::new(
+        composition_root::create_oidc_service(auth_service.clone(), pool.clone(), config.clone())
+            .await,
+    );
     let position_repo =
         Box::new(composition_root::create_position_postgres_repository(pool.clone()).await);
     let comment_repo =
         Box::new(composition_root::create_comment_postgres_repository(pool.clone()).await);
     let position_service = composition_root::create_position_service(position_repo).await;
     let comment_service = composition_root::create_comment_service(comment_repo).await;
+    let staleness_settings_repo = Box::new(
+        composition_root::create_staleness_settings_postgres_repository(pool.clone()).await,
+    );
+    let staleness_position_repo =
+        Box::new(composition_root::create_position_postgres_repository(pool.clone()).await);
+    let staleness_service = Arc::new(
+        composition_root::create_staleness_service(
+            staleness_settings_repo,
+            staleness_position_repo,
+            config.clone(),
+        )
+        .await,
+    );
     let observability = if config.observability_enabled {
         match shared::infrastructure::observability::init_observability(
             &config.service_name,
@@ -45,10 +79,89 @@ async fn main() {
 
     let user_repo_checker =
         Box::new(composition_root::create_user_postgres_repository(pool.clone()).await);
+    let personal_access_token_repo = Box::new(
+        composition_root::create_personal_access_token_postgres_repository(pool.clone()).await,
+    );
+    let session_repo =
+        Box::new(composition_root::create_session_postgres_repository(pool.clone()).await);
     let user_checker = Arc::new(
-        auth::application::user_status_checker::UserStatusCheckerImpl::new(Arc::new(
-            user_repo_checker,
-        )),
+        auth::application::user_status_checker::UserStatusCheckerImpl::new(
+            Arc::new(user_repo_checker),
+            token_revocations,
+            Arc::new(personal_access_token_repo),
+            Arc::new(session_repo),
+        ),
+    );
+
+    let digest_repo =
+        Box::new(composition_root::create_digest_postgres_repository(pool.clone()).await);
+    let digest_service =
+        Arc::new(composition_root::create_digest_service(digest_repo, config.clone()).await);
+
+    let goal_repo = Box::new(composition_root::create_goal_postgres_repository(pool.clone()).await);
+    let goal_service = Arc::new(composition_root::create_goal_service(goal_repo).await);
+
+    let account_repo =
+        Box::new(composition_root::create_account_postgres_repository(pool.clone()).await);
+    let account_service = Arc::new(
+        composition_root::create_account_service(account_repo, audit_log.clone(), config.clone())
+            .await,
+    );
+
+    let admin_repo =
+        Box::new(composition_root::create_admin_postgres_repository(pool.clone()).await);
+    let admin_service =
+        Arc::new(composition_root::create_admin_service(admin_repo, audit_log).await);
+
+    let staleness_job_service = staleness_service.clone();
+    shared::infrastructure::scheduler::spawn_periodic(
+        "ghost_stale_positions",
+        Duration::from_secs(config.staleness_job_interval_secs),
+        move || {
+            let service = staleness_job_service.clone();
+            async move {
+                match service
+                    .ghost_stale_positions(chrono::Local::now().date_naive())
+                    .await
+                {
+                    Ok(count) => tracing::info!(count, "Stale positions ghosted"),
+                    Err(e) => tracing::error!(error = %e, "ghost_stale_positions job failed"),
+                }
+            }
+        },
+    );
+
+    let digest_job_service = digest_service.clone();
+    shared::infrastructure::scheduler::spawn_periodic(
+        "send_weekly_digests",
+        Duration::from_secs(config.digest_job_interval_secs),
+        move || {
+            let service = digest_job_service.clone();
+            async move {
+                match service
+                    .send_weekly_digests(chrono::Local::now().date_naive())
+                    .await
+                {
+                    Ok(count) => tracing::info!(count, "Weekly digests enqueued"),
+                    Err(e) => tracing::error!(error = %e, "send_weekly_digests job failed"),
+                }
+            }
+        },
+    );
+
+    let purge_job_service = account_service.clone();
+    shared::infrastructure::scheduler::spawn_periodic(
+        "purge_deleted_accounts",
+        Duration::from_secs(config.account_purge_job_interval_secs),
+        move || {
+            let service = purge_job_service.clone();
+            async move {
+                match service.purge_due_accounts(chrono::Utc::now()).await {
+                    Ok(count) => tracing::info!(count, "Deleted accounts purged"),
+                    Err(e) => tracing::error!(error = %e, "purge_deleted_accounts job failed"),
+                }
+            }
+        },
     );
 
     let app = Router::new()
@@ -56,18 +169,60 @@ async fn main() {
             "/api-docs/openapi.json",
             shared::presentation::openapi::ApiDoc::openapi(),
         ))
+        .nest(
+            "/.well-known",
+            shared::presentation::well_known::create_well_known_routes(config.clone()),
+        )
         .nest(
             "/positions",
             positions::presentation::routes::create_position_routes(
                 Arc::new(position_service),
                 Arc::new(comment_service),
+                staleness_service,
                 config.clone(),
-                user_checker,
+                user_checker.clone(),
+            ),
+        )
+        .nest(
+            "/digest",
+            digest::presentation::routes::create_digest_routes(
+                digest_service,
+                config.clone(),
+                user_checker.clone(),
+            ),
+        )
+        .nest(
+            "/goals",
+            goals::presentation::routes::create_goal_routes(
+                goal_service,
+                config.clone(),
+                user_checker.clone(),
+            ),
+        )
+        .nest(
+            "/account",
+            account::presentation::routes::create_account_routes(
+                account_service,
+                config.clone(),
+                user_checker.clone(),
+            ),
+        )
+        .nest(
+            "/admin",
+            admin::presentation::routes::create_admin_routes(
+                admin_service,
+                config.clone(),
+                user_checker.clone(),
             ),
         )
         .nest(
             "/auth",
-            auth::presentation::routes::create_auth_routes(Arc::new(auth_service), config.clone()),
+            auth::presentation::routes::create_auth_routes(
+                auth_service,
+                oidc_service,
+                config.clone(),
+                user_checker,
+            ),
         )
         .layer(
             tower_http::cors::CorsLayer::new()
@@ -105,6 +260,10 @@ async fn main() {
     } else {
         app
     };
+    let app = app.layer(middleware::from_fn_with_state(
+        config.rate_limit_trust_forwarded_headers,
+        shared::infrastructure::http::request_context::capture_request_origin,
+    ));
     let app = if let Some(observability) = observability.clone() {
         let trace_layer = TraceLayer::new_for_http()
             .make_span_with(|request: &axum::http::Request<_>| {
@@ -128,16 +287,18 @@ async fn main() {
             shared::infrastructure::http::observability_middleware::request_observability,
         ))
         .layer(trace_layer)
+    } else {
+        app
+    };
+    // Outermost, so request logs and audit events carry the id either way.
+    let app = app
         .layer(PropagateRequestIdLayer::new(HeaderName::from_static(
             "x-request-id",
         )))
         .layer(SetRequestIdLayer::new(
             HeaderName::from_static("x-request-id"),
             MakeRequestUuid,
-        ))
-    } else {
-        app
-    };
+        ));
 
     let addr = format!("{}:{}", config.server_host, config.server_port);
     let listener = TcpListener::bind(&addr).await.unwrap();
@@ -1,3 +1,4 @@
 pub mod comment_service;
 pub mod errors;
 pub mod position_service;
+pub mod staleness_service;
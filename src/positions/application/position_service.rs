@@ -1,6 +1,7 @@
 use crate::positions::{
     application::errors::PositionServiceError,
     domain::entities::position::{Position, PositionUuid},
+    domain::entities::status_change::StatusChange,
     domain::repositories::position_repository::IPositionRepository,
 };
 
@@ -40,6 +41,14 @@ impl PositionService {
         self.repo.remove(position_uuid).await?;
         Ok(())
     }
+
+    pub async fn get_status_history(
+        &self,
+        position_id: PositionUuid,
+    ) -> Result<Vec<StatusChange>, PositionServiceError> {
+        let history = self.repo.get_status_history(position_id).await?;
+        Ok(history)
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +141,21 @@ mod tests {
         assert_eq!(updated.company.value(), "Updated Company");
     }
 
+    #[tokio::test]
+    async fn test_update_status_is_recorded_in_history() {
+        let service = create_service();
+        let mut position = create_fixture_position();
+        let position_id = position.id;
+        service.save(position.clone()).await.unwrap();
+
+        position.status = crate::positions::domain::entities::position::PositionStatus::Rejected;
+        service.update(position).await.unwrap();
+
+        let history = service.get_status_history(position_id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert!(!history[0].automatic);
+    }
+
     #[tokio::test]
     async fn test_update_position_not_found() {
         let service = create_service();
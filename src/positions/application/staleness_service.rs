@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use tracing::{info, warn};
+
+use crate::{
+    positions::{
+        application::errors::PositionServiceError,
+        domain::{
+            entities::{position::Staleness, staleness_settings::StalenessSettings},
+            repositories::{
+                position_repository::IPositionRepository,
+                staleness_settings_repository::IStalenessSettingsRepository,
+            },
+        },
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+pub struct StalenessService {
+    settings_repo: Box<dyn IStalenessSettingsRepository>,
+    position_repo: Box<dyn IPositionRepository>,
+    default_stale_after_days: i64,
+}
+
+impl StalenessService {
+    pub fn new(
+        settings_repo: Box<dyn IStalenessSettingsRepository>,
+        position_repo: Box<dyn IPositionRepository>,
+        default_stale_after_days: i64,
+    ) -> Self {
+        Self {
+            settings_repo,
+            position_repo,
+            default_stale_after_days,
+        }
+    }
+
+    pub async fn get_settings(
+        &self,
+        user_id: UserUuid,
+    ) -> Result<StalenessSettings, PositionServiceError> {
+        match self.settings_repo.get(user_id).await? {
+            Some(settings) => Ok(settings),
+            None => Ok(StalenessSettings::new(
+                user_id,
+                self.default_stale_after_days,
+                false,
+            )?),
+        }
+    }
+
+    pub async fn update_settings(
+        &self,
+        settings: StalenessSettings,
+    ) -> Result<(), PositionServiceError> {
+        self.settings_repo.save(settings).await?;
+        Ok(())
+    }
+
+    /// Moves every stale position of the users that opted in to auto-ghosting
+    /// into the terminal `Ghosted` status. Returns how many were transitioned.
+    pub async fn ghost_stale_positions(
+        &self,
+        today: NaiveDate,
+    ) -> Result<usize, PositionServiceError> {
+        let stale_after_days: HashMap<UserUuid, i64> = self
+            .settings_repo
+            .get_auto_ghost_enabled()
+            .await?
+            .into_iter()
+            .map(|settings| (settings.user_id, settings.stale_after_days()))
+            .collect();
+        if stale_after_days.is_empty() {
+            return Ok(0);
+        }
+
+        let positions = self
+            .position_repo
+            .get_stale_candidates(&stale_after_days, today)
+            .await?;
+        let mut ghosted = 0;
+        for position in positions {
+            let Some(&days) = stale_after_days.get(&position.user_id) else {
+                continue;
+            };
+            if position.staleness(days, today) != Staleness::Stale {
+                continue;
+            }
+
+            match self
+                .position_repo
+                .ghost_if_stale(position.id, days, today)
+                .await
+            {
+                Ok(false) => {}
+                Ok(true) => {
+                    info!(
+                        position_id = %position.id,
+                        user_id = %position.user_id,
+                        "Position marked as ghosted"
+                    );
+                    ghosted += 1;
+                }
+                Err(e) => {
+                    warn!(
+                        position_id = %position.id,
+                        error = %e,
+                        "staleness_service.ghost_stale_positions failed"
+                    );
+                }
+            }
+        }
+
+        Ok(ghosted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::positions::{
+        domain::entities::position::{PositionBuilder, PositionStatus},
+        infrastructure::persistence::repositories::{
+            position_in_memory_repository::PositionInMemoryRepository,
+            staleness_settings_in_memory_repository::StalenessSettingsInMemoryRepository,
+        },
+    };
+    use crate::shared::fixtures::date;
+
+    fn create_service(
+        settings_repo: StalenessSettingsInMemoryRepository,
+        position_repo: PositionInMemoryRepository,
+    ) -> StalenessService {
+        StalenessService::new(Box::new(settings_repo), Box::new(position_repo), 21)
+    }
+
+    #[tokio::test]
+    async fn test_get_settings_defaults_when_not_configured() {
+        let service = create_service(
+            StalenessSettingsInMemoryRepository::default(),
+            PositionInMemoryRepository::default(),
+        );
+
+        let settings = service.get_settings(UserUuid::new()).await.unwrap();
+
+        assert_eq!(settings.stale_after_days(), 21);
+        assert!(!settings.auto_ghost);
+    }
+
+    #[tokio::test]
+    async fn test_ghost_stale_positions_only_for_opted_in_users() {
+        let settings_repo = StalenessSettingsInMemoryRepository::default();
+        let position_repo = PositionInMemoryRepository::default();
+        let opted_in = UserUuid::new();
+        let opted_out = UserUuid::new();
+        settings_repo
+            .save(StalenessSettings::new(opted_in, 14, true).unwrap())
+            .await
+            .unwrap();
+        settings_repo
+            .save(StalenessSettings::new(opted_out, 14, false).unwrap())
+            .await
+            .unwrap();
+
+        let stale = PositionBuilder::new()
+            .with_user_uuid(&opted_in.to_string())
+            .unwrap()
+            .with_applied_on_date(date("2026-01-01"))
+            .build();
+        let fresh = PositionBuilder::new()
+            .with_user_uuid(&opted_in.to_string())
+            .unwrap()
+            .with_applied_on_date(date("2026-01-20"))
+            .build();
+        let not_opted_in = PositionBuilder::new()
+            .with_user_uuid(&opted_out.to_string())
+            .unwrap()
+            .with_applied_on_date(date("2026-01-01"))
+            .build();
+        for position in [stale.clone(), fresh.clone(), not_opted_in.clone()] {
+            position_repo.save(position).await.unwrap();
+        }
+
+        let service = create_service(settings_repo, position_repo.clone());
+        let ghosted = service
+            .ghost_stale_positions(date("2026-01-25"))
+            .await
+            .unwrap();
+
+        assert_eq!(ghosted, 1);
+        let stale = position_repo.get(stale.id).await.unwrap().unwrap();
+        assert_eq!(stale.status, PositionStatus::Ghosted);
+        let history = position_repo.get_status_history(stale.id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert!(history[0].automatic);
+        let fresh = position_repo.get(fresh.id).await.unwrap().unwrap();
+        assert_eq!(fresh.status, PositionStatus::CvSent);
+        let not_opted_in = position_repo.get(not_opted_in.id).await.unwrap().unwrap();
+        assert_eq!(not_opted_in.status, PositionStatus::CvSent);
+    }
+}
@@ -1,2 +1,4 @@
 pub mod comment;
 pub mod position;
+pub mod staleness_settings;
+pub mod status_change;
@@ -8,6 +8,9 @@ use crate::{
     shared::domain::{errors::SharedDomainError, value_objects::UserUuid},
 };
 
+pub use crate::shared::domain::position_status::PositionStatus;
+pub use crate::shared::domain::staleness::Staleness;
+
 #[derive(PartialEq, Clone, Debug, Copy)]
 pub struct PositionUuid {
     id: Uuid,
@@ -183,46 +186,6 @@ impl std::fmt::Display for Url {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub enum PositionStatus {
-    CvSent,
-    PhoneScreenScheduled,
-    TechnicalInterview,
-    OfferReceived,
-    Rejected,
-    Withdrawn,
-}
-
-impl FromStr for PositionStatus {
-    type Err = PositionDomainError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "CvSent" => Ok(PositionStatus::CvSent),
-            "PhoneScreenScheduled" => Ok(PositionStatus::PhoneScreenScheduled),
-            "TechnicalInterview" => Ok(PositionStatus::TechnicalInterview),
-            "OfferReceived" => Ok(PositionStatus::OfferReceived),
-            "Rejected" => Ok(PositionStatus::Rejected),
-            "Withdrawn" => Ok(PositionStatus::Withdrawn),
-            _ => Err(PositionDomainError::InvalidStatus(s.to_string())),
-        }
-    }
-}
-
-impl std::fmt::Display for PositionStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            PositionStatus::CvSent => "CvSent",
-            PositionStatus::PhoneScreenScheduled => "PhoneScreenScheduled",
-            PositionStatus::TechnicalInterview => "TechnicalInterview",
-            PositionStatus::OfferReceived => "OfferReceived",
-            PositionStatus::Rejected => "Rejected",
-            PositionStatus::Withdrawn => "Withdrawn",
-        };
-        write!(f, "{}", s)
-    }
-}
-
 #[derive(Debug, PartialEq, Clone)]
 pub struct Position {
     pub id: PositionUuid,
@@ -237,12 +200,45 @@ pub struct Position {
     pub updated_at: DateTime<Local>,
     pub deleted_at: Option<DateTime<Local>>,
     pub deleted: bool,
+    pub last_activity_at: Option<DateTime<Local>>,
+}
+
+impl FromStr for Staleness {
+    type Err = PositionDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(Staleness::Active),
+            "stale" => Ok(Staleness::Stale),
+            "ghosted" => Ok(Staleness::Ghosted),
+            _ => Err(PositionDomainError::InvalidStaleness(s.to_string())),
+        }
+    }
 }
 
 impl Position {
     pub fn is_deleted(&self) -> bool {
         self.deleted
     }
+
+    /// Last day anything happened on the position: the application itself,
+    /// a status change or a comment.
+    pub fn last_activity_on(&self) -> NaiveDate {
+        let applied_on = self.applied_on.date();
+        self.last_activity_at
+            .map(|at| at.date_naive())
+            .filter(|date| *date > applied_on)
+            .unwrap_or(applied_on)
+    }
+
+    pub fn staleness(&self, stale_after_days: i64, today: NaiveDate) -> Staleness {
+        Staleness::of(
+            self.status.application_state(),
+            self.last_activity_on(),
+            stale_after_days,
+            today,
+        )
+    }
 }
 
 pub struct PositionBuilder {
@@ -258,6 +254,7 @@ pub struct PositionBuilder {
     updated_at: DateTime<Local>,
     deleted_at: Option<DateTime<Local>>,
     deleted: bool,
+    last_activity_at: Option<DateTime<Local>>,
 }
 
 impl PositionBuilder {
@@ -335,6 +332,11 @@ impl PositionBuilder {
         self
     }
 
+    pub fn with_last_activity_at(mut self, last_activity_at: Option<DateTime<Local>>) -> Self {
+        self.last_activity_at = last_activity_at;
+        self
+    }
+
     pub fn build(self) -> Position {
         Position {
             id: self.id,
@@ -349,6 +351,7 @@ impl PositionBuilder {
             updated_at: self.updated_at,
             deleted_at: self.deleted_at,
             deleted: self.deleted,
+            last_activity_at: self.last_activity_at,
         }
     }
 }
@@ -368,13 +371,16 @@ impl Default for PositionBuilder {
             updated_at: Local::now(),
             deleted_at: None,
             deleted: false,
+            last_activity_at: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::shared::fixtures::create_fixture_position;
+    use chrono::TimeZone;
+
+    use crate::shared::fixtures::{create_fixture_position, date};
 
     use super::*;
 
@@ -418,4 +424,60 @@ mod tests {
         assert_eq!(position.url.value(), "https://me-the.url");
         assert_eq!(position.status, PositionStatus::PhoneScreenScheduled);
     }
+
+    #[test]
+    fn test_position_becomes_stale_without_activity() {
+        let position = PositionBuilder::new()
+            .with_applied_on_date(date("2026-01-01"))
+            .build();
+
+        assert_eq!(
+            position.staleness(14, date("2026-01-14")),
+            Staleness::Active
+        );
+        assert_eq!(position.staleness(14, date("2026-01-15")), Staleness::Stale);
+    }
+
+    #[test]
+    fn test_recent_activity_keeps_position_active() {
+        let noon =
+            date("2026-01-10").and_time(chrono::NaiveTime::MIN) + chrono::TimeDelta::hours(12);
+        let activity = Local.from_utc_datetime(&noon);
+        let position = PositionBuilder::new()
+            .with_applied_on_date(date("2026-01-01"))
+            .with_last_activity_at(Some(activity))
+            .build();
+
+        assert_eq!(position.last_activity_on(), date("2026-01-10"));
+        assert_eq!(
+            position.staleness(14, date("2026-01-20")),
+            Staleness::Active
+        );
+        assert_eq!(position.staleness(14, date("2026-01-24")), Staleness::Stale);
+    }
+
+    #[test]
+    fn test_terminal_positions_are_never_stale() {
+        let position = PositionBuilder::new()
+            .with_applied_on_date(date("2025-01-01"))
+            .with_status(PositionStatus::Rejected)
+            .build();
+
+        assert_eq!(
+            position.staleness(14, date("2026-01-01")),
+            Staleness::Active
+        );
+    }
+
+    #[test]
+    fn test_ghosted_position_staleness() {
+        let position = PositionBuilder::new()
+            .with_status(PositionStatus::Ghosted)
+            .build();
+
+        assert_eq!(
+            position.staleness(14, Local::now().date_naive()),
+            Staleness::Ghosted
+        );
+    }
 }
@@ -0,0 +1,57 @@
+use crate::{
+    positions::domain::errors::PositionDomainError, shared::domain::value_objects::UserUuid,
+};
+
+pub const MIN_STALE_AFTER_DAYS: i64 = 1;
+pub const MAX_STALE_AFTER_DAYS: i64 = 365;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StalenessSettings {
+    pub user_id: UserUuid,
+    stale_after_days: i64,
+    pub auto_ghost: bool,
+}
+
+impl StalenessSettings {
+    pub fn new(
+        user_id: UserUuid,
+        stale_after_days: i64,
+        auto_ghost: bool,
+    ) -> Result<Self, PositionDomainError> {
+        if !(MIN_STALE_AFTER_DAYS..=MAX_STALE_AFTER_DAYS).contains(&stale_after_days) {
+            return Err(PositionDomainError::InvalidStaleAfterDays(stale_after_days));
+        }
+        Ok(Self {
+            user_id,
+            stale_after_days,
+            auto_ghost,
+        })
+    }
+
+    pub fn stale_after_days(&self) -> i64 {
+        self.stale_after_days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stale_after_days_out_of_range() {
+        let result = StalenessSettings::new(UserUuid::new(), 0, false);
+
+        assert_eq!(result, Err(PositionDomainError::InvalidStaleAfterDays(0)));
+    }
+
+    #[test]
+    fn test_valid_settings() {
+        let result = StalenessSettings::new(UserUuid::new(), 30, true);
+
+        let Ok(settings) = result else {
+            panic!("Expected valid settings");
+        };
+        assert_eq!(settings.stale_after_days(), 30);
+        assert!(settings.auto_ghost);
+    }
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Local};
+
+use crate::positions::domain::entities::position::{PositionStatus, PositionUuid};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StatusChange {
+    pub position_id: PositionUuid,
+    pub from_status: PositionStatus,
+    pub to_status: PositionStatus,
+    pub automatic: bool,
+    pub changed_at: DateTime<Local>,
+}
@@ -8,11 +8,14 @@ pub enum PositionDomainError {
     #[error(transparent)]
     Shared(#[from] SharedDomainError),
 
-    #[error("Invalid status: `{0}`")]
-    InvalidStatus(String),
-
     #[error("Invalid user uuid: `{0}`")]
     InvalidUserUuid(String),
+
+    #[error("Invalid staleness: `{0}`")]
+    InvalidStaleness(String),
+
+    #[error("Invalid stale after days: `{0}`")]
+    InvalidStaleAfterDays(i64),
 }
 
 impl PositionDomainError {
@@ -98,7 +101,9 @@ mod tests {
 
     #[test]
     fn test_invalid_status_error() {
-        let error = PositionDomainError::InvalidStatus("unknown".to_string());
+        let error = PositionDomainError::from(SharedDomainError::InvalidPositionStatus(
+            "unknown".to_string(),
+        ));
         assert_eq!(error.to_string(), "Invalid status: `unknown`");
     }
 
@@ -130,7 +135,8 @@ mod tests {
 
     #[test]
     fn test_position_repo_conversion_error() {
-        let domain_error = PositionDomainError::InvalidStatus("bad".to_string());
+        let domain_error =
+            PositionDomainError::from(SharedDomainError::InvalidPositionStatus("bad".to_string()));
         let error = PositionRepoError::from(domain_error);
         assert!(matches!(error, PositionRepoError::ConversionError(_)));
     }
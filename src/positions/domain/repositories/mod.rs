@@ -1,2 +1,3 @@
 pub mod comment_repository;
 pub mod position_repository;
+pub mod staleness_settings_repository;
@@ -1,13 +1,39 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use chrono::NaiveDate;
 
 use crate::positions::domain::entities::position::{Position, PositionUuid};
+use crate::positions::domain::entities::status_change::StatusChange;
 use crate::positions::domain::errors::PositionRepoError;
+use crate::shared::domain::value_objects::UserUuid;
 
 #[async_trait]
 pub trait IPositionRepository: Send + Sync {
     async fn get_all(&self) -> Result<Vec<Position>, PositionRepoError>;
+    /// Open positions of the given users with no activity for their
+    /// `stale_after_days`. May include a few not stale yet, which callers
+    /// check with [`Position::staleness`].
+    async fn get_stale_candidates(
+        &self,
+        stale_after_days: &HashMap<UserUuid, i64>,
+        today: NaiveDate,
+    ) -> Result<Vec<Position>, PositionRepoError>;
     async fn get(&self, position_id: PositionUuid) -> Result<Option<Position>, PositionRepoError>;
     async fn save(&self, position: Position) -> Result<PositionUuid, PositionRepoError>;
     async fn update(&self, position: Position) -> Result<(), PositionRepoError>;
     async fn remove(&self, position_uuid: PositionUuid) -> Result<(), PositionRepoError>;
+    async fn get_status_history(
+        &self,
+        position_id: PositionUuid,
+    ) -> Result<Vec<StatusChange>, PositionRepoError>;
+    /// Moves the position to `Ghosted` if it is still stale once locked, so
+    /// a change made since it was read is not overwritten. Returns whether
+    /// it was ghosted.
+    async fn ghost_if_stale(
+        &self,
+        position_id: PositionUuid,
+        stale_after_days: i64,
+        today: NaiveDate,
+    ) -> Result<bool, PositionRepoError>;
 }
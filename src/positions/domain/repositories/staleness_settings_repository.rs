@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::positions::domain::entities::staleness_settings::StalenessSettings;
+use crate::positions::domain::errors::PositionRepoError;
+use crate::shared::domain::value_objects::UserUuid;
+
+#[async_trait]
+pub trait IStalenessSettingsRepository: Send + Sync {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<StalenessSettings>, PositionRepoError>;
+    async fn save(&self, settings: StalenessSettings) -> Result<(), PositionRepoError>;
+    async fn get_auto_ghost_enabled(&self) -> Result<Vec<StalenessSettings>, PositionRepoError>;
+}
@@ -1,4 +1,5 @@
-use crate::positions::domain::entities::position::{Position, PositionUuid};
+use crate::positions::domain::entities::position::{Position, PositionStatus, PositionUuid};
+use crate::positions::domain::errors::PositionRepoError;
 use crate::positions::domain::repositories::position_repository::IPositionRepository;
 
 #[cfg(test)]
@@ -23,7 +24,45 @@ pub async fn assert_repository_behavior(repo: Box<dyn IPositionRepository>, posi
     let all = repo.get_all().await.expect("Should get all positions");
     assert!(all.iter().any(|p| p.id == position_id));
 
-    // 3. Test remove (soft delete)
+    // 3. Test status changes are recorded in the history
+    let new_status = if fetched.status == PositionStatus::TechnicalInterview {
+        PositionStatus::CvSent
+    } else {
+        PositionStatus::TechnicalInterview
+    };
+    let mut updated = fetched.clone();
+    updated.status = new_status.clone();
+    updated.updated_at = chrono::Local::now();
+    repo.update(updated).await.expect("Should update position");
+
+    // The status change just now keeps the position active.
+    let today = chrono::Local::now().date_naive();
+    assert_eq!(repo.ghost_if_stale(position_id, 14, today).await, Ok(false));
+    let later = today + chrono::Days::new(30);
+    assert_eq!(repo.ghost_if_stale(position_id, 14, later).await, Ok(true));
+    assert_eq!(repo.ghost_if_stale(position_id, 14, later).await, Ok(false));
+
+    let history = repo
+        .get_status_history(position_id)
+        .await
+        .expect("Should get status history");
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].from_status, fetched.status);
+    assert_eq!(history[0].to_status, new_status);
+    assert!(!history[0].automatic);
+    assert_eq!(history[1].from_status, new_status);
+    assert_eq!(history[1].to_status, PositionStatus::Ghosted);
+    assert!(history[1].automatic);
+
+    let ghosted = repo
+        .get(position_id)
+        .await
+        .expect("Should not error on get")
+        .expect("Should find ghosted position");
+    assert_eq!(ghosted.status, PositionStatus::Ghosted);
+    assert!(ghosted.last_activity_at.is_some());
+
+    // 4. Test remove (soft delete)
     repo.remove(position_id)
         .await
         .expect("Should remove position");
@@ -37,7 +76,7 @@ pub async fn assert_repository_behavior(repo: Box<dyn IPositionRepository>, posi
     assert!(deleted_position.is_deleted());
     assert!(deleted_position.deleted_at.is_some());
 
-    // 4. Test getting non-existent position
+    // 5. Test getting non-existent position
     let non_existent_id = PositionUuid::new();
     let result = repo
         .get(non_existent_id)
@@ -48,11 +87,15 @@ pub async fn assert_repository_behavior(repo: Box<dyn IPositionRepository>, posi
         "Should return None for non-existent position, not an error"
     );
 
-    // 5. Test removing non-existent position (should be idempotent or return a consistent error)
+    // 6. Test removing non-existent position (should be idempotent or return a consistent error)
     // We'll decide on idempotency (Ok(())) for now as it's common in repos.
     let result = repo.remove(non_existent_id).await;
     assert!(
         result.is_ok(),
         "Remove should be idempotent and return Ok even if not found"
     );
+
+    // 7. Test ghosting a non-existent position
+    let result = repo.ghost_if_stale(non_existent_id, 14, later).await;
+    assert_eq!(result, Err(PositionRepoError::NotFound(non_existent_id)));
 }
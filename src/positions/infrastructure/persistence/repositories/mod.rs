@@ -2,6 +2,8 @@ pub mod comment_in_memory_repository;
 pub mod comment_postgres_repository;
 pub mod position_in_memory_repository;
 pub mod position_postgres_repository;
+pub mod staleness_settings_in_memory_repository;
+pub mod staleness_settings_postgres_repository;
 
 #[cfg(test)]
 pub mod comment_repository_tests;
@@ -1,23 +1,29 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use tokio::sync::RwLock;
 
 use crate::positions::domain::{
-    entities::position::{Position, PositionUuid},
+    entities::position::{Position, PositionStatus, PositionUuid, Staleness},
+    entities::status_change::StatusChange,
     errors::PositionRepoError,
     repositories::position_repository::IPositionRepository,
 };
+use crate::shared::domain::value_objects::UserUuid;
 
 #[derive(Clone)]
 pub struct PositionInMemoryRepository {
     positions: Arc<RwLock<Vec<Position>>>,
+    status_history: Arc<RwLock<Vec<StatusChange>>>,
 }
 
 impl Default for PositionInMemoryRepository {
     fn default() -> Self {
         PositionInMemoryRepository {
             positions: Arc::new(RwLock::new(vec![])),
+            status_history: Arc::new(RwLock::new(vec![])),
         }
     }
 }
@@ -38,6 +44,26 @@ impl IPositionRepository for PositionInMemoryRepository {
         Ok(self.positions.read().await.clone())
     }
 
+    async fn get_stale_candidates(
+        &self,
+        stale_after_days: &HashMap<UserUuid, i64>,
+        today: NaiveDate,
+    ) -> Result<Vec<Position>, PositionRepoError> {
+        Ok(self
+            .positions
+            .read()
+            .await
+            .iter()
+            .filter(|p| !p.is_deleted())
+            .filter(|p| {
+                stale_after_days
+                    .get(&p.user_id)
+                    .is_some_and(|days| p.staleness(*days, today) == Staleness::Stale)
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn remove(&self, position_uuid: PositionUuid) -> Result<(), PositionRepoError> {
         if let Some(position) = self
             .positions
@@ -58,15 +84,69 @@ impl IPositionRepository for PositionInMemoryRepository {
         Ok(uuid)
     }
 
-    async fn update(&self, position: Position) -> Result<(), PositionRepoError> {
+    async fn update(&self, mut position: Position) -> Result<(), PositionRepoError> {
         let mut positions = self.positions.write().await;
         if let Some(existing) = positions.iter_mut().find(|p| p.id == position.id) {
+            position.last_activity_at = existing.last_activity_at;
+            if existing.status != position.status {
+                position.last_activity_at = Some(position.updated_at);
+                self.status_history.write().await.push(StatusChange {
+                    position_id: position.id,
+                    from_status: existing.status.clone(),
+                    to_status: position.status.clone(),
+                    automatic: false,
+                    changed_at: position.updated_at,
+                });
+            }
             *existing = position;
             Ok(())
         } else {
             Err(PositionRepoError::NotFound(position.id))
         }
     }
+
+    async fn get_status_history(
+        &self,
+        position_id: PositionUuid,
+    ) -> Result<Vec<StatusChange>, PositionRepoError> {
+        Ok(self
+            .status_history
+            .read()
+            .await
+            .iter()
+            .filter(|change| change.position_id == position_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn ghost_if_stale(
+        &self,
+        position_id: PositionUuid,
+        stale_after_days: i64,
+        today: NaiveDate,
+    ) -> Result<bool, PositionRepoError> {
+        let mut positions = self.positions.write().await;
+        let Some(existing) = positions.iter_mut().find(|p| p.id == position_id) else {
+            return Err(PositionRepoError::NotFound(position_id));
+        };
+        if existing.is_deleted() || existing.staleness(stale_after_days, today) != Staleness::Stale
+        {
+            return Ok(false);
+        }
+
+        let now = chrono::Local::now();
+        self.status_history.write().await.push(StatusChange {
+            position_id,
+            from_status: existing.status.clone(),
+            to_status: PositionStatus::Ghosted,
+            automatic: true,
+            changed_at: now,
+        });
+        existing.status = PositionStatus::Ghosted;
+        existing.updated_at = now;
+        existing.last_activity_at = Some(now);
+        Ok(true)
+    }
 }
 
 #[cfg(test)]
@@ -1,17 +1,20 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use sqlx::postgres::PgPool;
 use tracing::{error, warn};
-use tracing_opentelemetry::OpenTelemetrySpanExt;
 use uuid::Uuid;
 
 use crate::positions::domain::{
-    entities::position::{Position, PositionBuilder, PositionStatus, PositionUuid},
+    entities::position::{Position, PositionBuilder, PositionStatus, PositionUuid, Staleness},
+    entities::status_change::StatusChange,
     errors::{PositionDomainError, PositionRepoError},
     repositories::position_repository::IPositionRepository,
 };
+use crate::shared::domain::value_objects::UserUuid;
+use crate::shared::infrastructure::observability::current_trace_context;
 
 struct PositionRow {
     id: Uuid,
@@ -26,6 +29,15 @@ struct PositionRow {
     updated_at: NaiveDateTime,
     deleted_at: Option<NaiveDateTime>,
     deleted: bool,
+    last_activity_at: Option<NaiveDateTime>,
+}
+
+struct StatusChangeRow {
+    position_id: Uuid,
+    from_status: String,
+    to_status: String,
+    automatic: bool,
+    changed_at: NaiveDateTime,
 }
 
 pub struct PositionPostgresRepository {
@@ -58,8 +70,145 @@ impl PositionPostgresRepository {
                     .map(|d| DateTime::<Local>::from(Utc.from_utc_datetime(&d))),
             )
             .with_deleted(row.deleted)
+            .with_last_activity_at(
+                row.last_activity_at
+                    .map(|d| DateTime::<Local>::from(Utc.from_utc_datetime(&d))),
+            )
             .build())
     }
+
+    fn status_change_from_row(row: StatusChangeRow) -> Result<StatusChange, PositionDomainError> {
+        Ok(StatusChange {
+            position_id: PositionUuid::from_uuid(row.position_id),
+            from_status: PositionStatus::from_str(&row.from_status)?,
+            to_status: PositionStatus::from_str(&row.to_status)?,
+            automatic: row.automatic,
+            changed_at: DateTime::<Local>::from(Utc.from_utc_datetime(&row.changed_at)),
+        })
+    }
+
+    async fn change_status(&self, position: &Position) -> Result<(), PositionRepoError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+
+        let current_status = sqlx::query_scalar!(
+            "SELECT status FROM positions WHERE id = $1 FOR UPDATE",
+            position.id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+
+        let Some(current_status) = current_status else {
+            warn!(
+                position_id = %position.id.value(),
+                error_kind = "not_found",
+                "position_repo.update failed"
+            );
+            return Err(PositionRepoError::NotFound(position.id));
+        };
+
+        sqlx::query!(
+            "UPDATE positions SET company = $1, role_title = $2, description = $3, applied_on = $4, url = $5, status = $6, updated_at = $7 WHERE id = $8",
+            position.company.value(),
+            position.role_title.value(),
+            position.description.value(),
+            position.applied_on.date(),
+            position.url.value(),
+            format!("{:?}", position.status),
+            position.updated_at.naive_utc(),
+            position.id.value(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+
+        let new_status = format!("{:?}", position.status);
+        if current_status != new_status {
+            sqlx::query!(
+                "INSERT INTO position_status_history (position_id, from_status, to_status, automatic, changed_at) VALUES ($1, $2, $3, false, $4)",
+                position.id.value(),
+                current_status,
+                new_status,
+                position.updated_at.naive_utc(),
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))
+    }
+
+    async fn ghost_locked_if_stale(
+        &self,
+        position_id: PositionUuid,
+        stale_after_days: i64,
+        today: NaiveDate,
+    ) -> Result<bool, PositionRepoError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+
+        // Locked first, so the read below sees every change committed before.
+        let locked = sqlx::query_scalar!(
+            "SELECT id FROM positions WHERE id = $1 FOR UPDATE",
+            position_id.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+        if locked.is_none() {
+            return Err(PositionRepoError::NotFound(position_id));
+        }
+
+        let row = sqlx::query_as!(
+            PositionRow,
+            "SELECT p.id, p.user_id, p.company, p.role_title, p.description, p.applied_on, p.url, p.status, p.created_at, p.updated_at, p.deleted_at, p.deleted, GREATEST((SELECT MAX(h.changed_at) FROM position_status_history h WHERE h.position_id = p.id), (SELECT MAX(c.created_at) FROM comments c WHERE c.position_id = p.id)) AS last_activity_at FROM positions p WHERE p.id = $1",
+            position_id.value()
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+        let position = Self::from_row(row)?;
+        if position.is_deleted() || position.staleness(stale_after_days, today) != Staleness::Stale
+        {
+            return Ok(false);
+        }
+
+        let now = Utc::now().naive_utc();
+        sqlx::query!(
+            "UPDATE positions SET status = $1, updated_at = $2 WHERE id = $3",
+            PositionStatus::Ghosted.to_string(),
+            now,
+            position_id.value(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+        sqlx::query!(
+            "INSERT INTO position_status_history (position_id, from_status, to_status, automatic, changed_at) VALUES ($1, $2, $3, true, $4)",
+            position_id.value(),
+            position.status.to_string(),
+            PositionStatus::Ghosted.to_string(),
+            now,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| PositionRepoError::DatabaseError(e.to_string()))?;
+        Ok(true)
+    }
 }
 
 #[async_trait]
@@ -105,13 +254,8 @@ impl IPositionRepository for PositionPostgresRepository {
         })?;
 
         // 2. Insert into scraper_queue
-        // Extract traceparent from current span for full propagation
-        let context = tracing::Span::current().context();
-        let mut carrier = std::collections::HashMap::new();
-        opentelemetry::global::get_text_map_propagator(|propagator| {
-            propagator.inject_context(&context, &mut carrier);
-        });
-        let traceparent = carrier.get("traceparent").cloned().unwrap_or_default();
+        // Carry the current trace over to the scraper
+        let traceparent = current_trace_context().unwrap_or_default();
 
         sqlx::query!(
             "INSERT INTO scraper_queue (url, user_id, position_id, trace_id, status) VALUES ($1, $2, $3, $4, 'PENDING')",
@@ -141,34 +285,8 @@ impl IPositionRepository for PositionPostgresRepository {
     }
 
     async fn update(&self, position: Position) -> Result<(), PositionRepoError> {
-        let result = sqlx::query!(
-            "UPDATE positions SET company = $1, role_title = $2, description = $3, applied_on = $4, url = $5, status = $6, updated_at = $7 WHERE id = $8",
-            position.company.value(),
-            position.role_title.value(),
-            position.description.value(),
-            position.applied_on.date(),
-            position.url.value(),
-            format!("{:?}", position.status),
-            position.updated_at.naive_utc(),
-            position.id.value(),
-        )
-        .execute(&self.pool)
-        .await;
-
-        match result {
-            Ok(result) => {
-                if result.rows_affected() == 0 {
-                    warn!(
-                        position_id = %position.id.value(),
-                        error_kind = "not_found",
-                        "position_repo.update failed"
-                    );
-                    Err(PositionRepoError::NotFound(position.id))
-                } else {
-                    Ok(())
-                }
-            }
-            Err(e) => {
+        self.change_status(&position).await.inspect_err(|e| {
+            if let PositionRepoError::DatabaseError(e) = e {
                 error!(
                     position_id = %position.id.value(),
                     user_id = %position.user_id.value(),
@@ -176,15 +294,14 @@ impl IPositionRepository for PositionPostgresRepository {
                     error = %e,
                     "position_repo.update failed"
                 );
-                Err(PositionRepoError::DatabaseError(e.to_string()))
             }
-        }
+        })
     }
 
     async fn get(&self, _position_id: PositionUuid) -> Result<Option<Position>, PositionRepoError> {
         let result = sqlx::query_as!(
             PositionRow,
-            "SELECT id, user_id, company, role_title, description, applied_on, url, status, created_at, updated_at, deleted_at, deleted FROM positions WHERE id = $1",
+            "SELECT p.id, p.user_id, p.company, p.role_title, p.description, p.applied_on, p.url, p.status, p.created_at, p.updated_at, p.deleted_at, p.deleted, GREATEST((SELECT MAX(h.changed_at) FROM position_status_history h WHERE h.position_id = p.id), (SELECT MAX(c.created_at) FROM comments c WHERE c.position_id = p.id)) AS last_activity_at FROM positions p WHERE p.id = $1",
             _position_id.value()
         )
         .fetch_optional(&self.pool)
@@ -218,7 +335,7 @@ impl IPositionRepository for PositionPostgresRepository {
     async fn get_all(&self) -> Result<Vec<Position>, PositionRepoError> {
         let result = sqlx::query_as!(
             PositionRow,
-            "SELECT id, user_id, company, role_title, description, applied_on, url, status, created_at, updated_at, deleted_at, deleted FROM positions"
+            "SELECT p.id, p.user_id, p.company, p.role_title, p.description, p.applied_on, p.url, p.status, p.created_at, p.updated_at, p.deleted_at, p.deleted, GREATEST((SELECT MAX(h.changed_at) FROM position_status_history h WHERE h.position_id = p.id), (SELECT MAX(c.created_at) FROM comments c WHERE c.position_id = p.id)) AS last_activity_at FROM positions p"
         )
             .fetch_all(&self.pool)
             .await;
@@ -246,6 +363,99 @@ impl IPositionRepository for PositionPostgresRepository {
         }
     }
 
+    async fn get_stale_candidates(
+        &self,
+        stale_after_days: &HashMap<UserUuid, i64>,
+        today: NaiveDate,
+    ) -> Result<Vec<Position>, PositionRepoError> {
+        let (user_ids, days): (Vec<Uuid>, Vec<i64>) = stale_after_days
+            .iter()
+            .map(|(user_id, days)| (user_id.value(), *days))
+            .unzip();
+        let terminal: Vec<String> = PositionStatus::TERMINAL
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        // Activity is compared by UTC date here and by local date in
+        // `Position::staleness`, hence the day of slack.
+        let rows = sqlx::query_as!(
+            PositionRow,
+            r#"SELECT p.id, p.user_id, p.company, p.role_title, p.description, p.applied_on, p.url, p.status, p.created_at, p.updated_at, p.deleted_at, p.deleted, a.last_activity_at
+            FROM positions p
+            JOIN UNNEST($1::uuid[], $2::int8[]) AS t(user_id, stale_after_days) ON t.user_id = p.user_id
+            CROSS JOIN LATERAL (SELECT GREATEST((SELECT MAX(h.changed_at) FROM position_status_history h WHERE h.position_id = p.id), (SELECT MAX(c.created_at) FROM comments c WHERE c.position_id = p.id)) AS last_activity_at) a
+            WHERE NOT p.deleted
+              AND p.status <> ALL($3)
+              AND GREATEST(p.applied_on, a.last_activity_at::date) <= $4::date - t.stale_after_days::int + 1"#,
+            &user_ids,
+            &days,
+            &terminal,
+            today,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                error_kind = "database_error",
+                error = %e,
+                "position_repo.get_stale_candidates failed"
+            );
+            PositionRepoError::DatabaseError(e.to_string())
+        })?;
+
+        rows.into_iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(PositionRepoError::from)
+    }
+
+    async fn get_status_history(
+        &self,
+        position_id: PositionUuid,
+    ) -> Result<Vec<StatusChange>, PositionRepoError> {
+        let rows = sqlx::query_as!(
+            StatusChangeRow,
+            "SELECT position_id, from_status, to_status, automatic, changed_at FROM position_status_history WHERE position_id = $1 ORDER BY changed_at ASC",
+            position_id.value()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                position_id = %position_id.value(),
+                error_kind = "database_error",
+                error = %e,
+                "position_repo.get_status_history failed"
+            );
+            PositionRepoError::DatabaseError(e.to_string())
+        })?;
+
+        rows.into_iter()
+            .map(Self::status_change_from_row)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(PositionRepoError::from)
+    }
+
+    async fn ghost_if_stale(
+        &self,
+        position_id: PositionUuid,
+        stale_after_days: i64,
+        today: NaiveDate,
+    ) -> Result<bool, PositionRepoError> {
+        self.ghost_locked_if_stale(position_id, stale_after_days, today)
+            .await
+            .inspect_err(|e| {
+                if let PositionRepoError::DatabaseError(e) = e {
+                    error!(
+                        position_id = %position_id.value(),
+                        error_kind = "database_error",
+                        error = %e,
+                        "position_repo.ghost_if_stale failed"
+                    );
+                }
+            })
+    }
+
     async fn remove(&self, _position_uuid: PositionUuid) -> Result<(), PositionRepoError> {
         let result = sqlx::query!(
             "UPDATE positions SET deleted = true, deleted_at = NOW() WHERE id = $1",
@@ -415,6 +625,102 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_stale_candidates_are_open_positions_past_their_users_threshold() {
+        let mut factory = TestFactory::new().await;
+        let opted_in = factory.create_random_user().await;
+        let other = factory.create_random_user().await;
+        let repository = PositionPostgresRepository::new(factory.pool.clone()).await;
+        let today = chrono::Local::now().date_naive();
+
+        let mut save = async |user_id, applied_on, status| {
+            let mut position = create_fixture_position();
+            position.user_id = user_id;
+            position.applied_on =
+                crate::positions::domain::entities::position::AppliedOn::from_date(applied_on);
+            position.status = status;
+            factory.track_position(position.id.value());
+            repository.save(position).await.unwrap()
+        };
+        let stale = save(
+            opted_in.id,
+            today - chrono::Days::new(30),
+            PositionStatus::CvSent,
+        )
+        .await;
+        save(
+            opted_in.id,
+            today - chrono::Days::new(3),
+            PositionStatus::CvSent,
+        )
+        .await;
+        save(
+            opted_in.id,
+            today - chrono::Days::new(30),
+            PositionStatus::Rejected,
+        )
+        .await;
+        save(
+            other.id,
+            today - chrono::Days::new(30),
+            PositionStatus::CvSent,
+        )
+        .await;
+
+        let candidates = repository
+            .get_stale_candidates(&HashMap::from([(opted_in.id, 14)]), today)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            candidates.iter().map(|p| p.id).collect::<Vec<_>>(),
+            vec![stale]
+        );
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_ghosting_keeps_changes_made_since_the_position_was_read() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = PositionPostgresRepository::new(factory.pool.clone()).await;
+        let today = chrono::Local::now().date_naive();
+
+        let mut edited = create_fixture_position();
+        edited.user_id = user.id;
+        factory.track_position(edited.id.value());
+        repository.save(edited.clone()).await.unwrap();
+        let mut reopened = create_fixture_position();
+        reopened.user_id = user.id;
+        factory.track_position(reopened.id.value());
+        repository.save(reopened.clone()).await.unwrap();
+
+        // Both were stale when the sweep read them, then the user got to them.
+        edited.company = crate::positions::domain::entities::position::Company::new("Edited Co");
+        repository.update(edited.clone()).await.unwrap();
+        reopened.status = PositionStatus::TechnicalInterview;
+        reopened.updated_at = chrono::Local::now();
+        repository.update(reopened.clone()).await.unwrap();
+
+        assert_eq!(
+            repository.ghost_if_stale(edited.id, 14, today).await,
+            Ok(true)
+        );
+        assert_eq!(
+            repository.ghost_if_stale(reopened.id, 14, today).await,
+            Ok(false)
+        );
+
+        let edited = repository.get(edited.id).await.unwrap().unwrap();
+        assert_eq!(edited.status, PositionStatus::Ghosted);
+        assert_eq!(edited.company.value(), "Edited Co");
+        let reopened = repository.get(reopened.id).await.unwrap().unwrap();
+        assert_eq!(reopened.status, PositionStatus::TechnicalInterview);
+
+        factory.teardown().await;
+    }
+
     #[tokio::test]
     async fn test_repository_contract() {
         let mut factory = TestFactory::new().await;
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{
+    positions::domain::{
+        entities::staleness_settings::StalenessSettings, errors::PositionRepoError,
+        repositories::staleness_settings_repository::IStalenessSettingsRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+#[derive(Clone, Default)]
+pub struct StalenessSettingsInMemoryRepository {
+    settings: Arc<RwLock<Vec<StalenessSettings>>>,
+}
+
+#[async_trait]
+impl IStalenessSettingsRepository for StalenessSettingsInMemoryRepository {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<StalenessSettings>, PositionRepoError> {
+        Ok(self
+            .settings
+            .read()
+            .await
+            .iter()
+            .find(|s| s.user_id == user_id)
+            .cloned())
+    }
+
+    async fn save(&self, settings: StalenessSettings) -> Result<(), PositionRepoError> {
+        let mut all = self.settings.write().await;
+        all.retain(|s| s.user_id != settings.user_id);
+        all.push(settings);
+        Ok(())
+    }
+
+    async fn get_auto_ghost_enabled(&self) -> Result<Vec<StalenessSettings>, PositionRepoError> {
+        Ok(self
+            .settings
+            .read()
+            .await
+            .iter()
+            .filter(|s| s.auto_ghost)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_overwrites_existing_settings() {
+        let repo = StalenessSettingsInMemoryRepository::default();
+        let user_id = UserUuid::new();
+
+        repo.save(StalenessSettings::new(user_id, 10, false).unwrap())
+            .await
+            .unwrap();
+        repo.save(StalenessSettings::new(user_id, 20, true).unwrap())
+            .await
+            .unwrap();
+
+        let settings = repo.get(user_id).await.unwrap().unwrap();
+        assert_eq!(settings.stale_after_days(), 20);
+        assert_eq!(repo.get_auto_ghost_enabled().await.unwrap().len(), 1);
+    }
+}
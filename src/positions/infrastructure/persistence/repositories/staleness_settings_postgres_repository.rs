@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    positions::domain::{
+        entities::staleness_settings::StalenessSettings,
+        errors::{PositionDomainError, PositionRepoError},
+        repositories::staleness_settings_repository::IStalenessSettingsRepository,
+    },
+    shared::domain::value_objects::UserUuid,
+};
+
+struct StalenessSettingsRow {
+    user_id: Uuid,
+    stale_after_days: i32,
+    auto_ghost: bool,
+}
+
+pub struct StalenessSettingsPostgresRepository {
+    pool: PgPool,
+}
+
+impl StalenessSettingsPostgresRepository {
+    pub async fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn from_row(row: StalenessSettingsRow) -> Result<StalenessSettings, PositionDomainError> {
+        StalenessSettings::new(
+            UserUuid::from_uuid(row.user_id),
+            i64::from(row.stale_after_days),
+            row.auto_ghost,
+        )
+    }
+}
+
+#[async_trait]
+impl IStalenessSettingsRepository for StalenessSettingsPostgresRepository {
+    async fn get(&self, user_id: UserUuid) -> Result<Option<StalenessSettings>, PositionRepoError> {
+        let row = sqlx::query_as!(
+            StalenessSettingsRow,
+            "SELECT user_id, stale_after_days, auto_ghost FROM staleness_settings WHERE user_id = $1",
+            user_id.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                user_id = %user_id.value(),
+                error_kind = "database_error",
+                error = %e,
+                "staleness_settings_repo.get failed"
+            );
+            PositionRepoError::DatabaseError(e.to_string())
+        })?;
+
+        row.map(Self::from_row)
+            .transpose()
+            .map_err(PositionRepoError::from)
+    }
+
+    async fn save(&self, settings: StalenessSettings) -> Result<(), PositionRepoError> {
+        let stale_after_days = i32::try_from(settings.stale_after_days())
+            .map_err(|_| PositionDomainError::InvalidStaleAfterDays(settings.stale_after_days()))?;
+
+        sqlx::query!(
+            "INSERT INTO staleness_settings (user_id, stale_after_days, auto_ghost, updated_at) VALUES ($1, $2, $3, NOW()) ON CONFLICT (user_id) DO UPDATE SET stale_after_days = EXCLUDED.stale_after_days, auto_ghost = EXCLUDED.auto_ghost, updated_at = NOW()",
+            settings.user_id.value(),
+            stale_after_days,
+            settings.auto_ghost,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                user_id = %settings.user_id.value(),
+                error_kind = "database_error",
+                error = %e,
+                "staleness_settings_repo.save failed"
+            );
+            PositionRepoError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    async fn get_auto_ghost_enabled(&self) -> Result<Vec<StalenessSettings>, PositionRepoError> {
+        let rows = sqlx::query_as!(
+            StalenessSettingsRow,
+            "SELECT user_id, stale_after_days, auto_ghost FROM staleness_settings WHERE auto_ghost"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                error_kind = "database_error",
+                error = %e,
+                "staleness_settings_repo.get_auto_ghost_enabled failed"
+            );
+            PositionRepoError::DatabaseError(e.to_string())
+        })?;
+
+        rows.into_iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(PositionRepoError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_save_and_get_settings() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let repository = StalenessSettingsPostgresRepository::new(factory.pool.clone()).await;
+
+        assert!(repository.get(user.id).await.unwrap().is_none());
+
+        repository
+            .save(StalenessSettings::new(user.id, 30, true).unwrap())
+            .await
+            .expect("Should save settings");
+        repository
+            .save(StalenessSettings::new(user.id, 45, true).unwrap())
+            .await
+            .expect("Should upsert settings");
+
+        let settings = repository.get(user.id).await.unwrap().unwrap();
+        assert_eq!(settings.stale_after_days(), 45);
+        assert!(
+            repository
+                .get_auto_ghost_enabled()
+                .await
+                .unwrap()
+                .iter()
+                .any(|s| s.user_id == user.id)
+        );
+
+        factory.teardown().await;
+    }
+}
@@ -58,6 +58,36 @@ mod tests {
         async fn is_account_disabled(&self, _user_id: &str) -> bool {
             self.is_disabled
         }
+
+        async fn is_token_revoked(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            false
+        }
+
+        async fn is_session_active(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            true
+        }
+
+        async fn is_email_verified(&self, _user_id: &str) -> bool {
+            true
+        }
+
+        async fn is_admin(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn authenticate_personal_access_token(
+            &self,
+            _token: &str,
+        ) -> Option<crate::shared::infrastructure::http::auth_extractor::PersonalAccessTokenGrant>
+        {
+            None
+        }
     }
 
     async fn setup_router_with_position(
@@ -91,9 +121,15 @@ mod tests {
         let comment_service = CommentService::new(comment_repo);
         let config = Config::test_default();
         let user_checker = std::sync::Arc::new(MockUserStatusChecker { is_disabled: false });
+        let staleness_service = crate::positions::application::staleness_service::StalenessService::new(
+            Box::new(crate::positions::infrastructure::persistence::repositories::staleness_settings_in_memory_repository::StalenessSettingsInMemoryRepository::default()),
+            Box::new(PositionInMemoryRepository::default()),
+            21,
+        );
         let app = create_position_routes(
             std::sync::Arc::new(position_service),
             std::sync::Arc::new(comment_service),
+            std::sync::Arc::new(staleness_service),
             std::sync::Arc::new(config.clone()),
             user_checker,
         );
@@ -7,8 +7,11 @@ use crate::{
     positions::{
         domain::entities::comment::{Comment, CommentBody, CommentUuid},
         domain::entities::position::{
-            AppliedOn, Company, Description, Position, PositionStatus, PositionUuid, RoleTitle, Url,
+            AppliedOn, Company, Description, Position, PositionStatus, PositionUuid, RoleTitle,
+            Staleness, Url,
         },
+        domain::entities::staleness_settings::StalenessSettings,
+        domain::entities::status_change::StatusChange,
         presentation::errors::{CommentApiError, PositionApiError},
     },
     shared::domain::{errors::SharedDomainError, value_objects::UserUuid},
@@ -29,10 +32,11 @@ pub struct PositionResponseDto {
     pub updated_at: String,
     pub deleted_at: Option<String>,
     pub deleted: bool,
+    pub staleness: String,
 }
 
-impl From<&Position> for PositionResponseDto {
-    fn from(position: &Position) -> Self {
+impl PositionResponseDto {
+    pub fn new(position: &Position, staleness: Staleness) -> Self {
         Self {
             id: position.id.to_string(),
             user_id: position.user_id.to_string(),
@@ -46,10 +50,60 @@ impl From<&Position> for PositionResponseDto {
             updated_at: position.updated_at.to_string(),
             deleted_at: position.deleted_at.map(|date| date.to_string()),
             deleted: position.deleted,
+            staleness: staleness.to_string(),
         }
     }
 }
 
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct PositionListQuery {
+    pub staleness: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct StatusChangeResponseDto {
+    pub from_status: String,
+    pub to_status: String,
+    pub automatic: bool,
+    pub changed_at: String,
+}
+
+impl From<&StatusChange> for StatusChangeResponseDto {
+    fn from(change: &StatusChange) -> Self {
+        Self {
+            from_status: change.from_status.to_string(),
+            to_status: change.to_status.to_string(),
+            automatic: change.automatic,
+            changed_at: change.changed_at.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct StalenessSettingsDto {
+    pub stale_after_days: i64,
+    pub auto_ghost: bool,
+}
+
+impl From<&StalenessSettings> for StalenessSettingsDto {
+    fn from(settings: &StalenessSettings) -> Self {
+        Self {
+            stale_after_days: settings.stale_after_days(),
+            auto_ghost: settings.auto_ghost,
+        }
+    }
+}
+
+impl StalenessSettingsDto {
+    pub fn to_settings(&self, user_id: UserUuid) -> Result<StalenessSettings, PositionApiError> {
+        Ok(StalenessSettings::new(
+            user_id,
+            self.stale_after_days,
+            self.auto_ghost,
+        )?)
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct PositionUuidDto {
     id: String,
@@ -147,6 +201,7 @@ impl SavePositionRequestDto {
             updated_at: chrono::Local::now(),
             deleted_at: None,
             deleted: false,
+            last_activity_at: None,
         };
         Ok(position)
     }
@@ -177,6 +232,7 @@ impl UpdatePositionRequestDto {
             updated_at: chrono::Local::now(),
             deleted_at: existing.deleted_at,
             deleted: existing.deleted,
+            last_activity_at: existing.last_activity_at,
         };
         Ok(position)
     }
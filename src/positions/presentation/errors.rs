@@ -28,6 +28,9 @@ pub enum PositionApiError {
 
     #[error("Domain error: `{0}`")]
     SharedDomainError(#[from] SharedDomainError),
+
+    #[error("Email not verified")]
+    EmailNotVerified,
 }
 
 #[derive(Error, Debug)]
@@ -64,6 +67,10 @@ impl IntoResponse for PositionApiError {
             ),
             PositionApiError::PositionDomainError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
             PositionApiError::SharedDomainError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
+            PositionApiError::EmailNotVerified => (
+                StatusCode::FORBIDDEN,
+                "Verify your email before creating positions".to_string(),
+            ),
         };
 
         (status, Json(ApiErrorResponse { message })).into_response()
@@ -114,7 +121,8 @@ mod tests {
 
     #[test]
     fn test_position_domain_error_response() {
-        let domain_error = PositionDomainError::InvalidStatus("bad".to_string());
+        let domain_error =
+            PositionDomainError::from(SharedDomainError::InvalidPositionStatus("bad".to_string()));
         let error = PositionApiError::from(domain_error);
         let response = error.into_response();
         assert_eq!(response_status(response), StatusCode::BAD_REQUEST);
@@ -1,10 +1,10 @@
 use crate::{
     positions::{
-        domain::entities::position::PositionUuid,
+        domain::entities::position::{PositionUuid, Staleness},
         presentation::{
             dtos::{
-                PositionResponseDto, PositionUuidDto, SavePositionRequestDto,
-                UpdatePositionRequestDto,
+                PositionListQuery, PositionResponseDto, PositionUuidDto, SavePositionRequestDto,
+                StatusChangeResponseDto, UpdatePositionRequestDto,
             },
             errors::PositionApiError,
             routes::PositionState,
@@ -16,16 +16,30 @@ use crate::{
 };
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
+use chrono::Local;
 use std::str::FromStr;
 
+async fn stale_after_days(
+    state: &PositionState,
+    user: &AuthenticatedUser,
+) -> Result<i64, PositionApiError> {
+    let user_id = UserUuid::from_str(&user.0)?;
+    let settings = state.staleness_service.get_settings(user_id).await?;
+    Ok(settings.stale_after_days())
+}
+
 #[utoipa::path(
     get,
     path = "/positions",
+    params(
+        ("staleness" = Option<String>, Query, description = "Filter by staleness: active, stale or ghosted")
+    ),
     responses(
         (status = 200, description = "List all positions", body = [PositionResponseDto]),
+        (status = 400, description = "Invalid staleness filter"),
         (status = 401, description = "Unauthorized")
     ),
     security(
@@ -34,11 +48,25 @@ use std::str::FromStr;
     tag = "Positions"
 )]
 pub async fn get_positions(
-    _user: AuthenticatedUser,
+    user: AuthenticatedUser,
     State(state): State<PositionState>,
+    Query(query): Query<PositionListQuery>,
 ) -> Result<Json<Vec<PositionResponseDto>>, PositionApiError> {
+    let filter = query
+        .staleness
+        .as_deref()
+        .map(Staleness::from_str)
+        .transpose()?;
+    let stale_after_days = stale_after_days(&state, &user).await?;
+    let today = Local::now().date_naive();
+
     let positions = state.service.get_positions().await?;
-    let positions_dto = positions.iter().map(PositionResponseDto::from).collect();
+    let positions_dto = positions
+        .iter()
+        .map(|position| (position, position.staleness(stale_after_days, today)))
+        .filter(|(_, staleness)| filter.is_none_or(|wanted| wanted == *staleness))
+        .map(|(position, staleness)| PositionResponseDto::new(position, staleness))
+        .collect();
     Ok(Json(positions_dto))
 }
 
@@ -59,14 +87,18 @@ pub async fn get_positions(
     tag = "Positions"
 )]
 pub async fn get_position(
-    _user: AuthenticatedUser,
+    user: AuthenticatedUser,
     State(state): State<PositionState>,
     Path(position_id): Path<PositionUuidDto>,
 ) -> Result<Json<PositionResponseDto>, PositionApiError> {
     let id: PositionUuid = position_id.try_into()?;
     let position = state.service.get_position(id).await?;
     match position {
-        Some(position) => Ok(Json(PositionResponseDto::from(&position))),
+        Some(position) => {
+            let stale_after_days = stale_after_days(&state, &user).await?;
+            let staleness = position.staleness(stale_after_days, Local::now().date_naive());
+            Ok(Json(PositionResponseDto::new(&position, staleness)))
+        }
         None => Err(PositionApiError::PositionNotFound(id)),
     }
 }
@@ -77,7 +109,8 @@ pub async fn get_position(
     request_body = SavePositionRequestDto,
     responses(
         (status = 200, description = "Position saved", body = PositionResponseDto),
-        (status = 401, description = "Unauthorized")
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Email not verified, when verification is required")
     ),
     security(
         ("bearer_auth" = [])
@@ -89,10 +122,17 @@ pub async fn save_position(
     State(state): State<PositionState>,
     Json(payload): Json<SavePositionRequestDto>,
 ) -> Result<Json<PositionResponseDto>, PositionApiError> {
+    if state.config.require_verified_email_for_positions
+        && !state.user_checker.is_email_verified(&user.0).await
+    {
+        return Err(PositionApiError::EmailNotVerified);
+    }
     let user_id = UserUuid::from_str(&user.0)?;
     let position = payload.to_new_position(user_id)?;
     state.service.save(position.clone()).await?;
-    Ok(Json(PositionResponseDto::from(&position)))
+    let stale_after_days = stale_after_days(&state, &user).await?;
+    let staleness = position.staleness(stale_after_days, Local::now().date_naive());
+    Ok(Json(PositionResponseDto::new(&position, staleness)))
 }
 
 #[utoipa::path(
@@ -113,7 +153,7 @@ pub async fn save_position(
     tag = "Positions"
 )]
 pub async fn update_position(
-    _user: AuthenticatedUser,
+    user: AuthenticatedUser,
     State(state): State<PositionState>,
     Path(position_id): Path<PositionUuidDto>,
     Json(payload): Json<UpdatePositionRequestDto>,
@@ -126,7 +166,14 @@ pub async fn update_position(
 
     let updated = payload.to_updated_position(existing)?;
     state.service.update(updated.clone()).await?;
-    Ok(Json(PositionResponseDto::from(&updated)))
+    let updated = state
+        .service
+        .get_position(id)
+        .await?
+        .ok_or(PositionApiError::PositionNotFound(id))?;
+    let stale_after_days = stale_after_days(&state, &user).await?;
+    let staleness = updated.staleness(stale_after_days, Local::now().date_naive());
+    Ok(Json(PositionResponseDto::new(&updated, staleness)))
 }
 
 #[utoipa::path(
@@ -153,3 +200,34 @@ pub async fn remove_position(
     state.service.remove(position_id.try_into()?).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    get,
+    path = "/positions/{id}/status-history",
+    params(
+        ("id" = String, Path, description = "Position ID")
+    ),
+    responses(
+        (status = 200, description = "Status changes of the position, oldest first", body = [StatusChangeResponseDto]),
+        (status = 404, description = "Position not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Positions"
+)]
+pub async fn get_status_history(
+    _user: AuthenticatedUser,
+    State(state): State<PositionState>,
+    Path(position_id): Path<PositionUuidDto>,
+) -> Result<Json<Vec<StatusChangeResponseDto>>, PositionApiError> {
+    let id: PositionUuid = position_id.try_into()?;
+    if state.service.get_position(id).await?.is_none() {
+        return Err(PositionApiError::PositionNotFound(id));
+    }
+    let history = state.service.get_status_history(id).await?;
+    Ok(Json(
+        history.iter().map(StatusChangeResponseDto::from).collect(),
+    ))
+}
@@ -4,6 +4,7 @@ pub mod dtos;
 pub mod errors;
 pub mod handlers;
 pub mod routes;
+pub mod staleness_handlers;
 
 use std::sync::Arc;
 
@@ -11,7 +12,10 @@ use axum::Router;
 
 use crate::{
     positions::{
-        application::{comment_service::CommentService, position_service::PositionService},
+        application::{
+            comment_service::CommentService, position_service::PositionService,
+            staleness_service::StalenessService,
+        },
         presentation::routes::create_position_routes,
     },
     shared::config::Config,
@@ -22,11 +26,18 @@ use crate::shared::infrastructure::http::auth_extractor::UserStatusChecker;
 pub fn build_router(
     service: Arc<PositionService>,
     comment_service: Arc<CommentService>,
+    staleness_service: Arc<StalenessService>,
     config: Arc<Config>,
     user_checker: Arc<dyn UserStatusChecker>,
 ) -> Router {
     Router::new().nest(
         "/positions",
-        create_position_routes(service, comment_service, config, user_checker),
+        create_position_routes(
+            service,
+            comment_service,
+            staleness_service,
+            config,
+            user_checker,
+        ),
     )
 }
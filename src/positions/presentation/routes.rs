@@ -9,10 +9,15 @@ use axum::{
 use crate::positions::presentation::comment_routes::create_comment_routes;
 use crate::{
     positions::{
-        application::{comment_service::CommentService, position_service::PositionService},
+        application::{
+            comment_service::CommentService, position_service::PositionService,
+            staleness_service::StalenessService,
+        },
         presentation::handlers::{
-            get_position, get_positions, remove_position, save_position, update_position,
+            get_position, get_positions, get_status_history, remove_position, save_position,
+            update_position,
         },
+        presentation::staleness_handlers::{get_staleness_settings, update_staleness_settings},
     },
     shared::config::Config,
 };
@@ -23,6 +28,7 @@ use crate::shared::infrastructure::http::auth_extractor::UserStatusChecker;
 pub struct PositionState {
     pub service: Arc<PositionService>,
     pub comment_service: Arc<CommentService>,
+    pub staleness_service: Arc<StalenessService>,
     pub config: Arc<Config>,
     pub user_checker: Arc<dyn UserStatusChecker>,
 }
@@ -54,18 +60,23 @@ impl FromRef<PositionState> for Arc<dyn UserStatusChecker> {
 pub fn create_position_routes(
     service: Arc<PositionService>,
     comment_service: Arc<CommentService>,
+    staleness_service: Arc<StalenessService>,
     config: Arc<Config>,
     user_checker: Arc<dyn UserStatusChecker>,
 ) -> Router {
     let state = PositionState {
         service,
         comment_service,
+        staleness_service,
         config,
         user_checker,
     };
     Router::new()
         .route("/", get(get_positions))
+        .route("/staleness-settings", get(get_staleness_settings))
+        .route("/staleness-settings", put(update_staleness_settings))
         .route("/{id}", get(get_position))
+        .route("/{id}/status-history", get(get_status_history))
         .route("/", post(save_position))
         .route("/{id}", put(update_position))
         .route("/{id}", delete(remove_position))
@@ -80,6 +91,7 @@ mod tests {
         domain::entities::position::PositionBuilder,
         domain::repositories::position_repository::IPositionRepository,
         infrastructure::persistence::repositories::position_in_memory_repository::PositionInMemoryRepository,
+        infrastructure::persistence::repositories::staleness_settings_in_memory_repository::StalenessSettingsInMemoryRepository,
     };
 
     use axum::{
@@ -92,6 +104,7 @@ mod tests {
     // Helper to setup the router with an in-memory repository
     struct MockUserStatusChecker {
         is_disabled: bool,
+        is_verified: bool,
     }
 
     #[async_trait::async_trait]
@@ -99,6 +112,44 @@ mod tests {
         async fn is_account_disabled(&self, _user_id: &str) -> bool {
             self.is_disabled
         }
+
+        async fn is_token_revoked(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            false
+        }
+
+        async fn is_session_active(
+            &self,
+            _claims: &crate::shared::infrastructure::http::auth_extractor::Claims,
+        ) -> bool {
+            true
+        }
+
+        async fn is_email_verified(&self, _user_id: &str) -> bool {
+            self.is_verified
+        }
+
+        async fn is_admin(&self, _user_id: &str) -> bool {
+            false
+        }
+
+        async fn authenticate_personal_access_token(
+            &self,
+            _token: &str,
+        ) -> Option<crate::shared::infrastructure::http::auth_extractor::PersonalAccessTokenGrant>
+        {
+            None
+        }
+    }
+
+    fn create_staleness_service() -> Arc<StalenessService> {
+        Arc::new(StalenessService::new(
+            Box::new(StalenessSettingsInMemoryRepository::default()),
+            Box::new(PositionInMemoryRepository::default()),
+            21,
+        ))
     }
 
     fn setup_router() -> (Router, Config) {
@@ -108,11 +159,15 @@ mod tests {
             crate::positions::infrastructure::persistence::repositories::comment_in_memory_repository::CommentInMemoryRepository::default(),
         )));
         let config = Config::test_default();
-        let user_checker = Arc::new(MockUserStatusChecker { is_disabled: false });
+        let user_checker = Arc::new(MockUserStatusChecker {
+            is_disabled: false,
+            is_verified: true,
+        });
         (
             create_position_routes(
                 service,
                 comment_service,
+                create_staleness_service(),
                 Arc::new(config.clone()),
                 user_checker,
             ),
@@ -189,6 +244,50 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_save_position_requires_verified_email_when_configured() {
+        let mut config = Config::test_default();
+        config.require_verified_email_for_positions = true;
+        let app = create_position_routes(
+            Arc::new(PositionService::new(Box::new(
+                PositionInMemoryRepository::default(),
+            ))),
+            Arc::new(CommentService::new(Box::new(
+                crate::positions::infrastructure::persistence::repositories::comment_in_memory_repository::CommentInMemoryRepository::default(),
+            ))),
+            create_staleness_service(),
+            Arc::new(config.clone()),
+            Arc::new(MockUserStatusChecker {
+                is_disabled: false,
+                is_verified: false,
+            }),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header("content-type", "application/json")
+                    .header("Authorization", get_auth_header(&config))
+                    .body(Body::from(
+                        r#"{
+                        "company": "Rust Corp",
+                        "role_title": "Senior Rust Developer",
+                        "description": "Senior Rust Developer needed",
+                        "applied_on": "Fri, 27 Oct 2023 12:00:00 +0000",
+                        "url": "https://rust.com/jobs/1",
+                        "status": "CvSent"
+                    }"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
     #[tokio::test]
     async fn test_get_position_by_id() {
         let repo = PositionInMemoryRepository::default();
@@ -201,8 +300,17 @@ mod tests {
             crate::positions::infrastructure::persistence::repositories::comment_in_memory_repository::CommentInMemoryRepository::default(),
         )));
         let config = Arc::new(Config::test_default());
-        let user_checker = Arc::new(MockUserStatusChecker { is_disabled: false });
-        let app = create_position_routes(service, comment_service, config.clone(), user_checker);
+        let user_checker = Arc::new(MockUserStatusChecker {
+            is_disabled: false,
+            is_verified: true,
+        });
+        let app = create_position_routes(
+            service,
+            comment_service,
+            create_staleness_service(),
+            config.clone(),
+            user_checker,
+        );
 
         let uri = format!("/{}", id);
         let response = app
@@ -231,8 +339,17 @@ mod tests {
             crate::positions::infrastructure::persistence::repositories::comment_in_memory_repository::CommentInMemoryRepository::default(),
         )));
         let config = Arc::new(Config::test_default());
-        let user_checker = Arc::new(MockUserStatusChecker { is_disabled: false });
-        let app = create_position_routes(service, comment_service, config.clone(), user_checker);
+        let user_checker = Arc::new(MockUserStatusChecker {
+            is_disabled: false,
+            is_verified: true,
+        });
+        let app = create_position_routes(
+            service,
+            comment_service,
+            create_staleness_service(),
+            config.clone(),
+            user_checker,
+        );
 
         let uri = format!("/{}", id);
         let response = app
@@ -262,8 +379,17 @@ mod tests {
             crate::positions::infrastructure::persistence::repositories::comment_in_memory_repository::CommentInMemoryRepository::default(),
         )));
         let config = Arc::new(Config::test_default());
-        let user_checker = Arc::new(MockUserStatusChecker { is_disabled: false });
-        let app = create_position_routes(service, comment_service, config.clone(), user_checker);
+        let user_checker = Arc::new(MockUserStatusChecker {
+            is_disabled: false,
+            is_verified: true,
+        });
+        let app = create_position_routes(
+            service,
+            comment_service,
+            create_staleness_service(),
+            config.clone(),
+            user_checker,
+        );
 
         let body_json = r#"
         {
@@ -292,4 +418,108 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_get_positions_filtered_by_staleness() {
+        let repo = PositionInMemoryRepository::default();
+        let stale = PositionBuilder::new()
+            .with_applied_on_date(chrono::Local::now().date_naive() - chrono::Days::new(60))
+            .build();
+        let active = PositionBuilder::new().build();
+        let stale_id = stale.id;
+        let _ = repo.save(stale).await;
+        let _ = repo.save(active).await;
+
+        let service = Arc::new(PositionService::new(Box::new(repo)));
+        let comment_service = Arc::new(CommentService::new(Box::new(
+            crate::positions::infrastructure::persistence::repositories::comment_in_memory_repository::CommentInMemoryRepository::default(),
+        )));
+        let config = Arc::new(Config::test_default());
+        let user_checker = Arc::new(MockUserStatusChecker {
+            is_disabled: false,
+            is_verified: true,
+        });
+        let app = create_position_routes(
+            service,
+            comment_service,
+            create_staleness_service(),
+            config.clone(),
+            user_checker,
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/?staleness=stale")
+                    .header("Authorization", get_auth_header(&config))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let positions: Vec<serde_json::Value> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0]["id"], stale_id.to_string());
+        assert_eq!(positions[0]["staleness"], "stale");
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_rejects_unknown_staleness() {
+        let (app, config) = setup_router();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/?staleness=forgotten")
+                    .header("Authorization", get_auth_header(&config))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_update_staleness_settings() {
+        let (app, config) = setup_router();
+        let auth_header = get_auth_header(&config);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/staleness-settings")
+                    .header("content-type", "application/json")
+                    .header("Authorization", &auth_header)
+                    .body(Body::from(r#"{"stale_after_days": 0, "auto_ghost": true}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/staleness-settings")
+                    .header("content-type", "application/json")
+                    .header("Authorization", &auth_header)
+                    .body(Body::from(
+                        r#"{"stale_after_days": 30, "auto_ghost": true}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 }
@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use axum::{Json, extract::State};
+
+use crate::{
+    positions::presentation::{
+        dtos::StalenessSettingsDto, errors::PositionApiError, routes::PositionState,
+    },
+    shared::{
+        domain::value_objects::UserUuid, infrastructure::http::auth_extractor::AuthenticatedUser,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/positions/staleness-settings",
+    responses(
+        (status = 200, description = "Staleness settings of the current user", body = StalenessSettingsDto),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Positions"
+)]
+pub async fn get_staleness_settings(
+    user: AuthenticatedUser,
+    State(state): State<PositionState>,
+) -> Result<Json<StalenessSettingsDto>, PositionApiError> {
+    let user_id = UserUuid::from_str(&user.0)?;
+    let settings = state.staleness_service.get_settings(user_id).await?;
+    Ok(Json(StalenessSettingsDto::from(&settings)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/positions/staleness-settings",
+    request_body = StalenessSettingsDto,
+    responses(
+        (status = 200, description = "Staleness settings updated", body = StalenessSettingsDto),
+        (status = 400, description = "Invalid settings"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Positions"
+)]
+pub async fn update_staleness_settings(
+    user: AuthenticatedUser,
+    State(state): State<PositionState>,
+    Json(payload): Json<StalenessSettingsDto>,
+) -> Result<Json<StalenessSettingsDto>, PositionApiError> {
+    let user_id = UserUuid::from_str(&user.0)?;
+    let settings = payload.to_settings(user_id)?;
+    state
+        .staleness_service
+        .update_settings(settings.clone())
+        .await?;
+    Ok(Json(StalenessSettingsDto::from(&settings)))
+}
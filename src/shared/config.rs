@@ -1,4 +1,8 @@
-use std::env;
+use std::{env, sync::Arc};
+
+use chrono::TimeDelta;
+
+use crate::shared::infrastructure::jwt_keys::JwtKeys;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,14 +16,41 @@ pub enum Environment {
     Production,
     Testing,
 }
+/// OpenID Connect issuer users can sign in with, under `name`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct OidcProviderConfig {
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scopes: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub postgres_url: String,
     pub environment: Environment,
     pub server_host: String,
     pub server_port: u16,
-    jwt_secret: String,
+    jwt_keys: Arc<JwtKeys>,
     pub jwt_expiration_time: i64,
+    pub refresh_token_expiration_time: i64,
+    pub email_verification_expiration_time: i64,
+    pub password_reset_expiration_time: i64,
+    pub email_change_expiration_time: i64,
+    pub mfa_token_expiration_time: i64,
+    pub totp_issuer: String,
+    pub oidc_providers: Vec<OidcProviderConfig>,
+    pub oidc_login_state_ttl_secs: i64,
+    pub login_delay_after_failures: u32,
+    pub login_delay_base_secs: i64,
+    pub login_delay_max_secs: i64,
+    pub login_lockout_threshold: u32,
+    pub login_lockout_secs: i64,
+    pub token_revocation_cache_ttl_secs: u64,
+    pub verification_email_cooldown_secs: i64,
+    pub verification_email_daily_cap: u32,
+    pub require_verified_email_for_positions: bool,
     pub allowed_origin: String,
     pub otlp_endpoint: String,
     pub observability_enabled: bool,
@@ -29,6 +60,13 @@ pub struct Config {
     pub rate_limit_requests_per_second: u32,
     pub rate_limit_burst: u32,
     pub rate_limit_trust_forwarded_headers: bool,
+    pub stale_after_days: i64,
+    pub staleness_job_interval_secs: u64,
+    pub digest_job_interval_secs: u64,
+    pub account_deletion_grace_period_secs: i64,
+    pub account_purge_job_interval_secs: u64,
+    /// Shared with the email worker, which signs unsubscribe links with it.
+    pub unsubscribe_secret: String,
 }
 
 impl Default for Config {
@@ -48,9 +86,8 @@ impl Default for Config {
             Environment::Production => Self::build_production_database_url(),
         };
 
-        if environment != Environment::Testing && env::var("JWT_SECRET").is_err() {
-            panic!("JWT_SECRET is not set");
-        }
+        let jwt_keys = Self::load_jwt_keys(&environment);
+        let unsubscribe_secret = Self::load_unsubscribe_secret(&environment);
 
         Config {
             postgres_url,
@@ -60,11 +97,74 @@ impl Default for Config {
                 .unwrap_or_else(|_| "3000".to_string())
                 .parse()
                 .unwrap_or(3000),
-            jwt_secret: env::var("JWT_SECRET").unwrap_or_else(|_| "secret".to_string()),
+            jwt_keys,
             jwt_expiration_time: env::var("JWT_EXPIRATION_TIME")
+                .unwrap_or_else(|_| "900".to_string())
+                .parse()
+                .unwrap_or(60 * 15),
+            refresh_token_expiration_time: env::var("REFRESH_TOKEN_EXPIRATION_TIME")
+                .unwrap_or_else(|_| "2592000".to_string())
+                .parse()
+                .unwrap_or(60 * 60 * 24 * 30),
+            email_verification_expiration_time: env::var("EMAIL_VERIFICATION_EXPIRATION_TIME")
                 .unwrap_or_else(|_| "10800".to_string())
                 .parse()
                 .unwrap_or(60 * 60 * 3),
+            password_reset_expiration_time: env::var("PASSWORD_RESET_EXPIRATION_TIME")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(60 * 60),
+            email_change_expiration_time: env::var("EMAIL_CHANGE_EXPIRATION_TIME")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(60 * 60),
+            mfa_token_expiration_time: env::var("MFA_TOKEN_EXPIRATION_TIME")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .unwrap_or(60 * 5),
+            totp_issuer: env::var("TOTP_ISSUER").unwrap_or_else(|_| "Seeker".to_string()),
+            oidc_providers: Self::load_oidc_providers(),
+            oidc_login_state_ttl_secs: env::var("OIDC_LOGIN_STATE_TTL_SECS")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()
+                .unwrap_or(60 * 10),
+            login_delay_after_failures: env::var("LOGIN_DELAY_AFTER_FAILURES")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .unwrap_or(3),
+            login_delay_base_secs: env::var("LOGIN_DELAY_BASE_SECS")
+                .unwrap_or_else(|_| "1".to_string())
+                .parse()
+                .unwrap_or(1),
+            login_delay_max_secs: env::var("LOGIN_DELAY_MAX_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+            login_lockout_threshold: env::var("LOGIN_LOCKOUT_THRESHOLD")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .unwrap_or(10),
+            login_lockout_secs: env::var("LOGIN_LOCKOUT_SECS")
+                .unwrap_or_else(|_| "900".to_string())
+                .parse()
+                .unwrap_or(60 * 15),
+            token_revocation_cache_ttl_secs: env::var("TOKEN_REVOCATION_CACHE_TTL_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+            verification_email_cooldown_secs: env::var("VERIFICATION_EMAIL_COOLDOWN_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            verification_email_daily_cap: env::var("VERIFICATION_EMAIL_DAILY_CAP")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+            require_verified_email_for_positions: env::var("REQUIRE_VERIFIED_EMAIL_FOR_POSITIONS")
+                .unwrap_or_else(|_| "false".to_string())
+                .to_lowercase()
+                .as_str()
+                == "true",
             allowed_origin: env::var("CORS_ALLOWED_ORIGIN")
                 .unwrap_or_else(|_| "http://localhost:3001".to_string()),
             otlp_endpoint: env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
@@ -95,6 +195,27 @@ impl Default for Config {
                 .to_lowercase()
                 .as_str()
                 == "true",
+            stale_after_days: env::var("STALE_AFTER_DAYS")
+                .unwrap_or_else(|_| "21".to_string())
+                .parse()
+                .unwrap_or(21),
+            staleness_job_interval_secs: env::var("STALENESS_JOB_INTERVAL_SECS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+            digest_job_interval_secs: env::var("DIGEST_JOB_INTERVAL_SECS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+            account_deletion_grace_period_secs: env::var("ACCOUNT_DELETION_GRACE_PERIOD_SECS")
+                .unwrap_or_else(|_| "259200".to_string())
+                .parse()
+                .unwrap_or(60 * 60 * 24 * 3),
+            account_purge_job_interval_secs: env::var("ACCOUNT_PURGE_JOB_INTERVAL_SECS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+            unsubscribe_secret,
         }
     }
 }
@@ -112,8 +233,66 @@ impl Config {
         })
     }
 
-    pub fn get_jwt_secret(&self) -> String {
-        self.jwt_secret.clone()
+    /// Without `JWT_SIGNING_KEY_FILE` testing runs sign with a throwaway key;
+    /// production refuses to start.
+    fn load_jwt_keys(environment: &Environment) -> Arc<JwtKeys> {
+        let Ok(signing_key_file) = env::var("JWT_SIGNING_KEY_FILE") else {
+            if *environment == Environment::Testing {
+                return JwtKeys::ephemeral();
+            }
+            panic!("JWT_SIGNING_KEY_FILE is not set");
+        };
+        let grace_period_secs = env::var("JWT_KEY_GRACE_PERIOD_SECS")
+            .unwrap_or_else(|_| "86400".to_string())
+            .parse()
+            .unwrap_or(60 * 60 * 24);
+
+        JwtKeys::load(
+            &env::var("JWT_SIGNING_KEY_ID").unwrap_or_else(|_| "default".to_string()),
+            &signing_key_file,
+            &env::var("JWT_RETIRED_KEYS").unwrap_or_default(),
+            TimeDelta::seconds(grace_period_secs),
+        )
+        .map(Arc::new)
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn load_unsubscribe_secret(environment: &Environment) -> String {
+        match env::var("UNSUBSCRIBE_SECRET") {
+            Ok(secret) if !secret.is_empty() => secret,
+            _ if *environment == Environment::Testing => "testing-unsubscribe-secret".to_string(),
+            _ => panic!("UNSUBSCRIBE_SECRET is not set"),
+        }
+    }
+
+    /// `OIDC_PROVIDERS` lists the provider names; each one is configured
+    /// with `OIDC_<NAME>_ISSUER`, `OIDC_<NAME>_CLIENT_ID`,
+    /// `OIDC_<NAME>_CLIENT_SECRET` and optionally `OIDC_<NAME>_SCOPES`.
+    fn load_oidc_providers() -> Vec<OidcProviderConfig> {
+        env::var("OIDC_PROVIDERS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                let var = |suffix: &str| format!("OIDC_{}_{}", name.to_uppercase(), suffix);
+                let required = |suffix: &str| {
+                    env::var(var(suffix)).unwrap_or_else(|_| panic!("{} is not set", var(suffix)))
+                };
+                OidcProviderConfig {
+                    issuer: required("ISSUER").trim_end_matches('/').to_string(),
+                    client_id: required("CLIENT_ID"),
+                    client_secret: required("CLIENT_SECRET"),
+                    scopes: env::var(var("SCOPES"))
+                        .unwrap_or_else(|_| "openid email profile".to_string()),
+                    name,
+                }
+            })
+            .collect()
+    }
+
+    pub fn jwt_keys(&self) -> &JwtKeys {
+        &self.jwt_keys
     }
 
     #[cfg(test)]
@@ -124,8 +303,25 @@ impl Config {
             environment: Environment::Testing,
             server_host: "127.0.0.1".to_string(),
             server_port: 3000,
-            jwt_secret: "secret".to_string(),
+            jwt_keys: JwtKeys::ephemeral(),
             jwt_expiration_time: 60 * 60 * 3,
+            refresh_token_expiration_time: 60 * 60 * 24 * 30,
+            email_verification_expiration_time: 60 * 60 * 3,
+            password_reset_expiration_time: 60 * 60,
+            email_change_expiration_time: 60 * 60,
+            mfa_token_expiration_time: 60 * 5,
+            totp_issuer: "Seeker".to_string(),
+            oidc_providers: Vec::new(),
+            oidc_login_state_ttl_secs: 60 * 10,
+            login_delay_after_failures: 3,
+            login_delay_base_secs: 1,
+            login_delay_max_secs: 30,
+            login_lockout_threshold: 10,
+            login_lockout_secs: 60 * 15,
+            token_revocation_cache_ttl_secs: 30,
+            verification_email_cooldown_secs: 60,
+            verification_email_daily_cap: 5,
+            require_verified_email_for_positions: false,
             allowed_origin: "http://localhost:3001".to_string(),
             otlp_endpoint: "http://localhost:4317".to_string(),
             observability_enabled: false,
@@ -135,6 +331,12 @@ impl Config {
             rate_limit_requests_per_second: 10,
             rate_limit_burst: 20,
             rate_limit_trust_forwarded_headers: false,
+            stale_after_days: 21,
+            staleness_job_interval_secs: 3600,
+            digest_job_interval_secs: 3600,
+            account_deletion_grace_period_secs: 60 * 60 * 24 * 3,
+            account_purge_job_interval_secs: 3600,
+            unsubscribe_secret: "testing-unsubscribe-secret".to_string(),
         }
     }
 }
@@ -161,21 +363,80 @@ mod tests {
     #[test]
     fn test_config_load_production_default() {
         // Ensure ENVIRONMENT is unset or set to something else
-        temp_env::with_var("JWT_SECRET", Some("secret"), || {
-            temp_env::with_var_unset("ENVIRONMENT", || {
+        let key_file = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/shared/infrastructure/testdata/jwt_rs256.pem"
+        );
+        temp_env::with_vars(
+            [
+                ("JWT_SIGNING_KEY_FILE", Some(key_file)),
+                ("UNSUBSCRIBE_SECRET", Some("production-secret")),
+                ("ENVIRONMENT", None),
+            ],
+            || {
                 let config = Config::default();
                 assert_eq!(config.environment, Environment::Production);
-            });
-        });
+                assert_eq!(config.jwt_keys().signing_kid(), "default");
+                assert_eq!(config.unsubscribe_secret, "production-secret");
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "UNSUBSCRIBE_SECRET is not set")]
+    fn test_should_panic_when_unsubscribe_secret_is_not_set_and_env_is_production() {
+        temp_env::with_vars_unset(["UNSUBSCRIBE_SECRET", "ENVIRONMENT"], || {
+            Config::load_unsubscribe_secret(&Environment::Production);
+        })
     }
 
     #[test]
-    #[should_panic(expected = "JWT_SECRET is not set")]
-    fn test_should_panic_when_jwt_secret_is_not_set_and_env_is_production() {
-        temp_env::with_var_unset("JWT_SECRET", || {
+    #[should_panic(expected = "JWT_SIGNING_KEY_FILE is not set")]
+    fn test_should_panic_when_jwt_signing_key_is_not_set_and_env_is_production() {
+        temp_env::with_var_unset("JWT_SIGNING_KEY_FILE", || {
             temp_env::with_var_unset("ENVIRONMENT", || {
                 let _ = Config::default();
             });
         })
     }
+
+    #[test]
+    fn test_oidc_providers_are_read_per_name() {
+        temp_env::with_vars(
+            [
+                ("OIDC_PROVIDERS", Some("Google, ")),
+                ("OIDC_GOOGLE_ISSUER", Some("https://accounts.google.com/")),
+                ("OIDC_GOOGLE_CLIENT_ID", Some("client")),
+                ("OIDC_GOOGLE_CLIENT_SECRET", Some("secret")),
+                ("OIDC_GOOGLE_SCOPES", None),
+            ],
+            || {
+                assert_eq!(
+                    Config::load_oidc_providers(),
+                    vec![OidcProviderConfig {
+                        name: "google".to_string(),
+                        issuer: "https://accounts.google.com".to_string(),
+                        client_id: "client".to_string(),
+                        client_secret: "secret".to_string(),
+                        scopes: "openid email profile".to_string(),
+                    }]
+                );
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "OIDC_GITLAB_CLIENT_ID is not set")]
+    fn test_should_panic_when_an_oidc_provider_is_incomplete() {
+        temp_env::with_vars(
+            [
+                ("OIDC_PROVIDERS", Some("gitlab")),
+                ("OIDC_GITLAB_ISSUER", Some("https://gitlab.com")),
+                ("OIDC_GITLAB_CLIENT_ID", None),
+            ],
+            || {
+                Config::load_oidc_providers();
+            },
+        );
+    }
 }
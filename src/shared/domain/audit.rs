@@ -0,0 +1,247 @@
+use std::{fmt::Display, str::FromStr};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::shared::domain::{errors::SharedDomainError, value_objects::UserUuid};
+
+/// Security-relevant things that happen to an account.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum AuditEventKind {
+    LoginSucceeded,
+    LoginFailed,
+    Signup,
+    EmailVerified,
+    EmailChanged,
+    PasswordChanged,
+    PasswordReset,
+    TwoFactorEnabled,
+    TwoFactorDisabled,
+    /// Logout, logout everywhere, a revoked personal access token or a
+    /// refresh token family closed after reuse.
+    TokenRevoked,
+    AccountDisabled,
+    AccountEnabled,
+    /// Admin operations that are not about a single account.
+    AdminAction,
+}
+
+impl AuditEventKind {
+    pub const ALL: [AuditEventKind; 13] = [
+        AuditEventKind::LoginSucceeded,
+        AuditEventKind::LoginFailed,
+        AuditEventKind::Signup,
+        AuditEventKind::EmailVerified,
+        AuditEventKind::EmailChanged,
+        AuditEventKind::PasswordChanged,
+        AuditEventKind::PasswordReset,
+        AuditEventKind::TwoFactorEnabled,
+        AuditEventKind::TwoFactorDisabled,
+        AuditEventKind::TokenRevoked,
+        AuditEventKind::AccountDisabled,
+        AuditEventKind::AccountEnabled,
+        AuditEventKind::AdminAction,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEventKind::LoginSucceeded => "login_succeeded",
+            AuditEventKind::LoginFailed => "login_failed",
+            AuditEventKind::Signup => "signup",
+            AuditEventKind::EmailVerified => "email_verified",
+            AuditEventKind::EmailChanged => "email_changed",
+            AuditEventKind::PasswordChanged => "password_changed",
+            AuditEventKind::PasswordReset => "password_reset",
+            AuditEventKind::TwoFactorEnabled => "two_factor_enabled",
+            AuditEventKind::TwoFactorDisabled => "two_factor_disabled",
+            AuditEventKind::TokenRevoked => "token_revoked",
+            AuditEventKind::AccountDisabled => "account_disabled",
+            AuditEventKind::AccountEnabled => "account_enabled",
+            AuditEventKind::AdminAction => "admin_action",
+        }
+    }
+}
+
+impl FromStr for AuditEventKind {
+    type Err = SharedDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuditEventKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| SharedDomainError::InvalidAuditEventKind(s.to_string()))
+    }
+}
+
+impl Display for AuditEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// What the caller knows about an event. Where the request came from is
+/// added by the [`IAuditLog`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub kind: AuditEventKind,
+    /// Account the event is about, `None` e.g. for logins with an unknown email.
+    pub user_id: Option<UserUuid>,
+    /// Who did it when it was not the user themselves, i.e. an admin.
+    pub actor_id: Option<UserUuid>,
+    pub detail: Option<String>,
+}
+
+impl AuditEntry {
+    pub fn new(kind: AuditEventKind, user_id: Option<UserUuid>) -> Self {
+        Self {
+            kind,
+            user_id,
+            actor_id: None,
+            detail: None,
+        }
+    }
+
+    pub fn by(mut self, actor_id: UserUuid) -> Self {
+        self.actor_id = Some(actor_id);
+        self
+    }
+
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Where a request came from, as far as the server can tell.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestOrigin {
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    pub trace_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub kind: AuditEventKind,
+    pub user_id: Option<UserUuid>,
+    pub actor_id: Option<UserUuid>,
+    pub detail: Option<String>,
+    pub origin: RequestOrigin,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    pub fn new(entry: AuditEntry, origin: RequestOrigin, occurred_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind: entry.kind,
+            user_id: entry.user_id,
+            actor_id: entry.actor_id,
+            detail: entry.detail,
+            origin,
+            occurred_at,
+        }
+    }
+}
+
+pub const DEFAULT_AUDIT_PAGE_SIZE: i64 = 50;
+pub const MAX_AUDIT_PAGE_SIZE: i64 = 200;
+
+/// Newest events first. `before` pages backwards in time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditQuery {
+    pub user_id: Option<UserUuid>,
+    pub kind: Option<AuditEventKind>,
+    pub before: Option<DateTime<Utc>>,
+    pub limit: i64,
+}
+
+impl AuditQuery {
+    pub fn new(
+        user_id: Option<UserUuid>,
+        kind: Option<AuditEventKind>,
+        before: Option<DateTime<Utc>>,
+        limit: Option<i64>,
+    ) -> Self {
+        Self {
+            user_id,
+            kind,
+            before,
+            limit: limit
+                .unwrap_or(DEFAULT_AUDIT_PAGE_SIZE)
+                .clamp(1, MAX_AUDIT_PAGE_SIZE),
+        }
+    }
+
+    pub fn matches(&self, event: &AuditEvent) -> bool {
+        self.user_id
+            .is_none_or(|user_id| event.user_id == Some(user_id))
+            && self.kind.is_none_or(|kind| event.kind == kind)
+            && self.before.is_none_or(|before| event.occurred_at < before)
+    }
+}
+
+/// Append-only record of [`AuditEvent`]s, shared by every bounded context.
+#[async_trait]
+pub trait IAuditLog: Send + Sync {
+    /// Stamps the entry with the origin of the current request. Failures are
+    /// logged rather than returned, so auditing never breaks the action.
+    async fn record(&self, entry: AuditEntry);
+    async fn search(&self, query: &AuditQuery) -> Result<Vec<AuditEvent>, SharedDomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_audit_event_kind_round_trips_through_strings() {
+        for kind in AuditEventKind::ALL {
+            assert_eq!(AuditEventKind::from_str(kind.as_str()), Ok(kind));
+        }
+        assert!(matches!(
+            AuditEventKind::from_str("logout"),
+            Err(SharedDomainError::InvalidAuditEventKind(_))
+        ));
+    }
+
+    #[test]
+    fn test_audit_query_filters_events() {
+        let now = Utc::now();
+        let user_id = UserUuid::new();
+        let event = AuditEvent::new(
+            AuditEntry::new(AuditEventKind::LoginFailed, Some(user_id)),
+            RequestOrigin::default(),
+            now,
+        );
+
+        assert!(AuditQuery::new(None, None, None, None).matches(&event));
+        assert!(
+            AuditQuery::new(Some(user_id), Some(AuditEventKind::LoginFailed), None, None)
+                .matches(&event)
+        );
+        assert!(!AuditQuery::new(Some(UserUuid::new()), None, None, None).matches(&event));
+        assert!(!AuditQuery::new(None, Some(AuditEventKind::Signup), None, None).matches(&event));
+        assert!(!AuditQuery::new(None, None, Some(now), None).matches(&event));
+        assert!(
+            AuditQuery::new(None, None, Some(now + TimeDelta::seconds(1)), None).matches(&event)
+        );
+    }
+
+    #[test]
+    fn test_audit_query_limit_is_clamped() {
+        assert_eq!(
+            AuditQuery::new(None, None, None, None).limit,
+            DEFAULT_AUDIT_PAGE_SIZE
+        );
+        assert_eq!(AuditQuery::new(None, None, None, Some(0)).limit, 1);
+        assert_eq!(
+            AuditQuery::new(None, None, None, Some(5_000)).limit,
+            MAX_AUDIT_PAGE_SIZE
+        );
+    }
+}
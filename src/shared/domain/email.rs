@@ -0,0 +1,135 @@
+use std::{fmt::Display, str::FromStr};
+
+use sha2::{Digest, Sha256};
+
+use crate::shared::domain::errors::SharedDomainError;
+
+/// What an email is about, which decides whether the user can opt out of it.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum EmailCategory {
+    /// Verification links, sign-in alerts, account changes. Always sent.
+    Security,
+    Reminders,
+    Digests,
+    Marketing,
+}
+
+impl EmailCategory {
+    pub const ALL: [EmailCategory; 4] = [
+        EmailCategory::Security,
+        EmailCategory::Reminders,
+        EmailCategory::Digests,
+        EmailCategory::Marketing,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailCategory::Security => "security",
+            EmailCategory::Reminders => "reminders",
+            EmailCategory::Digests => "digests",
+            EmailCategory::Marketing => "marketing",
+        }
+    }
+
+    /// Security emails ignore preferences, unsubscribe links and the
+    /// suppression list.
+    pub fn can_opt_out(&self) -> bool {
+        *self != EmailCategory::Security
+    }
+
+    /// Whether users who never changed their preferences get these emails.
+    pub fn enabled_by_default(&self) -> bool {
+        *self != EmailCategory::Marketing
+    }
+}
+
+impl FromStr for EmailCategory {
+    type Err = SharedDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EmailCategory::ALL
+            .into_iter()
+            .find(|category| category.as_str() == s)
+            .ok_or_else(|| SharedDomainError::InvalidEmailCategory(s.to_string()))
+    }
+}
+
+impl Display for EmailCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Names what an email is for: its template and whatever tells this
+/// occurrence apart. An email is queued at most once per key, so whoever
+/// sends it can retry, and the worker derives the `Message-ID` from it.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    pub fn new(template: &str, parts: &[&str]) -> Self {
+        Self(
+            std::iter::once(template)
+                .chain(parts.iter().copied())
+                .collect::<Vec<_>>()
+                .join(":"),
+        )
+    }
+
+    /// For emails told apart by a link, whose token would make a long key.
+    pub fn from_link(template: &str, link: &str) -> Self {
+        Self::new(
+            template,
+            &[&format!("{:x}", Sha256::digest(link.as_bytes()))],
+        )
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categories_round_trip_through_strings() {
+        for category in EmailCategory::ALL {
+            assert_eq!(category.as_str().parse::<EmailCategory>(), Ok(category));
+        }
+        assert_eq!(
+            "newsletter".parse::<EmailCategory>(),
+            Err(SharedDomainError::InvalidEmailCategory(
+                "newsletter".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_idempotency_keys_name_the_template_and_the_occurrence() {
+        let user_id = "6f1c2f2e-8a4b-4c55-9d1e-3a7b9c0d1e2f";
+
+        assert_eq!(
+            IdempotencyKey::new("weekly_digest", &[user_id, "2026-03-02"]).as_str(),
+            "weekly_digest:6f1c2f2e-8a4b-4c55-9d1e-3a7b9c0d1e2f:2026-03-02"
+        );
+        assert_eq!(
+            IdempotencyKey::from_link("verify_email", "https://seeker.example/?token=a"),
+            IdempotencyKey::from_link("verify_email", "https://seeker.example/?token=a")
+        );
+        assert_ne!(
+            IdempotencyKey::from_link("verify_email", "https://seeker.example/?token=a"),
+            IdempotencyKey::from_link("verify_email", "https://seeker.example/?token=b")
+        );
+    }
+
+    #[test]
+    fn test_only_security_emails_cannot_be_opted_out_of() {
+        assert!(!EmailCategory::Security.can_opt_out());
+        assert!(EmailCategory::Reminders.can_opt_out());
+        assert!(EmailCategory::Marketing.can_opt_out());
+        assert!(!EmailCategory::Marketing.enabled_by_default());
+        assert!(EmailCategory::Digests.enabled_by_default());
+    }
+}
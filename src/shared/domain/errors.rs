@@ -20,6 +20,21 @@ pub enum SharedDomainError {
     #[error("Invalid date/time value")]
     InvalidDateTime,
 
+    #[error("Invalid token scope: `{0}`")]
+    InvalidTokenScope(String),
+
+    #[error("Invalid user role: `{0}`")]
+    InvalidUserRole(String),
+
+    #[error("Invalid audit event kind: `{0}`")]
+    InvalidAuditEventKind(String),
+
+    #[error("Invalid email category: `{0}`")]
+    InvalidEmailCategory(String),
+
+    #[error("Invalid status: `{0}`")]
+    InvalidPositionStatus(String),
+
     #[error("Internal error: {0}")]
     InternalError(String),
 }
@@ -59,6 +74,12 @@ mod tests {
         assert!(error.to_string().contains("2026-99-99"));
     }
 
+    #[test]
+    fn test_invalid_position_status_error() {
+        let error = SharedDomainError::InvalidPositionStatus("Hired".to_string());
+        assert_eq!(error.to_string(), "Invalid status: `Hired`");
+    }
+
     #[test]
     fn test_invalid_datetime_error() {
         let error = SharedDomainError::InvalidDateTime;
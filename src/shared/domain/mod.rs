@@ -1,2 +1,6 @@
+pub mod audit;
+pub mod email;
 pub mod errors;
+pub mod position_status;
+pub mod staleness;
 pub mod value_objects;
@@ -0,0 +1,101 @@
+use std::str::FromStr;
+
+use crate::shared::domain::{errors::SharedDomainError, staleness::ApplicationState};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PositionStatus {
+    CvSent,
+    PhoneScreenScheduled,
+    TechnicalInterview,
+    OfferReceived,
+    Rejected,
+    Withdrawn,
+    Ghosted,
+}
+
+impl PositionStatus {
+    pub const TERMINAL: [PositionStatus; 4] = [
+        PositionStatus::OfferReceived,
+        PositionStatus::Rejected,
+        PositionStatus::Withdrawn,
+        PositionStatus::Ghosted,
+    ];
+
+    /// Statuses reached by getting an interview.
+    pub const INTERVIEW: [PositionStatus; 2] = [
+        PositionStatus::PhoneScreenScheduled,
+        PositionStatus::TechnicalInterview,
+    ];
+
+    pub fn is_terminal(&self) -> bool {
+        Self::TERMINAL.contains(self)
+    }
+
+    pub fn is_interview(&self) -> bool {
+        Self::INTERVIEW.contains(self)
+    }
+
+    /// How an application with this status counts for staleness.
+    pub fn application_state(&self) -> ApplicationState {
+        match self {
+            PositionStatus::Ghosted => ApplicationState::Ghosted,
+            status if status.is_terminal() => ApplicationState::Closed,
+            _ => ApplicationState::Open,
+        }
+    }
+}
+
+impl FromStr for PositionStatus {
+    type Err = SharedDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CvSent" => Ok(PositionStatus::CvSent),
+            "PhoneScreenScheduled" => Ok(PositionStatus::PhoneScreenScheduled),
+            "TechnicalInterview" => Ok(PositionStatus::TechnicalInterview),
+            "OfferReceived" => Ok(PositionStatus::OfferReceived),
+            "Rejected" => Ok(PositionStatus::Rejected),
+            "Withdrawn" => Ok(PositionStatus::Withdrawn),
+            "Ghosted" => Ok(PositionStatus::Ghosted),
+            _ => Err(SharedDomainError::InvalidPositionStatus(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for PositionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            PositionStatus::CvSent => "CvSent",
+            PositionStatus::PhoneScreenScheduled => "PhoneScreenScheduled",
+            PositionStatus::TechnicalInterview => "TechnicalInterview",
+            PositionStatus::OfferReceived => "OfferReceived",
+            PositionStatus::Rejected => "Rejected",
+            PositionStatus::Withdrawn => "Withdrawn",
+            PositionStatus::Ghosted => "Ghosted",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_application_state_of_each_status() {
+        assert_eq!(
+            PositionStatus::TechnicalInterview.application_state(),
+            ApplicationState::Open
+        );
+        assert_eq!(
+            PositionStatus::OfferReceived.application_state(),
+            ApplicationState::Closed
+        );
+        assert_eq!(
+            PositionStatus::Ghosted.application_state(),
+            ApplicationState::Ghosted
+        );
+        assert!(PositionStatus::PhoneScreenScheduled.is_interview());
+        assert!(!PositionStatus::CvSent.is_interview());
+    }
+}
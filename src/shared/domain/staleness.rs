@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+
+/// Whether an application still waits for news. Positions and digests both
+/// judge it with [`Staleness::of`], so they agree on what is stale.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Staleness {
+    Active,
+    Stale,
+    Ghosted,
+}
+
+/// Where an application stands, as far as staleness goes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ApplicationState {
+    Open,
+    /// Got an answer or was given up, so it can no longer go stale.
+    Closed,
+    Ghosted,
+}
+
+impl Staleness {
+    /// An open application is stale once `stale_after_days` went by since
+    /// its last activity.
+    pub fn of(
+        state: ApplicationState,
+        last_activity_on: NaiveDate,
+        stale_after_days: i64,
+        today: NaiveDate,
+    ) -> Self {
+        match state {
+            ApplicationState::Ghosted => Staleness::Ghosted,
+            ApplicationState::Closed => Staleness::Active,
+            ApplicationState::Open if (today - last_activity_on).num_days() >= stale_after_days => {
+                Staleness::Stale
+            }
+            ApplicationState::Open => Staleness::Active,
+        }
+    }
+}
+
+impl std::fmt::Display for Staleness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Staleness::Active => "active",
+            Staleness::Stale => "stale",
+            Staleness::Ghosted => "ghosted",
+        };
+        write!(f, "{}", s)
+    }
+}
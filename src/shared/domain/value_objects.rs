@@ -1,13 +1,13 @@
 use std::{fmt::Display, str::FromStr};
 
-use argon2::{Argon2, PasswordHasher, password_hash::SaltString};
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier, password_hash::SaltString};
 use rand::rngs::OsRng;
 use uuid::Uuid;
 use zxcvbn::{Score, zxcvbn};
 
 use crate::shared::domain::errors::SharedDomainError;
 
-#[derive(PartialEq, Clone, Debug, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Copy)]
 pub struct UserUuid {
     id: Uuid,
 }
@@ -26,6 +26,10 @@ impl UserUuid {
     pub fn new() -> Self {
         UserUuid { id: Uuid::new_v4() }
     }
+
+    pub fn from_uuid(id: Uuid) -> Self {
+        UserUuid { id }
+    }
 }
 
 impl FromStr for UserUuid {
@@ -80,6 +84,90 @@ impl UserPassword {
 
         Ok(password_hash)
     }
+
+    pub fn verify(&self, candidate: &str) -> Result<bool, SharedDomainError> {
+        let hash = PasswordHash::new(&self.password)
+            .map_err(|e| SharedDomainError::InternalError(e.to_string()))?;
+        Ok(Argon2::default()
+            .verify_password(candidate.as_bytes(), &hash)
+            .is_ok())
+    }
+}
+
+/// Starts every personal access token, so the extractor can tell them apart
+/// from JWTs at a glance.
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "skr_pat_";
+
+/// What a personal access token lets its holder do. Sessions are not scoped.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TokenScope {
+    /// Only reads, on every resource.
+    ReadOnly,
+    /// Reads, and writes to positions and their comments.
+    PositionsWrite,
+}
+
+impl TokenScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenScope::ReadOnly => "read-only",
+            TokenScope::PositionsWrite => "positions-write",
+        }
+    }
+}
+
+impl FromStr for TokenScope {
+    type Err = SharedDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read-only" => Ok(TokenScope::ReadOnly),
+            "positions-write" => Ok(TokenScope::PositionsWrite),
+            other => Err(SharedDomainError::InvalidTokenScope(other.to_string())),
+        }
+    }
+}
+
+impl Display for TokenScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// What a user may do beyond their own data.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum UserRole {
+    #[default]
+    User,
+    /// Manages other accounts and the background queues.
+    Admin,
+}
+
+impl UserRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserRole::User => "user",
+            UserRole::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for UserRole {
+    type Err = SharedDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(UserRole::User),
+            "admin" => Ok(UserRole::Admin),
+            other => Err(SharedDomainError::InvalidUserRole(other.to_string())),
+        }
+    }
+}
+
+impl Display for UserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 #[cfg(test)]
@@ -162,4 +250,42 @@ mod tests {
             Err(_) => panic!("Expected successful hashing"),
         }
     }
+
+    #[test]
+    fn test_user_password_verify() {
+        let result = UserPassword::new("S0m3V3ryStr0ngP@ssw0rd!");
+        let Ok(password) = result else {
+            panic!("Expected a valid password");
+        };
+        assert_eq!(password.verify("S0m3V3ryStr0ngP@ssw0rd!"), Ok(true));
+        assert_eq!(password.verify("wrong"), Ok(false));
+        assert!(
+            UserPassword::set_password_already_hashed("not-a-hash")
+                .verify("wrong")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_token_scope_round_trips_through_strings() {
+        for scope in [TokenScope::ReadOnly, TokenScope::PositionsWrite] {
+            assert_eq!(TokenScope::from_str(scope.as_str()), Ok(scope));
+        }
+        assert!(matches!(
+            TokenScope::from_str("admin"),
+            Err(SharedDomainError::InvalidTokenScope(_))
+        ));
+    }
+
+    #[test]
+    fn test_user_role_round_trips_through_strings() {
+        for role in [UserRole::User, UserRole::Admin] {
+            assert_eq!(UserRole::from_str(role.as_str()), Ok(role));
+        }
+        assert_eq!(UserRole::default(), UserRole::User);
+        assert!(matches!(
+            UserRole::from_str("root"),
+            Err(SharedDomainError::InvalidUserRole(_))
+        ));
+    }
 }
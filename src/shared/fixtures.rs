@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use uuid::Uuid;
 
 use crate::positions::domain::entities::comment::{Comment, CommentBuilder};
@@ -42,3 +43,6 @@ pub fn valid_password() -> &'static str {
 pub fn valid_id() -> String {
     Uuid::new_v4().to_string()
 }
+pub fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").expect("Should parse date")
+}
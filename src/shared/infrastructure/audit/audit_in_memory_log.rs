@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::RwLock;
+
+use crate::shared::{
+    domain::{
+        audit::{AuditEntry, AuditEvent, AuditQuery, IAuditLog},
+        errors::SharedDomainError,
+    },
+    infrastructure::http::request_context::current_request_origin,
+};
+
+#[derive(Clone, Default)]
+pub struct AuditInMemoryLog {
+    events: Arc<RwLock<Vec<AuditEvent>>>,
+}
+
+impl AuditInMemoryLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Everything recorded so far, oldest first.
+    pub async fn events(&self) -> Vec<AuditEvent> {
+        self.events.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl IAuditLog for AuditInMemoryLog {
+    async fn record(&self, entry: AuditEntry) {
+        let mut events = self.events.write().await;
+        // Keep timestamps strictly increasing so ordering is deterministic.
+        let now = events
+            .last()
+            .map(|last| (last.occurred_at + chrono::TimeDelta::microseconds(1)).max(Utc::now()))
+            .unwrap_or_else(Utc::now);
+        events.push(AuditEvent::new(entry, current_request_origin(), now));
+    }
+
+    async fn search(&self, query: &AuditQuery) -> Result<Vec<AuditEvent>, SharedDomainError> {
+        Ok(self
+            .events
+            .read()
+            .await
+            .iter()
+            .rev()
+            .filter(|event| query.matches(event))
+            .take(query.limit as usize)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::shared::{
+    domain::{
+        audit::{AuditEntry, AuditEvent, AuditEventKind, AuditQuery, IAuditLog, RequestOrigin},
+        errors::SharedDomainError,
+        value_objects::UserUuid,
+    },
+    infrastructure::http::request_context::current_request_origin,
+};
+
+struct AuditEventRow {
+    id: Uuid,
+    kind: String,
+    user_id: Option<Uuid>,
+    actor_id: Option<Uuid>,
+    detail: Option<String>,
+    ip: Option<String>,
+    user_agent: Option<String>,
+    request_id: Option<String>,
+    trace_id: Option<String>,
+    occurred_at: DateTime<Utc>,
+}
+
+impl TryFrom<AuditEventRow> for AuditEvent {
+    type Error = SharedDomainError;
+
+    fn try_from(row: AuditEventRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            kind: AuditEventKind::from_str(&row.kind)?,
+            user_id: row.user_id.map(UserUuid::from_uuid),
+            actor_id: row.actor_id.map(UserUuid::from_uuid),
+            detail: row.detail,
+            origin: RequestOrigin {
+                ip: row.ip,
+                user_agent: row.user_agent,
+                request_id: row.request_id,
+                trace_id: row.trace_id,
+            },
+            occurred_at: row.occurred_at,
+        })
+    }
+}
+
+pub struct AuditPostgresLog {
+    pool: PgPool,
+}
+
+impl AuditPostgresLog {
+    pub async fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn database_error(operation: &'static str, e: sqlx::Error) -> SharedDomainError {
+    error!(
+        error_kind = "database_error",
+        error = %e,
+        "audit_log.{} failed",
+        operation
+    );
+    SharedDomainError::InternalError(e.to_string())
+}
+
+#[async_trait]
+impl IAuditLog for AuditPostgresLog {
+    async fn record(&self, entry: AuditEntry) {
+        let event = AuditEvent::new(entry, current_request_origin(), Utc::now());
+        let _ = sqlx::query!(
+            "INSERT INTO audit_events (id, kind, user_id, actor_id, detail, ip, user_agent, request_id, trace_id, occurred_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+            event.id,
+            event.kind.as_str(),
+            event.user_id.map(|id| id.value()),
+            event.actor_id.map(|id| id.value()),
+            event.detail,
+            event.origin.ip,
+            event.origin.user_agent,
+            event.origin.request_id,
+            event.origin.trace_id,
+            event.occurred_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("record", e));
+    }
+
+    async fn search(&self, query: &AuditQuery) -> Result<Vec<AuditEvent>, SharedDomainError> {
+        sqlx::query_as!(
+            AuditEventRow,
+            "SELECT id, kind, user_id, actor_id, detail, ip, user_agent, request_id, trace_id, occurred_at FROM audit_events WHERE ($1::uuid IS NULL OR user_id = $1) AND ($2::text IS NULL OR kind = $2) AND ($3::timestamptz IS NULL OR occurred_at < $3) ORDER BY occurred_at DESC, id LIMIT $4",
+            query.user_id.map(|id| id.value()),
+            query.kind.map(|kind| kind.as_str()),
+            query.before,
+            query.limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("search", e))?
+        .into_iter()
+        .map(AuditEvent::try_from)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::infrastructure::test_factory::TestFactory;
+
+    #[tokio::test]
+    async fn test_recorded_events_are_listed_newest_first() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let log = AuditPostgresLog::new(factory.pool.clone()).await;
+
+        log.record(AuditEntry::new(AuditEventKind::Signup, Some(user.id)))
+            .await;
+        log.record(
+            AuditEntry::new(AuditEventKind::LoginFailed, Some(user.id))
+                .detail("invalid_credentials"),
+        )
+        .await;
+
+        let events = log
+            .search(&AuditQuery::new(Some(user.id), None, None, None))
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, AuditEventKind::LoginFailed);
+        assert_eq!(events[0].detail.as_deref(), Some("invalid_credentials"));
+        assert_eq!(events[1].kind, AuditEventKind::Signup);
+
+        let failed = log
+            .search(&AuditQuery::new(
+                Some(user.id),
+                Some(AuditEventKind::Signup),
+                None,
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(failed.len(), 1);
+
+        factory.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn test_audit_events_cannot_be_changed_and_outlive_their_user() {
+        let mut factory = TestFactory::new().await;
+        let user = factory.create_random_user().await;
+        let log = AuditPostgresLog::new(factory.pool.clone()).await;
+        log.record(AuditEntry::new(AuditEventKind::Signup, Some(user.id)))
+            .await;
+
+        let update = sqlx::query!(
+            "UPDATE audit_events SET detail = 'tampered' WHERE user_id = $1",
+            user.id.value()
+        )
+        .execute(&factory.pool)
+        .await;
+        assert!(update.is_err());
+        let delete = sqlx::query!(
+            "DELETE FROM audit_events WHERE user_id = $1",
+            user.id.value()
+        )
+        .execute(&factory.pool)
+        .await;
+        assert!(delete.is_err());
+        let anonymise = sqlx::query!(
+            "UPDATE audit_events SET user_id = NULL, ip = NULL, user_agent = NULL WHERE user_id = $1",
+            user.id.value()
+        )
+        .execute(&factory.pool)
+        .await;
+        assert!(
+            anonymise.is_err(),
+            "Only the events of purged users can be anonymised"
+        );
+
+        factory.teardown().await;
+
+        let remaining = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM audit_events WHERE user_id = $1"#,
+            user.id.value()
+        )
+        .fetch_one(&factory.pool)
+        .await
+        .unwrap();
+        assert_eq!(remaining, 1);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod audit_in_memory_log;
+pub mod audit_postgres_log;
@@ -0,0 +1,25 @@
+use serde_json::{Value, json};
+
+use crate::shared::domain::email::EmailCategory;
+
+/// Locale emails are queued in until users can pick one. The email worker
+/// falls back to English for locales it has no templates for.
+pub const EMAIL_LOCALE: &str = "en";
+
+/// `email_queue` payload asking the email worker to render `template`, one
+/// of those in `workers/email/templates`, with `variables`. The worker skips
+/// emails the recipient opted out of by `category`.
+pub fn email_queue_payload(
+    to: &str,
+    category: EmailCategory,
+    template: &str,
+    variables: Value,
+) -> Value {
+    json!({
+        "to": to,
+        "locale": EMAIL_LOCALE,
+        "category": category.as_str(),
+        "template": template,
+        "variables": variables
+    })
+}
@@ -1,32 +1,57 @@
 use crate::shared::config::Config;
+use crate::shared::domain::value_objects::{PERSONAL_ACCESS_TOKEN_PREFIX, TokenScope, UserRole};
 use axum::{
     Json,
-    extract::{FromRef, FromRequestParts},
+    extract::{FromRef, FromRequestParts, OriginalUri},
     http::{StatusCode, header::AUTHORIZATION, request::Parts},
     response::IntoResponse,
 };
 use chrono::Utc;
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, errors::ErrorKind};
+use jsonwebtoken::errors::ErrorKind;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use thiserror::Error;
+use uuid::Uuid;
+
+/// Audience of session tokens. Tokens minted for anything else (email links,
+/// ...) carry another audience and are rejected by the extractor.
+pub const ACCESS_TOKEN_AUDIENCE: &str = "access";
 
 pub fn create_jwt(sub: &str, email: &str, config: &Config) -> Result<String, AuthExtractorError> {
-    let expiration = Utc::now().timestamp() + config.jwt_expiration_time;
+    create_jwt_with_ttl(
+        sub,
+        email,
+        config.jwt_expiration_time,
+        0,
+        UserRole::User,
+        None,
+        config,
+    )
+}
+
+pub fn create_jwt_with_ttl(
+    sub: &str,
+    email: &str,
+    ttl_secs: i64,
+    token_version: i32,
+    role: UserRole,
+    session_id: Option<Uuid>,
+    config: &Config,
+) -> Result<String, AuthExtractorError> {
+    let expiration = Utc::now().timestamp() + ttl_secs;
 
     let claims = Claims {
         sub: sub.to_string(),
         exp: expiration as usize,
         email: email.to_string(),
+        aud: ACCESS_TOKEN_AUDIENCE.to_string(),
+        jti: Uuid::new_v4().to_string(),
+        ver: token_version,
+        role,
+        sid: session_id.map(|id| id.to_string()),
     };
 
-    let token = jsonwebtoken::encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(config.get_jwt_secret().as_bytes()),
-    );
-
-    match token {
+    match config.jwt_keys().encode(&claims) {
         Ok(token) => Ok(token),
         Err(_) => Err(AuthExtractorError::InternalError(
             "Failed to create token".to_string(),
@@ -40,6 +65,10 @@ pub enum AuthExtractorError {
     TokenExpired,
     #[error("Invalid token")]
     InvalidToken,
+    #[error("Token scope does not allow this request")]
+    InsufficientScope,
+    #[error("Admin role required")]
+    AdminRequired,
     #[error("Internal error: {0}")]
     InternalError(String),
 }
@@ -50,6 +79,9 @@ impl IntoResponse for AuthExtractorError {
             AuthExtractorError::TokenExpired | AuthExtractorError::InvalidToken => {
                 StatusCode::UNAUTHORIZED
             }
+            AuthExtractorError::InsufficientScope | AuthExtractorError::AdminRequired => {
+                StatusCode::FORBIDDEN
+            }
             AuthExtractorError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
         let body = serde_json::json!({
@@ -59,20 +91,77 @@ impl IntoResponse for AuthExtractorError {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub exp: usize,
     pub email: String,
+    pub aud: String,
+    /// Unique token id, used to revoke a single token on logout.
+    pub jti: String,
+    /// The user's token version at issue time; logging out everywhere bumps it.
+    pub ver: i32,
+    /// Role at issue time, for clients. [`AdminUser`] checks the stored one.
+    #[serde(default, with = "role_claim")]
+    pub role: UserRole,
+    /// Session the token was issued to. [`AuthenticatedClaims`] rejects the
+    /// token as soon as the session is revoked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+}
+
+mod role_claim {
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use crate::shared::domain::value_objects::UserRole;
+
+    pub fn serialize<S: Serializer>(role: &UserRole, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(role.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UserRole, D::Error> {
+        UserRole::from_str(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// What a valid personal access token resolves to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalAccessTokenGrant {
+    pub user_id: String,
+    pub scope: TokenScope,
 }
 
 #[async_trait::async_trait]
 pub trait UserStatusChecker: Send + Sync {
     async fn is_account_disabled(&self, user_id: &str) -> bool;
+    async fn is_token_revoked(&self, claims: &Claims) -> bool;
+    /// `false` when the session in the `sid` claim was revoked or expired.
+    /// Not cached, so revoking a session applies to the next request.
+    async fn is_session_active(&self, claims: &Claims) -> bool;
+    async fn is_email_verified(&self, user_id: &str) -> bool;
+    async fn is_admin(&self, user_id: &str) -> bool;
+    /// `None` when the token is unknown, expired or revoked.
+    async fn authenticate_personal_access_token(
+        &self,
+        token: &str,
+    ) -> Option<PersonalAccessTokenGrant>;
 }
 
+/// Accepts session JWTs and personal access tokens. The latter only get
+/// through when their scope covers the request.
 pub struct AuthenticatedUser(pub String);
 
+/// Same checks as [`AuthenticatedUser`] but for session JWTs only, for
+/// handlers that need the whole token, e.g. to revoke it.
+pub struct AuthenticatedClaims(pub Claims);
+
+/// An [`AuthenticatedUser`] whose stored role is admin. The role is read
+/// on every request rather than from the token, so a demotion applies at
+/// once.
+pub struct AdminUser(pub String);
+
 impl<S> FromRequestParts<S> for AuthenticatedUser
 where
     S: Send + Sync,
@@ -82,18 +171,99 @@ where
     type Rejection = AuthExtractorError;
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        let auth_header = parts
-            .headers
-            .get(AUTHORIZATION)
-            .and_then(|value| value.to_str().ok())
-            .ok_or(AuthExtractorError::InvalidToken)?;
+        let Some(token) =
+            bearer_token(parts)?.filter(|token| token.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX))
+        else {
+            let AuthenticatedClaims(claims) =
+                AuthenticatedClaims::from_request_parts(parts, state).await?;
+            return Ok(AuthenticatedUser(claims.sub));
+        };
 
-        let token = auth_header
-            .strip_prefix("Bearer ")
+        let user_checker = Arc::<dyn UserStatusChecker>::from_ref(state);
+        let grant = user_checker
+            .authenticate_personal_access_token(token)
+            .await
             .ok_or(AuthExtractorError::InvalidToken)?;
+        if user_checker.is_account_disabled(&grant.user_id).await {
+            tracing::warn!(
+                error_kind = "account_disabled",
+                user_id = %grant.user_id,
+                "Account is disabled"
+            );
+            return Err(AuthExtractorError::InvalidToken);
+        }
+        if !scope_allows(grant.scope, parts) {
+            tracing::warn!(
+                error_kind = "insufficient_scope",
+                user_id = %grant.user_id,
+                scope = %grant.scope,
+                method = %parts.method,
+                "Personal access token scope does not allow the request"
+            );
+            return Err(AuthExtractorError::InsufficientScope);
+        }
+
+        set_request_user_id(parts, &grant.user_id);
+        Ok(AuthenticatedUser(grant.user_id))
+    }
+}
+
+fn bearer_token(parts: &Parts) -> Result<Option<&str>, AuthExtractorError> {
+    let Some(auth_header) = parts.headers.get(AUTHORIZATION) else {
+        return Ok(None);
+    };
+    auth_header
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(Some)
+        .ok_or(AuthExtractorError::InvalidToken)
+}
+
+/// Reads are allowed to every scope, except under `/admin`. Writes only to
+/// `positions-write`, and only under `/positions`, which includes comments.
+fn scope_allows(scope: TokenScope, parts: &Parts) -> bool {
+    // Nested routers see the path without their prefix.
+    let path = parts
+        .extensions
+        .get::<OriginalUri>()
+        .map_or(parts.uri.path(), |uri| uri.path());
+    if path == "/admin" || path.starts_with("/admin/") {
+        return false;
+    }
+    if parts.method.is_safe() {
+        return true;
+    }
+    match scope {
+        TokenScope::ReadOnly => false,
+        TokenScope::PositionsWrite => path == "/positions" || path.starts_with("/positions/"),
+    }
+}
+
+fn set_request_user_id(parts: &Parts, user_id: &str) {
+    if let Some(holder) =
+        parts
+            .extensions
+            .get::<crate::shared::infrastructure::http::observability_middleware::RequestUserId>()
+    {
+        holder.set(user_id.to_string());
+    }
+}
+
+impl<S> FromRequestParts<S> for AuthenticatedClaims
+where
+    S: Send + Sync,
+    Arc<Config>: FromRef<S>,
+    Arc<dyn UserStatusChecker>: FromRef<S>,
+{
+    type Rejection = AuthExtractorError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let token = bearer_token(parts)?.ok_or(AuthExtractorError::InvalidToken)?;
 
         let config = Arc::<Config>::from_ref(state);
-        let user_id = validate_token(token, &config)?;
+        let claims = decode_claims(token, &config)?;
+        let user_id = claims.sub.clone();
 
         let user_checker = Arc::<dyn UserStatusChecker>::from_ref(state);
         if user_checker.is_account_disabled(&user_id).await {
@@ -104,28 +274,73 @@ where
             );
             return Err(AuthExtractorError::InvalidToken);
         }
+        if user_checker.is_token_revoked(&claims).await {
+            tracing::warn!(
+                error_kind = "token_revoked",
+                user_id = %user_id,
+                "Token has been revoked"
+            );
+            return Err(AuthExtractorError::InvalidToken);
+        }
+        if !user_checker.is_session_active(&claims).await {
+            tracing::warn!(
+                error_kind = "session_revoked",
+                user_id = %user_id,
+                session_id = claims.sid.as_deref().unwrap_or_default(),
+                "Session is no longer active"
+            );
+            return Err(AuthExtractorError::InvalidToken);
+        }
+
+        set_request_user_id(parts, &user_id);
+        Ok(AuthenticatedClaims(claims))
+    }
+}
+
+impl<S> FromRequestParts<S> for AdminUser
+where
+    S: Send + Sync,
+    Arc<Config>: FromRef<S>,
+    Arc<dyn UserStatusChecker>: FromRef<S>,
+{
+    type Rejection = AuthExtractorError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let AuthenticatedUser(user_id) =
+            AuthenticatedUser::from_request_parts(parts, state).await?;
 
-        if let Some(holder) = parts.extensions.get::<
-            crate::shared::infrastructure::http::observability_middleware::RequestUserId,
-        >() {
-            holder.set(user_id.clone());
+        let user_checker = Arc::<dyn UserStatusChecker>::from_ref(state);
+        if !user_checker.is_admin(&user_id).await {
+            tracing::warn!(
+                error_kind = "admin_required",
+                user_id = %user_id,
+                "Admin role required"
+            );
+            return Err(AuthExtractorError::AdminRequired);
         }
-        Ok(AuthenticatedUser(user_id))
+        Ok(AdminUser(user_id))
     }
 }
 
 pub fn validate_token(token: &str, config: &Config) -> Result<String, AuthExtractorError> {
-    let token_data = jsonwebtoken::decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(config.get_jwt_secret().as_bytes()),
-        &Validation::default(),
-    );
-
-    match token_data {
-        Ok(data) => Ok(data.claims.sub),
+    decode_claims(token, config).map(|claims| claims.sub)
+}
+
+pub fn decode_claims(token: &str, config: &Config) -> Result<Claims, AuthExtractorError> {
+    match config
+        .jwt_keys()
+        .decode::<Claims>(token, ACCESS_TOKEN_AUDIENCE)
+    {
+        Ok(claims) => Ok(claims),
         Err(err) => match err.kind() {
             ErrorKind::ExpiredSignature => Err(AuthExtractorError::TokenExpired),
-            ErrorKind::InvalidToken => Err(AuthExtractorError::InvalidToken),
+            ErrorKind::InvalidToken
+            | ErrorKind::InvalidSignature
+            | ErrorKind::InvalidAlgorithm
+            | ErrorKind::Base64(_)
+            | ErrorKind::InvalidAudience
+            | ErrorKind::MissingRequiredClaim(_)
+            | ErrorKind::Json(_) => Err(AuthExtractorError::InvalidToken),
             _ => Err(AuthExtractorError::InternalError(
                 "Failed to decode token".to_string(),
             )),
@@ -136,7 +351,6 @@ pub fn validate_token(token: &str, config: &Config) -> Result<String, AuthExtrac
 #[cfg(test)]
 mod tests {
     use super::*;
-    use uuid::Uuid;
 
     #[test]
     fn test_create_jwt() {
@@ -186,9 +400,37 @@ mod tests {
         assert_eq!(result.unwrap_err(), AuthExtractorError::TokenExpired);
     }
 
+    const PAT: &str = "skr_pat_secret";
+
     #[derive(Clone)]
     struct MockUserStatusChecker {
         is_disabled: bool,
+        is_revoked: bool,
+        is_session_revoked: bool,
+        is_admin: bool,
+        grant: Option<PersonalAccessTokenGrant>,
+    }
+
+    impl MockUserStatusChecker {
+        fn active() -> Self {
+            Self {
+                is_disabled: false,
+                is_revoked: false,
+                is_session_revoked: false,
+                is_admin: false,
+                grant: None,
+            }
+        }
+
+        fn with_token(user_id: &str, scope: TokenScope) -> Self {
+            Self {
+                grant: Some(PersonalAccessTokenGrant {
+                    user_id: user_id.to_string(),
+                    scope,
+                }),
+                ..Self::active()
+            }
+        }
     }
 
     #[async_trait::async_trait]
@@ -196,6 +438,29 @@ mod tests {
         async fn is_account_disabled(&self, _user_id: &str) -> bool {
             self.is_disabled
         }
+
+        async fn is_token_revoked(&self, _claims: &Claims) -> bool {
+            self.is_revoked
+        }
+
+        async fn is_session_active(&self, _claims: &Claims) -> bool {
+            !self.is_session_revoked
+        }
+
+        async fn is_email_verified(&self, _user_id: &str) -> bool {
+            true
+        }
+
+        async fn is_admin(&self, _user_id: &str) -> bool {
+            self.is_admin
+        }
+
+        async fn authenticate_personal_access_token(
+            &self,
+            token: &str,
+        ) -> Option<PersonalAccessTokenGrant> {
+            self.grant.clone().filter(|_| token == PAT)
+        }
     }
 
     #[derive(Clone)]
@@ -225,7 +490,7 @@ mod tests {
 
         let state = TestState {
             config: Arc::new(config),
-            user_checker: Arc::new(MockUserStatusChecker { is_disabled: false }),
+            user_checker: Arc::new(MockUserStatusChecker::active()),
         };
 
         let (mut parts, _) = axum::http::Request::builder()
@@ -250,7 +515,10 @@ mod tests {
 
         let state = TestState {
             config: Arc::new(config),
-            user_checker: Arc::new(MockUserStatusChecker { is_disabled: true }),
+            user_checker: Arc::new(MockUserStatusChecker {
+                is_disabled: true,
+                ..MockUserStatusChecker::active()
+            }),
         };
 
         let (mut parts, _) = axum::http::Request::builder()
@@ -264,12 +532,246 @@ mod tests {
         assert!(matches!(result, Err(AuthExtractorError::InvalidToken)));
     }
 
+    #[tokio::test]
+    async fn test_authenticated_user_extractor_revoked_token() {
+        let config = Config::test_default();
+        let token = create_jwt(&Uuid::new_v4().to_string(), "test@test.com", &config).unwrap();
+
+        let state = TestState {
+            config: Arc::new(config),
+            user_checker: Arc::new(MockUserStatusChecker {
+                is_revoked: true,
+                ..MockUserStatusChecker::active()
+            }),
+        };
+
+        let (mut parts, _) = axum::http::Request::builder()
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        let result = AuthenticatedUser::from_request_parts(&mut parts, &state).await;
+
+        assert!(matches!(result, Err(AuthExtractorError::InvalidToken)));
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_user_extractor_revoked_session() {
+        let config = Config::test_default();
+        let token = create_jwt_with_ttl(
+            &Uuid::new_v4().to_string(),
+            "test@test.com",
+            60,
+            0,
+            UserRole::User,
+            Some(Uuid::new_v4()),
+            &config,
+        )
+        .unwrap();
+        assert!(decode_claims(&token, &config).unwrap().sid.is_some());
+
+        let checker = MockUserStatusChecker {
+            is_session_revoked: true,
+            ..MockUserStatusChecker::active()
+        };
+        let result = extract_with_token(checker, "GET", "/goals", &token).await;
+
+        assert!(matches!(result, Err(AuthExtractorError::InvalidToken)));
+    }
+
+    async fn extract_with_token(
+        checker: MockUserStatusChecker,
+        method: &str,
+        uri: &str,
+        token: &str,
+    ) -> Result<AuthenticatedUser, AuthExtractorError> {
+        let state = TestState {
+            config: Arc::new(Config::test_default()),
+            user_checker: Arc::new(checker),
+        };
+        let (mut parts, _) = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .body(())
+            .unwrap()
+            .into_parts();
+        AuthenticatedUser::from_request_parts(&mut parts, &state).await
+    }
+
+    #[tokio::test]
+    async fn test_personal_access_tokens_authenticate_their_owner() {
+        let sub = Uuid::new_v4().to_string();
+        let checker = MockUserStatusChecker::with_token(&sub, TokenScope::ReadOnly);
+
+        let auth_user = extract_with_token(checker.clone(), "GET", "/goals", PAT)
+            .await
+            .unwrap();
+        let unknown = extract_with_token(checker, "GET", "/goals", "skr_pat_unknown").await;
+
+        assert_eq!(auth_user.0, sub);
+        assert!(matches!(unknown, Err(AuthExtractorError::InvalidToken)));
+    }
+
+    #[tokio::test]
+    async fn test_personal_access_token_scopes_limit_writes() {
+        let sub = Uuid::new_v4().to_string();
+        let read_only = MockUserStatusChecker::with_token(&sub, TokenScope::ReadOnly);
+        let positions_write = MockUserStatusChecker::with_token(&sub, TokenScope::PositionsWrite);
+
+        let read_only_write =
+            extract_with_token(read_only.clone(), "POST", "/positions", PAT).await;
+        let read_only_read = extract_with_token(read_only, "GET", "/positions/1", PAT).await;
+        let position_write =
+            extract_with_token(positions_write.clone(), "PUT", "/positions/1", PAT).await;
+        let comment_write = extract_with_token(
+            positions_write.clone(),
+            "POST",
+            "/positions/1/comments",
+            PAT,
+        )
+        .await;
+        let goal_write = extract_with_token(positions_write, "POST", "/goals", PAT).await;
+
+        assert!(matches!(
+            read_only_write,
+            Err(AuthExtractorError::InsufficientScope)
+        ));
+        assert!(read_only_read.is_ok());
+        assert!(position_write.is_ok());
+        assert!(comment_write.is_ok());
+        assert!(matches!(
+            goal_write,
+            Err(AuthExtractorError::InsufficientScope)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_personal_access_tokens_of_disabled_accounts_are_rejected() {
+        let checker = MockUserStatusChecker {
+            is_disabled: true,
+            ..MockUserStatusChecker::with_token(&Uuid::new_v4().to_string(), TokenScope::ReadOnly)
+        };
+
+        let result = extract_with_token(checker, "GET", "/goals", PAT).await;
+
+        assert!(matches!(result, Err(AuthExtractorError::InvalidToken)));
+    }
+
+    #[tokio::test]
+    async fn test_admin_user_requires_the_admin_role() {
+        let config = Config::test_default();
+        let sub = Uuid::new_v4().to_string();
+        let token = create_jwt(&sub, "admin@test.com", &config).unwrap();
+
+        let mut results = Vec::new();
+        for is_admin in [true, false] {
+            let state = TestState {
+                config: Arc::new(config.clone()),
+                user_checker: Arc::new(MockUserStatusChecker {
+                    is_admin,
+                    ..MockUserStatusChecker::active()
+                }),
+            };
+            let (mut parts, _) = axum::http::Request::builder()
+                .header(AUTHORIZATION, format!("Bearer {}", token))
+                .body(())
+                .unwrap()
+                .into_parts();
+            results.push(AdminUser::from_request_parts(&mut parts, &state).await);
+        }
+
+        assert_eq!(results[0].as_ref().unwrap().0, sub);
+        assert!(matches!(results[1], Err(AuthExtractorError::AdminRequired)));
+    }
+
+    #[tokio::test]
+    async fn test_personal_access_tokens_never_reach_admin_routes() {
+        let checker = MockUserStatusChecker {
+            is_admin: true,
+            ..MockUserStatusChecker::with_token(&Uuid::new_v4().to_string(), TokenScope::ReadOnly)
+        };
+
+        let result = extract_with_token(checker, "GET", "/admin/users", PAT).await;
+
+        assert!(matches!(result, Err(AuthExtractorError::InsufficientScope)));
+    }
+
+    #[test]
+    fn test_role_claim_defaults_to_user() {
+        let config = Config::test_default();
+        let sub = Uuid::new_v4().to_string();
+        let admin_token = create_jwt_with_ttl(
+            &sub,
+            "admin@test.com",
+            config.jwt_expiration_time,
+            0,
+            UserRole::Admin,
+            None,
+            &config,
+        )
+        .unwrap();
+        let legacy: Claims = serde_json::from_value(serde_json::json!({
+            "sub": sub,
+            "exp": 0,
+            "email": "old@test.com",
+            "aud": ACCESS_TOKEN_AUDIENCE,
+            "jti": "jti",
+            "ver": 0,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            decode_claims(&admin_token, &config).unwrap().role,
+            UserRole::Admin
+        );
+        assert_eq!(legacy.role, UserRole::User);
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_claims_reject_personal_access_tokens() {
+        let state = TestState {
+            config: Arc::new(Config::test_default()),
+            user_checker: Arc::new(MockUserStatusChecker::with_token(
+                &Uuid::new_v4().to_string(),
+                TokenScope::PositionsWrite,
+            )),
+        };
+        let (mut parts, _) = axum::http::Request::builder()
+            .header(AUTHORIZATION, format!("Bearer {}", PAT))
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        let result = AuthenticatedClaims::from_request_parts(&mut parts, &state).await;
+
+        assert!(matches!(result, Err(AuthExtractorError::InvalidToken)));
+    }
+
+    #[test]
+    fn test_tokens_get_unique_ids() {
+        let config = Config::test_default();
+        let sub = Uuid::new_v4().to_string();
+
+        let first = decode_claims(
+            &create_jwt(&sub, "test@test.com", &config).unwrap(),
+            &config,
+        );
+        let second = decode_claims(
+            &create_jwt(&sub, "test@test.com", &config).unwrap(),
+            &config,
+        );
+
+        assert_ne!(first.unwrap().jti, second.unwrap().jti);
+    }
+
     #[tokio::test]
     async fn test_authenticated_user_extractor_invalid_token() {
         let config = Config::test_default();
         let state = TestState {
             config: Arc::new(config),
-            user_checker: Arc::new(MockUserStatusChecker { is_disabled: false }),
+            user_checker: Arc::new(MockUserStatusChecker::active()),
         };
 
         let (mut parts, _) = axum::http::Request::builder()
@@ -288,7 +790,7 @@ mod tests {
         let config = Config::test_default();
         let state = TestState {
             config: Arc::new(config),
-            user_checker: Arc::new(MockUserStatusChecker { is_disabled: false }),
+            user_checker: Arc::new(MockUserStatusChecker::active()),
         };
 
         let (mut parts, _) = axum::http::Request::builder()
@@ -315,6 +817,18 @@ mod tests {
         assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
+    #[test]
+    fn test_auth_extractor_error_insufficient_scope() {
+        let response = AuthExtractorError::InsufficientScope.into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_auth_extractor_error_admin_required() {
+        let response = AuthExtractorError::AdminRequired.into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
     #[test]
     fn test_auth_extractor_error_internal() {
         let error = AuthExtractorError::InternalError("test".to_string());
@@ -1,5 +1,6 @@
 pub mod auth_extractor;
 pub mod observability_middleware;
 pub mod ratelimit_middleware;
+pub mod request_context;
 
 pub use ratelimit_middleware::{RateLimitConfig, RateLimitState, rate_limit};
@@ -150,19 +150,25 @@ pub async fn rate_limit(
 }
 
 fn extract_client_key(request: &Request<Body>, trust_forwarded: bool) -> String {
+    client_ip(request, trust_forwarded).unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Best guess at the caller's IP. Forwarding headers win only when the
+/// deployment says a trusted proxy sets them.
+pub(crate) fn client_ip(request: &Request<Body>, trust_forwarded: bool) -> Option<String> {
     if trust_forwarded && let Some(ip) = forwarded_for(request) {
-        return ip;
+        return Some(ip);
     }
 
     if let Some(ConnectInfo(addr)) = request.extensions().get::<ConnectInfo<SocketAddr>>() {
-        return addr.ip().to_string();
+        return Some(addr.ip().to_string());
     }
 
     if !trust_forwarded && let Some(ip) = forwarded_for(request) {
-        return ip;
+        return Some(ip);
     }
 
-    "unknown".to_string()
+    None
 }
 
 fn forwarded_for(request: &Request<Body>) -> Option<String> {
@@ -0,0 +1,107 @@
+use axum::{
+    body::Body,
+    extract::State,
+    http::{Request, header::USER_AGENT},
+    middleware::Next,
+    response::Response,
+};
+use opentelemetry::trace::{TraceContextExt, TraceId};
+use tower_http::request_id::RequestId;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::shared::{
+    domain::audit::RequestOrigin, infrastructure::http::ratelimit_middleware::client_ip,
+};
+
+const MAX_USER_AGENT_LEN: usize = 512;
+
+tokio::task_local! {
+    static REQUEST_ORIGIN: RequestOrigin;
+}
+
+/// Makes the caller's IP, user agent and request id available to
+/// [`current_request_origin`] for the rest of the request, so services can
+/// stamp audit events without threading them through every signature.
+pub async fn capture_request_origin(
+    State(trust_forwarded_headers): State<bool>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let origin = RequestOrigin {
+        ip: client_ip(&request, trust_forwarded_headers),
+        user_agent: request
+            .headers()
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(MAX_USER_AGENT_LEN).collect()),
+        request_id: request
+            .extensions()
+            .get::<RequestId>()
+            .and_then(|id| id.header_value().to_str().ok())
+            .map(str::to_string),
+        trace_id: None,
+    };
+    REQUEST_ORIGIN.scope(origin, next.run(request)).await
+}
+
+/// Origin of the request being handled, or an empty one outside a request
+/// (background jobs, tests).
+pub fn current_request_origin() -> RequestOrigin {
+    let mut origin = REQUEST_ORIGIN
+        .try_with(RequestOrigin::clone)
+        .unwrap_or_default();
+    let trace_id = tracing::Span::current()
+        .context()
+        .span()
+        .span_context()
+        .trace_id();
+    if trace_id != TraceId::INVALID {
+        origin.trace_id = Some(trace_id.to_string());
+    }
+    origin
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, http::StatusCode, middleware, routing::get};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_request_origin_is_visible_to_handlers() {
+        async fn handler() -> String {
+            let origin = current_request_origin();
+            format!(
+                "{}|{}",
+                origin.ip.unwrap_or_default(),
+                origin.user_agent.unwrap_or_default()
+            )
+        }
+        let app = Router::new()
+            .route("/", get(handler))
+            .layer(middleware::from_fn_with_state(true, capture_request_origin));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header("x-forwarded-for", "203.0.113.7")
+                    .header(USER_AGENT, "curl/8.0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"203.0.113.7|curl/8.0");
+    }
+
+    #[test]
+    fn test_current_request_origin_outside_a_request_is_empty() {
+        assert_eq!(current_request_origin(), RequestOrigin::default());
+    }
+}
@@ -0,0 +1,512 @@
+use std::sync::{Arc, OnceLock};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, TimeDelta, Utc};
+use ed25519_dalek::pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation,
+    errors::{Error as JwtError, ErrorKind},
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+    },
+};
+use rand::{Rng, rngs::OsRng};
+use rsa::{
+    RsaPrivateKey, RsaPublicKey, pkcs1::DecodeRsaPrivateKey, pkcs1::DecodeRsaPublicKey,
+    traits::PublicKeyParts,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum JwtKeyError {
+    #[error("Cannot read JWT key `{path}`: {reason}")]
+    Unreadable { path: String, reason: String },
+    #[error("JWT key `{0}` is not an RSA or Ed25519 PEM")]
+    UnsupportedKey(String),
+    #[error("JWT signing key `{0}` has no private part")]
+    NotAPrivateKey(String),
+    #[error("Invalid retired JWT key `{0}`, expected `kid=path@retired_at`")]
+    InvalidRetiredKey(String),
+    #[error("Duplicated JWT key id `{0}`")]
+    DuplicatedKeyId(String),
+}
+
+/// A key that no longer signs but keeps verifying tokens until
+/// `retired_at` plus the grace period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetiredKey {
+    pub kid: String,
+    pub pem: String,
+    pub retired_at: DateTime<Utc>,
+}
+
+struct VerificationKey {
+    jwk: Jwk,
+    algorithm: Algorithm,
+    decoding_key: DecodingKey,
+    valid_until: Option<DateTime<Utc>>,
+}
+
+impl VerificationKey {
+    fn kid(&self) -> &str {
+        self.jwk.common.key_id.as_deref().unwrap_or_default()
+    }
+
+    fn is_valid(&self, now: DateTime<Utc>) -> bool {
+        self.valid_until.is_none_or(|until| until > now)
+    }
+}
+
+struct ParsedKey {
+    algorithm: Algorithm,
+    params: AlgorithmParameters,
+    decoding_key: DecodingKey,
+    encoding_key: Option<EncodingKey>,
+}
+
+/// Keys that sign and verify JWTs, told apart by the `kid` header. One key
+/// signs; it and the retired keys still within their grace period verify,
+/// and all of them are published as a JWKS.
+pub struct JwtKeys {
+    signing_kid: String,
+    signing_algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    verification_keys: Vec<VerificationKey>,
+}
+
+impl std::fmt::Debug for JwtKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtKeys")
+            .field("signing_kid", &self.signing_kid)
+            .field("signing_algorithm", &self.signing_algorithm)
+            .field(
+                "verification_kids",
+                &self
+                    .verification_keys
+                    .iter()
+                    .map(VerificationKey::kid)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl JwtKeys {
+    pub fn new(
+        signing_kid: &str,
+        signing_pem: &str,
+        retired: Vec<RetiredKey>,
+        grace_period: TimeDelta,
+    ) -> Result<Self, JwtKeyError> {
+        let signing = parse_pem(signing_pem)
+            .ok_or_else(|| JwtKeyError::UnsupportedKey(signing_kid.to_string()))?;
+        let encoding_key = signing
+            .encoding_key
+            .clone()
+            .ok_or_else(|| JwtKeyError::NotAPrivateKey(signing_kid.to_string()))?;
+        let signing_algorithm = signing.algorithm;
+
+        let mut verification_keys = vec![verification_key(signing_kid, signing, None)];
+        for key in retired {
+            if verification_keys.iter().any(|k| k.kid() == key.kid) {
+                return Err(JwtKeyError::DuplicatedKeyId(key.kid));
+            }
+            let parsed =
+                parse_pem(&key.pem).ok_or_else(|| JwtKeyError::UnsupportedKey(key.kid.clone()))?;
+            verification_keys.push(verification_key(
+                &key.kid,
+                parsed,
+                Some(key.retired_at + grace_period),
+            ));
+        }
+
+        Ok(Self {
+            signing_kid: signing_kid.to_string(),
+            signing_algorithm,
+            encoding_key,
+            verification_keys,
+        })
+    }
+
+    /// Reads the signing key and the retired ones, given as a comma separated
+    /// list of `kid=path@retired_at` (RFC 3339). Retired keys may be public.
+    pub fn load(
+        signing_kid: &str,
+        signing_key_path: &str,
+        retired_keys: &str,
+        grace_period: TimeDelta,
+    ) -> Result<Self, JwtKeyError> {
+        let retired = retired_keys
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let invalid = || JwtKeyError::InvalidRetiredKey(entry.to_string());
+                let (kid, rest) = entry.split_once('=').ok_or_else(invalid)?;
+                let (path, retired_at) = rest.rsplit_once('@').ok_or_else(invalid)?;
+                let retired_at = DateTime::parse_from_rfc3339(retired_at)
+                    .map_err(|_| invalid())?
+                    .with_timezone(&Utc);
+                Ok(RetiredKey {
+                    kid: kid.trim().to_string(),
+                    pem: read_key(path.trim())?,
+                    retired_at,
+                })
+            })
+            .collect::<Result<Vec<_>, JwtKeyError>>()?;
+
+        Self::new(
+            signing_kid,
+            &read_key(signing_key_path)?,
+            retired,
+            grace_period,
+        )
+    }
+
+    /// A random Ed25519 key shared by the whole process, for tests and
+    /// `ENVIRONMENT=testing` runs without key files. Tokens do not survive a
+    /// restart.
+    pub fn ephemeral() -> Arc<Self> {
+        static KEYS: OnceLock<Arc<JwtKeys>> = OnceLock::new();
+        KEYS.get_or_init(|| {
+            let secret: [u8; 32] = OsRng.r#gen();
+            let pem = ed25519_dalek::SigningKey::from_bytes(&secret)
+                .to_pkcs8_pem(Default::default())
+                .map(|pem| pem.to_string())
+                .unwrap_or_default();
+            Arc::new(
+                Self::new("ephemeral", &pem, Vec::new(), TimeDelta::zero())
+                    .unwrap_or_else(|e| panic!("Cannot create the ephemeral JWT key: {e}")),
+            )
+        })
+        .clone()
+    }
+
+    pub fn signing_kid(&self) -> &str {
+        &self.signing_kid
+    }
+
+    pub fn encode<T: Serialize>(&self, claims: &T) -> Result<String, JwtError> {
+        let mut header = Header::new(self.signing_algorithm);
+        header.kid = Some(self.signing_kid.clone());
+        jsonwebtoken::encode(&header, claims, &self.encoding_key)
+    }
+
+    /// Tokens without a `kid`, or whose key is unknown or past its grace
+    /// period, are rejected as invalid.
+    pub fn decode<T: DeserializeOwned>(&self, token: &str, audience: &str) -> Result<T, JwtError> {
+        let kid = jsonwebtoken::decode_header(token)?
+            .kid
+            .ok_or(ErrorKind::InvalidToken)?;
+        let now = Utc::now();
+        let key = self
+            .verification_keys
+            .iter()
+            .find(|key| key.kid() == kid && key.is_valid(now))
+            .ok_or(ErrorKind::InvalidToken)?;
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.set_audience(&[audience]);
+        jsonwebtoken::decode::<T>(token, &key.decoding_key, &validation).map(|data| data.claims)
+    }
+
+    /// Public keys that verify tokens at `now`.
+    pub fn jwks(&self, now: DateTime<Utc>) -> JwkSet {
+        JwkSet {
+            keys: self
+                .verification_keys
+                .iter()
+                .filter(|key| key.is_valid(now))
+                .map(|key| key.jwk.clone())
+                .collect(),
+        }
+    }
+}
+
+fn read_key(path: &str) -> Result<String, JwtKeyError> {
+    std::fs::read_to_string(path).map_err(|e| JwtKeyError::Unreadable {
+        path: path.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+fn verification_key(
+    kid: &str,
+    parsed: ParsedKey,
+    valid_until: Option<DateTime<Utc>>,
+) -> VerificationKey {
+    let key_algorithm = match parsed.algorithm {
+        Algorithm::EdDSA => KeyAlgorithm::EdDSA,
+        _ => KeyAlgorithm::RS256,
+    };
+    VerificationKey {
+        jwk: Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                key_algorithm: Some(key_algorithm),
+                key_id: Some(kid.to_string()),
+                ..Default::default()
+            },
+            algorithm: parsed.params,
+        },
+        algorithm: parsed.algorithm,
+        decoding_key: parsed.decoding_key,
+        valid_until,
+    }
+}
+
+/// Accepts PKCS#8 Ed25519 keys and PKCS#1 or PKCS#8 RSA keys, private or
+/// public.
+fn parse_pem(pem: &str) -> Option<ParsedKey> {
+    if let Ok(key) = ed25519_dalek::SigningKey::from_pkcs8_pem(pem) {
+        return ed25519_key(
+            key.verifying_key(),
+            EncodingKey::from_ed_pem(pem.as_bytes()).ok(),
+        );
+    }
+    if let Ok(key) = ed25519_dalek::VerifyingKey::from_public_key_pem(pem) {
+        return ed25519_key(key, None);
+    }
+    if let Ok(key) =
+        RsaPrivateKey::from_pkcs8_pem(pem).or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem))
+    {
+        return Some(rsa_key(
+            &key.to_public_key(),
+            EncodingKey::from_rsa_pem(pem.as_bytes()).ok(),
+        ));
+    }
+    RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+        .ok()
+        .map(|key| rsa_key(&key, None))
+}
+
+fn ed25519_key(
+    key: ed25519_dalek::VerifyingKey,
+    encoding_key: Option<EncodingKey>,
+) -> Option<ParsedKey> {
+    let x = URL_SAFE_NO_PAD.encode(key.as_bytes());
+    Some(ParsedKey {
+        algorithm: Algorithm::EdDSA,
+        decoding_key: DecodingKey::from_ed_components(&x).ok()?,
+        params: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x,
+        }),
+        encoding_key,
+    })
+}
+
+fn rsa_key(key: &RsaPublicKey, encoding_key: Option<EncodingKey>) -> ParsedKey {
+    let (n, e) = (key.n().to_bytes_be(), key.e().to_bytes_be());
+    ParsedKey {
+        algorithm: Algorithm::RS256,
+        decoding_key: DecodingKey::from_rsa_raw_components(&n, &e),
+        params: AlgorithmParameters::RSA(RSAKeyParameters {
+            key_type: RSAKeyType::RSA,
+            n: URL_SAFE_NO_PAD.encode(n),
+            e: URL_SAFE_NO_PAD.encode(e),
+        }),
+        encoding_key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::pkcs8::EncodePublicKey;
+    use serde::Deserialize;
+
+    const RSA_PEM: &str = include_str!("testdata/jwt_rs256.pem");
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct TestClaims {
+        sub: String,
+        aud: String,
+        exp: usize,
+    }
+
+    fn claims() -> TestClaims {
+        TestClaims {
+            sub: "user".to_string(),
+            aud: "access".to_string(),
+            exp: (Utc::now().timestamp() + 60) as usize,
+        }
+    }
+
+    fn ed25519_pems() -> (String, String) {
+        let key = ed25519_dalek::SigningKey::from_bytes(&OsRng.r#gen());
+        let private = key.to_pkcs8_pem(Default::default()).unwrap().to_string();
+        let public = key
+            .verifying_key()
+            .to_public_key_pem(Default::default())
+            .unwrap();
+        (private, public)
+    }
+
+    fn rsa_public_pem() -> String {
+        use rsa::pkcs8::EncodePublicKey;
+        RsaPrivateKey::from_pkcs8_pem(RSA_PEM)
+            .unwrap()
+            .to_public_key()
+            .to_public_key_pem(Default::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tokens_carry_the_kid_and_round_trip_with_both_algorithms() {
+        let (ed25519, _) = ed25519_pems();
+        for (pem, algorithm) in [(RSA_PEM, Algorithm::RS256), (&ed25519, Algorithm::EdDSA)] {
+            let keys = JwtKeys::new("2026-10", pem, Vec::new(), TimeDelta::zero()).unwrap();
+
+            let claims = claims();
+            let token = keys.encode(&claims).unwrap();
+
+            let header = jsonwebtoken::decode_header(&token).unwrap();
+            assert_eq!(header.kid.as_deref(), Some("2026-10"));
+            assert_eq!(header.alg, algorithm);
+            assert_eq!(keys.decode::<TestClaims>(&token, "access").unwrap(), claims);
+            assert!(keys.decode::<TestClaims>(&token, "other").is_err());
+        }
+    }
+
+    #[test]
+    fn test_retired_keys_verify_during_the_grace_period_only() {
+        let (old_private, old_public) = ed25519_pems();
+        let old_keys = JwtKeys::new("old", &old_private, Vec::new(), TimeDelta::zero()).unwrap();
+        let token = old_keys.encode(&claims()).unwrap();
+        let rotated = |retired_at| {
+            JwtKeys::new(
+                "new",
+                RSA_PEM,
+                vec![RetiredKey {
+                    kid: "old".to_string(),
+                    pem: old_public.clone(),
+                    retired_at,
+                }],
+                TimeDelta::hours(1),
+            )
+            .unwrap()
+        };
+
+        let within_grace = rotated(Utc::now() - TimeDelta::minutes(30));
+        let past_grace = rotated(Utc::now() - TimeDelta::hours(2));
+
+        assert!(within_grace.decode::<TestClaims>(&token, "access").is_ok());
+        assert_eq!(within_grace.jwks(Utc::now()).keys.len(), 2);
+        assert!(past_grace.decode::<TestClaims>(&token, "access").is_err());
+        assert_eq!(past_grace.jwks(Utc::now()).keys.len(), 1);
+    }
+
+    #[test]
+    fn test_tokens_of_unknown_keys_are_rejected() {
+        let keys = JwtKeys::new("a", RSA_PEM, Vec::new(), TimeDelta::zero()).unwrap();
+        let (other, _) = ed25519_pems();
+        let forged = JwtKeys::new("a", &other, Vec::new(), TimeDelta::zero())
+            .unwrap()
+            .encode(&claims())
+            .unwrap();
+        let unknown = JwtKeys::new("b", &other, Vec::new(), TimeDelta::zero())
+            .unwrap()
+            .encode(&claims())
+            .unwrap();
+
+        assert!(keys.decode::<TestClaims>(&forged, "access").is_err());
+        assert_eq!(
+            keys.decode::<TestClaims>(&unknown, "access")
+                .unwrap_err()
+                .kind(),
+            &ErrorKind::InvalidToken
+        );
+    }
+
+    #[test]
+    fn test_jwks_publishes_public_parameters() {
+        let keys = JwtKeys::new(
+            "rsa",
+            RSA_PEM,
+            vec![RetiredKey {
+                kid: "rsa-public".to_string(),
+                pem: rsa_public_pem(),
+                retired_at: Utc::now(),
+            }],
+            TimeDelta::hours(1),
+        )
+        .unwrap();
+
+        let jwks = serde_json::to_value(keys.jwks(Utc::now())).unwrap();
+
+        let published = &jwks["keys"][0];
+        assert_eq!(published["kid"], "rsa");
+        assert_eq!(published["kty"], "RSA");
+        assert_eq!(published["alg"], "RS256");
+        assert_eq!(published["use"], "sig");
+        assert_eq!(published["e"], "AQAB");
+        assert_eq!(published["n"], jwks["keys"][1]["n"]);
+        assert!(published.get("d").is_none());
+    }
+
+    #[test]
+    fn test_signing_key_must_be_private_and_kids_unique() {
+        let (private, public) = ed25519_pems();
+
+        assert_eq!(
+            JwtKeys::new("a", &public, Vec::new(), TimeDelta::zero()).unwrap_err(),
+            JwtKeyError::NotAPrivateKey("a".to_string())
+        );
+        assert_eq!(
+            JwtKeys::new("a", "not a pem", Vec::new(), TimeDelta::zero()).unwrap_err(),
+            JwtKeyError::UnsupportedKey("a".to_string())
+        );
+        let duplicated = JwtKeys::new(
+            "a",
+            &private,
+            vec![RetiredKey {
+                kid: "a".to_string(),
+                pem: public,
+                retired_at: Utc::now(),
+            }],
+            TimeDelta::zero(),
+        );
+        assert_eq!(
+            duplicated.unwrap_err(),
+            JwtKeyError::DuplicatedKeyId("a".to_string())
+        );
+    }
+
+    #[test]
+    fn test_load_parses_retired_key_entries() {
+        let dir = std::env::temp_dir().join(format!("jwt-keys-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (private, public) = ed25519_pems();
+        let signing_path = dir.join("signing.pem");
+        let retired_path = dir.join("retired.pem");
+        std::fs::write(&signing_path, private).unwrap();
+        std::fs::write(&retired_path, public).unwrap();
+        let signing_path = signing_path.to_str().unwrap();
+
+        let keys = JwtKeys::load(
+            "new",
+            signing_path,
+            &format!(" old={}@2999-01-01T00:00:00Z ", retired_path.display()),
+            TimeDelta::zero(),
+        )
+        .unwrap();
+        let invalid = JwtKeys::load("new", signing_path, "old-without-path", TimeDelta::zero());
+        let missing = JwtKeys::load("new", "/nonexistent.pem", "", TimeDelta::zero());
+
+        assert_eq!(keys.jwks(Utc::now()).keys.len(), 2);
+        assert_eq!(
+            invalid.unwrap_err(),
+            JwtKeyError::InvalidRetiredKey("old-without-path".to_string())
+        );
+        assert!(matches!(
+            missing.unwrap_err(),
+            JwtKeyError::Unreadable { .. }
+        ));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,5 +1,9 @@
+pub mod audit;
+pub mod email_queue;
 pub mod http;
+pub mod jwt_keys;
 pub mod observability;
 pub mod postgres_conn;
+pub mod scheduler;
 #[cfg(test)]
 pub mod test_factory;
@@ -9,6 +9,7 @@ use opentelemetry_sdk::{
     Resource, logs::SdkLoggerProvider, metrics::SdkMeterProvider, trace::SdkTracerProvider,
 };
 use opentelemetry_semantic_conventions::resource::SERVICE_NAME;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
 #[derive(Clone)]
@@ -137,3 +138,14 @@ pub fn shutdown_observability(observability: &Observability) {
     let _ = observability.meter_provider.shutdown();
     let _ = observability.logger_provider.shutdown();
 }
+
+/// The W3C `traceparent` of the current span, stored with queued jobs so
+/// their workers continue the same trace.
+pub fn current_trace_context() -> Option<String> {
+    let context = tracing::Span::current().context();
+    let mut carrier = std::collections::HashMap::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut carrier);
+    });
+    carrier.get("traceparent").cloned()
+}
@@ -0,0 +1,53 @@
+use std::{future::Future, time::Duration};
+
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tracing::info;
+
+/// Runs `task` every `period` on a background tokio task. The first run
+/// happens after one full period so startup is not slowed down by jobs.
+pub fn spawn_periodic<F, Fut>(name: &'static str, period: Duration, mut task: F) -> JoinHandle<()>
+where
+    F: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        info!(
+            job = name,
+            period_secs = period.as_secs(),
+            "Periodic job scheduled"
+        );
+        loop {
+            interval.tick().await;
+            task().await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_spawn_periodic_runs_task_every_period() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let handle = spawn_periodic("test", Duration::from_secs(10), move || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        tokio::time::sleep(Duration::from_secs(35)).await;
+        handle.abort();
+
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+use crate::shared::domain::{audit::AuditEvent, errors::SharedDomainError};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AuditEventDto {
+    pub id: String,
+    /// e.g. `login_succeeded`, `login_failed`, `password_changed`, `token_revoked`
+    pub kind: String,
+    pub user_id: Option<String>,
+    /// Admin who did it, when it was not the user
+    pub actor_id: Option<String>,
+    /// e.g. why a login failed or which tokens were revoked
+    pub detail: Option<String>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    pub trace_id: Option<String>,
+    /// RFC 3339 timestamp
+    pub occurred_at: String,
+}
+
+impl From<AuditEvent> for AuditEventDto {
+    fn from(event: AuditEvent) -> Self {
+        Self {
+            id: event.id.to_string(),
+            kind: event.kind.to_string(),
+            user_id: event.user_id.map(|id| id.to_string()),
+            actor_id: event.actor_id.map(|id| id.to_string()),
+            detail: event.detail,
+            ip: event.origin.ip,
+            user_agent: event.origin.user_agent,
+            request_id: event.origin.request_id,
+            trace_id: event.origin.trace_id,
+            occurred_at: event.occurred_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Parses the RFC 3339 `before` cursor of audit event listings.
+pub fn parse_before(before: Option<&str>) -> Result<Option<DateTime<Utc>>, SharedDomainError> {
+    before
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|at| at.with_timezone(&Utc))
+                .map_err(|_| SharedDomainError::InvalidDateTime)
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_before() {
+        assert_eq!(parse_before(None), Ok(None));
+        assert!(
+            parse_before(Some("2026-03-15T10:00:00Z"))
+                .unwrap()
+                .is_some()
+        );
+        assert_eq!(
+            parse_before(Some("yesterday")),
+            Err(SharedDomainError::InvalidDateTime)
+        );
+    }
+}
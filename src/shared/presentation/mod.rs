@@ -1,4 +1,6 @@
+pub mod audit_dtos;
 pub mod openapi;
+pub mod well_known;
 
 use serde::Serialize;
 
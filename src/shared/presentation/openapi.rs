@@ -3,32 +3,128 @@ use utoipa::{
     openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
 };
 
-use crate::auth::presentation::dtos::{LoginDto, SignupDto, SuccesfullLoginDto, UserUuidDto};
+use crate::account::presentation::dtos::{
+    AccountDeletionDto, AccountExportDto, DeleteAccountDto, ExportedAccessTokenDto,
+    ExportedCommentDto, ExportedGoalDto, ExportedGoalEventDto, ExportedIdentityDto,
+    ExportedPositionDto, ExportedProfileDto, ExportedScraperJobDto, ExportedSettingsDto,
+    ExportedStatusChangeDto, NotificationPreferencesDto, UpdateNotificationPreferencesDto,
+};
+use crate::admin::presentation::dtos::{
+    EmailQueueHealthDto, EmailSuppressionDto, ManagedUserDto, QueueHealthDto, RedriveResultDto,
+    ScraperQueueHealthDto, SuppressEmailDto, UserPageDto,
+};
+use crate::auth::presentation::dtos::{
+    ChangeEmailDto, ChangePasswordDto, ConfirmEmailChangeDto, CreatePersonalAccessTokenDto,
+    CreatedPersonalAccessTokenDto, ForgotPasswordDto, LoginDto, LoginResultDto, LogoutDto,
+    MfaCodeDto, MfaDisableDto, MfaEnrollDto, MfaEnrollmentDto, MfaLoginDto, MfaRequiredDto,
+    OidcCallbackDto, OidcProvidersDto, PersonalAccessTokenDto, RecoveryCodesDto, RefreshTokenDto,
+    ResetPasswordDto, SessionDto, SignupDto, SuccesfullLoginDto, UserUuidDto,
+};
+use crate::digest::presentation::dtos::DigestSubscriptionDto;
+use crate::goals::presentation::dtos::{
+    GoalResponseDto, GoalStreakDto, LogGoalEventRequestDto, PeriodProgressDto, SaveGoalRequestDto,
+};
 use crate::positions::presentation::dtos::{
     CommentResponseDto, CommentUuidDto, PositionResponseDto, PositionUuidDto,
-    SaveCommentRequestDto, SavePositionRequestDto, UpdateCommentRequestDto,
-    UpdatePositionRequestDto,
+    SaveCommentRequestDto, SavePositionRequestDto, StalenessSettingsDto, StatusChangeResponseDto,
+    UpdateCommentRequestDto, UpdatePositionRequestDto,
 };
+use crate::shared::presentation::audit_dtos::AuditEventDto;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         crate::auth::presentation::handlers::login,
+        crate::auth::presentation::handlers::login_mfa,
+        crate::auth::presentation::handlers::refresh,
+        crate::auth::presentation::handlers::logout,
+        crate::auth::presentation::handlers::logout_all,
+        crate::auth::presentation::handlers::forgot_password,
+        crate::auth::presentation::handlers::reset_password,
         crate::auth::presentation::handlers::signup,
+        crate::auth::presentation::handlers::resend_verification,
+        crate::auth::presentation::handlers::change_password,
+        crate::auth::presentation::handlers::change_email,
+        crate::auth::presentation::handlers::confirm_email_change,
+        crate::auth::presentation::handlers::mfa_enroll,
+        crate::auth::presentation::handlers::mfa_confirm,
+        crate::auth::presentation::handlers::mfa_disable,
+        crate::auth::presentation::handlers::create_personal_access_token,
+        crate::auth::presentation::handlers::list_personal_access_tokens,
+        crate::auth::presentation::handlers::revoke_personal_access_token,
+        crate::auth::presentation::handlers::list_sessions,
+        crate::auth::presentation::handlers::revoke_session,
+        crate::auth::presentation::oidc_handlers::list_oidc_providers,
+        crate::auth::presentation::oidc_handlers::oidc_authorize,
+        crate::auth::presentation::oidc_handlers::oidc_callback,
         crate::positions::presentation::handlers::get_positions,
         crate::positions::presentation::handlers::get_position,
         crate::positions::presentation::handlers::save_position,
         crate::positions::presentation::handlers::update_position,
         crate::positions::presentation::handlers::remove_position,
+        crate::positions::presentation::handlers::get_status_history,
+        crate::positions::presentation::staleness_handlers::get_staleness_settings,
+        crate::positions::presentation::staleness_handlers::update_staleness_settings,
         crate::positions::presentation::comment_handlers::get_comments_for_position,
         crate::positions::presentation::comment_handlers::get_comment,
         crate::positions::presentation::comment_handlers::save_comment,
         crate::positions::presentation::comment_handlers::update_comment,
         crate::positions::presentation::comment_handlers::remove_comment,
+        crate::digest::presentation::handlers::get_subscription,
+        crate::digest::presentation::handlers::update_subscription,
+        crate::goals::presentation::handlers::get_goals,
+        crate::goals::presentation::handlers::get_goal,
+        crate::goals::presentation::handlers::save_goal,
+        crate::goals::presentation::handlers::update_goal,
+        crate::goals::presentation::handlers::remove_goal,
+        crate::goals::presentation::handlers::get_goal_history,
+        crate::goals::presentation::handlers::get_goal_streak,
+        crate::goals::presentation::handlers::log_goal_event,
+        crate::account::presentation::handlers::export_account,
+        crate::account::presentation::handlers::delete_account,
+        crate::account::presentation::handlers::get_account_deletion,
+        crate::account::presentation::handlers::cancel_account_deletion,
+        crate::account::presentation::handlers::list_security_events,
+        crate::account::presentation::handlers::get_notification_preferences,
+        crate::account::presentation::handlers::update_notification_preferences,
+        crate::account::presentation::handlers::unsubscribe,
+        crate::admin::presentation::handlers::list_users,
+        crate::admin::presentation::handlers::disable_user,
+        crate::admin::presentation::handlers::enable_user,
+        crate::admin::presentation::handlers::verify_user_email,
+        crate::admin::presentation::handlers::get_queue_health,
+        crate::admin::presentation::handlers::retry_scraper_jobs,
+        crate::admin::presentation::handlers::redrive_emails,
+        crate::admin::presentation::handlers::list_email_suppressions,
+        crate::admin::presentation::handlers::suppress_email,
+        crate::admin::presentation::handlers::unsuppress_email,
+        crate::admin::presentation::handlers::list_audit_events,
+        crate::shared::presentation::well_known::jwks,
     ),
     components(
         schemas(
             LoginDto,
+            LoginResultDto,
+            MfaRequiredDto,
+            MfaLoginDto,
+            MfaEnrollDto,
+            MfaEnrollmentDto,
+            MfaCodeDto,
+            RecoveryCodesDto,
+            MfaDisableDto,
+            CreatePersonalAccessTokenDto,
+            PersonalAccessTokenDto,
+            CreatedPersonalAccessTokenDto,
+            SessionDto,
+            OidcProvidersDto,
+            OidcCallbackDto,
+            RefreshTokenDto,
+            LogoutDto,
+            ForgotPasswordDto,
+            ResetPasswordDto,
+            ChangePasswordDto,
+            ChangeEmailDto,
+            ConfirmEmailChangeDto,
             SignupDto,
             SuccesfullLoginDto,
             UserUuidDto,
@@ -36,17 +132,53 @@ use crate::positions::presentation::dtos::{
             PositionUuidDto,
             SavePositionRequestDto,
             UpdatePositionRequestDto,
+            StatusChangeResponseDto,
+            StalenessSettingsDto,
             CommentResponseDto,
             CommentUuidDto,
             SaveCommentRequestDto,
-            UpdateCommentRequestDto
+            UpdateCommentRequestDto,
+            DigestSubscriptionDto,
+            SaveGoalRequestDto,
+            LogGoalEventRequestDto,
+            GoalResponseDto,
+            PeriodProgressDto,
+            GoalStreakDto,
+            DeleteAccountDto,
+            AccountDeletionDto,
+            AccountExportDto,
+            ExportedProfileDto,
+            ExportedPositionDto,
+            ExportedCommentDto,
+            ExportedStatusChangeDto,
+            ExportedGoalDto,
+            ExportedGoalEventDto,
+            ExportedSettingsDto,
+            ExportedScraperJobDto,
+            ExportedAccessTokenDto,
+            ExportedIdentityDto,
+            NotificationPreferencesDto,
+            UpdateNotificationPreferencesDto,
+            ManagedUserDto,
+            UserPageDto,
+            QueueHealthDto,
+            EmailQueueHealthDto,
+            ScraperQueueHealthDto,
+            RedriveResultDto,
+            EmailSuppressionDto,
+            SuppressEmailDto,
+            AuditEventDto
         )
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "Auth", description = "Authentication endpoints"),
         (name = "Positions", description = "Job positions management"),
-        (name = "Comments", description = "Comments for positions")
+        (name = "Comments", description = "Comments for positions"),
+        (name = "Digest", description = "Weekly job search digest email"),
+        (name = "Goals", description = "Job search goals, progress and streaks"),
+        (name = "Account", description = "Data export, account deletion and security events"),
+        (name = "Admin", description = "User administration, queue health and the audit log, admins only")
     )
 )]
 pub struct ApiDoc;
@@ -0,0 +1,58 @@
+use axum::{Json, Router, extract::State, routing::get};
+use chrono::Utc;
+use jsonwebtoken::jwk::JwkSet;
+use std::sync::Arc;
+
+use crate::shared::config::Config;
+
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    responses(
+        (status = 200, description = "Public keys that verify the issued JWTs, identified by `kid`", content_type = "application/json")
+    ),
+    tag = "Auth"
+)]
+pub async fn jwks(State(config): State<Arc<Config>>) -> Json<JwkSet> {
+    Json(config.jwt_keys().jwks(Utc::now()))
+}
+
+pub fn create_well_known_routes(config: Arc<Config>) -> Router {
+    Router::new()
+        .route("/jwks.json", get(jwks))
+        .with_state(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_jwks_publishes_the_signing_key() {
+        let config = Config::test_default();
+        let app = create_well_known_routes(Arc::new(config.clone()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/jwks.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let jwks: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(jwks["keys"][0]["kid"], config.jwt_keys().signing_kid());
+        assert!(jwks["keys"][0].get("d").is_none());
+    }
+}
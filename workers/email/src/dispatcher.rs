@@ -0,0 +1,430 @@
+use crate::{EmailJob, JobOutcome, Mailer, health::ListenerStatus, process_job, retry::env_number};
+use chrono::Utc;
+use sqlx::{Pool, Postgres, postgres::PgListener};
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tokio::task::JoinSet;
+use tokio::time::{Duration, sleep, timeout};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// Pause between attempts to get the notification listener back.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// How many emails a worker sends at once and how it finds them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DispatcherConfig {
+    pub concurrency: usize,
+    /// Catch-up poll for retries coming due and notifications missed while
+    /// the listener was reconnecting.
+    pub poll_interval: Duration,
+    /// How long a claimed job is left to its worker before other workers
+    /// may claim it again. A running send keeps extending its claim.
+    pub claim_timeout: Duration,
+    /// How long in-flight sends may take to finish on shutdown.
+    pub shutdown_timeout: Duration,
+}
+
+impl DispatcherConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        let concurrency = env_number("EMAIL_CONCURRENCY", 4)?;
+        anyhow::ensure!(concurrency > 0, "EMAIL_CONCURRENCY must be at least 1");
+        let claim_timeout_secs = env_number("EMAIL_CLAIM_TIMEOUT_SECS", 300)?;
+        anyhow::ensure!(
+            claim_timeout_secs > 0,
+            "EMAIL_CLAIM_TIMEOUT_SECS must be at least 1"
+        );
+        Ok(Self {
+            concurrency,
+            poll_interval: Duration::from_secs(env_number("EMAIL_POLL_INTERVAL_SECS", 30)?),
+            claim_timeout: Duration::from_secs(claim_timeout_secs),
+            shutdown_timeout: Duration::from_secs(env_number("EMAIL_SHUTDOWN_TIMEOUT_SECS", 25)?),
+        })
+    }
+}
+
+/// Sends due jobs, up to `concurrency` at a time, until `shutdown`
+/// resolves, then waits for the sends in flight.
+pub async fn run(
+    pool: Pool<Postgres>,
+    mailer: Arc<Mailer>,
+    config: DispatcherConfig,
+    listener_status: ListenerStatus,
+    shutdown: impl Future<Output = ()>,
+) -> anyhow::Result<()> {
+    let listener = listen(&pool).await?;
+    let wake = Arc::new(Notify::new());
+    let listener = tokio::spawn(forward_notifications(
+        pool.clone(),
+        listener,
+        wake.clone(),
+        listener_status,
+    ));
+    tokio::pin!(shutdown);
+
+    let mut in_flight = InFlight::default();
+    loop {
+        let free = config.concurrency.saturating_sub(in_flight.len());
+        if free > 0 {
+            match claim_due_jobs(&pool, free, config.claim_timeout).await {
+                Ok(jobs) => {
+                    if !jobs.is_empty() {
+                        info!("Claimed {} due jobs.", jobs.len());
+                    }
+                    let now = Utc::now();
+                    for job in jobs {
+                        mailer
+                            .metrics
+                            .queue_lag((now - job.due_at).to_std().unwrap_or_default());
+                        in_flight.spawn(
+                            pool.clone(),
+                            mailer.clone(),
+                            job,
+                            config.claim_timeout,
+                            wake.clone(),
+                        );
+                    }
+                }
+                Err(e) => error!("Error claiming due jobs: {:?}", e),
+            }
+        }
+
+        tokio::select! {
+            _ = &mut shutdown => break,
+            _ = wake.notified() => {}
+            Some(result) = in_flight.tasks.join_next() => {
+                if let Err(e) = result {
+                    error!("Email job task failed: {:?}", e);
+                }
+            }
+            _ = sleep(config.poll_interval) => {}
+        }
+    }
+
+    listener.abort();
+    let aborted = drain(in_flight, config.shutdown_timeout).await;
+    if !aborted.is_empty() {
+        release_jobs(&pool, &aborted).await?;
+        info!(
+            "Released {} emails for another worker to send.",
+            aborted.len()
+        );
+    }
+    Ok(())
+}
+
+/// The sends in flight, with the jobs they hold a claim on.
+#[derive(Default)]
+struct InFlight {
+    tasks: JoinSet<()>,
+    /// Jobs whose send has not finished, so the ones cut off on shutdown can
+    /// be released.
+    jobs: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+impl InFlight {
+    fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    fn spawn(
+        &mut self,
+        pool: Pool<Postgres>,
+        mailer: Arc<Mailer>,
+        job: EmailJob,
+        claim_timeout: Duration,
+        wake: Arc<Notify>,
+    ) {
+        let job_id = job.id;
+        let jobs = self.jobs.clone();
+        lock(&jobs).insert(job_id);
+        self.tasks.spawn(async move {
+            match process_job(&pool, job, &mailer, claim_timeout).await {
+                Ok(JobOutcome::Done) => {}
+                // Deferred jobs are due again before the next poll.
+                Ok(JobOutcome::Deferred(wait)) => {
+                    tokio::spawn(async move {
+                        sleep(wait).await;
+                        wake.notify_one();
+                    });
+                }
+                Err(e) => error!("Failed to process job {}: {:?}", job_id, e),
+            }
+            // Not reached by sends aborted on shutdown.
+            lock(&jobs).remove(&job_id);
+        });
+    }
+}
+
+fn lock(jobs: &Mutex<HashSet<Uuid>>) -> std::sync::MutexGuard<'_, HashSet<Uuid>> {
+    jobs.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+async fn listen(pool: &Pool<Postgres>) -> anyhow::Result<PgListener> {
+    let mut listener = PgListener::connect_with(pool).await?;
+    listener.listen("email_queue").await?;
+    info!("Listening for notifications on 'email_queue'...");
+    Ok(listener)
+}
+
+/// Wakes the dispatcher on every notification. A lost connection is
+/// reconnected here rather than inside the listener, so readiness reflects
+/// it and the dispatcher catches up on notifications sent meanwhile.
+async fn forward_notifications(
+    pool: Pool<Postgres>,
+    mut listener: PgListener,
+    wake: Arc<Notify>,
+    status: ListenerStatus,
+) {
+    loop {
+        status.set_listening(true);
+        loop {
+            match listener.try_recv().await {
+                Ok(Some(notification)) => {
+                    debug!(payload = notification.payload(), "Received notification");
+                    wake.notify_one();
+                }
+                Ok(None) => {
+                    warn!("Lost the connection listening for notifications");
+                    break;
+                }
+                Err(e) => {
+                    error!("Error receiving notification: {:?}", e);
+                    break;
+                }
+            }
+        }
+        status.set_listening(false);
+
+        listener = loop {
+            sleep(RECONNECT_DELAY).await;
+            match listen(&pool).await {
+                Ok(listener) => break listener,
+                Err(e) => error!("Failed to listen for notifications: {:?}", e),
+            }
+        };
+        wake.notify_one();
+    }
+}
+
+/// Claims up to `limit` due jobs by moving their next attempt past the claim
+/// timeout. Rows locked by other workers are skipped, so replicas never
+/// claim the same job; jobs of a worker that died are due again once the
+/// claim times out.
+pub async fn claim_due_jobs(
+    pool: &Pool<Postgres>,
+    limit: usize,
+    claim_timeout: Duration,
+) -> anyhow::Result<Vec<EmailJob>> {
+    let jobs = sqlx::query_as::<_, EmailJob>(
+        "UPDATE email_queue q SET next_attempt_at = NOW() + make_interval(secs => $2) \
+         FROM (SELECT id, next_attempt_at FROM email_queue WHERE processed = false AND NOT dead AND next_attempt_at <= NOW() ORDER BY next_attempt_at LIMIT $1 FOR UPDATE SKIP LOCKED) due \
+         WHERE q.id = due.id \
+         RETURNING q.id, q.payload, q.attempt_count, q.user_id, q.trace_id, due.next_attempt_at AS due_at, q.idempotency_key, q.sending_at",
+    )
+    .bind(limit as i64)
+    .bind(claim_timeout.as_secs_f64())
+    .fetch_all(pool)
+    .await?;
+    Ok(jobs)
+}
+
+/// Pushes the claim of a job still being sent past another claim timeout,
+/// so no other worker picks it up meanwhile.
+pub async fn extend_claim(
+    pool: &Pool<Postgres>,
+    job_id: Uuid,
+    claim_timeout: Duration,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        "UPDATE email_queue SET next_attempt_at = NOW() + make_interval(secs => $2) WHERE id = $1 AND processed = false AND NOT dead",
+    )
+    .bind(job_id)
+    .bind(claim_timeout.as_secs_f64())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Puts claimed jobs back in the queue, due now and with no send recorded,
+/// for jobs that did not get to send their email.
+pub async fn release_jobs(pool: &Pool<Postgres>, job_ids: &[Uuid]) -> anyhow::Result<()> {
+    sqlx::query(
+        "UPDATE email_queue SET sending_at = NULL, next_attempt_at = NOW() WHERE id = ANY($1) AND processed = false AND NOT dead",
+    )
+    .bind(job_ids)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Waits for the sends in flight. Those still running after `timeout` are
+/// aborted, and their jobs are returned so they can be released.
+async fn drain(mut in_flight: InFlight, shutdown_timeout: Duration) -> Vec<Uuid> {
+    if in_flight.tasks.is_empty() {
+        return Vec::new();
+    }
+    info!(
+        "Shutting down, waiting for {} emails in flight...",
+        in_flight.len()
+    );
+    let finished = timeout(shutdown_timeout, async {
+        while let Some(result) = in_flight.tasks.join_next().await {
+            if let Err(e) = result {
+                error!("Email job task failed: {:?}", e);
+            }
+        }
+    })
+    .await;
+    if finished.is_err() {
+        warn!(
+            "{} emails still sending after {}s, aborting them",
+            in_flight.len(),
+            shutdown_timeout.as_secs()
+        );
+        in_flight.tasks.abort_all();
+        // Aborted sends must have stopped before their jobs are released.
+        while in_flight.tasks.join_next().await.is_some() {}
+    }
+    lock(&in_flight.jobs).drain().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{CountingSender, mailer, queue_email};
+    use crate::{email_sender::EmailSender, templates::RenderedEmail};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CLAIM_TIMEOUT: Duration = Duration::from_secs(300);
+
+    /// Never gets the email out.
+    struct HangingSender;
+
+    #[async_trait::async_trait]
+    impl EmailSender for HangingSender {
+        async fn send(&self, _to: &str, _email: &RenderedEmail) -> anyhow::Result<()> {
+            std::future::pending().await
+        }
+    }
+
+    async fn wait_until_sending(pool: &Pool<Postgres>, job_id: Uuid) {
+        timeout(Duration::from_secs(5), async {
+            loop {
+                let sending: bool = sqlx::query_scalar(
+                    "SELECT sending_at IS NOT NULL FROM email_queue WHERE id = $1",
+                )
+                .bind(job_id)
+                .fetch_one(pool)
+                .await
+                .unwrap();
+                if sending {
+                    break;
+                }
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("The send should start");
+    }
+
+    #[tokio::test]
+    async fn test_drain_waits_for_sends_in_flight() {
+        let sent = Arc::new(AtomicUsize::new(0));
+        let mut in_flight = InFlight::default();
+        for _ in 0..3 {
+            let sent = sent.clone();
+            in_flight.tasks.spawn(async move {
+                sleep(Duration::from_millis(20)).await;
+                sent.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        let aborted = drain(in_flight, Duration::from_secs(5)).await;
+
+        assert_eq!(sent.load(Ordering::SeqCst), 3);
+        assert!(aborted.is_empty());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_drain_gives_up_on_sends_past_the_timeout(pool: Pool<Postgres>) {
+        let job_id = queue_email(&pool).await;
+        let config = DispatcherConfig {
+            concurrency: 1,
+            poll_interval: Duration::from_secs(30),
+            claim_timeout: CLAIM_TIMEOUT,
+            shutdown_timeout: Duration::from_millis(20),
+        };
+
+        let started = tokio::time::Instant::now();
+        run(
+            pool.clone(),
+            Arc::new(mailer(Arc::new(HangingSender))),
+            config,
+            ListenerStatus::default(),
+            wait_until_sending(&pool, job_id),
+        )
+        .await
+        .unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        let (sending, due, attempt_count, processed, dead): (bool, bool, i32, Option<bool>, bool) =
+            sqlx::query_as(
+                "SELECT sending_at IS NOT NULL, next_attempt_at <= NOW(), attempt_count, processed, dead FROM email_queue WHERE id = $1",
+            )
+            .bind(job_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(!sending, "An aborted send is not recorded as sent");
+        assert!(due, "An aborted send is due again right away");
+        assert_eq!((attempt_count, processed, dead), (0, Some(false), false));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_a_send_aborted_on_shutdown_is_sent_again_once_reclaimed(pool: Pool<Postgres>) {
+        let job_id = queue_email(&pool).await;
+        let job = claim_due_jobs(&pool, 1, CLAIM_TIMEOUT)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let mut in_flight = InFlight::default();
+        in_flight.spawn(
+            pool.clone(),
+            Arc::new(mailer(Arc::new(HangingSender))),
+            job,
+            CLAIM_TIMEOUT,
+            Arc::new(Notify::new()),
+        );
+        wait_until_sending(&pool, job_id).await;
+
+        let aborted = drain(in_flight, Duration::from_millis(20)).await;
+        assert_eq!(aborted, vec![job_id]);
+        release_jobs(&pool, &aborted).await.unwrap();
+
+        let job = claim_due_jobs(&pool, 1, CLAIM_TIMEOUT)
+            .await
+            .unwrap()
+            .pop()
+            .expect("The released job should be due again");
+        assert_eq!(job.id, job_id);
+        let sender = Arc::new(CountingSender::default());
+        assert_eq!(
+            process_job(&pool, job, &mailer(sender.clone()), CLAIM_TIMEOUT)
+                .await
+                .unwrap(),
+            JobOutcome::Done
+        );
+        assert_eq!(sender.0.load(Ordering::SeqCst), 1);
+        let (processed, dead): (Option<bool>, bool) =
+            sqlx::query_as("SELECT processed, dead FROM email_queue WHERE id = $1")
+                .bind(job_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((processed, dead), (Some(true), false));
+    }
+}
@@ -0,0 +1,580 @@
+use anyhow::{Context, bail};
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{
+        Mailbox, MultiPart,
+        header::{ContentType, HeaderName, HeaderValue},
+    },
+    transport::smtp::{
+        PoolConfig,
+        authentication::Credentials,
+        client::{Tls, TlsParameters},
+    },
+};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::sync::Arc;
+use tokio::time::{Duration, sleep};
+use tracing::info;
+
+use crate::templates::RenderedEmail;
+
+#[async_trait::async_trait]
+pub trait EmailSender: Send + Sync {
+    async fn send(&self, to: &str, email: &RenderedEmail) -> anyhow::Result<()>;
+}
+
+/// Picks the sender named by `EMAIL_SENDER`: `stdout` (the default, for
+/// development) or `smtp`.
+pub fn from_env() -> anyhow::Result<Arc<dyn EmailSender>> {
+    let kind = env::var("EMAIL_SENDER").unwrap_or_else(|_| "stdout".to_string());
+    match kind.to_lowercase().as_str() {
+        "stdout" => Ok(Arc::new(StdoutEmailSender)),
+        "smtp" => {
+            let config = SmtpConfig::from_lookup(|key| env::var(key).ok())?;
+            info!(
+                host = %config.host,
+                port = config.port,
+                tls = ?config.tls,
+                pool_max_size = config.pool_max_size,
+                "Sending emails through SMTP"
+            );
+            Ok(Arc::new(SmtpEmailSender::new(&config)?))
+        }
+        other => bail!("Unknown EMAIL_SENDER '{other}', expected 'stdout' or 'smtp'"),
+    }
+}
+
+pub struct StdoutEmailSender;
+
+#[async_trait::async_trait]
+impl EmailSender for StdoutEmailSender {
+    async fn send(&self, to: &str, email: &RenderedEmail) -> anyhow::Result<()> {
+        println!("--------------------------------------------------");
+        println!("📧 SENDING EMAIL");
+        println!("To: {}", to);
+        println!("Subject: {}", email.subject);
+        println!("Body: {}", email.text);
+        if let Some(html) = &email.html {
+            println!("HTML part: {} bytes", html.len());
+        }
+        if let Some(url) = &email.list_unsubscribe {
+            println!("List-Unsubscribe: <{}>", url);
+        }
+        if let Some(key) = &email.idempotency_key {
+            println!("Idempotency key: {}", key);
+        }
+        println!("--------------------------------------------------");
+        sleep(Duration::from_millis(500)).await;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmtpTls {
+    /// Plain connection upgraded with `STARTTLS`, which the server must offer.
+    StartTls,
+    /// TLS from the first byte, usually on port 465.
+    Implicit,
+    /// Unencrypted, only for local sinks.
+    None,
+}
+
+impl SmtpTls {
+    fn default_port(self) -> u16 {
+        match self {
+            SmtpTls::StartTls => 587,
+            SmtpTls::Implicit => 465,
+            SmtpTls::None => 25,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub tls: SmtpTls,
+    /// Username and password, when the server requires authentication.
+    pub credentials: Option<(String, String)>,
+    pub from: String,
+    /// Open connections kept for reuse between emails.
+    pub pool_max_size: u32,
+    pub timeout: Duration,
+}
+
+impl SmtpConfig {
+    /// Reads the `SMTP_*` variables through `lookup`.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let host = lookup("SMTP_HOST").context("SMTP_HOST must be set")?;
+        let from = lookup("SMTP_FROM").context("SMTP_FROM must be set")?;
+        let tls = match lookup("SMTP_TLS")
+            .unwrap_or_else(|| "starttls".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "starttls" => SmtpTls::StartTls,
+            "tls" => SmtpTls::Implicit,
+            "none" => SmtpTls::None,
+            other => bail!("Unknown SMTP_TLS '{other}', expected 'starttls', 'tls' or 'none'"),
+        };
+        let port = match lookup("SMTP_PORT") {
+            Some(port) => port.parse().context("SMTP_PORT must be a port number")?,
+            None => tls.default_port(),
+        };
+        let credentials = match (lookup("SMTP_USERNAME"), lookup("SMTP_PASSWORD")) {
+            (Some(username), Some(password)) => Some((username, password)),
+            (None, None) => None,
+            _ => bail!("SMTP_USERNAME and SMTP_PASSWORD must be set together"),
+        };
+        let pool_max_size = match lookup("SMTP_POOL_MAX_SIZE") {
+            Some(size) => size
+                .parse()
+                .context("SMTP_POOL_MAX_SIZE must be a positive number")?,
+            None => 4,
+        };
+        let timeout_secs = match lookup("SMTP_TIMEOUT_SECS") {
+            Some(secs) => secs
+                .parse()
+                .context("SMTP_TIMEOUT_SECS must be a number of seconds")?,
+            None => 30,
+        };
+
+        Ok(Self {
+            host,
+            port,
+            tls,
+            credentials,
+            from,
+            pool_max_size,
+            timeout: Duration::from_secs(timeout_secs),
+        })
+    }
+}
+
+pub struct SmtpEmailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailSender {
+    pub fn new(config: &SmtpConfig) -> anyhow::Result<Self> {
+        let from = config
+            .from
+            .parse()
+            .with_context(|| format!("Invalid SMTP_FROM '{}'", config.from))?;
+        let tls = match config.tls {
+            SmtpTls::StartTls => Tls::Required(TlsParameters::new(config.host.clone())?),
+            SmtpTls::Implicit => Tls::Wrapper(TlsParameters::new(config.host.clone())?),
+            SmtpTls::None => Tls::None,
+        };
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+            .port(config.port)
+            .tls(tls)
+            .timeout(Some(config.timeout))
+            .pool_config(PoolConfig::new().max_size(config.pool_max_size));
+        if let Some((username, password)) = &config.credentials {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+
+    /// The same for every send of an email; without a key lettre makes up
+    /// a random one.
+    fn message_id(&self, idempotency_key: &str) -> String {
+        format!(
+            "<{:x}@{}>",
+            Sha256::digest(idempotency_key.as_bytes()),
+            self.from.email.domain()
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailSender for SmtpEmailSender {
+    async fn send(&self, to: &str, email: &RenderedEmail) -> anyhow::Result<()> {
+        let builder = Message::builder()
+            .from(self.from.clone())
+            .to(to
+                .parse()
+                .with_context(|| format!("Invalid recipient '{to}'"))?)
+            .subject(&email.subject)
+            .message_id(
+                email
+                    .idempotency_key
+                    .as_deref()
+                    .map(|key| self.message_id(key)),
+            );
+        // RFC 8058: mailbox providers show an unsubscribe button that POSTs
+        // `List-Unsubscribe=One-Click` to the URL.
+        let builder = match &email.list_unsubscribe {
+            Some(url) => builder
+                .raw_header(HeaderValue::new(
+                    HeaderName::new_from_ascii_str("List-Unsubscribe"),
+                    format!("<{url}>"),
+                ))
+                .raw_header(HeaderValue::new(
+                    HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                    "List-Unsubscribe=One-Click".to_string(),
+                )),
+            None => builder,
+        };
+        let message = match &email.html {
+            Some(html) => builder.multipart(MultiPart::alternative_plain_html(
+                email.text.clone(),
+                html.clone(),
+            )),
+            None => builder
+                .header(ContentType::TEXT_PLAIN)
+                .body(email.text.clone()),
+        }
+        .context("Failed to build email")?;
+        self.transport
+            .send(message)
+            .await
+            .context("SMTP server rejected the email")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    #[derive(Debug, Default, Clone)]
+    struct Delivery {
+        auth: Option<String>,
+        mail_from: String,
+        rcpt_to: Vec<String>,
+        data: String,
+    }
+
+    /// Minimal in-process SMTP server: no TLS, accepts `AUTH PLAIN` and
+    /// refuses recipients at `rejected.test`.
+    #[derive(Clone, Default)]
+    struct FakeSmtpServer {
+        deliveries: Arc<Mutex<Vec<Delivery>>>,
+        connections: Arc<Mutex<usize>>,
+    }
+
+    impl FakeSmtpServer {
+        async fn start() -> (Self, u16) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let server = Self::default();
+            let handle = server.clone();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    *handle.connections.lock().unwrap() += 1;
+                    tokio::spawn(handle.clone().serve(stream));
+                }
+            });
+            (server, port)
+        }
+
+        async fn serve(self, stream: tokio::net::TcpStream) {
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            let mut current = Delivery::default();
+            let mut auth = None;
+            writer.write_all(b"220 fake ESMTP\r\n").await.unwrap();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let command = line.to_uppercase();
+                let reply: &[u8] = if command.starts_with("EHLO") {
+                    b"250-fake\r\n250-AUTH PLAIN\r\n250 8BITMIME\r\n"
+                } else if let Some(credentials) = line.strip_prefix("AUTH PLAIN ") {
+                    auth = Some(credentials.to_string());
+                    b"235 2.7.0 Authenticated\r\n"
+                } else if command.starts_with("MAIL FROM:") {
+                    current = Delivery {
+                        auth: auth.clone(),
+                        mail_from: line[10..].to_string(),
+                        ..Delivery::default()
+                    };
+                    b"250 OK\r\n"
+                } else if command.starts_with("RCPT TO:") {
+                    if command.contains("@REJECTED.TEST") {
+                        b"550 5.1.1 No such user\r\n"
+                    } else {
+                        current.rcpt_to.push(line[8..].to_string());
+                        b"250 OK\r\n"
+                    }
+                } else if command == "DATA" {
+                    writer
+                        .write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n")
+                        .await
+                        .unwrap();
+                    while let Ok(Some(data_line)) = lines.next_line().await {
+                        if data_line == "." {
+                            break;
+                        }
+                        current.data.push_str(&data_line);
+                        current.data.push('\n');
+                    }
+                    self.deliveries
+                        .lock()
+                        .unwrap()
+                        .push(std::mem::take(&mut current));
+                    b"250 OK queued\r\n"
+                } else if command == "RSET" || command == "NOOP" {
+                    b"250 OK\r\n"
+                } else if command == "QUIT" {
+                    writer.write_all(b"221 Bye\r\n").await.unwrap();
+                    return;
+                } else {
+                    b"502 Command not implemented\r\n"
+                };
+                writer.write_all(reply).await.unwrap();
+            }
+        }
+
+        fn deliveries(&self) -> Vec<Delivery> {
+            self.deliveries.lock().unwrap().clone()
+        }
+
+        fn connections(&self) -> usize {
+            *self.connections.lock().unwrap()
+        }
+    }
+
+    fn local_config(port: u16) -> SmtpConfig {
+        SmtpConfig {
+            host: "127.0.0.1".to_string(),
+            port,
+            tls: SmtpTls::None,
+            credentials: Some(("worker".to_string(), "s3cret".to_string())),
+            from: "Seeker <no-reply@seeker.test>".to_string(),
+            pool_max_size: 2,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn plain_text(subject: &str, text: &str) -> RenderedEmail {
+        RenderedEmail {
+            subject: subject.to_string(),
+            text: text.to_string(),
+            html: None,
+            list_unsubscribe: None,
+            idempotency_key: None,
+        }
+    }
+
+    fn config_from(vars: &[(&str, &str)]) -> anyhow::Result<SmtpConfig> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        SmtpConfig::from_lookup(|key| vars.get(key).cloned())
+    }
+
+    #[tokio::test]
+    async fn test_smtp_sender_authenticates_and_delivers() {
+        let (server, port) = FakeSmtpServer::start().await;
+        let sender = SmtpEmailSender::new(&local_config(port)).unwrap();
+
+        sender
+            .send(
+                "user@example.com",
+                &plain_text("Verify your email", "Hello,\n\nClick the link."),
+            )
+            .await
+            .unwrap();
+
+        let deliveries = server.deliveries();
+        assert_eq!(deliveries.len(), 1);
+        let delivery = &deliveries[0];
+        // base64 of "\0worker\0s3cret"
+        assert_eq!(delivery.auth.as_deref(), Some("AHdvcmtlcgBzM2NyZXQ="));
+        assert_eq!(delivery.mail_from, "<no-reply@seeker.test>");
+        assert_eq!(delivery.rcpt_to, vec!["<user@example.com>"]);
+        assert!(delivery.data.contains("Subject: Verify your email"));
+        assert!(delivery.data.contains("To: user@example.com"));
+        assert!(delivery.data.contains("Click the link."));
+    }
+
+    #[tokio::test]
+    async fn test_smtp_sender_sends_html_emails_as_alternatives() {
+        let (server, port) = FakeSmtpServer::start().await;
+        let sender = SmtpEmailSender::new(&local_config(port)).unwrap();
+        let email = RenderedEmail {
+            subject: "Verify your email".to_string(),
+            text: "Hello,\n\nClick the link.".to_string(),
+            html: Some("<p>Hello,</p><p>Click the link.</p>".to_string()),
+            list_unsubscribe: None,
+            idempotency_key: None,
+        };
+
+        sender.send("user@example.com", &email).await.unwrap();
+
+        let data = &server.deliveries()[0].data;
+        assert!(data.contains("Content-Type: multipart/alternative"));
+        assert!(data.contains("Content-Type: text/plain; charset=utf-8"));
+        assert!(data.contains("Content-Type: text/html; charset=utf-8"));
+        assert!(data.contains("<p>Hello,</p><p>Click the link.</p>"));
+    }
+
+    #[tokio::test]
+    async fn test_smtp_sender_adds_one_click_unsubscribe_headers() {
+        let (server, port) = FakeSmtpServer::start().await;
+        let sender = SmtpEmailSender::new(&local_config(port)).unwrap();
+        let email = RenderedEmail {
+            list_unsubscribe: Some(
+                "https://api.seeker.example/account/notifications/unsubscribe?token=abc"
+                    .to_string(),
+            ),
+            ..plain_text("Your weekly digest", "Body")
+        };
+
+        sender.send("user@example.com", &email).await.unwrap();
+        sender
+            .send("user@example.com", &plain_text("Verify your email", "Body"))
+            .await
+            .unwrap();
+
+        let deliveries = server.deliveries();
+        assert!(deliveries[0].data.contains(
+            "List-Unsubscribe: <https://api.seeker.example/account/notifications/unsubscribe?token=abc>"
+        ));
+        assert!(
+            deliveries[0]
+                .data
+                .contains("List-Unsubscribe-Post: List-Unsubscribe=One-Click")
+        );
+        assert!(!deliveries[1].data.contains("List-Unsubscribe"));
+    }
+
+    #[tokio::test]
+    async fn test_smtp_sender_derives_the_message_id_from_the_idempotency_key() {
+        let (server, port) = FakeSmtpServer::start().await;
+        let sender = SmtpEmailSender::new(&local_config(port)).unwrap();
+        let keyed = |key: &str| RenderedEmail {
+            idempotency_key: Some(key.to_string()),
+            ..plain_text("Verify your email", "Body")
+        };
+
+        for email in [
+            keyed("verify_email:a"),
+            keyed("verify_email:a"),
+            keyed("verify_email:b"),
+        ] {
+            sender.send("user@example.com", &email).await.unwrap();
+        }
+
+        let message_ids: Vec<String> = server
+            .deliveries()
+            .iter()
+            .map(|delivery| {
+                delivery
+                    .data
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Message-ID: "))
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(message_ids[0], message_ids[1]);
+        assert_ne!(message_ids[0], message_ids[2]);
+        assert!(message_ids[0].ends_with("@seeker.test>"));
+    }
+
+    #[tokio::test]
+    async fn test_smtp_sender_reuses_pooled_connections() {
+        let (server, port) = FakeSmtpServer::start().await;
+        let sender = SmtpEmailSender::new(&local_config(port)).unwrap();
+
+        for subject in ["First", "Second", "Third"] {
+            sender
+                .send("user@example.com", &plain_text(subject, "Body"))
+                .await
+                .unwrap();
+            // The pool takes the connection back in a background task.
+            sleep(Duration::from_millis(50)).await;
+        }
+
+        assert_eq!(server.deliveries().len(), 3);
+        assert_eq!(server.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_smtp_sender_reports_rejected_recipients() {
+        let (server, port) = FakeSmtpServer::start().await;
+        let sender = SmtpEmailSender::new(&local_config(port)).unwrap();
+
+        let result = sender
+            .send("ghost@rejected.test", &plain_text("Hello", "Body"))
+            .await;
+
+        assert!(result.is_err());
+        assert!(server.deliveries().is_empty());
+        assert!(
+            sender
+                .send("not an address", &plain_text("Hello", "Body"))
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_smtp_config_defaults_follow_the_tls_mode() {
+        let starttls = config_from(&[
+            ("SMTP_HOST", "smtp.example.com"),
+            ("SMTP_FROM", "no-reply@example.com"),
+        ])
+        .unwrap();
+        let implicit = config_from(&[
+            ("SMTP_HOST", "smtp.example.com"),
+            ("SMTP_FROM", "no-reply@example.com"),
+            ("SMTP_TLS", "tls"),
+            ("SMTP_USERNAME", "worker"),
+            ("SMTP_PASSWORD", "s3cret"),
+        ])
+        .unwrap();
+        let sink = config_from(&[
+            ("SMTP_HOST", "mailpit"),
+            ("SMTP_FROM", "no-reply@example.com"),
+            ("SMTP_TLS", "none"),
+            ("SMTP_PORT", "1025"),
+        ])
+        .unwrap();
+
+        assert_eq!((starttls.tls, starttls.port), (SmtpTls::StartTls, 587));
+        assert_eq!(starttls.credentials, None);
+        assert_eq!(starttls.pool_max_size, 4);
+        assert_eq!((implicit.tls, implicit.port), (SmtpTls::Implicit, 465));
+        assert_eq!(
+            implicit.credentials,
+            Some(("worker".to_string(), "s3cret".to_string()))
+        );
+        assert_eq!((sink.tls, sink.port), (SmtpTls::None, 1025));
+    }
+
+    #[test]
+    fn test_smtp_config_rejects_incomplete_settings() {
+        assert!(config_from(&[("SMTP_FROM", "no-reply@example.com")]).is_err());
+        assert!(config_from(&[("SMTP_HOST", "smtp.example.com")]).is_err());
+        assert!(
+            config_from(&[
+                ("SMTP_HOST", "smtp.example.com"),
+                ("SMTP_FROM", "no-reply@example.com"),
+                ("SMTP_USERNAME", "worker"),
+            ])
+            .is_err()
+        );
+        assert!(
+            config_from(&[
+                ("SMTP_HOST", "smtp.example.com"),
+                ("SMTP_FROM", "no-reply@example.com"),
+                ("SMTP_TLS", "ssl3"),
+            ])
+            .is_err()
+        );
+    }
+}
@@ -1,64 +1,152 @@
+mod dispatcher;
+mod email_sender;
+mod health;
+mod observability;
+mod rate_limit;
+mod retry;
+mod templates;
+mod unsubscribe;
+
 use anyhow::Context;
-use opentelemetry::KeyValue;
-use opentelemetry::trace::TracerProvider;
-use opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge;
-use opentelemetry_otlp::WithExportConfig;
-use opentelemetry_sdk::{Resource, logs::SdkLoggerProvider, trace::SdkTracerProvider};
-use opentelemetry_semantic_conventions::resource::SERVICE_NAME;
-use serde::{Deserialize, Serialize};
-use sqlx::{
-    Pool, Postgres,
-    postgres::{PgListener, PgNotification, PgPoolOptions},
-};
+use dispatcher::DispatcherConfig;
+use email_sender::EmailSender;
+use health::ListenerStatus;
+use observability::{EmailMetrics, init_observability};
+use rate_limit::SendRateLimiter;
+use retry::RetryPolicy;
+use serde::Deserialize;
+use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
 use std::env;
 use std::sync::Arc;
-use tokio::time::{Duration, sleep};
-use tracing::{error, info};
+use std::time::Instant;
+use templates::{EmailTemplates, RenderedEmail};
+use tokio::time::Duration;
+use tracing::{Instrument, debug, error, info, warn};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
-use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+use unsubscribe::{UnsubscribeLink, UnsubscribeLinks};
 use uuid::Uuid;
 
-#[async_trait::async_trait]
-pub trait EmailSender: Send + Sync {
-    async fn send(&self, to: &str, subject: &str, body: &str) -> anyhow::Result<()>;
+#[derive(Debug, sqlx::FromRow)]
+struct EmailJob {
+    id: Uuid,
+    payload: sqlx::types::Json<EmailPayload>,
+    attempt_count: i32,
+    user_id: Option<Uuid>,
+    trace_id: Option<String>,
+    /// When the job came due, before it was claimed.
+    due_at: chrono::DateTime<chrono::Utc>,
+    idempotency_key: Option<String>,
+    /// Set when an earlier claim got as far as handing the email over.
+    sending_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-pub struct StdoutEmailSender;
-
-#[async_trait::async_trait]
-impl EmailSender for StdoutEmailSender {
-    async fn send(&self, to: &str, subject: &str, body: &str) -> anyhow::Result<()> {
-        println!("--------------------------------------------------");
-        println!("📧 SENDING EMAIL");
-        println!("To: {}", to);
-        println!("Subject: {}", subject);
-        println!("Body: {}", body);
-        println!("--------------------------------------------------");
-        sleep(Duration::from_millis(500)).await;
-        Ok(())
-    }
+/// How far a claimed job got.
+#[derive(Debug, PartialEq)]
+enum JobOutcome {
+    /// Sent, skipped, or scheduled for a retry or given up on after failing.
+    Done,
+    /// Put back in the queue until the send rate limits allow it.
+    Deferred(Duration),
 }
 
+/// Longest `last_error` kept on a job.
+const MAX_ERROR_LEN: usize = 1000;
+
+/// Emails are queued as a template with its variables. Rows queued before
+/// templates existed carry the rendered text instead.
 #[derive(Debug, Deserialize)]
-struct NotificationPayload {
-    id: Uuid,
+#[serde(untagged)]
+enum EmailPayload {
+    Templated {
+        to: String,
+        /// Rows queued before categories existed are all security emails.
+        #[serde(default = "security_category")]
+        category: String,
+        template: String,
+        locale: Option<String>,
+        #[serde(default)]
+        variables: serde_json::Map<String, serde_json::Value>,
+    },
+    Rendered {
+        to: String,
+        subject: String,
+        body: String,
+    },
 }
 
-#[derive(Debug, sqlx::FromRow)]
-struct EmailJob {
-    id: Uuid,
-    payload: sqlx::types::Json<EmailPayload>,
-    #[allow(dead_code)]
-    processed: bool,
-    user_id: Option<Uuid>,
-    trace_id: Option<String>,
+impl EmailPayload {
+    fn to(&self) -> &str {
+        match self {
+            EmailPayload::Templated { to, .. } | EmailPayload::Rendered { to, .. } => to,
+        }
+    }
+
+    fn category(&self) -> &str {
+        match self {
+            EmailPayload::Templated { category, .. } => category,
+            EmailPayload::Rendered { .. } => unsubscribe::SECURITY,
+        }
+    }
+
+    fn render(
+        &self,
+        templates: &EmailTemplates,
+        unsubscribe: Option<&UnsubscribeLink>,
+    ) -> anyhow::Result<RenderedEmail> {
+        match self {
+            EmailPayload::Templated {
+                template,
+                locale,
+                variables,
+                ..
+            } => {
+                let page_url = unsubscribe.map(|link| link.page_url.as_str());
+                let email = templates.render(template, locale.as_deref(), variables, page_url)?;
+                Ok(RenderedEmail {
+                    list_unsubscribe: unsubscribe.map(|link| link.one_click_url.clone()),
+                    ..email
+                })
+            }
+            EmailPayload::Rendered { subject, body, .. } => Ok(RenderedEmail {
+                subject: subject.clone(),
+                text: body.clone(),
+                html: None,
+                list_unsubscribe: None,
+                idempotency_key: None,
+            }),
+        }
+    }
+}
+
+fn security_category() -> String {
+    unsubscribe::SECURITY.to_string()
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct EmailPayload {
-    to: String,
-    subject: String,
-    body: String,
+/// What processing a job needs besides the database.
+struct Mailer {
+    sender: Arc<dyn EmailSender>,
+    templates: EmailTemplates,
+    retry_policy: RetryPolicy,
+    /// `None` sends opt-out emails without unsubscribe links.
+    unsubscribe_links: Option<UnsubscribeLinks>,
+    rate_limiter: SendRateLimiter,
+    metrics: EmailMetrics,
+}
+
+impl Mailer {
+    /// Opt-out emails to known users carry a signed unsubscribe link.
+    fn unsubscribe_link(
+        &self,
+        payload: &EmailPayload,
+        user_id: Option<Uuid>,
+    ) -> Option<UnsubscribeLink> {
+        let category = payload.category();
+        if category == unsubscribe::SECURITY {
+            return None;
+        }
+        let links = self.unsubscribe_links.as_ref()?;
+        Some(links.for_recipient(user_id?, category))
+    }
 }
 
 #[tokio::main]
@@ -73,7 +161,7 @@ async fn main() -> anyhow::Result<()> {
         .unwrap_or_else(|_| "http://localhost:4317".to_string());
     let service_name = env::var("SERVICE_NAME").unwrap_or_else(|_| "email-worker".to_string());
 
-    let _observability = if observability_enabled {
+    let observability = if observability_enabled {
         match init_observability(&service_name, &otlp_endpoint) {
             Ok(obs) => Some(obs),
             Err(err) => {
@@ -90,195 +178,245 @@ async fn main() -> anyhow::Result<()> {
 
     info!("Starting Email Worker...");
 
+    let dispatcher_config = DispatcherConfig::from_env()?;
+    // One connection per send, plus the listener and the claiming queries.
     let pool = PgPoolOptions::new()
-        .max_connections(5)
+        .max_connections(dispatcher_config.concurrency as u32 + 2)
         .connect(&database_url)
         .await
         .context("Failed to connect to Postgres")?;
 
     info!("Connected to database.");
 
-    let email_sender = Arc::new(StdoutEmailSender);
-
-    if let Err(e) = process_pending_jobs(&pool, email_sender.clone()).await {
-        error!("Error processing pending jobs: {:?}", e);
-    }
-
-    listen_for_jobs(pool, email_sender).await
-}
-
-struct Observability {
-    _logger_provider: SdkLoggerProvider,
-    _tracer_provider: SdkTracerProvider,
-}
-
-fn init_observability(service_name: &str, otlp_endpoint: &str) -> anyhow::Result<Observability> {
-    let resource = Resource::builder()
-        .with_attributes(vec![KeyValue::new(SERVICE_NAME, service_name.to_string())])
-        .build();
-
-    let trace_exporter = opentelemetry_otlp::SpanExporter::builder()
-        .with_tonic()
-        .with_endpoint(otlp_endpoint)
-        .build()
-        .context("OTLP trace exporter error")?;
-
-    let tracer_provider = SdkTracerProvider::builder()
-        .with_resource(resource.clone())
-        .with_batch_exporter(trace_exporter)
-        .build();
-
-    let static_service_name: &'static str = Box::leak(service_name.to_string().into_boxed_str());
-    let tracer = tracer_provider.tracer(static_service_name);
-
-    let log_exporter = opentelemetry_otlp::LogExporter::builder()
-        .with_tonic()
-        .with_endpoint(otlp_endpoint)
-        .build()
-        .context("OTLP log exporter error")?;
-
-    let logger_provider = SdkLoggerProvider::builder()
-        .with_resource(resource)
-        .with_batch_exporter(log_exporter)
-        .build();
-
-    let otel_logs_layer = OpenTelemetryTracingBridge::new(&logger_provider);
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-    let fmt_layer = tracing_subscriber::fmt::layer()
-        .json()
-        .with_current_span(true)
-        .with_span_list(true);
-
-    let otel_trace_layer = tracing_opentelemetry::layer().with_tracer(tracer);
-
-    tracing_subscriber::registry()
-        .with(filter)
-        .with(fmt_layer)
-        .with(otel_trace_layer)
-        .with(otel_logs_layer)
-        .init();
-
-    Ok(Observability {
-        _logger_provider: logger_provider,
-        _tracer_provider: tracer_provider,
-    })
-}
-
-async fn process_pending_jobs(
-    pool: &Pool<Postgres>,
-    sender: Arc<dyn EmailSender>,
-) -> anyhow::Result<()> {
-    info!("Checking for pending jobs...");
-
-    let job_ids: Vec<Uuid> =
-        sqlx::query_scalar("SELECT id FROM email_queue WHERE processed = false")
-            .fetch_all(pool)
-            .await?;
-
-    if job_ids.is_empty() {
-        info!("No pending jobs found.");
-    } else {
-        info!("Found {} potentially pending jobs.", job_ids.len());
-        for id in job_ids {
-            if let Err(e) = process_job_transactional(pool, id, sender.clone()).await {
-                error!("Failed to process pending job {}: {:?}", id, e);
-            }
+    let mailer = Arc::new(Mailer {
+        sender: email_sender::from_env()?,
+        templates: EmailTemplates::embedded()?,
+        retry_policy: RetryPolicy::from_env()?,
+        unsubscribe_links: unsubscribe_links_from_env(),
+        rate_limiter: SendRateLimiter::from_env()?,
+        metrics: observability
+            .as_ref()
+            .map(|obs| obs.metrics.clone())
+            .unwrap_or_else(EmailMetrics::noop),
+    });
+
+    let listener_status = ListenerStatus::default();
+    let health_addr = env::var("HEALTH_ADDR").unwrap_or_else(|_| "0.0.0.0:8080".to_string());
+    let health_router = health::router(pool.clone(), listener_status.clone());
+    let health_server = tokio::spawn(async move {
+        if let Err(e) = health::serve(&health_addr, health_router).await {
+            error!("Health check server failed: {:?}", e);
         }
+    });
+
+    let result = dispatcher::run(
+        pool,
+        mailer,
+        dispatcher_config,
+        listener_status,
+        shutdown_signal(),
+    )
+    .await;
+    health_server.abort();
+    if let Some(ref observability) = observability {
+        observability.shutdown();
     }
-
+    result?;
+    info!("Email worker stopped.");
     Ok(())
 }
 
-async fn listen_for_jobs(pool: Pool<Postgres>, sender: Arc<dyn EmailSender>) -> anyhow::Result<()> {
-    let mut listener = PgListener::connect_with(&pool).await?;
-    listener.listen("email_queue").await?;
-
-    info!("Listening for notifications on 'email_queue'...");
+/// Resolves on Ctrl+C or SIGTERM, which is how containers are stopped.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("Failed to listen for Ctrl+C: {:?}", e);
+            std::future::pending::<()>().await;
+        }
+    };
 
-    loop {
-        let notification = match listener.recv().await {
-            Ok(n) => n,
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
             Err(e) => {
-                error!("Error receiving notification: {:?}", e);
-                sleep(Duration::from_secs(5)).await;
-                continue;
+                error!("Failed to listen for SIGTERM: {:?}", e);
+                std::future::pending::<()>().await;
             }
-        };
-
-        if let Err(e) = handle_notification(&pool, notification, sender.clone()).await {
-            error!("Error handling notification: {:?}", e);
         }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
     }
+    info!("Shutdown signal received.");
 }
 
-async fn handle_notification(
-    pool: &Pool<Postgres>,
-    notification: PgNotification,
-    sender: Arc<dyn EmailSender>,
-) -> anyhow::Result<()> {
-    let payload_str = notification.payload();
-    let payload = serde_json::from_str::<NotificationPayload>(payload_str).context(format!(
-        "Failed to parse notification payload: '{}'",
-        payload_str
-    ))?;
-
-    info!("Received notification for job: {}", payload.id);
-
-    process_job_transactional(pool, payload.id, sender).await?;
-
-    Ok(())
+fn unsubscribe_links_from_env() -> Option<UnsubscribeLinks> {
+    let links = UnsubscribeLinks::from_lookup(|key| env::var(key).ok());
+    if links.is_none() {
+        warn!("UNSUBSCRIBE_SECRET is not set, emails go out without unsubscribe links");
+    }
+    links
 }
 
-async fn process_job_transactional(
+/// Sends a claimed job. A failed send is scheduled for a retry, or marks
+/// the job dead once the retry policy gives up. Emails that cannot be
+/// rendered are marked dead right away, as retrying would not help.
+///
+/// The send is recorded right before the email is handed over, and cleared
+/// whenever the send is known not to have gone out. A job claimed again with
+/// that record, after a crash or a lost update, may have been delivered, so
+/// it is marked dead rather than sent twice. The claim is extended while the
+/// send runs, so no other worker picks the job up meanwhile.
+async fn process_job(
     pool: &Pool<Postgres>,
-    job_id: Uuid,
-    sender: Arc<dyn EmailSender>,
-) -> anyhow::Result<()> {
-    let mut tx = pool.begin().await?;
+    job: EmailJob,
+    mailer: &Mailer,
+    claim_timeout: Duration,
+) -> anyhow::Result<JobOutcome> {
+    let trace_id = job.trace_id.as_deref().unwrap_or("-");
+    let user_id = job
+        .user_id
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| "-".to_string());
+
+    let span = tracing::info_span!(
+        "email_job",
+        job_id = %job.id,
+        user_id = %user_id,
+        trace_id = %trace_id
+    );
+
+    if let Some(parent_context) = extract_parent_context(trace_id) {
+        span.set_parent(parent_context);
+    }
 
-    let job_opt = sqlx::query_as::<_, EmailJob>(
-        "SELECT id, payload, processed, user_id, trace_id FROM email_queue WHERE id = $1 AND processed = false FOR UPDATE SKIP LOCKED"
-    )
-    .bind(job_id)
-    .fetch_optional(&mut *tx)
-    .await?;
+    async {
+        info!(job_id = %job.id, user_id = %user_id, trace_id = %trace_id, attempt = job.attempt_count + 1, "Processing job");
+        let payload = &job.payload.0;
+        if let Some(sending_at) = job.sending_at {
+            let last_error = format!(
+                "Interrupted while sending at {}, the email may have been delivered",
+                sending_at.to_rfc3339()
+            );
+            sqlx::query(
+                "UPDATE email_queue SET last_error = $2, dead = true, dead_at = NOW() WHERE id = $1",
+            )
+            .bind(job.id)
+            .bind(&last_error)
+            .execute(pool)
+            .await?;
+            mailer.metrics.dead_lettered(payload.category());
+            error!(
+                job_id = %job.id,
+                user_id = %user_id,
+                trace_id = %trace_id,
+                error = %last_error,
+                "Email may have been sent already, giving up"
+            );
+            return Ok(JobOutcome::Done);
+        }
 
-    if let Some(job) = job_opt {
-        let trace_id = job.trace_id.as_deref().unwrap_or("-");
-        let user_id = job
-            .user_id
-            .map(|id| id.to_string())
-            .unwrap_or_else(|| "-".to_string());
-
-        let span = tracing::info_span!(
-            "email_job",
-            job_id = %job.id,
-            user_id = %user_id,
-            trace_id = %trace_id
-        );
+        if let Some(reason) = skip_reason(pool, payload, job.user_id).await? {
+            sqlx::query(
+                "UPDATE email_queue SET processed = true, processed_at = NOW(), skipped_reason = $2 WHERE id = $1",
+            )
+            .bind(job.id)
+            .bind(reason)
+            .execute(pool)
+            .await?;
+            info!(
+                job_id = %job.id,
+                user_id = %user_id,
+                trace_id = %trace_id,
+                category = payload.category(),
+                reason,
+                "Email skipped"
+            );
+            return Ok(JobOutcome::Done);
+        }
+
+        let unsubscribe = mailer.unsubscribe_link(payload, job.user_id);
+        let email = match payload.render(&mailer.templates, unsubscribe.as_ref()) {
+            Ok(email) => email,
+            Err(e) => {
+                let last_error = error_summary(&e);
+                sqlx::query(
+                    "UPDATE email_queue SET last_error = $2, dead = true, dead_at = NOW() WHERE id = $1",
+                )
+                .bind(job.id)
+                .bind(&last_error)
+                .execute(pool)
+                .await?;
+                mailer.metrics.dead_lettered(payload.category());
+                error!(
+                    job_id = %job.id,
+                    user_id = %user_id,
+                    trace_id = %trace_id,
+                    error = %last_error,
+                    "Failed to render email, giving up"
+                );
+                return Ok(JobOutcome::Done);
+            }
+        };
 
-        if let Some(parent_context) = extract_parent_context(trace_id) {
-            span.set_parent(parent_context);
+        let email = RenderedEmail {
+            idempotency_key: job.idempotency_key.clone(),
+            ..email
+        };
+
+        if let Err(wait) = mailer.rate_limiter.acquire(job.id, payload.to(), Instant::now()) {
+            sqlx::query(
+                "UPDATE email_queue SET next_attempt_at = NOW() + make_interval(secs => $2) WHERE id = $1",
+            )
+            .bind(job.id)
+            .bind(wait.as_secs_f64())
+            .execute(pool)
+            .await?;
+            mailer.metrics.throttled(payload.category());
+            debug!(
+                job_id = %job.id,
+                user_id = %user_id,
+                trace_id = %trace_id,
+                wait_ms = wait.as_millis() as u64,
+                "Send rate limit reached, email deferred"
+            );
+            return Ok(JobOutcome::Deferred(wait));
         }
 
-        let _enter = span.enter();
+        sqlx::query("UPDATE email_queue SET sending_at = NOW() WHERE id = $1")
+            .bind(job.id)
+            .execute(pool)
+            .await?;
 
-        info!(job_id = %job.id, user_id = %user_id, trace_id = %trace_id, "Processing job");
-        let payload = job.payload.0;
+        let send = mailer.sender.send(payload.to(), &email);
+        tokio::pin!(send);
+        let sent = loop {
+            tokio::select! {
+                sent = &mut send => break sent,
+                _ = tokio::time::sleep(claim_timeout / 2) => {
+                    if let Err(e) = dispatcher::extend_claim(pool, job.id, claim_timeout).await {
+                        warn!(job_id = %job.id, error = %e, "Failed to extend the claim of a send in progress");
+                    }
+                }
+            }
+        };
 
-        match sender
-            .send(&payload.to, &payload.subject, &payload.body)
-            .await
-        {
+        match sent {
             Ok(_) => {
                 sqlx::query(
-                    "UPDATE email_queue SET processed = true, processed_at = NOW() WHERE id = $1",
+                    "UPDATE email_queue SET processed = true, processed_at = NOW(), last_error = NULL WHERE id = $1",
                 )
                 .bind(job.id)
-                .execute(&mut *tx)
+                .execute(pool)
                 .await?;
-
-                tx.commit().await?;
+                mailer.metrics.sent(payload.category());
                 info!(
                     job_id = %job.id,
                     user_id = %user_id,
@@ -287,15 +425,128 @@ async fn process_job_transactional(
                 );
             }
             Err(e) => {
-                error!("Failed to send email: {:?}", e);
-                return Err(e);
+                if let Err(db_error) = record_send_failure(pool, &job, mailer, &e).await {
+                    // The email did not go out, so it must not be left looking
+                    // like it might have.
+                    if let Err(release_error) = dispatcher::release_jobs(pool, &[job.id]).await {
+                        error!(job_id = %job.id, error = %release_error, "Failed to release a job whose send failed");
+                    }
+                    return Err(db_error);
+                }
             }
         }
+
+        Ok(JobOutcome::Done)
     }
+    .instrument(span)
+    .await
+}
 
+/// Schedules a retry of a failed send, or marks the job dead once the retry
+/// policy gives up.
+async fn record_send_failure(
+    pool: &Pool<Postgres>,
+    job: &EmailJob,
+    mailer: &Mailer,
+    error: &anyhow::Error,
+) -> anyhow::Result<()> {
+    let payload = &job.payload.0;
+    let trace_id = job.trace_id.as_deref().unwrap_or("-");
+    let user_id = job
+        .user_id
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let attempt_count = job.attempt_count + 1;
+    let last_error = error_summary(error);
+    match mailer.retry_policy.next_delay(attempt_count) {
+        Some(delay) => {
+            sqlx::query(
+                "UPDATE email_queue SET attempt_count = $2, last_error = $3, next_attempt_at = NOW() + make_interval(secs => $4), sending_at = NULL WHERE id = $1",
+            )
+            .bind(job.id)
+            .bind(attempt_count)
+            .bind(&last_error)
+            .bind(delay.as_secs_f64())
+            .execute(pool)
+            .await?;
+            mailer.metrics.failed(payload.category());
+            mailer.metrics.retried(payload.category());
+            warn!(
+                job_id = %job.id,
+                user_id = %user_id,
+                trace_id = %trace_id,
+                attempt_count,
+                retry_in_secs = delay.as_secs(),
+                error = %last_error,
+                "Failed to send email, retrying later"
+            );
+        }
+        None => {
+            sqlx::query(
+                "UPDATE email_queue SET attempt_count = $2, last_error = $3, dead = true, dead_at = NOW(), sending_at = NULL WHERE id = $1",
+            )
+            .bind(job.id)
+            .bind(attempt_count)
+            .bind(&last_error)
+            .execute(pool)
+            .await?;
+            mailer.metrics.failed(payload.category());
+            mailer.metrics.dead_lettered(payload.category());
+            error!(
+                job_id = %job.id,
+                user_id = %user_id,
+                trace_id = %trace_id,
+                attempt_count,
+                error = %last_error,
+                "Failed to send email, giving up"
+            );
+        }
+    }
     Ok(())
 }
 
+/// Why an opt-out email must not be sent: its recipient is on the
+/// suppression list, or turned its category off. Security emails always go.
+async fn skip_reason(
+    pool: &Pool<Postgres>,
+    payload: &EmailPayload,
+    user_id: Option<Uuid>,
+) -> anyhow::Result<Option<&'static str>> {
+    let category = payload.category();
+    if category == unsubscribe::SECURITY {
+        return Ok(None);
+    }
+
+    let suppressed: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM email_suppressions WHERE email = LOWER(TRIM($1)))",
+    )
+    .bind(payload.to())
+    .fetch_one(pool)
+    .await?;
+    if suppressed {
+        return Ok(Some("suppressed"));
+    }
+
+    let Some(user_id) = user_id else {
+        return Ok(None);
+    };
+    let enabled: Option<bool> = sqlx::query_scalar(
+        "SELECT enabled FROM notification_preferences WHERE user_id = $1 AND category = $2",
+    )
+    .bind(user_id)
+    .bind(category)
+    .fetch_optional(pool)
+    .await?;
+    if !enabled.unwrap_or_else(|| unsubscribe::enabled_by_default(category)) {
+        return Ok(Some("unsubscribed"));
+    }
+    Ok(None)
+}
+
+fn error_summary(error: &anyhow::Error) -> String {
+    format!("{error:#}").chars().take(MAX_ERROR_LEN).collect()
+}
+
 fn extract_parent_context(traceparent: &str) -> Option<opentelemetry::Context> {
     let mut carrier = std::collections::HashMap::new();
     carrier.insert("traceparent".to_string(), traceparent.to_string());
@@ -303,3 +554,291 @@ fn extract_parent_context(traceparent: &str) -> Option<opentelemetry::Context> {
     let propagator = opentelemetry::global::get_text_map_propagator(|p| p.extract(&carrier));
     Some(propagator)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(payload: serde_json::Value) -> RenderedEmail {
+        let payload: EmailPayload = serde_json::from_value(payload).unwrap();
+        assert_eq!(payload.to(), "user@example.com");
+        payload
+            .render(&EmailTemplates::embedded().unwrap(), None)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_templated_payloads_render_both_parts() {
+        let email = render(serde_json::json!({
+            "to": "user@example.com",
+            "locale": "en",
+            "template": "reset_password",
+            "variables": { "link": "https://seeker.example/auth/reset-password?token=abc" },
+        }));
+
+        assert_eq!(email.subject, "Reset your password");
+        assert!(email.text.contains("?token=abc"));
+        assert!(email.html.is_some());
+    }
+
+    #[test]
+    fn test_templates_without_variables_need_none() {
+        let email = render(serde_json::json!({
+            "to": "user@example.com",
+            "template": "account_deleted",
+        }));
+
+        assert_eq!(email.subject, "Your account was deleted");
+    }
+
+    #[test]
+    fn test_payloads_queued_before_templates_are_sent_as_plain_text() {
+        let email = render(serde_json::json!({
+            "to": "user@example.com",
+            "subject": "Verify your email",
+            "body": "Hello,\n\nClick the link.",
+        }));
+
+        assert_eq!(
+            email,
+            RenderedEmail {
+                subject: "Verify your email".to_string(),
+                text: "Hello,\n\nClick the link.".to_string(),
+                html: None,
+                list_unsubscribe: None,
+                idempotency_key: None,
+            }
+        );
+    }
+
+    const CLAIM_TIMEOUT: Duration = Duration::from_secs(300);
+
+    pub(crate) fn mailer(sender: Arc<dyn EmailSender>) -> Mailer {
+        Mailer {
+            sender,
+            templates: EmailTemplates::embedded().unwrap(),
+            retry_policy: RetryPolicy {
+                max_attempts: 8,
+                base_delay: Duration::from_secs(30),
+                max_delay: Duration::from_secs(3600),
+            },
+            rate_limiter: SendRateLimiter::new(
+                rate_limit::SendRate {
+                    per_second: 0.0,
+                    burst: 0,
+                },
+                rate_limit::SendRate {
+                    per_second: 0.0,
+                    burst: 0,
+                },
+                Instant::now(),
+            ),
+            metrics: EmailMetrics::noop(),
+            unsubscribe_links: UnsubscribeLinks::from_lookup(|key| match key {
+                "UNSUBSCRIBE_SECRET" => Some("test-unsubscribe-secret".to_string()),
+                "API_URL" => Some("https://api.seeker.example".to_string()),
+                "FRONTEND_URL" => Some("https://seeker.example".to_string()),
+                _ => None,
+            }),
+        }
+    }
+
+    #[derive(Default)]
+    pub(crate) struct CountingSender(pub(crate) std::sync::atomic::AtomicUsize);
+
+    #[async_trait::async_trait]
+    impl EmailSender for CountingSender {
+        async fn send(&self, _to: &str, _email: &RenderedEmail) -> anyhow::Result<()> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /// Fails the first send, and takes `delay` over every send.
+    #[derive(Default)]
+    struct FlakySender {
+        sends: std::sync::atomic::AtomicUsize,
+        delay: Duration,
+    }
+
+    #[async_trait::async_trait]
+    impl EmailSender for FlakySender {
+        async fn send(&self, _to: &str, _email: &RenderedEmail) -> anyhow::Result<()> {
+            tokio::time::sleep(self.delay).await;
+            match self.sends.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+                0 => anyhow::bail!("connection refused"),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    pub(crate) async fn queue_email(pool: &Pool<Postgres>) -> Uuid {
+        sqlx::query_scalar(
+            "INSERT INTO email_queue (payload, idempotency_key) VALUES ($1, 'account_deleted:1') RETURNING id",
+        )
+        .bind(serde_json::json!({
+            "to": "user@example.com",
+            "template": "account_deleted",
+        }))
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn test_only_opt_out_emails_to_users_get_unsubscribe_links() {
+        let mailer = mailer(Arc::new(email_sender::StdoutEmailSender));
+        let payload = |category: Option<&str>| -> EmailPayload {
+            let mut payload = serde_json::json!({
+                "to": "user@example.com",
+                "template": "account_deleted",
+            });
+            if let Some(category) = category {
+                payload["category"] = category.into();
+            }
+            serde_json::from_value(payload).unwrap()
+        };
+        let user_id = Some(Uuid::new_v4());
+
+        let marketing = payload(Some("marketing"));
+        let link = mailer.unsubscribe_link(&marketing, user_id).unwrap();
+        let email = marketing.render(&mailer.templates, Some(&link)).unwrap();
+
+        assert_eq!(email.list_unsubscribe, Some(link.one_click_url.clone()));
+        assert!(email.text.contains(&link.page_url));
+        let query = link.page_url.split_once('?').unwrap().1;
+        assert!(email.html.unwrap().contains(query));
+        assert_eq!(mailer.unsubscribe_link(&marketing, None), None);
+        assert_eq!(
+            mailer.unsubscribe_link(&payload(Some("security")), user_id),
+            None
+        );
+        assert_eq!(mailer.unsubscribe_link(&payload(None), user_id), None);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_an_email_whose_send_was_not_recorded_is_not_sent_again(pool: Pool<Postgres>) {
+        let sender = Arc::new(CountingSender::default());
+        let mailer = mailer(sender.clone());
+        queue_email(&pool).await;
+        // The update marking the email sent fails once the email is out.
+        sqlx::query(
+            "CREATE FUNCTION lose_update() RETURNS trigger AS $$ BEGIN RAISE EXCEPTION 'connection lost'; END $$ LANGUAGE plpgsql",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "CREATE TRIGGER lose_update BEFORE UPDATE ON email_queue FOR EACH ROW WHEN (NEW.processed) EXECUTE FUNCTION lose_update()",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let claim = || dispatcher::claim_due_jobs(&pool, 1, Duration::ZERO);
+        let job = claim().await.unwrap().pop().unwrap();
+        assert!(
+            process_job(&pool, job, &mailer, CLAIM_TIMEOUT)
+                .await
+                .is_err()
+        );
+        sqlx::query("DROP TRIGGER lose_update ON email_queue")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let job = claim().await.unwrap().pop().unwrap();
+        assert_eq!(
+            process_job(&pool, job, &mailer, CLAIM_TIMEOUT)
+                .await
+                .unwrap(),
+            JobOutcome::Done
+        );
+
+        assert_eq!(sender.0.load(std::sync::atomic::Ordering::SeqCst), 1);
+        let (processed, dead): (Option<bool>, bool) =
+            sqlx::query_as("SELECT processed, dead FROM email_queue")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((processed, dead), (Some(false), true));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_a_failed_send_whose_retry_was_not_recorded_is_sent_again(pool: Pool<Postgres>) {
+        let sender = Arc::new(FlakySender::default());
+        let mailer = mailer(sender.clone());
+        let job_id = queue_email(&pool).await;
+        // The update scheduling the retry fails once the send failed.
+        sqlx::query(
+            "CREATE FUNCTION lose_update() RETURNS trigger AS $$ BEGIN RAISE EXCEPTION 'connection lost'; END $$ LANGUAGE plpgsql",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "CREATE TRIGGER lose_update BEFORE UPDATE ON email_queue FOR EACH ROW WHEN (NEW.attempt_count > OLD.attempt_count) EXECUTE FUNCTION lose_update()",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let claim = || dispatcher::claim_due_jobs(&pool, 1, CLAIM_TIMEOUT);
+        let job = claim().await.unwrap().pop().unwrap();
+        assert!(
+            process_job(&pool, job, &mailer, CLAIM_TIMEOUT)
+                .await
+                .is_err()
+        );
+        sqlx::query("DROP TRIGGER lose_update ON email_queue")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let job = claim().await.unwrap().pop().unwrap();
+        assert_eq!(job.id, job_id);
+        assert_eq!(job.sending_at, None);
+        assert_eq!(
+            process_job(&pool, job, &mailer, CLAIM_TIMEOUT)
+                .await
+                .unwrap(),
+            JobOutcome::Done
+        );
+
+        assert_eq!(sender.sends.load(std::sync::atomic::Ordering::SeqCst), 2);
+        let (processed, dead): (Option<bool>, bool) =
+            sqlx::query_as("SELECT processed, dead FROM email_queue")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((processed, dead), (Some(true), false));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_a_send_in_progress_keeps_its_claim(pool: Pool<Postgres>) {
+        let claim_timeout = Duration::from_millis(200);
+        let mailer = mailer(Arc::new(FlakySender {
+            sends: std::sync::atomic::AtomicUsize::new(1),
+            delay: Duration::from_millis(700),
+        }));
+        queue_email(&pool).await;
+
+        let job = dispatcher::claim_due_jobs(&pool, 1, claim_timeout)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let send = tokio::spawn({
+            let pool = pool.clone();
+            async move { process_job(&pool, job, &mailer, claim_timeout).await }
+        });
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        assert!(
+            dispatcher::claim_due_jobs(&pool, 1, claim_timeout)
+                .await
+                .unwrap()
+                .is_empty(),
+            "Another worker must not claim a job still being sent"
+        );
+        assert_eq!(send.await.unwrap().unwrap(), JobOutcome::Done);
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::Context;
+use opentelemetry::{
+    KeyValue, global,
+    metrics::{Counter, Histogram, Meter, MeterProvider},
+    trace::TracerProvider,
+};
+use opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    Resource, logs::SdkLoggerProvider, metrics::SdkMeterProvider, trace::SdkTracerProvider,
+};
+use opentelemetry_semantic_conventions::resource::SERVICE_NAME;
+use tokio::time::Duration;
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+
+pub struct Observability {
+    pub metrics: EmailMetrics,
+    tracer_provider: SdkTracerProvider,
+    meter_provider: SdkMeterProvider,
+    logger_provider: SdkLoggerProvider,
+}
+
+impl Observability {
+    pub fn shutdown(&self) {
+        let _ = self.tracer_provider.shutdown();
+        let _ = self.meter_provider.shutdown();
+        let _ = self.logger_provider.shutdown();
+    }
+}
+
+/// What happens to emails, tagged with their category.
+#[derive(Clone)]
+pub struct EmailMetrics {
+    sent: Counter<u64>,
+    failed: Counter<u64>,
+    retried: Counter<u64>,
+    dead_lettered: Counter<u64>,
+    throttled: Counter<u64>,
+    queue_lag_ms: Histogram<f64>,
+}
+
+impl EmailMetrics {
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            sent: meter
+                .u64_counter("emails_sent_total")
+                .with_description("Emails handed over to the sender")
+                .build(),
+            failed: meter
+                .u64_counter("emails_failed_total")
+                .with_description("Failed sends, whether retried or not")
+                .build(),
+            retried: meter
+                .u64_counter("emails_retried_total")
+                .with_description("Failed sends scheduled for another attempt")
+                .build(),
+            dead_lettered: meter
+                .u64_counter("emails_dead_lettered_total")
+                .with_description("Emails given up on and marked dead")
+                .build(),
+            throttled: meter
+                .u64_counter("emails_throttled_total")
+                .with_description("Sends put off by the send rate limits")
+                .build(),
+            queue_lag_ms: meter
+                .f64_histogram("email_queue_lag_ms")
+                .with_description("Time due emails waited before a worker claimed them")
+                .build(),
+        }
+    }
+
+    /// Metrics going nowhere, for when observability is disabled.
+    pub fn noop() -> Self {
+        Self::new(&global::meter("email-worker"))
+    }
+
+    pub fn sent(&self, category: &str) {
+        self.sent.add(1, &attributes(category));
+    }
+
+    pub fn failed(&self, category: &str) {
+        self.failed.add(1, &attributes(category));
+    }
+
+    pub fn retried(&self, category: &str) {
+        self.retried.add(1, &attributes(category));
+    }
+
+    pub fn dead_lettered(&self, category: &str) {
+        self.dead_lettered.add(1, &attributes(category));
+    }
+
+    pub fn throttled(&self, category: &str) {
+        self.throttled.add(1, &attributes(category));
+    }
+
+    pub fn queue_lag(&self, lag: Duration) {
+        self.queue_lag_ms.record(lag.as_secs_f64() * 1000.0, &[]);
+    }
+}
+
+fn attributes(category: &str) -> [KeyValue; 1] {
+    [KeyValue::new("category", category.to_string())]
+}
+
+pub fn init_observability(
+    service_name: &str,
+    otlp_endpoint: &str,
+) -> anyhow::Result<Observability> {
+    let resource = Resource::builder()
+        .with_attributes(vec![KeyValue::new(SERVICE_NAME, service_name.to_string())])
+        .build();
+
+    let trace_exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(otlp_endpoint)
+        .build()
+        .context("OTLP trace exporter error")?;
+
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_resource(resource.clone())
+        .with_batch_exporter(trace_exporter)
+        .build();
+
+    let static_service_name: &'static str = Box::leak(service_name.to_string().into_boxed_str());
+    let tracer = tracer_provider.tracer(static_service_name);
+
+    let metric_exporter = opentelemetry_otlp::MetricExporter::builder()
+        .with_tonic()
+        .with_endpoint(otlp_endpoint)
+        .build()
+        .context("OTLP metric exporter error")?;
+
+    let meter_provider = SdkMeterProvider::builder()
+        .with_resource(resource.clone())
+        .with_periodic_exporter(metric_exporter)
+        .build();
+
+    let meter = meter_provider.meter(static_service_name);
+    global::set_meter_provider(meter_provider.clone());
+    let metrics = EmailMetrics::new(&meter);
+
+    let log_exporter = opentelemetry_otlp::LogExporter::builder()
+        .with_tonic()
+        .with_endpoint(otlp_endpoint)
+        .build()
+        .context("OTLP log exporter error")?;
+
+    let logger_provider = SdkLoggerProvider::builder()
+        .with_resource(resource)
+        .with_batch_exporter(log_exporter)
+        .build();
+
+    let otel_logs_layer = OpenTelemetryTracingBridge::new(&logger_provider);
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .json()
+        .with_current_span(true)
+        .with_span_list(true);
+
+    let otel_trace_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt_layer)
+        .with(otel_trace_layer)
+        .with(otel_logs_layer)
+        .init();
+
+    Ok(Observability {
+        metrics,
+        tracer_provider,
+        meter_provider,
+        logger_provider,
+    })
+}
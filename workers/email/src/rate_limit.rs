@@ -0,0 +1,265 @@
+use crate::retry::env_number;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+const STALE_AFTER: Duration = Duration::from_secs(10 * 60);
+
+/// How fast emails may go out: `per_second` on average, with bursts of up
+/// to `burst`. A rate of zero lifts the limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SendRate {
+    pub per_second: f64,
+    pub burst: u32,
+}
+
+/// Limits sends from this worker overall and per recipient domain, so a
+/// burst of emails to one provider does not get us blocked. Each replica
+/// applies the limits on its own.
+///
+/// A send over the limits reserves the next free slot, so a burst is paced
+/// out at once instead of every email waiting for the same next token.
+pub struct SendRateLimiter {
+    global_rate: SendRate,
+    domain_rate: SendRate,
+    state: Mutex<LimiterState>,
+}
+
+struct LimiterState {
+    global: TokenBucket,
+    domains: HashMap<String, TokenBucket>,
+    /// When jobs that had to wait may be sent.
+    reservations: HashMap<Uuid, Instant>,
+    last_cleanup: Instant,
+}
+
+impl SendRateLimiter {
+    pub fn new(global_rate: SendRate, domain_rate: SendRate, now: Instant) -> Self {
+        Self {
+            global_rate,
+            domain_rate,
+            state: Mutex::new(LimiterState {
+                global: TokenBucket::new(global_rate.burst, now),
+                domains: HashMap::new(),
+                reservations: HashMap::new(),
+                last_cleanup: now,
+            }),
+        }
+    }
+
+    pub fn from_env() -> anyhow::Result<Self> {
+        let global_rate = SendRate {
+            per_second: env_number("EMAIL_RATE_PER_SEC", 10.0)?,
+            burst: env_number("EMAIL_RATE_BURST", 20)?,
+        };
+        let domain_rate = SendRate {
+            per_second: env_number("EMAIL_DOMAIN_RATE_PER_SEC", 2.0)?,
+            burst: env_number("EMAIL_DOMAIN_RATE_BURST", 10)?,
+        };
+        Ok(Self::new(global_rate, domain_rate, Instant::now()))
+    }
+
+    /// Takes one send of `job_id` from both the global and the recipient
+    /// domain's budget. When either is spent, the job gets the next free
+    /// slot and is told how long to wait for it.
+    pub fn acquire(&self, job_id: Uuid, to: &str, now: Instant) -> Result<(), Duration> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if now.saturating_duration_since(state.last_cleanup) >= CLEANUP_INTERVAL {
+            state.cleanup(now);
+        }
+
+        if let Some(&slot) = state.reservations.get(&job_id) {
+            if now < slot {
+                return Err(slot - now);
+            }
+            state.reservations.remove(&job_id);
+            return Ok(());
+        }
+
+        let LimiterState {
+            global,
+            domains,
+            reservations,
+            ..
+        } = &mut *state;
+        let domain = domains
+            .entry(recipient_domain(to))
+            .or_insert_with(|| TokenBucket::new(self.domain_rate.burst, now));
+        global.refill(now, self.global_rate);
+        domain.refill(now, self.domain_rate);
+
+        let wait = global
+            .wait(self.global_rate)
+            .max(domain.wait(self.domain_rate));
+        global.take(self.global_rate);
+        domain.take(self.domain_rate);
+        match wait {
+            Some(wait) => {
+                reservations.insert(job_id, now + wait);
+                Err(wait)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl LimiterState {
+    fn cleanup(&mut self, now: Instant) {
+        self.domains
+            .retain(|_, bucket| now.saturating_duration_since(bucket.last_seen) <= STALE_AFTER);
+        // Jobs another worker ended up sending never come back for theirs.
+        self.reservations
+            .retain(|_, slot| now.saturating_duration_since(*slot) <= STALE_AFTER);
+        self.last_cleanup = now;
+    }
+}
+
+fn recipient_domain(to: &str) -> String {
+    to.rsplit_once('@')
+        .map(|(_, domain)| domain.trim().to_lowercase())
+        .unwrap_or_default()
+}
+
+/// Refills at `per_second` up to `burst` tokens. Reserved sends take their
+/// token up front, so the balance can drop below zero and later sends wait
+/// for it to recover.
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+    last_seen: Instant,
+}
+
+impl TokenBucket {
+    fn new(burst: u32, now: Instant) -> Self {
+        Self {
+            tokens: burst as f64,
+            last_refill: now,
+            last_seen: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant, rate: SendRate) {
+        self.last_seen = now;
+        if now <= self.last_refill {
+            return;
+        }
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        let added = elapsed * rate.per_second;
+        self.tokens = (self.tokens + added).min(rate.burst as f64);
+        self.last_refill = now;
+    }
+
+    /// How long until a token is available, `None` when one is.
+    fn wait(&self, rate: SendRate) -> Option<Duration> {
+        if rate.per_second <= 0.0 || self.tokens >= 1.0 {
+            return None;
+        }
+        let needed = 1.0 - self.tokens;
+        Some(Duration::from_secs_f64(needed / rate.per_second))
+    }
+
+    fn take(&mut self, rate: SendRate) {
+        if rate.per_second > 0.0 {
+            self.tokens -= 1.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(
+        global: f64,
+        global_burst: u32,
+        domain: f64,
+        domain_burst: u32,
+        now: Instant,
+    ) -> SendRateLimiter {
+        SendRateLimiter::new(
+            SendRate {
+                per_second: global,
+                burst: global_burst,
+            },
+            SendRate {
+                per_second: domain,
+                burst: domain_burst,
+            },
+            now,
+        )
+    }
+
+    fn job(n: u128) -> Uuid {
+        Uuid::from_u128(n)
+    }
+
+    #[test]
+    fn test_each_domain_has_its_own_budget() {
+        let now = Instant::now();
+        let limiter = limiter(100.0, 100, 1.0, 2, now);
+
+        assert_eq!(limiter.acquire(job(1), "a@gmail.com", now), Ok(()));
+        assert_eq!(limiter.acquire(job(2), "b@GMAIL.com", now), Ok(()));
+        assert_eq!(
+            limiter.acquire(job(3), "c@gmail.com", now),
+            Err(Duration::from_secs(1))
+        );
+        assert_eq!(limiter.acquire(job(4), "a@yahoo.com", now), Ok(()));
+    }
+
+    #[test]
+    fn test_the_global_budget_caps_all_domains() {
+        let now = Instant::now();
+        let limiter = limiter(2.0, 2, 10.0, 10, now);
+
+        assert_eq!(limiter.acquire(job(1), "a@gmail.com", now), Ok(()));
+        assert_eq!(limiter.acquire(job(2), "a@yahoo.com", now), Ok(()));
+        assert_eq!(
+            limiter.acquire(job(3), "a@proton.me", now),
+            Err(Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn test_waiting_jobs_keep_their_slot() {
+        let now = Instant::now();
+        let limiter = limiter(100.0, 100, 1.0, 1, now);
+        let at = |secs| now + Duration::from_secs(secs);
+
+        assert_eq!(limiter.acquire(job(1), "a@gmail.com", now), Ok(()));
+        assert_eq!(
+            limiter.acquire(job(2), "b@gmail.com", now),
+            Err(Duration::from_secs(1))
+        );
+        assert_eq!(
+            limiter.acquire(job(3), "c@gmail.com", now),
+            Err(Duration::from_secs(2))
+        );
+
+        assert_eq!(limiter.acquire(job(2), "b@gmail.com", at(1)), Ok(()));
+        assert_eq!(
+            limiter.acquire(job(3), "c@gmail.com", at(1)),
+            Err(Duration::from_secs(1))
+        );
+        assert_eq!(
+            limiter.acquire(job(4), "d@gmail.com", at(1)),
+            Err(Duration::from_secs(2))
+        );
+        assert_eq!(limiter.acquire(job(3), "c@gmail.com", at(2)), Ok(()));
+    }
+
+    #[test]
+    fn test_a_zero_rate_lifts_the_limit() {
+        let now = Instant::now();
+        let limiter = limiter(0.0, 0, 0.0, 0, now);
+
+        for n in 0..100 {
+            assert_eq!(limiter.acquire(job(n), "a@gmail.com", now), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::Context;
+use std::env;
+use tokio::time::Duration;
+
+/// How failed sends are retried: exponential backoff from `base_delay`,
+/// capped at `max_delay`, until `max_attempts` sends have failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: i32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn from_env() -> anyhow::Result<Self> {
+        Ok(Self {
+            max_attempts: env_number("EMAIL_MAX_ATTEMPTS", 8)?,
+            base_delay: Duration::from_secs(env_number("EMAIL_RETRY_BASE_SECS", 30)?),
+            max_delay: Duration::from_secs(env_number("EMAIL_RETRY_MAX_SECS", 3600)?),
+        })
+    }
+
+    /// Delay before the next try once `attempt_count` sends have failed, or
+    /// `None` when the email should be given up on.
+    pub fn next_delay(&self, attempt_count: i32) -> Option<Duration> {
+        self.next_delay_with_jitter(attempt_count, fastrand::f64())
+    }
+
+    /// `jitter` in `[0, 1)` spreads the retry over the upper half of the
+    /// backoff window, so emails failing together do not retry together.
+    fn next_delay_with_jitter(&self, attempt_count: i32, jitter: f64) -> Option<Duration> {
+        if attempt_count >= self.max_attempts {
+            return None;
+        }
+        let exponent = attempt_count.saturating_sub(1).clamp(0, 30) as u32;
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(exponent))
+            .min(self.max_delay);
+        Some(backoff.mul_f64(0.5 + jitter / 2.0))
+    }
+}
+
+pub fn env_number<T: std::str::FromStr>(key: &str, default: T) -> anyhow::Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    match env::var(key) {
+        Ok(value) => value
+            .parse()
+            .with_context(|| format!("{key} must be a number, got '{value}'")),
+        Err(_) => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(30),
+            max_delay: Duration::from_secs(300),
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = policy();
+        let without_jitter = |attempts| policy.next_delay_with_jitter(attempts, 0.999_999);
+
+        assert_eq!(without_jitter(1).map(|d| d.as_secs()), Some(29));
+        assert_eq!(without_jitter(2).map(|d| d.as_secs()), Some(59));
+        assert_eq!(without_jitter(3).map(|d| d.as_secs()), Some(119));
+        assert_eq!(without_jitter(4).map(|d| d.as_secs()), Some(239));
+        assert_eq!(
+            RetryPolicy {
+                max_attempts: 50,
+                ..policy.clone()
+            }
+            .next_delay_with_jitter(40, 0.999_999)
+            .map(|d| d.as_secs()),
+            Some(299)
+        );
+    }
+
+    #[test]
+    fn test_jitter_stays_in_the_upper_half_of_the_window() {
+        let policy = policy();
+
+        assert_eq!(
+            policy.next_delay_with_jitter(2, 0.0),
+            Some(Duration::from_secs(30))
+        );
+        for _ in 0..100 {
+            let delay = policy.next_delay(2).unwrap();
+            assert!(delay >= Duration::from_secs(30) && delay < Duration::from_secs(60));
+        }
+    }
+
+    #[test]
+    fn test_emails_are_given_up_after_the_last_attempt() {
+        let policy = policy();
+
+        assert!(policy.next_delay(4).is_some());
+        assert_eq!(policy.next_delay(5), None);
+        assert_eq!(policy.next_delay(6), None);
+    }
+}
@@ -0,0 +1,339 @@
+use anyhow::{Context, anyhow};
+use chrono::{DateTime, Utc};
+use minijinja::{Environment, UndefinedBehavior, context};
+use serde::Serialize;
+
+/// Locale every template has; requests for other locales fall back to it.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Each template comes in three parts per locale: `<name>.subject.txt`,
+/// `<name>.txt` and `<name>.html`. The text and HTML parts extend the shared
+/// `layout.txt` and `layout.html`.
+macro_rules! embed_templates {
+    ($locale:literal: [$($name:literal),* $(,)?]) => {
+        [$(
+            embed_templates!(@part $locale, $name, ".subject.txt"),
+            embed_templates!(@part $locale, $name, ".txt"),
+            embed_templates!(@part $locale, $name, ".html"),
+        )*]
+    };
+    (@part $locale:literal, $name:literal, $suffix:literal) => {
+        (
+            concat!($locale, "/", $name, $suffix),
+            include_str!(concat!("../templates/", $locale, "/", $name, $suffix)),
+        )
+    };
+}
+
+const SHARED: [(&str, &str); 3] = [
+    ("layout.txt", include_str!("../templates/layout.txt")),
+    ("layout.html", include_str!("../templates/layout.html")),
+    ("macros.html", include_str!("../templates/macros.html")),
+];
+
+const EN: [(&str, &str); 36] = embed_templates!("en": [
+    "account_deleted",
+    "account_deletion_scheduled",
+    "confirm_email_change",
+    "email_change_requested",
+    "new_sign_in",
+    "personal_access_token_created",
+    "reset_password",
+    "sign_ins_blocked",
+    "two_factor_disabled",
+    "two_factor_enabled",
+    "verify_email",
+    "weekly_digest",
+]);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub text: String,
+    /// Absent for emails queued as plain text before templates existed.
+    pub html: Option<String>,
+    /// One-click unsubscribe URL for the `List-Unsubscribe` header.
+    pub list_unsubscribe: Option<String>,
+    /// What the email is for, as queued by the backend. Resends of the same
+    /// email get the same `Message-ID` from it, so mailbox providers drop
+    /// the copy when a send that looked failed went through.
+    pub idempotency_key: Option<String>,
+}
+
+pub struct EmailTemplates {
+    env: Environment<'static>,
+}
+
+impl EmailTemplates {
+    /// The templates built into the worker.
+    pub fn embedded() -> anyhow::Result<Self> {
+        Self::from_sources(SHARED.into_iter().chain(EN))
+    }
+
+    fn from_sources(
+        sources: impl IntoIterator<Item = (&'static str, &'static str)>,
+    ) -> anyhow::Result<Self> {
+        let mut env = Environment::new();
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+        env.set_trim_blocks(true);
+        env.set_lstrip_blocks(true);
+        env.add_filter("datetime", format_datetime);
+        for (name, source) in sources {
+            env.add_template(name, source)
+                .with_context(|| format!("Invalid email template '{name}'"))?;
+        }
+        Ok(Self { env })
+    }
+
+    /// Renders `template` in the closest locale available. Unknown templates
+    /// and missing variables are errors. The layouts link `unsubscribe_link`
+    /// in their footer when there is one.
+    pub fn render(
+        &self,
+        template: &str,
+        locale: Option<&str>,
+        variables: &impl Serialize,
+        unsubscribe_link: Option<&str>,
+    ) -> anyhow::Result<RenderedEmail> {
+        let locale = self.resolve_locale(template, locale.unwrap_or(DEFAULT_LOCALE))?;
+        let variables = minijinja::Value::from_serialize(variables);
+        let part = |suffix: &str| -> anyhow::Result<String> {
+            let name = format!("{locale}/{template}{suffix}");
+            self.env
+                .get_template(&name)
+                .and_then(|t| t.render(context! { unsubscribe_link, ..variables.clone() }))
+                .map_err(|e| anyhow!("Failed to render '{name}': {e:#}"))
+        };
+
+        let subject = part(".subject.txt")?.trim().to_string();
+        let text = part(".txt")?;
+        let html_name = format!("{locale}/{template}.html");
+        let html = self
+            .env
+            .get_template(&html_name)
+            .and_then(|t| {
+                t.render(context! { subject, locale, unsubscribe_link, ..variables.clone() })
+            })
+            .map_err(|e| anyhow!("Failed to render '{html_name}': {e:#}"))?;
+
+        Ok(RenderedEmail {
+            subject,
+            text,
+            html: Some(html),
+            list_unsubscribe: None,
+            idempotency_key: None,
+        })
+    }
+
+    /// Tries `pt-BR`, then `pt`, then the default locale.
+    fn resolve_locale(&self, template: &str, requested: &str) -> anyhow::Result<String> {
+        let requested = requested.trim().to_lowercase().replace('_', "-");
+        let language = requested.split('-').next().unwrap_or_default().to_string();
+        [requested, language, DEFAULT_LOCALE.to_string()]
+            .into_iter()
+            .find(|locale| {
+                self.env
+                    .get_template(&format!("{locale}/{template}.txt"))
+                    .is_ok()
+            })
+            .ok_or_else(|| anyhow!("Unknown email template '{template}'"))
+    }
+}
+
+/// Formats RFC 3339 timestamps the way every email shows them.
+fn format_datetime(value: String) -> Result<String, minijinja::Error> {
+    let datetime = DateTime::parse_from_rfc3339(&value).map_err(|e| {
+        minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            format!("'{value}' is not an RFC 3339 timestamp: {e}"),
+        )
+    })?;
+    Ok(datetime
+        .with_timezone(&Utc)
+        .format("%Y-%m-%d %H:%M UTC")
+        .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn templates() -> EmailTemplates {
+        EmailTemplates::embedded().unwrap()
+    }
+
+    fn position(company: &str, status: &str, applied_on: &str, last: &str) -> Value {
+        json!({
+            "company": company,
+            "role_title": "Backend Engineer",
+            "status": status,
+            "applied_on": applied_on,
+            "last_activity_on": last,
+        })
+    }
+
+    fn full_digest() -> Value {
+        json!({
+            "week_start": "2026-01-05",
+            "week_end": "2026-01-11",
+            "dashboard_link": "https://seeker.example/dashboard",
+            "new_applications": [position("Rust Corp", "CvSent", "2026-01-06", "2026-01-06")],
+            "status_changes": [{
+                "company": "Ferris Inc",
+                "role_title": "Backend Engineer",
+                "from_status": "PhoneScreenScheduled",
+                "to_status": "TechnicalInterview",
+                "changed_on": "2026-01-09",
+            }],
+            "upcoming_interviews": [
+                position("Crab Labs", "PhoneScreenScheduled", "2025-12-15", "2026-01-02"),
+                position("Ferris Inc", "TechnicalInterview", "2025-12-20", "2026-01-09"),
+            ],
+            "stale_applications": [position("Quiet Ltd", "CvSent", "2025-11-01", "2025-11-01")],
+            "pending_follow_ups": [position("Crab Labs", "PhoneScreenScheduled", "2025-12-15", "2026-01-02")],
+        })
+    }
+
+    #[test]
+    fn test_render_verify_email() {
+        let email = templates()
+            .render(
+                "verify_email",
+                Some("en"),
+                &json!({ "link": "https://seeker.example/auth/verify-email?token=abc" }),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(email.subject, "Verify your email");
+        insta::assert_snapshot!("verify_email_text", email.text);
+        insta::assert_snapshot!("verify_email_html", email.html.unwrap());
+    }
+
+    #[test]
+    fn test_render_new_sign_in() {
+        let email = templates()
+            .render(
+                "new_sign_in",
+                None,
+                &json!({
+                    "device": "Firefox on Linux",
+                    "ip": null,
+                    "signed_in_at": "2026-03-01T09:30:12.345Z",
+                }),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(email.subject, "New sign-in to your account");
+        insta::assert_snapshot!("new_sign_in_text", email.text);
+    }
+
+    #[test]
+    fn test_render_full_weekly_digest() {
+        let email = templates()
+            .render(
+                "weekly_digest",
+                Some("en"),
+                &full_digest(),
+                Some("https://seeker.example/notifications/unsubscribe?token=abc"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            email.subject,
+            "Your weekly job search digest (2026-01-05 - 2026-01-11)"
+        );
+        insta::assert_snapshot!("weekly_digest_full_text", email.text);
+        insta::assert_snapshot!("weekly_digest_full_html", email.html.unwrap());
+    }
+
+    #[test]
+    fn test_render_empty_weekly_digest() {
+        let email = templates()
+            .render(
+                "weekly_digest",
+                Some("en"),
+                &json!({
+                    "week_start": "2026-01-05",
+                    "week_end": "2026-01-11",
+                    "dashboard_link": "https://seeker.example/dashboard",
+                    "new_applications": [],
+                    "status_changes": [],
+                    "upcoming_interviews": [],
+                    "stale_applications": [],
+                    "pending_follow_ups": [],
+                }),
+                None,
+            )
+            .unwrap();
+
+        insta::assert_snapshot!("weekly_digest_empty_text", email.text);
+    }
+
+    #[test]
+    fn test_html_part_escapes_variables() {
+        let email = templates()
+            .render(
+                "personal_access_token_created",
+                None,
+                &json!({ "name": "<script>alert(1)</script>", "scope": "read" }),
+                None,
+            )
+            .unwrap();
+
+        let html = email.html.unwrap();
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;&#x2f;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(email.text.contains("\"<script>alert(1)</script>\""));
+    }
+
+    #[test]
+    fn test_locale_falls_back_to_language_then_default() {
+        let spanish = [
+            ("es/verify_email.subject.txt", "Verifica tu email"),
+            ("es/verify_email.txt", "Hola, {{ link }}"),
+            ("es/verify_email.html", "<p>Hola, {{ link }}</p>"),
+        ];
+        let templates =
+            EmailTemplates::from_sources(SHARED.into_iter().chain(EN).chain(spanish)).unwrap();
+        let variables = json!({ "link": "https://seeker.example" });
+        let subject = |locale| {
+            templates
+                .render("verify_email", Some(locale), &variables, None)
+                .unwrap()
+                .subject
+        };
+
+        assert_eq!(subject("es"), "Verifica tu email");
+        assert_eq!(subject("es_MX"), "Verifica tu email");
+        assert_eq!(subject("fr-FR"), "Verify your email");
+        assert_eq!(
+            templates
+                .render("reset_password", Some("es"), &variables, None)
+                .unwrap()
+                .subject,
+            "Reset your password"
+        );
+    }
+
+    #[test]
+    fn test_unknown_templates_and_missing_variables_fail() {
+        let templates = templates();
+
+        let unknown = templates.render("welcome_aboard", None, &json!({}), None);
+        let missing = templates.render("verify_email", None, &json!({}), None);
+        let bad_date =
+            templates.render("sign_ins_blocked", None, &json!({ "until": "soon" }), None);
+
+        assert!(
+            unknown
+                .unwrap_err()
+                .to_string()
+                .contains("Unknown email template 'welcome_aboard'")
+        );
+        assert!(missing.unwrap_err().to_string().contains("en/verify_email"));
+        assert!(bad_date.unwrap_err().to_string().contains("RFC 3339"));
+    }
+}
@@ -0,0 +1,124 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+/// Category of emails nobody can opt out of.
+pub const SECURITY: &str = "security";
+
+/// Whether users who never changed their preferences get `category` emails.
+/// Mirrors `EmailCategory::enabled_by_default` in the backend.
+pub fn enabled_by_default(category: &str) -> bool {
+    category != "marketing"
+}
+
+/// Builds the signed one-click unsubscribe links (RFC 8058) of opt-out
+/// emails. The backend verifies them with the same secret.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsubscribeLinks {
+    secret: String,
+    api_url: String,
+    frontend_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsubscribeLink {
+    /// `List-Unsubscribe` target, which mailbox providers POST to.
+    pub one_click_url: String,
+    /// Confirmation page of the frontend, linked from the footer.
+    pub page_url: String,
+}
+
+impl UnsubscribeLinks {
+    /// Reads `UNSUBSCRIBE_SECRET`, `API_URL` and `FRONTEND_URL` through
+    /// `lookup`. `None` without a secret.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let secret = lookup("UNSUBSCRIBE_SECRET").filter(|secret| !secret.is_empty())?;
+        let url = |key, default: &str| {
+            lookup(key)
+                .unwrap_or_else(|| default.to_string())
+                .trim_end_matches('/')
+                .to_string()
+        };
+        Some(Self {
+            secret,
+            api_url: url("API_URL", "http://localhost:3000"),
+            frontend_url: url("FRONTEND_URL", "http://localhost:3001"),
+        })
+    }
+
+    pub fn for_recipient(&self, user_id: Uuid, category: &str) -> UnsubscribeLink {
+        let token = sign(&self.secret, user_id, category);
+        UnsubscribeLink {
+            one_click_url: format!(
+                "{}/account/notifications/unsubscribe?token={token}",
+                self.api_url
+            ),
+            page_url: format!(
+                "{}/notifications/unsubscribe?token={token}",
+                self.frontend_url
+            ),
+        }
+    }
+}
+
+/// `<user_id>.<category>.<base64url HMAC-SHA256 of the first two parts>`, the
+/// format `UnsubscribeToken` of the backend's account context verifies.
+fn sign(secret: &str, user_id: Uuid, category: &str) -> String {
+    let message = format!("{user_id}.{category}");
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(message.as_bytes());
+    let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+    format!("{message}.{signature}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn links(vars: &[(&str, &str)]) -> Option<UnsubscribeLinks> {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        UnsubscribeLinks::from_lookup(|key| vars.get(key).map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn test_tokens_match_the_ones_the_backend_verifies() {
+        // Same vector as `src/account/domain/entities/unsubscribe_token.rs`.
+        let user_id = Uuid::parse_str("6f1c2f2e-8a4b-4c55-9d1e-3a7b9c0d1e2f").unwrap();
+
+        assert_eq!(
+            sign("test-unsubscribe-secret", user_id, "digests"),
+            "6f1c2f2e-8a4b-4c55-9d1e-3a7b9c0d1e2f.digests.selhZ2sIU1V5JMNkxZ6RhHTKA8EWPuwQtTrmXvIg2Cs"
+        );
+    }
+
+    #[test]
+    fn test_links_point_at_the_api_and_the_frontend() {
+        let links = links(&[
+            ("UNSUBSCRIBE_SECRET", "test-unsubscribe-secret"),
+            ("API_URL", "https://api.seeker.example/"),
+            ("FRONTEND_URL", "https://seeker.example"),
+        ])
+        .unwrap();
+        let user_id = Uuid::parse_str("6f1c2f2e-8a4b-4c55-9d1e-3a7b9c0d1e2f").unwrap();
+
+        let link = links.for_recipient(user_id, "digests");
+
+        assert_eq!(
+            link.one_click_url,
+            "https://api.seeker.example/account/notifications/unsubscribe?token=6f1c2f2e-8a4b-4c55-9d1e-3a7b9c0d1e2f.digests.selhZ2sIU1V5JMNkxZ6RhHTKA8EWPuwQtTrmXvIg2Cs"
+        );
+        assert!(
+            link.page_url
+                .starts_with("https://seeker.example/notifications/unsubscribe?token=6f1c2f2e-")
+        );
+    }
+
+    #[test]
+    fn test_no_links_without_a_secret() {
+        assert_eq!(links(&[("API_URL", "https://api.seeker.example")]), None);
+        assert_eq!(links(&[("UNSUBSCRIBE_SECRET", "")]), None);
+    }
+}